@@ -6,6 +6,7 @@ use std::ffi::OsStr;
 use std::fs;
 use std::lazy::SyncLazy;
 use std::path::{Path, PathBuf};
+use std::process;
 use walkdir::WalkDir;
 
 use crate::clippy_project_root;
@@ -24,16 +25,22 @@ static CLIPPY_BUILD_TIME: SyncLazy<Option<std::time::SystemTime>> = SyncLazy::ne
     fs::metadata(path).ok()?.modified().ok()
 });
 
+/// Updates the reference files of the tests whose name contains `filter`, or of all tests if
+/// `filter` is `None`. With `check`, the reference files are only compared to the test output and
+/// the process exits with an error if any of them differ.
+///
 /// # Panics
 ///
 /// Panics if the path to a test file is broken
-pub fn bless(ignore_timestamp: bool) {
+pub fn bless(ignore_timestamp: bool, filter: Option<&str>, check: bool) {
     let test_suite_dirs = [
         clippy_project_root().join("tests").join("ui"),
         clippy_project_root().join("tests").join("ui-internal"),
+        clippy_project_root().join("tests").join("ui-json"),
         clippy_project_root().join("tests").join("ui-toml"),
         clippy_project_root().join("tests").join("ui-cargo"),
     ];
+    let mut outdated = false;
     for test_suite_dir in &test_suite_dirs {
         WalkDir::new(test_suite_dir)
             .into_iter()
@@ -41,37 +48,71 @@ pub fn bless(ignore_timestamp: bool) {
             .filter(|f| f.path().extension() == Some(OsStr::new("rs")))
             .for_each(|f| {
                 let test_name = f.path().strip_prefix(test_suite_dir).unwrap();
-                for &ext in &["stdout", "stderr", "fixed"] {
-                    let test_name_ext = format!("stage-id.{}", ext);
-                    update_reference_file(
-                        f.path().with_extension(ext),
-                        test_name.with_extension(test_name_ext),
-                        ignore_timestamp,
-                    );
+                if filter.map_or(false, |filter| !test_name.to_string_lossy().contains(filter)) {
+                    return;
+                }
+                // A test with revisions has separate reference files for each revision, e.g.
+                // `foo.edition2018.stderr`
+                let revisions = test_revisions(f.path());
+                let suffixes = if revisions.is_empty() {
+                    vec![String::new()]
+                } else {
+                    revisions.iter().map(|revision| format!("{}.", revision)).collect()
+                };
+                for suffix in &suffixes {
+                    for &ext in &["stdout", "stderr", "fixed"] {
+                        let test_name_ext = format!("{}stage-id.{}", suffix, ext);
+                        outdated |= update_reference_file(
+                            f.path().with_extension(format!("{}{}", suffix, ext)),
+                            test_name.with_extension(test_name_ext),
+                            ignore_timestamp,
+                            check,
+                        );
+                    }
                 }
             });
     }
+
+    if check && outdated {
+        println!("Some reference files differ from the test output. Please run `cargo dev bless` to update them.");
+        process::exit(1);
+    }
 }
 
-fn update_reference_file(reference_file_path: PathBuf, test_name: PathBuf, ignore_timestamp: bool) {
+/// Updates the reference file with the output of the last test run, if it changed. Returns `true`
+/// if the reference file was (or, with `check`, would have been) changed.
+fn update_reference_file(
+    reference_file_path: PathBuf,
+    test_name: PathBuf,
+    ignore_timestamp: bool,
+    check: bool,
+) -> bool {
     let test_output_path = build_dir().join(test_name);
     let relative_reference_file_path = reference_file_path.strip_prefix(clippy_project_root()).unwrap();
 
     // If compiletest did not write any changes during the test run,
     // we don't have to update anything
     if !test_output_path.exists() {
-        return;
+        return false;
     }
 
     // If the test output was not updated since the last clippy build, it may be outdated
     if !ignore_timestamp && !updated_since_clippy_build(&test_output_path).unwrap_or(true) {
-        return;
+        return false;
     }
 
     let test_output_file = fs::read(&test_output_path).expect("Unable to read test output file");
     let reference_file = fs::read(&reference_file_path).unwrap_or_default();
 
-    if test_output_file != reference_file {
+    if test_output_file == reference_file {
+        false
+    } else if check {
+        println!(
+            "{} differs from the test output",
+            relative_reference_file_path.display()
+        );
+        true
+    } else {
         // If a test run caused an output file to change, update the reference file
         println!("updating {}", &relative_reference_file_path.display());
         fs::copy(test_output_path, &reference_file_path).expect("Could not update reference file");
@@ -84,9 +125,22 @@ fn update_reference_file(reference_file_path: PathBuf, test_name: PathBuf, ignor
             println!("removing {}", &relative_reference_file_path.display());
             fs::remove_file(reference_file_path).expect("Could not remove reference file");
         }
+        true
     }
 }
 
+/// Returns the revisions declared with `// revisions: a b` in the header of the test file.
+fn test_revisions(test_path: &Path) -> Vec<String> {
+    let content = fs::read_to_string(test_path).unwrap_or_default();
+    content
+        .lines()
+        .take_while(|line| line.is_empty() || line.starts_with("//") || line.starts_with("#!"))
+        .filter_map(|line| line.strip_prefix("// revisions:"))
+        .flat_map(str::split_whitespace)
+        .map(ToString::to_string)
+        .collect()
+}
+
 fn updated_since_clippy_build(path: &Path) -> Option<bool> {
     let clippy_build_time = (*CLIPPY_BUILD_TIME)?;
     let modified = fs::metadata(path).ok()?.modified().ok()?;
@@ -0,0 +1,120 @@
+//! `dogfood` builds Clippy and runs it on Clippy's own crates, with the same lint levels as the
+//! dogfood test. With `fix`, the suggestions are applied to the code instead.
+
+use crate::clippy_project_root;
+use std::env;
+use std::path::PathBuf;
+use std::process::{self, Command};
+
+/// The crates Clippy is run on, as directories relative to the project root. `clippy` is the
+/// root crate, which contains the driver and `cargo-clippy`.
+pub const COMPONENTS: &[&str] = &[
+    "clippy",
+    "clippy_lints",
+    "clippy_utils",
+    "clippy_dev",
+    "rustc_tools_util",
+];
+
+/// The lint groups which have to pass on Clippy's own code, also denied by `tests/dogfood.rs`.
+/// `disallowed_method` enforces the `disallowed-methods` from `clippy.toml`.
+pub const LINTS: &[&str] = &[
+    "clippy::all",
+    "clippy::pedantic",
+    "clippy::disallowed_method",
+    "clippy::internal",
+];
+
+#[derive(Debug, Default)]
+pub struct DogfoodOptions<'a> {
+    /// The features to enable on every component, instead of `--all-features`
+    pub features: Option<&'a str>,
+    /// Applies the suggestions instead of failing on the first warning
+    pub fix: bool,
+    /// Allows `fix` to change files in a dirty working directory
+    pub allow_dirty: bool,
+}
+
+/// Runs Clippy on the given components, or on all of them if `components` is empty. Exits with an
+/// error if Clippy fails on any component.
+///
+/// # Panics
+///
+/// Panics if `cargo` can't be run
+pub fn run(components: &[&str], options: &DogfoodOptions<'_>) {
+    for component in components {
+        if !COMPONENTS.contains(component) {
+            eprintln!(
+                "error: unknown component `{}`, expected one of: {}",
+                component,
+                COMPONENTS.join(", ")
+            );
+            process::exit(1);
+        }
+    }
+
+    // The internal lints are only available with the `internal-lints` feature
+    let build_status = Command::new("cargo")
+        .current_dir(clippy_project_root())
+        .args(&["build", "--features", "internal-lints"])
+        .status()
+        .expect("failed to run cargo");
+    if !build_status.success() {
+        eprintln!("error: building Clippy failed");
+        process::exit(1);
+    }
+
+    let components = if components.is_empty() { COMPONENTS } else { components };
+    let mut failed = Vec::new();
+    for component in components {
+        println!("dogfooding {}", component);
+        if !clippy_command(component, options)
+            .status()
+            .expect("failed to run cargo-clippy")
+            .success()
+        {
+            failed.push(*component);
+        }
+    }
+
+    if !failed.is_empty() {
+        eprintln!("error: Clippy failed on: {}", failed.join(", "));
+        process::exit(1);
+    }
+}
+
+fn clippy_command(component: &str, options: &DogfoodOptions<'_>) -> Command {
+    let root = clippy_project_root();
+    let dir = if component == "clippy" {
+        root.clone()
+    } else {
+        root.join(component)
+    };
+
+    let target_dir = env::var_os("CARGO_TARGET_DIR").map_or_else(|| root.join("target"), PathBuf::from);
+
+    let mut command = Command::new(target_dir.join("debug").join("cargo-clippy"));
+    command
+        .current_dir(dir)
+        .env("CLIPPY_DOGFOOD", "1")
+        .env("CARGO_INCREMENTAL", "0")
+        .arg("clippy")
+        .arg("--all-targets");
+    match options.features {
+        Some(features) => command.args(&["--features", features]),
+        None => command.arg("--all-features"),
+    };
+    if options.fix {
+        command.arg(if options.allow_dirty { "--fix-dirty" } else { "--fix" });
+    }
+
+    command.arg("--");
+    // Denied lints would stop `cargo fix` before it applies anything
+    let level = if options.fix { "-W" } else { "-D" };
+    for lint in LINTS {
+        command.args(&[level, lint]);
+    }
+    // disable debuginfo to generate less data in the target dir
+    command.arg("-Cdebuginfo=0");
+    command
+}
@@ -0,0 +1,204 @@
+//! `explain` prints the documentation of a lint to the terminal.
+//!
+//! Like `util/export.py`, which generates the lint list of the website, it reads the doc comments
+//! of the lint declarations and the configuration options from `clippy_lints/src/utils/conf.rs`.
+
+use crate::{clippy_project_root, gather_all, Lint};
+use regex::Regex;
+use std::fs;
+use std::lazy::SyncLazy;
+use std::path::PathBuf;
+
+static CONF_RE: SyncLazy<Regex> = SyncLazy::new(|| {
+    Regex::new(
+        r#"(?mx)
+    ^\s*///\ Lint:\ (?P<lints>[\w,\s]+)\.\ (?P<doc>.*)\n
+    \s*\((?P<name>[^:]+):\s*(?P<ty>[^=]+?)\s*=\s*(?P<default>(?s:.*?))\),$
+"#,
+    )
+    .unwrap()
+});
+
+/// A `clippy.toml` option affecting a lint.
+#[derive(Debug, PartialEq)]
+pub struct ConfOption {
+    /// The key of the option, e.g. `too-many-arguments-threshold`
+    pub name: String,
+    pub ty: String,
+    pub default: String,
+    pub doc: String,
+}
+
+/// Prints the documentation, default level and configuration options of a lint.
+///
+/// # Panics
+///
+/// Panics if a source file of `clippy_lints` can't be read
+pub fn run(name: &str) {
+    let name = name.trim_start_matches("clippy::").replace('-', "_").to_lowercase();
+    let lint = match gather_all().find(|l| l.name == name) {
+        Some(lint) => lint,
+        None => {
+            eprintln!("error: unknown lint `{}`", name);
+            std::process::exit(1);
+        },
+    };
+
+    println!("clippy::{}", lint.name);
+    match &lint.deprecation {
+        Some(reason) => println!("deprecated: {}", reason),
+        None => println!("group: {}, default level: {}", lint.group, default_level(&lint.group)),
+    }
+    if let Some(version) = &lint.version {
+        println!("added in: {}", version);
+    }
+    println!();
+
+    let path = declaration_file(&lint);
+    let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+    for line in lint_docs(&contents, &lint.name) {
+        println!("{}", line);
+    }
+
+    let conf_path = clippy_project_root().join("clippy_lints/src/utils/conf.rs");
+    let conf = fs::read_to_string(&conf_path).unwrap_or_else(|e| panic!("Cannot read {}: {}", conf_path.display(), e));
+    let options = conf_options(&conf, &lint.name);
+    if !options.is_empty() {
+        println!();
+        println!("configuration options (`clippy.toml`):");
+        for option in options {
+            println!("  {}: {} = {}", option.name, option.ty, option.default);
+            println!("    {}", option.doc);
+        }
+    }
+}
+
+/// The level of the lints in a group if it isn't configured, the same as on the website.
+fn default_level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "deny",
+        "suspicious" | "style" | "complexity" | "perf" | "internal_warn" => "warn",
+        "internal" => "deny (internal)",
+        _ => "allow",
+    }
+}
+
+/// Returns the file declaring the lints of the module of `lint`.
+fn declaration_file(lint: &Lint) -> PathBuf {
+    let module_path = clippy_project_root()
+        .join("clippy_lints/src")
+        .join(lint.module.replace("::", "/"));
+    if module_path.join("mod.rs").exists() {
+        module_path.join("mod.rs")
+    } else {
+        module_path.with_extension("rs")
+    }
+}
+
+/// Returns the lines of the doc comment of the declaration of the lint `name`. Lines hidden from
+/// the code examples with `# ` are left out, like rustdoc does.
+#[must_use]
+pub fn lint_docs(contents: &str, name: &str) -> Vec<String> {
+    let decl = format!("pub {},", name.to_uppercase());
+    let mut docs = Vec::new();
+    let mut in_code = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with("declare_clippy_lint!") || line.starts_with("declare_deprecated_lint!") {
+            docs.clear();
+            in_code = false;
+        } else if line == decl {
+            return docs;
+        } else if let Some(doc) = line.strip_prefix("///") {
+            let doc = doc.strip_prefix(' ').unwrap_or(doc);
+            if doc.starts_with("```") {
+                in_code = !in_code;
+            } else if in_code && (doc == "#" || doc.starts_with("# ")) {
+                continue;
+            }
+            docs.push(doc.to_string());
+        }
+    }
+    Vec::new()
+}
+
+/// Returns the configuration options of `conf.rs` which affect the lint `name`.
+#[must_use]
+pub fn conf_options(conf: &str, name: &str) -> Vec<ConfOption> {
+    CONF_RE
+        .captures_iter(conf)
+        .filter(|m| m["lints"].split(',').any(|lint| lint.trim().eq_ignore_ascii_case(name)))
+        .map(|m| ConfOption {
+            name: m["name"].trim().replace('_', "-"),
+            ty: m["ty"].to_string(),
+            // multi-line defaults are put on one line
+            default: m["default"].split_whitespace().collect::<Vec<_>>().join(" "),
+            doc: m["doc"].to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_lint_docs() {
+    let contents = r#"
+declare_clippy_lint! {
+    /// **What it does:** Checks for foo.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 1;
+    /// foo(x);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub FOO,
+    style,
+    "foo"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for bar.
+    pub BAR,
+    pedantic,
+    "bar"
+}
+"#;
+    let expected = vec![
+        "**What it does:** Checks for foo.",
+        "",
+        "**Example:**",
+        "```rust",
+        "foo(x);",
+        "```",
+    ];
+    assert_eq!(expected, lint_docs(contents, "foo"));
+    assert_eq!(vec!["**What it does:** Checks for bar."], lint_docs(contents, "bar"));
+    assert!(lint_docs(contents, "baz").is_empty());
+}
+
+#[test]
+fn test_conf_options() {
+    let conf = r#"
+define_Conf! {
+    /// Lint: FOO, BAR. The maximum number of foos
+    (foo_threshold: u64 = 7),
+    /// Lint: BAR. Words to bar
+    (bar_words: Vec<String> = [
+        "foo",
+        "bar",
+    ].iter().map(ToString::to_string).collect()),
+}
+"#;
+    let expected = vec![ConfOption {
+        name: "foo-threshold".to_string(),
+        ty: "u64".to_string(),
+        default: "7".to_string(),
+        doc: "The maximum number of foos".to_string(),
+    }];
+    assert_eq!(expected, conf_options(conf, "foo"));
+    let bar = conf_options(conf, "bar");
+    assert_eq!(2, bar.len());
+    assert_eq!(
+        r#"[ "foo", "bar", ].iter().map(ToString::to_string).collect()"#,
+        bar[1].default
+    );
+    assert!(conf_options(conf, "baz").is_empty());
+}
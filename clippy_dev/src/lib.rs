@@ -13,7 +13,10 @@ use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 pub mod bless;
+pub mod dogfood;
+pub mod explain;
 pub mod fmt;
+pub mod mutants;
 pub mod new_lint;
 pub mod serve;
 pub mod setup;
@@ -25,6 +28,7 @@ static DEC_CLIPPY_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
         r#"(?x)
     declare_clippy_lint!\s*[\{(]
     (?:\s+///.*)*
+    (?:\s+\#\[clippy::version\s*=\s*"(?P<version>[^"]*)"\])?
     \s+pub\s+(?P<name>[A-Z_][A-Z_0-9]*)\s*,\s*
     (?P<cat>[a-z_]+)\s*,\s*
     "(?P<desc>(?:[^"\\]+|\\(?s).(?-s))*)"\s*[})]
@@ -44,6 +48,16 @@ static DEC_DEPRECATED_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
     )
     .unwrap()
 });
+static DEC_LINT_PASS_RE: SyncLazy<Regex> = SyncLazy::new(|| {
+    Regex::new(
+        r#"(?x)
+    (?:declare_lint_pass|impl_lint_pass)!\s*\(
+    (?:\s*///.*)*
+    \s*(?P<name>[A-Za-z_][A-Za-z_0-9]*)\s*=>
+"#,
+    )
+    .unwrap()
+});
 static NL_ESCAPE_RE: SyncLazy<Regex> = SyncLazy::new(|| Regex::new(r#"\\\n\s*"#).unwrap());
 
 pub static DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
@@ -56,6 +70,9 @@ pub struct Lint {
     pub desc: String,
     pub deprecation: Option<String>,
     pub module: String,
+    /// The value of the `#[clippy::version]` attribute, `None` for deprecated lints and lints
+    /// without the attribute
+    pub version: Option<String>,
 }
 
 impl Lint {
@@ -67,6 +84,7 @@ impl Lint {
             desc: NL_ESCAPE_RE.replace(&desc.replace("\\\"", "\""), "").to_string(),
             deprecation: deprecation.map(ToString::to_string),
             module: module.to_string(),
+            version: None,
         }
     }
 
@@ -99,6 +117,23 @@ impl Lint {
     }
 }
 
+/// Lint pass data parsed from the Clippy source code.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LintPass {
+    pub name: String,
+    pub module: String,
+}
+
+impl LintPass {
+    #[must_use]
+    pub fn new(name: &str, module: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            module: module.to_string(),
+        }
+    }
+}
+
 /// Generates the Vec items for `register_lint_group` calls in `clippy_lints/src/lib.rs`.
 #[must_use]
 pub fn gen_lint_group_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
@@ -148,6 +183,17 @@ pub fn gen_deprecated<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String>
         .collect::<Vec<String>>()
 }
 
+/// Generates the `match` arms of `lint_pass_lints` in `clippy_lints/src/lib.rs`.
+///
+/// Each arm maps the name of a lint pass, as returned by `LintPass::name`, to its lints.
+#[must_use]
+pub fn gen_lint_pass_list<'a>(passes: impl Iterator<Item = &'a LintPass>) -> Vec<String> {
+    passes
+        .map(|p| format!("        \"{}\" => {}::{}::get_lints(),", p.name, p.module, p.name))
+        .sorted()
+        .collect::<Vec<String>>()
+}
+
 #[must_use]
 pub fn gen_register_lint_list<'a>(
     internal_lints: impl Iterator<Item = &'a Lint>,
@@ -180,8 +226,24 @@ pub fn gather_all() -> impl Iterator<Item = Lint> {
     lint_files().flat_map(|f| gather_from_file(&f))
 }
 
+/// Gathers all files in `src/clippy_lints` and gathers all lint passes inside
+pub fn gather_all_passes() -> impl Iterator<Item = LintPass> {
+    lint_files().flat_map(|f| gather_passes_from_file(&f))
+}
+
 fn gather_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = Lint> {
     let content = fs::read_to_string(dir_entry.path()).unwrap();
+    parse_contents(&content, &module_of(dir_entry))
+}
+
+fn gather_passes_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = LintPass> {
+    let content = fs::read_to_string(dir_entry.path()).unwrap();
+    parse_passes(&content, &module_of(dir_entry))
+}
+
+/// Returns the module path of a file in `clippy_lints/src`, e.g. `methods` for
+/// `clippy_lints/src/methods/mod.rs`
+fn module_of(dir_entry: &walkdir::DirEntry) -> String {
     let path = dir_entry.path();
     let filename = path.file_stem().unwrap();
     let path_buf = path.with_file_name(filename);
@@ -194,19 +256,18 @@ fn gather_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = Lint>
         rel_path = rel_path.parent().unwrap();
     }
 
-    let module = rel_path
+    rel_path
         .components()
         .map(|c| c.as_os_str().to_str().unwrap())
         .collect::<Vec<_>>()
-        .join("::");
-
-    parse_contents(&content, &module)
+        .join("::")
 }
 
 fn parse_contents(content: &str, module: &str) -> impl Iterator<Item = Lint> {
-    let lints = DEC_CLIPPY_LINT_RE
-        .captures_iter(content)
-        .map(|m| Lint::new(&m["name"], &m["cat"], &m["desc"], None, module));
+    let lints = DEC_CLIPPY_LINT_RE.captures_iter(content).map(|m| Lint {
+        version: m.name("version").map(|version| version.as_str().to_string()),
+        ..Lint::new(&m["name"], &m["cat"], &m["desc"], None, module)
+    });
     let deprecated = DEC_DEPRECATED_LINT_RE
         .captures_iter(content)
         .map(|m| Lint::new(&m["name"], "Deprecated", &m["desc"], Some(&m["desc"]), module));
@@ -214,6 +275,14 @@ fn parse_contents(content: &str, module: &str) -> impl Iterator<Item = Lint> {
     lints.chain(deprecated).collect::<Vec<Lint>>().into_iter()
 }
 
+fn parse_passes(content: &str, module: &str) -> impl Iterator<Item = LintPass> {
+    DEC_LINT_PASS_RE
+        .captures_iter(content)
+        .map(|m| LintPass::new(&m["name"], module))
+        .collect::<Vec<LintPass>>()
+        .into_iter()
+}
+
 /// Collects all .rs files in the `clippy_lints/src` directory
 fn lint_files() -> impl Iterator<Item = walkdir::DirEntry> {
     // We use `WalkDir` instead of `fs::read_dir` here in order to recurse into subdirectories.
@@ -379,6 +448,14 @@ declare_clippy_lint!{
     "single line"
 }
 
+declare_clippy_lint! {
+    /// some doc comment
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_MAP,
+    style,
+    "with version"
+}
+
 /// some doc comment
 declare_deprecated_lint! {
     pub SHOULD_ASSERT_EQ,
@@ -392,6 +469,10 @@ declare_deprecated_lint! {
     let expected = vec![
         Lint::new("ptr_arg", "style", "really long text", None, "module_name"),
         Lint::new("doc_markdown", "pedantic", "single line", None, "module_name"),
+        Lint {
+            version: Some("1.55.0".to_string()),
+            ..Lint::new("manual_map", "style", "with version", None, "module_name")
+        },
         Lint::new(
             "should_assert_eq",
             "Deprecated",
@@ -403,6 +484,33 @@ declare_deprecated_lint! {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn test_parse_passes() {
+    let result: Vec<LintPass> = parse_passes(
+        r#"
+declare_lint_pass!(PtrArg => [PTR_ARG]);
+
+impl_lint_pass!(DocMarkdown => [
+    DOC_MARKDOWN,
+]);
+
+declare_lint_pass!(
+    /// some doc comment
+    Documented => [DOCUMENTED]
+);
+    "#,
+        "module_name",
+    )
+    .collect();
+
+    let expected = vec![
+        LintPass::new("PtrArg", "module_name"),
+        LintPass::new("DocMarkdown", "module_name"),
+        LintPass::new("Documented", "module_name"),
+    ];
+    assert_eq!(expected, result);
+}
+
 #[test]
 fn test_replace_region() {
     let text = "\nabc\n123\n789\ndef\nghi";
@@ -487,8 +595,8 @@ fn test_gen_changelog_lint_list() {
         Lint::new("should_assert_eq2", "group2", "abc", None, "module_name"),
     ];
     let expected = vec![
-        format!("[`should_assert_eq`]: {}#should_assert_eq", DOCS_LINK.to_string()),
-        format!("[`should_assert_eq2`]: {}#should_assert_eq2", DOCS_LINK.to_string()),
+        format!("[`should_assert_eq`]: {}#should_assert_eq", DOCS_LINK),
+        format!("[`should_assert_eq2`]: {}#should_assert_eq2", DOCS_LINK),
     ];
     assert_eq!(expected, gen_changelog_lint_list(lints.iter()));
 }
@@ -544,6 +652,19 @@ fn test_gen_modules_list() {
     assert_eq!(expected, gen_modules_list(lints.iter()));
 }
 
+#[test]
+fn test_gen_lint_pass_list() {
+    let passes = vec![
+        LintPass::new("SecondPass", "module_name"),
+        LintPass::new("FirstPass", "another_module"),
+    ];
+    let expected = vec![
+        "        \"FirstPass\" => another_module::FirstPass::get_lints(),".to_string(),
+        "        \"SecondPass\" => module_name::SecondPass::get_lints(),".to_string(),
+    ];
+    assert_eq!(expected, gen_lint_pass_list(passes.iter()));
+}
+
 #[test]
 fn test_gen_lint_group_list() {
     let lints = vec![
@@ -3,13 +3,38 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
-use clippy_dev::{bless, fmt, new_lint, serve, setup, stderr_length_check, update_lints};
+use clippy_dev::{bless, dogfood, explain, fmt, mutants, new_lint, serve, setup, stderr_length_check, update_lints};
 fn main() {
     let matches = get_clap_config();
 
     match matches.subcommand() {
         ("bless", Some(matches)) => {
-            bless::bless(matches.is_present("ignore-timestamp"));
+            bless::bless(
+                matches.is_present("ignore-timestamp"),
+                matches.value_of("test"),
+                matches.is_present("check"),
+            );
+        },
+        ("dogfood", Some(matches)) => {
+            let components: Vec<_> = matches
+                .values_of("component")
+                .map(Iterator::collect)
+                .unwrap_or_default();
+            dogfood::run(
+                &components,
+                &dogfood::DogfoodOptions {
+                    features: matches.value_of("features"),
+                    fix: matches.is_present("fix") || matches.is_present("allow-dirty"),
+                    allow_dirty: matches.is_present("allow-dirty"),
+                },
+            );
+        },
+        ("explain", Some(matches)) => {
+            explain::run(
+                matches
+                    .value_of("lint")
+                    .expect("this field is mandatory and therefore always valid"),
+            );
         },
         ("fmt", Some(matches)) => {
             fmt::run(matches.is_present("check"), matches.is_present("verbose"));
@@ -28,11 +53,16 @@ fn main() {
                 matches.value_of("pass"),
                 matches.value_of("name"),
                 matches.value_of("category"),
+                matches.value_of("config"),
             ) {
                 Ok(_) => update_lints::run(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {}", e),
             }
         },
+        ("mutants", Some(matches)) => {
+            let lints: Vec<_> = matches.values_of("lint").map(Iterator::collect).unwrap_or_default();
+            mutants::run(&lints, matches.is_present("list"));
+        },
         ("limit_stderr_length", _) => {
             stderr_length_check::check();
         },
@@ -55,7 +85,7 @@ fn main() {
         ("serve", Some(matches)) => {
             let port = matches.value_of("port").unwrap().parse().unwrap();
             let lint = matches.value_of("lint");
-            serve::run(port, lint);
+            serve::run(port, lint, matches.is_present("json"));
         },
         _ => {},
     }
@@ -71,6 +101,47 @@ fn get_clap_config<'a>() -> ArgMatches<'a> {
                     Arg::with_name("ignore-timestamp")
                         .long("ignore-timestamp")
                         .help("Include files updated before clippy was built"),
+                )
+                .arg(
+                    Arg::with_name("check")
+                        .long("check")
+                        .help("Exit with an error if any reference file would be changed, without changing it"),
+                )
+                .arg(Arg::with_name("test").help("Only bless the tests whose name contains this (optional)")),
+        )
+        .subcommand(
+            SubCommand::with_name("dogfood")
+                .about("Build Clippy and run it on its own crates")
+                .arg(
+                    Arg::with_name("features")
+                        .long("features")
+                        .help("Features to enable on the linted crates, instead of all features")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("fix")
+                        .long("fix")
+                        .help("Automatically apply the lint suggestions to the code"),
+                )
+                .arg(
+                    Arg::with_name("allow-dirty")
+                        .long("allow-dirty")
+                        .help("Like `--fix`, but also works if the working directory has uncommitted changes"),
+                )
+                .arg(
+                    Arg::with_name("component")
+                        .multiple(true)
+                        .possible_values(dogfood::COMPONENTS)
+                        .help("The crates to lint (defaults to all crates)"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("explain")
+                .about("Print the documentation, default level and configuration options of a lint")
+                .arg(
+                    Arg::with_name("lint")
+                        .help("The name of the lint, ex: needless_return or clippy::needless-return")
+                        .required(true),
                 ),
         )
         .subcommand(
@@ -150,6 +221,36 @@ fn get_clap_config<'a>() -> ArgMatches<'a> {
                             "internal_warn",
                         ])
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("config")
+                        .long("config")
+                        .help(
+                            "Add a configuration value for the lint to `clippy.toml`, \
+                             ex: my_threshold:u64 or my_threshold:u64=10",
+                        )
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("mutants")
+                .about("Check how well the UI tests of lints catch mutations of their implementation")
+                .long_about(
+                    "Applies small mutations (inverted comparisons, swapped boolean operators, removed guards) \
+                 to the `check*` functions of lints and runs the UI tests of the lint for every mutation. \
+                 Mutations that don't make any test fail are reported as survived.\n\n\
+                 This rebuilds Clippy for every mutation and takes a while, so it is best run periodically \
+                 or on a few lints at a time.",
+                )
+                .arg(
+                    Arg::with_name("list")
+                        .long("list")
+                        .help("Only list the mutations, without running any tests"),
+                )
+                .arg(
+                    Arg::with_name("lint")
+                        .multiple(true)
+                        .help("The lints to test (defaults to all lints)"),
                 ),
         )
         .subcommand(
@@ -218,7 +319,12 @@ fn get_clap_config<'a>() -> ArgMatches<'a> {
                         .default_value("8000")
                         .validator_os(serve::validate_port),
                 )
-                .arg(Arg::with_name("lint").help("Which lint's page to load initially (optional)")),
+                .arg(Arg::with_name("lint").help("Which lint's page to load initially (optional)"))
+                .arg(
+                    Arg::with_name("json")
+                        .long("json")
+                        .help("Generate the lint list with the metadata collector instead of `util/export.py`"),
+                ),
         )
         .get_matches()
 }
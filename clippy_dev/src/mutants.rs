@@ -0,0 +1,341 @@
+//! `mutants` measures how well the UI tests of a lint cover its implementation.
+//!
+//! It applies small mutations (inverted comparisons, swapped boolean operators, removed guards)
+//! to the `check*` functions of a lint and reruns the UI tests of that lint for every mutation. If
+//! the tests still pass, the mutation "survived", which means that the mutated logic isn't covered
+//! by any test.
+
+use crate::{clippy_project_root, gather_all, Lint};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use walkdir::WalkDir;
+
+/// Replacements applied to binary operators. The surrounding spaces make sure that only operators
+/// formatted by rustfmt are matched and e.g. generics or `->` are left alone.
+const OPERATOR_MUTATIONS: &[(&str, &str)] = &[
+    (" == ", " != "),
+    (" != ", " == "),
+    (" < ", " >= "),
+    (" > ", " <= "),
+    (" <= ", " > "),
+    (" >= ", " < "),
+    (" && ", " || "),
+    (" || ", " && "),
+];
+
+/// A single mutation of one line in a lint source file.
+#[derive(Debug, PartialEq)]
+pub struct Mutant {
+    /// The index of the mutated line, starting at 0
+    pub line: usize,
+    pub mutated_line: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Outcome {
+    /// The tests failed with the mutation applied
+    Caught,
+    /// The tests passed with the mutation applied
+    Survived,
+    /// Clippy doesn't build with the mutation applied
+    Unviable,
+}
+
+/// Restores the original contents of a mutated file, even if the test run panics.
+struct RestoreFile<'a> {
+    path: &'a Path,
+    contents: &'a str,
+}
+
+impl Drop for RestoreFile<'_> {
+    fn drop(&mut self) {
+        fs::write(self.path, self.contents)
+            .unwrap_or_else(|e| panic!("Could not restore {}: {}", self.path.display(), e));
+    }
+}
+
+/// Runs the mutation tests for the given lints, or for all lints if `lints` is empty. With
+/// `list_only` the mutants are only printed, but not tested.
+///
+/// # Panics
+///
+/// Panics if a lint source file can't be read or written
+pub fn run(lints: &[&str], list_only: bool) {
+    let all_lints: Vec<Lint> = Lint::usable_lints(&gather_all().collect::<Vec<_>>());
+    for name in lints {
+        if !all_lints.iter().any(|l| l.name == *name) {
+            eprintln!("error: unknown lint `{}`", name);
+            std::process::exit(1);
+        }
+    }
+
+    let mut survived_total = 0;
+    for lint in all_lints
+        .iter()
+        .filter(|l| lints.is_empty() || lints.contains(&l.name.as_str()))
+    {
+        let path = lint_source_file(lint);
+        let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+        let mutants = find_all(&contents);
+        let relative_path = path.strip_prefix(clippy_project_root()).unwrap();
+
+        if list_only {
+            for mutant in &mutants {
+                println!(
+                    "{}:{}: {}",
+                    relative_path.display(),
+                    mutant.line + 1,
+                    mutant.description
+                );
+            }
+            continue;
+        }
+
+        if !has_ui_tests(&lint.name) {
+            println!("{}: no UI tests found, skipping", lint.name);
+            continue;
+        }
+        // Without any mutation applied, the tests have to pass (which is what makes a mutant survive).
+        // Otherwise every mutant would count as caught.
+        if test_outcome(&lint.name) != Outcome::Survived {
+            println!("{}: tests fail without mutations, skipping", lint.name);
+            continue;
+        }
+
+        let mut survived = Vec::new();
+        let mut caught = 0;
+        let mut unviable = 0;
+        for mutant in &mutants {
+            match test_mutant(&path, &contents, mutant, &lint.name) {
+                Outcome::Caught => caught += 1,
+                Outcome::Unviable => unviable += 1,
+                Outcome::Survived => survived.push(mutant),
+            }
+        }
+
+        println!(
+            "{}: {} mutants, {} caught, {} survived, {} unviable",
+            lint.name,
+            mutants.len(),
+            caught,
+            survived.len(),
+            unviable
+        );
+        for mutant in &survived {
+            println!(
+                "    survived: {}:{}: {}",
+                relative_path.display(),
+                mutant.line + 1,
+                mutant.description
+            );
+        }
+        survived_total += survived.len();
+    }
+
+    if survived_total > 0 {
+        println!();
+        println!(
+            "{} mutants survived. Consider adding tests that cover them.",
+            survived_total
+        );
+    }
+}
+
+/// Returns the file that contains the implementation of the lint. Lints in modules that are split
+/// by lint, like `methods`, live in a file named after the lint.
+fn lint_source_file(lint: &Lint) -> PathBuf {
+    let module_path = clippy_project_root()
+        .join("clippy_lints/src")
+        .join(lint.module.replace("::", "/"));
+    let lint_file = module_path.join(format!("{}.rs", lint.name));
+    if lint_file.exists() {
+        lint_file
+    } else if module_path.join("mod.rs").exists() {
+        module_path.join("mod.rs")
+    } else {
+        module_path.with_extension("rs")
+    }
+}
+
+/// Collects the mutants for all lines inside of `check*` functions.
+#[must_use]
+pub fn find_all(contents: &str) -> Vec<Mutant> {
+    let mut mutants = Vec::new();
+    // The brace depth inside of the current `check*` function, if any
+    let mut depth: Option<usize> = None;
+
+    for (idx, line) in contents.lines().enumerate() {
+        let code = strip_comment(line);
+        if depth.is_none() {
+            if code.contains("fn check") {
+                depth = Some(0);
+            } else {
+                continue;
+            }
+        }
+
+        let in_body = depth.map_or(false, |d| d > 0);
+        let mut current = depth.unwrap_or(0);
+        let mut opened = false;
+        for c in code.chars() {
+            match c {
+                '{' => {
+                    current += 1;
+                    opened = true;
+                },
+                '}' => current = current.saturating_sub(1),
+                _ => {},
+            }
+        }
+        depth = if current == 0 && (opened || in_body) {
+            None
+        } else {
+            Some(current)
+        };
+
+        if in_body {
+            mutants.extend(mutate_line(idx, line, code));
+        } else if !opened && code.trim_end().ends_with(';') {
+            // A function declaration without a body
+            depth = None;
+        }
+    }
+
+    mutants
+}
+
+fn mutate_line(idx: usize, line: &str, code: &str) -> Vec<Mutant> {
+    let mut mutants = Vec::new();
+
+    for &(from, to) in OPERATOR_MUTATIONS {
+        for (pos, _) in code.match_indices(from) {
+            if in_string_literal(code, pos) {
+                continue;
+            }
+            mutants.push(Mutant {
+                line: idx,
+                mutated_line: format!("{}{}{}", &line[..pos], to, &line[pos + from.len()..]),
+                description: format!("replace `{}` with `{}`", from.trim(), to.trim()),
+            });
+        }
+    }
+
+    // Remove guards like `if cond {` and `if cond;` (inside of `if_chain!`) by replacing the
+    // condition with `true`.
+    let trimmed = code.trim_start();
+    let indent = &code[..code.len() - trimmed.len()];
+    if let Some(cond) = trimmed.strip_prefix("if ") {
+        let cond = cond.trim_end();
+        let (cond, end) = if let Some(cond) = cond.strip_suffix(" {") {
+            (cond, " {")
+        } else if let Some(cond) = cond.strip_suffix(';') {
+            (cond, ";")
+        } else {
+            return mutants;
+        };
+        if !cond.starts_with("let ") && !cond.starts_with("match ") && cond != "true" {
+            mutants.push(Mutant {
+                line: idx,
+                mutated_line: format!("{}if true{}", indent, end),
+                description: format!("remove guard `if {}`", cond),
+            });
+        }
+    }
+
+    mutants
+}
+
+/// Returns the line without a trailing `//` comment.
+fn strip_comment(line: &str) -> &str {
+    line.match_indices("//")
+        .find(|&(pos, _)| !in_string_literal(line, pos))
+        .map_or(line, |(pos, _)| &line[..pos])
+}
+
+fn in_string_literal(line: &str, pos: usize) -> bool {
+    line[..pos].matches('"').count() % 2 == 1
+}
+
+fn has_ui_tests(lint_name: &str) -> bool {
+    WalkDir::new(clippy_project_root().join("tests"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .any(|f| {
+            f.path().extension().map_or(false, |ext| ext == "rs") && f.path().to_string_lossy().contains(lint_name)
+        })
+}
+
+fn test_mutant(path: &Path, contents: &str, mutant: &Mutant, lint_name: &str) -> Outcome {
+    let mutated: Vec<&str> = contents
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            if idx == mutant.line {
+                &*mutant.mutated_line
+            } else {
+                line
+            }
+        })
+        .collect();
+    let mut mutated = mutated.join("\n");
+    if contents.ends_with('\n') {
+        mutated.push('\n');
+    }
+
+    let _restore = RestoreFile { path, contents };
+    fs::write(path, mutated).unwrap_or_else(|e| panic!("Cannot write to {}: {}", path.display(), e));
+    test_outcome(lint_name)
+}
+
+fn test_outcome(lint_name: &str) -> Outcome {
+    let cargo_test = |no_run: bool| {
+        let mut cmd = Command::new("cargo");
+        cmd.current_dir(clippy_project_root())
+            .args(&["test", "--test", "compile-test"])
+            .env("TESTNAME", lint_name)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        if no_run {
+            cmd.arg("--no-run");
+        }
+        cmd.status().expect("failed to run cargo").success()
+    };
+
+    if !cargo_test(true) {
+        Outcome::Unviable
+    } else if cargo_test(false) {
+        Outcome::Survived
+    } else {
+        Outcome::Caught
+    }
+}
+
+#[test]
+fn test_find_all() {
+    let contents = r#"
+fn helper() -> bool {
+    1 == 2
+}
+
+impl LateLintPass<'_> for Foo {
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        // a == b
+        if a < b && !c {
+            span_lint(cx, FOO, expr.span, "a != b");
+        }
+        if let Some(x) = y {}
+    }
+}
+"#;
+    let mutants: Vec<_> = find_all(contents).into_iter().map(|m| m.mutated_line).collect();
+    assert_eq!(
+        mutants,
+        vec![
+            "        if a >= b && !c {",
+            "        if a < b || !c {",
+            "        if true {",
+        ]
+    );
+}
@@ -8,9 +8,57 @@ struct LintData<'a> {
     pass: &'a str,
     name: &'a str,
     category: &'a str,
+    config: Option<ConfigData<'a>>,
     project_root: PathBuf,
 }
 
+/// A configuration value of the new lint, parsed from `name:type` or `name:type=default`.
+#[derive(Debug, PartialEq)]
+struct ConfigData<'a> {
+    name: &'a str,
+    ty: &'a str,
+    default: Option<&'a str>,
+}
+
+impl<'a> ConfigData<'a> {
+    fn parse(config: &'a str) -> io::Result<Self> {
+        let (name, rest) = config.split_once(':').ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid configuration `{}`, expected `name:type`", config),
+            )
+        })?;
+        let (ty, default) = match rest.split_once('=') {
+            Some((ty, default)) => (ty, Some(default.trim())),
+            None => (rest, None),
+        };
+        Ok(Self {
+            name: name.trim(),
+            ty: ty.trim(),
+            default,
+        })
+    }
+
+    /// Whether the type is `Copy`, so that the configuration value doesn't have to be cloned.
+    fn is_copy(&self) -> bool {
+        const COPY_TYPES: [&str; 15] = [
+            "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+            "usize",
+        ];
+        let ty = self
+            .ty
+            .strip_prefix("Option<")
+            .and_then(|ty| ty.strip_suffix('>'))
+            .unwrap_or(self.ty);
+        COPY_TYPES.contains(&ty)
+    }
+
+    /// The name of the key in `clippy.toml`.
+    fn key(&self) -> String {
+        self.name.replace('_', "-")
+    }
+}
+
 trait Context {
     fn context<C: AsRef<str>>(self, text: C) -> Self;
 }
@@ -27,21 +75,34 @@ impl<T> Context for io::Result<T> {
     }
 }
 
-/// Creates the files required to implement and test a new lint and runs `update_lints`.
+/// Creates the files required to implement and test a new lint, registers its lint pass and adds
+/// its configuration value, if any. `update_lints` has to be run afterwards.
 ///
 /// # Errors
 ///
-/// This function errors out if the files couldn't be created or written to.
-pub fn create(pass: Option<&str>, lint_name: Option<&str>, category: Option<&str>) -> io::Result<()> {
+/// This function errors out if the files couldn't be created or written to, or if the
+/// configuration isn't of the form `name:type` or `name:type=default`.
+pub fn create(
+    pass: Option<&str>,
+    lint_name: Option<&str>,
+    category: Option<&str>,
+    config: Option<&str>,
+) -> io::Result<()> {
     let lint = LintData {
         pass: pass.expect("`pass` argument is validated by clap"),
         name: lint_name.expect("`name` argument is validated by clap"),
         category: category.expect("`category` argument is validated by clap"),
+        config: config.map(ConfigData::parse).transpose()?,
         project_root: clippy_project_root(),
     };
 
     create_lint(&lint).context("Unable to create lint implementation")?;
-    create_test(&lint).context("Unable to create a test for the new lint")
+    create_test(&lint).context("Unable to create a test for the new lint")?;
+    register_pass(&lint).context("Unable to register the lint pass")?;
+    if let Some(config) = &lint.config {
+        add_config(&lint, config).context("Unable to add the configuration value")?;
+    }
+    Ok(())
 }
 
 fn create_lint(lint: &LintData<'_>) -> io::Result<()> {
@@ -54,7 +115,9 @@ fn create_lint(lint: &LintData<'_>) -> io::Result<()> {
     };
 
     let camel_case_name = to_camel_case(lint.name);
+    let version = get_stabilization_version(&lint.project_root)?;
     let lint_contents = get_lint_file_contents(
+        &version,
         pass_type,
         pass_lifetimes,
         lint.name,
@@ -62,6 +125,7 @@ fn create_lint(lint: &LintData<'_>) -> io::Result<()> {
         lint.category,
         pass_import,
         context_import,
+        lint.config.as_ref(),
     );
 
     let lint_path = format!("clippy_lints/src/{}.rs", lint.name);
@@ -88,12 +152,89 @@ fn create_test(lint: &LintData<'_>) -> io::Result<()> {
         fs::create_dir(&test_dir)?;
 
         create_project_layout(lint.name, &test_dir, "fail", "Content that triggers the lint goes here")?;
-        create_project_layout(lint.name, &test_dir, "pass", "This file should not trigger the lint")
+        create_project_layout(lint.name, &test_dir, "pass", "This file should not trigger the lint")?;
     } else {
         let test_path = format!("tests/ui/{}.rs", lint.name);
         let test_contents = get_test_file_contents(lint.name, None);
-        write_file(lint.project_root.join(test_path), test_contents)
+        write_file(lint.project_root.join(test_path), test_contents)?;
+    }
+
+    if let Some(config) = &lint.config {
+        let test_dir = lint.project_root.join(format!("tests/ui-toml/{}", lint.name));
+        fs::create_dir(&test_dir)?;
+        write_file(test_dir.join("clippy.toml"), get_clippy_toml_contents(config))?;
+        write_file(
+            test_dir.join(format!("{}.rs", lint.name)),
+            get_test_file_contents(lint.name, None),
+        )?;
     }
+
+    Ok(())
+}
+
+/// Adds the registration of the lint pass at the end of `register_plugins` in
+/// `clippy_lints/src/lib.rs`.
+fn register_pass(lint: &LintData<'_>) -> io::Result<()> {
+    let path = lint.project_root.join("clippy_lints/src/lib.rs");
+    let contents = fs::read_to_string(&path)?;
+    let end_of_registrations = contents
+        .find("\n\n    utils::timings::time_late_passes(store, sess, first_late_pass);")
+        .ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::NotFound,
+                "couldn't find the end of `register_plugins`, please register the lint pass manually",
+            )
+        })?;
+
+    let mut registration = String::new();
+    let mut pass = format!("{}::{}", lint.name, to_camel_case(lint.name));
+    if let Some(config) = &lint.config {
+        let clone = if config.is_copy() { "" } else { ".clone()" };
+        registration.push_str(&format!(
+            "\n    let {name} = conf.{name}{clone};",
+            name = config.name,
+            clone = clone
+        ));
+        pass = format!("{}::new({}{})", pass, config.name, clone);
+    }
+    let closure = if lint.config.is_some() { "move || " } else { "|| " };
+    registration.push_str(&format!(
+        "\n    store.register_{}_pass({}box {});",
+        lint.pass, closure, pass
+    ));
+
+    let mut new_contents = contents;
+    new_contents.insert_str(end_of_registrations, &registration);
+    fs::write(&path, new_contents)
+}
+
+/// Adds the configuration value to `define_Conf!` in `clippy_lints/src/utils/conf.rs` and to the
+/// list of expected keys in the `toml_unknown_key` test.
+fn add_config(lint: &LintData<'_>, config: &ConfigData<'_>) -> io::Result<()> {
+    let conf_path = lint.project_root.join("clippy_lints/src/utils/conf.rs");
+    let contents = fs::read_to_string(&conf_path)?;
+    let end_of_conf = contents
+        .find("define_Conf! {")
+        .and_then(|start| contents[start..].find("\n}\n").map(|end| start + end))
+        .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "couldn't find the end of `define_Conf!`"))?;
+    let entry = format!(
+        "\n    /// Lint: {}. TODO: document the configuration value\n    ({}: {} = {}),",
+        lint.name.to_uppercase(),
+        config.name,
+        config.ty,
+        config.default.unwrap_or("Default::default()")
+    );
+    let mut new_contents = contents;
+    new_contents.insert_str(end_of_conf, &entry);
+    fs::write(&conf_path, new_contents)?;
+
+    // The deserialization error lists all keys in the order of `define_Conf!`
+    let stderr_path = lint
+        .project_root
+        .join("tests/ui-toml/toml_unknown_key/conf_unknown_key.stderr");
+    let stderr = fs::read_to_string(&stderr_path)?;
+    let new_stderr = stderr.replacen(", `third-party`", &format!(", `{}`, `third-party`", config.key()), 1);
+    fs::write(&stderr_path, new_stderr)
 }
 
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
@@ -112,7 +253,7 @@ fn to_camel_case(name: &str) -> String {
     name.split('_')
         .map(|s| {
             if s.is_empty() {
-                String::from("")
+                String::new()
             } else {
                 [&s[0..1].to_uppercase(), &s[1..]].concat()
             }
@@ -120,6 +261,17 @@ fn to_camel_case(name: &str) -> String {
         .collect()
 }
 
+/// Returns the Rust version in which a lint added now is released, derived from the version of the
+/// `clippy` package: `0.1.55` becomes `1.55.0`.
+fn get_stabilization_version(project_root: &Path) -> io::Result<String> {
+    let manifest = fs::read_to_string(project_root.join("Cargo.toml"))?;
+    manifest
+        .lines()
+        .find_map(|line| line.strip_prefix("version = \"0.1.")?.strip_suffix('"'))
+        .map(|minor| format!("1.{}.0", minor))
+        .ok_or_else(|| io::Error::new(ErrorKind::Other, "unable to find the `clippy` version in `Cargo.toml`"))
+}
+
 fn get_test_file_contents(lint_name: &str, header_commands: Option<&str>) -> String {
     let mut contents = format!(
         "#![warn(clippy::{})]
@@ -138,6 +290,10 @@ fn main() {{
     contents
 }
 
+fn get_clippy_toml_contents(config: &ConfigData<'_>) -> String {
+    format!("# {} = <a value that is different from the default>\n", config.key())
+}
+
 fn get_manifest_contents(lint_name: &str, hint: &str) -> String {
     format!(
         r#"
@@ -154,7 +310,9 @@ publish = false
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_lint_file_contents(
+    version: &str,
     pass_type: &str,
     pass_lifetimes: &str,
     lint_name: &str,
@@ -162,10 +320,43 @@ fn get_lint_file_contents(
     category: &str,
     pass_import: &str,
     context_import: &str,
+    config: Option<&ConfigData<'_>>,
 ) -> String {
+    let (pass_macro, pass_declaration) = match config {
+        Some(config) => (
+            "impl_lint_pass",
+            format!(
+                "pub struct {name_camel} {{
+    {config_name}: {config_ty},
+}}
+
+impl {name_camel} {{
+    #[must_use]
+    pub fn new({config_name}: {config_ty}) -> Self {{
+        Self {{ {config_name} }}
+    }}
+}}
+
+impl_lint_pass!({name_camel} => [{name_upper}]);",
+                name_camel = camel_case_name,
+                name_upper = lint_name.to_uppercase(),
+                config_name = config.name,
+                config_ty = config.ty,
+            ),
+        ),
+        None => (
+            "declare_lint_pass",
+            format!(
+                "declare_lint_pass!({name_camel} => [{name_upper}]);",
+                name_camel = camel_case_name,
+                name_upper = lint_name.to_uppercase(),
+            ),
+        ),
+    };
+
     format!(
         "use rustc_lint::{{{type}, {context_import}}};
-use rustc_session::{{declare_lint_pass, declare_tool_lint}};
+use rustc_session::{{{pass_macro}, declare_tool_lint}};
 {pass_import}
 
 declare_clippy_lint! {{
@@ -184,22 +375,26 @@ declare_clippy_lint! {{
     /// ```rust
     /// // example code which does not raise clippy warning
     /// ```
+    #[clippy::version = \"{version}\"]
     pub {name_upper},
     {category},
     \"default lint description\"
 }}
 
-declare_lint_pass!({name_camel} => [{name_upper}]);
+{pass_declaration}
 
 impl {type}{lifetimes} for {name_camel} {{}}
 ",
+        version=version,
         type=pass_type,
         lifetimes=pass_lifetimes,
         name_upper=lint_name.to_uppercase(),
         name_camel=camel_case_name,
         category=category,
         pass_import=pass_import,
-        context_import=context_import
+        context_import=context_import,
+        pass_macro=pass_macro,
+        pass_declaration=pass_declaration,
     )
 }
 
@@ -217,3 +412,31 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_config_data() {
+    let config = ConfigData::parse("my_threshold:u64").unwrap();
+    assert_eq!(
+        config,
+        ConfigData {
+            name: "my_threshold",
+            ty: "u64",
+            default: None
+        }
+    );
+    assert!(config.is_copy());
+    assert_eq!(config.key(), "my-threshold");
+
+    let config = ConfigData::parse("allowed_names: Vec<String> = Vec::new()").unwrap();
+    assert_eq!(
+        config,
+        ConfigData {
+            name: "allowed_names",
+            ty: "Vec<String>",
+            default: Some("Vec::new()")
+        }
+    );
+    assert!(!config.is_copy());
+
+    assert!(ConfigData::parse("my_threshold").is_err());
+}
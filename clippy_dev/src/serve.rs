@@ -1,20 +1,40 @@
 use std::ffi::{OsStr, OsString};
+use std::fs;
 use std::path::Path;
 use std::process::Command;
 use std::thread;
 use std::time::{Duration, SystemTime};
 
+const LINTS_FILE: &str = "util/gh-pages/lints.json";
+/// The output of the metadata collector lint, see `clippy_lints/src/utils/internal_lints/metadata_collector.rs`
+const METADATA_FILE: &str = "util/gh-pages/metadata_collection.json";
+
+/// If `json` is set, the lint list is generated by the metadata collector instead of
+/// `util/export.py`.
+///
 /// # Panics
 ///
-/// Panics if the python commands could not be spawned
-pub fn run(port: u16, lint: Option<&str>) -> ! {
+/// Panics if the python or cargo commands could not be spawned
+pub fn run(port: u16, lint: Option<&str>, json: bool) -> ! {
     let mut url = Some(match lint {
         None => format!("http://localhost:{}", port),
         Some(lint) => format!("http://localhost:{}/#{}", port, lint),
     });
 
     loop {
-        if mtime("util/gh-pages/lints.json") < mtime("clippy_lints/src") {
+        if json {
+            if mtime(METADATA_FILE) < mtime("clippy_lints/src") {
+                Command::new("cargo")
+                    .arg("collect-metadata")
+                    .spawn()
+                    .unwrap()
+                    .wait()
+                    .unwrap();
+            }
+            if mtime(LINTS_FILE) < mtime(METADATA_FILE) {
+                fs::copy(METADATA_FILE, LINTS_FILE).unwrap();
+            }
+        } else if mtime(LINTS_FILE) < mtime("clippy_lints/src") {
             Command::new("python3")
                 .arg("util/export.py")
                 .spawn()
@@ -1,9 +1,13 @@
 use crate::{
-    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_modules_list, gen_register_lint_list,
-    replace_region_in_file, Lint, DOCS_LINK,
+    gather_all, gather_all_passes, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_lint_pass_list,
+    gen_modules_list, gen_register_lint_list, replace_region_in_file, Lint, DOCS_LINK,
 };
+use regex::Regex;
+use std::lazy::SyncLazy;
 use std::path::Path;
 
+static VERSION_RE: SyncLazy<Regex> = SyncLazy::new(|| Regex::new(r"^(?:pre )?\d+\.\d+\.\d+$").unwrap());
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum UpdateMode {
     Check,
@@ -20,6 +24,8 @@ pub fn run(update_mode: UpdateMode) {
     let mut sorted_usable_lints = usable_lints.clone();
     sorted_usable_lints.sort_by_key(|lint| lint.name.clone());
 
+    check_lint_versions(&usable_lints);
+
     let usable_lint_count = round_to_fifty(usable_lints.len());
 
     let mut file_change = false;
@@ -82,6 +88,22 @@ pub fn run(update_mode: UpdateMode) {
     )
     .changed;
 
+    file_change |= replace_region_in_file(
+        Path::new("clippy_lints/src/lib.rs"),
+        "begin lint passes",
+        "end lint passes",
+        false,
+        update_mode == UpdateMode::Change,
+        || {
+            // the internal passes in `utils` are only compiled with some features and never skipped
+            let passes: Vec<_> = gather_all_passes()
+                .filter(|pass| !pass.module.starts_with("utils"))
+                .collect();
+            gen_lint_pass_list(passes.iter())
+        },
+    )
+    .changed;
+
     // Generate lists of lints in the clippy::all lint group
     file_change |= replace_region_in_file(
         Path::new("clippy_lints/src/lib.rs"),
@@ -147,6 +169,34 @@ pub fn print_lints() {
     println!("there are {} lints", usable_lint_count);
 }
 
+/// Exits with an error if a lint has no `#[clippy::version]` attribute, or if it isn't a version
+/// like `1.55.0` or `pre 1.29.0`.
+fn check_lint_versions(usable_lints: &[Lint]) {
+    let mut invalid = false;
+    for lint in usable_lints {
+        match &lint.version {
+            None => {
+                eprintln!(
+                    "error: `{}` in `{}` is missing the `#[clippy::version]` attribute",
+                    lint.name, lint.module
+                );
+                invalid = true;
+            },
+            Some(version) if !VERSION_RE.is_match(version) => {
+                eprintln!(
+                    "error: `{}` in `{}` has an invalid version `{}`, expected a version like `1.55.0`",
+                    lint.name, lint.module, version
+                );
+                invalid = true;
+            },
+            Some(_) => {},
+        }
+    }
+    if invalid {
+        std::process::exit(1);
+    }
+}
+
 fn round_to_fifty(count: usize) -> usize {
     count / 50 * 50
 }
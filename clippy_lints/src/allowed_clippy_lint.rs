@@ -0,0 +1,113 @@
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::in_external_macro;
+use rustc_ast::{Attribute, Crate};
+use rustc_lint::{EarlyContext, EarlyLintPass, Level, LintContext};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::process;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[allow(..)]` attributes which allow Clippy lints. Once the
+    /// crate has been linted, the number of reported attributes of each lint is printed. Passing
+    /// `--warn-on-all-allows` to Clippy enables this lint, and makes `cargo clippy` print a single
+    /// summary for all linted crates.
+    ///
+    /// **Why is this bad?** It isn't, but it helps to review where and how often lints are
+    /// suppressed, e.g. before enabling more lints in CI.
+    ///
+    /// **Known problems:** Allowing a lint group containing this lint, like
+    /// `#[allow(clippy::restriction)]`, also silences the lint for that attribute.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[allow(clippy::needless_return)]
+    /// fn f() -> u32 {
+    ///     return 1;
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ALLOWED_CLIPPY_LINT,
+    restriction,
+    "`#[allow(..)]` attributes allowing Clippy lints"
+}
+
+#[derive(Default)]
+pub struct AllowAudit {
+    /// The number of reported `#[allow(clippy::..)]` of each lint, including the lint groups
+    allowed: BTreeMap<String, usize>,
+}
+
+impl_lint_pass!(AllowAudit => [ALLOWED_CLIPPY_LINT]);
+
+impl EarlyLintPass for AllowAudit {
+    fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
+        if !attr.has_name(sym::allow)
+            || in_external_macro(cx.sess(), attr.span)
+            || cx.builder.lint_level(ALLOWED_CLIPPY_LINT).0 == Level::Allow
+        {
+            return;
+        }
+
+        for item in attr.meta_item_list().unwrap_or_default() {
+            let path = match item.meta_item() {
+                Some(meta_item) => &meta_item.path,
+                None => continue,
+            };
+            if let [tool, lint] = &*path.segments {
+                if tool.ident.name == sym::clippy {
+                    let lint_name = format!("clippy::{}", lint.ident.name);
+                    span_lint(
+                        cx,
+                        ALLOWED_CLIPPY_LINT,
+                        item.span(),
+                        &format!("`{}` is allowed here", lint_name),
+                    );
+                    *self.allowed.entry(lint_name).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
+        if self.allowed.is_empty() {
+            return;
+        }
+
+        // `cargo clippy` collects the numbers of all crates in this directory and prints them once
+        if let Some(dir) = env::var_os("CLIPPY_ALLOW_AUDIT") {
+            let lines: String = self
+                .allowed
+                .iter()
+                .map(|(lint_name, count)| format!("{} {}\n", lint_name, count))
+                .collect();
+            // Each crate is linted by its own process. Never follow an existing file or symlink.
+            let written = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(Path::new(&dir).join(process::id().to_string()))
+                .and_then(|mut file| file.write_all(lines.as_bytes()));
+            if let Err(e) = written {
+                cx.sess()
+                    .struct_warn(&format!("failed to record the allowed Clippy lints: {}", e))
+                    .emit();
+            }
+            return;
+        }
+
+        let total: usize = self.allowed.values().sum();
+        let mut diag = cx.sess().struct_warn(&format!(
+            "{} Clippy lint{} allowed by attributes in this crate",
+            total,
+            if total == 1 { " is" } else { "s are" }
+        ));
+        for (lint_name, count) in &self.allowed {
+            diag.note(&format!("`{}`: {}", lint_name, count));
+        }
+        diag.emit();
+    }
+}
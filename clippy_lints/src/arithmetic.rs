@@ -18,13 +18,17 @@ declare_clippy_lint! {
     /// release mode. Division by zero will cause a panic in either mode. In some applications one
     /// wants explicitly checked, wrapping or saturating arithmetic.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** The integer operations which can overflow or panic are also linted by
+    /// [`arithmetic_side_effects`](#arithmetic_side_effects), which additionally covers
+    /// overloaded operators and skips divisions by nonzero literals. Enabling both lints reports
+    /// these operations twice, so only one of them should be enabled.
     ///
     /// **Example:**
     /// ```rust
     /// # let a = 0;
     /// a + 1;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub INTEGER_ARITHMETIC,
     restriction,
     "any integer arithmetic expression which could overflow or panic"
@@ -43,6 +47,7 @@ declare_clippy_lint! {
     /// # let a = 0.0;
     /// a + 1.0;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub FLOAT_ARITHMETIC,
     restriction,
     "any floating-point arithmetic statement"
@@ -0,0 +1,198 @@
+use clippy_utils::consts::constant_simple;
+use clippy_utils::diagnostics::{note_allow_location, span_lint_hir_and_then};
+use clippy_utils::{match_def_path, paths};
+use rustc_ast::ast::LitKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BinOpKind, Body, BodyOwnerKind, Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for arithmetic operations (`+`, `-`, `*`, `/`, `%`, `<<`, `>>`
+    /// and unary `-`) which can overflow or panic, on integers as well as on types overloading
+    /// the operators.
+    ///
+    /// Operations on constants, which are checked at compile time, and divisions or remainders
+    /// by a nonzero literal aren't linted. Operators of `Wrapping<T>` and `String`, and of the
+    /// types in the `arithmetic-side-effects-allowed` configuration, are known not to panic.
+    ///
+    /// **Why is this bad?** Integer overflow panics in debug builds and wraps in release builds,
+    /// division by zero panics in either. Safety-critical code which must be free of implicit
+    /// panics should use `checked_*`, `wrapping_*` or `saturating_*` methods instead, together
+    /// with the `indexing_slicing` lint for indexing.
+    ///
+    /// **Known problems:** No bounds analysis is attempted, operations which can't overflow
+    /// because of the values involved are linted as well. The integer operations are also linted
+    /// by [`integer_arithmetic`](#integer_arithmetic), which this lint supersedes, so only one of
+    /// them should be enabled.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let n = 1u32;
+    /// let m = n + 1;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let n = 1u32;
+    /// let m = n.checked_add(1);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ARITHMETIC_SIDE_EFFECTS,
+    restriction,
+    "any arithmetic expression that can overflow or panic"
+}
+
+pub struct ArithmeticSideEffects {
+    allowed_paths: Vec<Vec<String>>,
+    expr_span: Option<Span>,
+    /// The span of the `const` or `static` body being checked, whose operations are evaluated
+    /// at compile time
+    const_span: Option<Span>,
+}
+
+impl ArithmeticSideEffects {
+    pub fn new(arithmetic_side_effects_allowed: &[String]) -> Self {
+        Self {
+            allowed_paths: arithmetic_side_effects_allowed
+                .iter()
+                .map(|path| path.split("::").map(ToString::to_string).collect())
+                .collect(),
+            expr_span: None,
+            const_span: None,
+        }
+    }
+
+    fn is_allowed_adt(&self, cx: &LateContext<'_>, did: DefId) -> bool {
+        if cx.tcx.is_diagnostic_item(sym::string_type, did) || match_def_path(cx, did, &paths::WRAPPING) {
+            return true;
+        }
+        let def_path = cx.get_def_path(did);
+        self.allowed_paths.iter().any(|path| {
+            path.len() == def_path.len()
+                && def_path
+                    .iter()
+                    .zip(path)
+                    .all(|(sym, segment)| sym.as_str() == segment.as_str())
+        })
+    }
+
+    /// Checks if the operator of `ty` can overflow or panic.
+    fn has_side_effects(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        match *ty.peel_refs().kind() {
+            ty::Int(_) | ty::Uint(_) => true,
+            ty::Adt(adt, _) => !self.is_allowed_adt(cx, adt.did),
+            _ => false,
+        }
+    }
+
+    fn issue_lint(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        span_lint_hir_and_then(
+            cx,
+            ARITHMETIC_SIDE_EFFECTS,
+            expr.hir_id,
+            expr.span,
+            "arithmetic operation that can potentially result in unexpected side-effects",
+            // Usually allowed for the items which have been checked for overflows
+            |diag| note_allow_location(cx, diag, ARITHMETIC_SIDE_EFFECTS, expr.hir_id),
+        );
+        self.expr_span = Some(expr.span);
+    }
+}
+
+impl_lint_pass!(ArithmeticSideEffects => [ARITHMETIC_SIDE_EFFECTS]);
+
+impl<'tcx> LateLintPass<'tcx> for ArithmeticSideEffects {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.expr_span.is_some() || expr.span.from_expansion() {
+            return;
+        }
+        if let Some(span) = self.const_span {
+            if span.contains(expr.span) {
+                return;
+            }
+        }
+
+        let typeck = cx.typeck_results();
+        match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs) => {
+                if !matches!(
+                    op.node,
+                    BinOpKind::Add
+                        | BinOpKind::Sub
+                        | BinOpKind::Mul
+                        | BinOpKind::Div
+                        | BinOpKind::Rem
+                        | BinOpKind::Shl
+                        | BinOpKind::Shr
+                ) {
+                    return;
+                }
+                // Operators of primitive types are only overloaded for references of them
+                let lhs_ty = typeck.expr_ty(lhs);
+                if !lhs_ty.peel_refs().is_integral() && !typeck.is_method_call(expr) {
+                    return;
+                }
+                if !self.has_side_effects(cx, lhs_ty) || constant_simple(cx, typeck, expr).is_some() {
+                    return;
+                }
+                // Dividing by a nonzero literal can't panic, nor overflow as `MIN / -1` does
+                if matches!(op.node, BinOpKind::Div | BinOpKind::Rem)
+                    && typeck.expr_ty(rhs).peel_refs().is_integral()
+                    && matches!(rhs.kind, ExprKind::Lit(ref lit) if !matches!(lit.node, LitKind::Int(0, _)))
+                {
+                    return;
+                }
+                self.issue_lint(cx, expr);
+            },
+            ExprKind::Unary(UnOp::Neg, arg) => {
+                let arg_ty = typeck.expr_ty(arg);
+                if (arg_ty.peel_refs().is_integral() || typeck.is_method_call(expr))
+                    && self.has_side_effects(cx, arg_ty)
+                    && constant_simple(cx, typeck, expr).is_none()
+                {
+                    self.issue_lint(cx, expr);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_expr_post(&mut self, _: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if Some(expr.span) == self.expr_span {
+            self.expr_span = None;
+        }
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'_>, body: &Body<'_>) {
+        let body_owner = cx.tcx.hir().body_owner(body.id());
+
+        match cx.tcx.hir().body_owner_kind(body_owner) {
+            BodyOwnerKind::Static(_) | BodyOwnerKind::Const => {
+                let body_span = cx.tcx.hir().span(body_owner);
+
+                if let Some(span) = self.const_span {
+                    if span.contains(body_span) {
+                        return;
+                    }
+                }
+                self.const_span = Some(body_span);
+            },
+            BodyOwnerKind::Fn | BodyOwnerKind::Closure => (),
+        }
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'_>, body: &Body<'_>) {
+        let body_owner = cx.tcx.hir().body_owner(body.id());
+        let body_span = cx.tcx.hir().span(body_owner);
+
+        if let Some(span) = self.const_span {
+            if span.contains(body_span) {
+                return;
+            }
+        }
+        self.const_span = None;
+    }
+}
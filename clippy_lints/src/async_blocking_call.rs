@@ -0,0 +1,165 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{fn_def_id, in_external_macro, path_to_res};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Crate, Expr, ExprKind, GeneratorKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to blocking functions like `std::fs::read` or
+    /// `std::thread::sleep` inside of `async` functions, blocks and closures.
+    ///
+    /// Additional blocking functions can be configured with the `disallowed-blocking-paths`
+    /// option in clippy.toml.
+    ///
+    /// **Why is this bad?** A blocking call stops the executor thread the future is polled on.
+    /// No other task can make progress on that thread until the call returns.
+    ///
+    /// **Known problems:** Calls inside of (non-async) closures are not linted, since those are
+    /// commonly passed to `spawn_blocking` or similar functions.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// async fn read_config() -> String {
+    ///     std::fs::read_to_string("config.toml").unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn read_config() -> String {
+    ///     tokio::fs::read_to_string("config.toml").await.unwrap()
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ASYNC_BLOCKING_CALL,
+    pedantic,
+    "calling a blocking function inside of async code"
+}
+
+/// The blocking functions that are always linted, with an async alternative.
+const BLOCKING_FUNCTIONS: &[(&str, &str)] = &[
+    ("std::fs::canonicalize", "tokio::fs::canonicalize"),
+    ("std::fs::copy", "tokio::fs::copy"),
+    ("std::fs::create_dir", "tokio::fs::create_dir"),
+    ("std::fs::create_dir_all", "tokio::fs::create_dir_all"),
+    ("std::fs::hard_link", "tokio::fs::hard_link"),
+    ("std::fs::metadata", "tokio::fs::metadata"),
+    ("std::fs::read", "tokio::fs::read"),
+    ("std::fs::read_dir", "tokio::fs::read_dir"),
+    ("std::fs::read_link", "tokio::fs::read_link"),
+    ("std::fs::read_to_string", "tokio::fs::read_to_string"),
+    ("std::fs::remove_dir", "tokio::fs::remove_dir"),
+    ("std::fs::remove_dir_all", "tokio::fs::remove_dir_all"),
+    ("std::fs::remove_file", "tokio::fs::remove_file"),
+    ("std::fs::rename", "tokio::fs::rename"),
+    ("std::fs::set_permissions", "tokio::fs::set_permissions"),
+    ("std::fs::symlink_metadata", "tokio::fs::symlink_metadata"),
+    ("std::fs::write", "tokio::fs::write"),
+    ("std::fs::File::create", "tokio::fs::File::create"),
+    ("std::fs::File::open", "tokio::fs::File::open"),
+    ("std::thread::sleep", "tokio::time::sleep"),
+];
+
+#[derive(Clone, Debug)]
+pub struct AsyncBlockingCall {
+    conf_paths: Vec<String>,
+    /// The blocking functions and their async alternative, if one is known
+    def_ids: FxHashMap<DefId, Option<&'static str>>,
+}
+
+impl AsyncBlockingCall {
+    pub fn new(conf_paths: Vec<String>) -> Self {
+        Self {
+            conf_paths,
+            def_ids: FxHashMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(AsyncBlockingCall => [ASYNC_BLOCKING_CALL]);
+
+fn resolve(cx: &LateContext<'_>, path: &str) -> Option<DefId> {
+    match path_to_res(cx, &path.split("::").collect::<Vec<_>>()) {
+        Res::Def(_, id) => Some(id),
+        _ => None,
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for AsyncBlockingCall {
+    fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
+        for &(path, alternative) in BLOCKING_FUNCTIONS {
+            if let Some(id) = resolve(cx, path) {
+                self.def_ids.insert(id, Some(alternative));
+            }
+        }
+        for path in &self.conf_paths {
+            if let Some(id) = resolve(cx, path) {
+                self.def_ids.entry(id).or_insert(None);
+            }
+        }
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        if let Some(GeneratorKind::Async(_)) = body.generator_kind {
+            let mut visitor = BlockingCallVisitor {
+                cx,
+                def_ids: &self.def_ids,
+            };
+            visitor.visit_expr(&body.value);
+        }
+    }
+}
+
+struct BlockingCallVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    def_ids: &'a FxHashMap<DefId, Option<&'static str>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BlockingCallVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        // Nested `async` blocks and closures are checked on their own. Calls in other closures
+        // don't necessarily run in async code.
+        if let ExprKind::Closure(..) = expr.kind {
+            return;
+        }
+
+        if_chain! {
+            if let Some(def_id) = fn_def_id(self.cx, expr);
+            if let Some(alternative) = self.def_ids.get(&def_id);
+            if !in_external_macro(self.cx.sess(), expr.span);
+            then {
+                let help = match alternative {
+                    Some(alternative) => format!(
+                        "use an async alternative like `{}`, or move the call into `spawn_blocking`",
+                        alternative
+                    ),
+                    None => "move the call into `spawn_blocking` or use an async alternative".to_string(),
+                };
+                span_lint_and_help(
+                    self.cx,
+                    ASYNC_BLOCKING_CALL,
+                    expr.span,
+                    &format!(
+                        "call to blocking function `{}` in async code",
+                        self.cx.tcx.def_path_str(def_id)
+                    ),
+                    None,
+                    &help,
+                );
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -45,6 +45,7 @@ declare_clippy_lint! {
     /// // stronger than `Relaxed`.
     /// let _ = x.fetch_update(Ordering::Relaxed, Ordering::SeqCst, |val| Some(val + val));
     /// ```
+    #[clippy::version = "1.42.0"]
     pub INVALID_ATOMIC_ORDERING,
     correctness,
     "usage of invalid atomic ordering in atomic operations and memory fences"
@@ -1,6 +1,7 @@
 //! checks for attributes
 
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::in_external_macro;
 use clippy_utils::match_panic_def_id;
 use clippy_utils::source::{first_line_of_span, is_present_in_source, snippet_opt, without_block_comments};
 use if_chain::if_chain;
@@ -10,7 +11,6 @@ use rustc_hir::{
     Block, Expr, ExprKind, ImplItem, ImplItemKind, Item, ItemKind, StmtKind, TraitFn, TraitItem, TraitItemKind,
 };
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
@@ -61,6 +61,7 @@ declare_clippy_lint! {
     /// #[inline(always)]
     /// fn not_quite_hot_code(..) { ... }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub INLINE_ALWAYS,
     pedantic,
     "use of `#[inline(always)]`"
@@ -95,6 +96,7 @@ declare_clippy_lint! {
     /// #[macro_use]
     /// extern crate baz;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub USELESS_ATTRIBUTE,
     correctness,
     "use of lint attributes on `extern crate` items"
@@ -114,6 +116,7 @@ declare_clippy_lint! {
     /// #[deprecated(since = "forever")]
     /// fn something_else() { /* ... */ }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub DEPRECATED_SEMVER,
     correctness,
     "use of `#[deprecated(since = \"x\")]` where x is not semver"
@@ -149,6 +152,7 @@ declare_clippy_lint! {
     /// #[allow(dead_code)]
     /// fn this_is_fine_too() { }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub EMPTY_LINE_AFTER_OUTER_ATTR,
     nursery,
     "empty line after outer attribute"
@@ -172,6 +176,7 @@ declare_clippy_lint! {
     /// ```rust
     /// #![deny(clippy::as_conversions)]
     /// ```
+    #[clippy::version = "1.47.0"]
     pub BLANKET_CLIPPY_RESTRICTION_LINTS,
     suspicious,
     "enabling the complete restriction group"
@@ -201,6 +206,7 @@ declare_clippy_lint! {
     /// #[rustfmt::skip]
     /// fn main() { }
     /// ```
+    #[clippy::version = "1.32.0"]
     pub DEPRECATED_CFG_ATTR,
     complexity,
     "usage of `cfg_attr(rustfmt)` instead of tool attributes"
@@ -234,11 +240,46 @@ declare_clippy_lint! {
     /// fn conditional() { }
     /// ```
     /// Check the [Rust Reference](https://doc.rust-lang.org/reference/conditional-compilation.html#target_os) for more details.
+    #[clippy::version = "1.45.0"]
     pub MISMATCHED_TARGET_OS,
     correctness,
     "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[allow]` attributes on Clippy lints which don't give a
+    /// reason, e.g. `#[allow(clippy::needless_return)]` instead of
+    /// `#[allow(clippy::needless_return, reason = "...")]`.
+    ///
+    /// **Why is this bad?** Allowing a lint without a reason makes it hard to tell later on
+    /// whether the allow is still needed, or why the lint was wrong in the first place.
+    ///
+    /// **Known problems:** Reasons require `#![feature(lint_reasons)]`, so this lint is only
+    /// useful on nightly. Attributes added by `cfg_attr` are not checked.
+    ///
+    /// **Example:**
+    ///
+    /// Bad:
+    /// ```rust,ignore
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[allow(clippy::needless_return)]
+    /// fn f() {}
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[allow(clippy::needless_return, reason = "the early return mirrors the other branches")]
+    /// fn f() {}
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ALLOW_ATTRIBUTES_WITHOUT_REASON,
+    restriction,
+    "`#[allow]` attributes on Clippy lints without a reason"
+}
+
 declare_lint_pass!(Attributes => [
     INLINE_ALWAYS,
     DEPRECATED_SEMVER,
@@ -492,6 +533,7 @@ fn is_word(nmi: &NestedMetaItem, expected: Symbol) -> bool {
 }
 
 declare_lint_pass!(EarlyAttributes => [
+    ALLOW_ATTRIBUTES_WITHOUT_REASON,
     DEPRECATED_CFG_ATTR,
     MISMATCHED_TARGET_OS,
     EMPTY_LINE_AFTER_OUTER_ATTR,
@@ -503,6 +545,7 @@ impl EarlyLintPass for EarlyAttributes {
     }
 
     fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
+        check_allow_without_reason(cx, attr);
         check_deprecated_cfg_attr(cx, attr);
         check_mismatched_target_os(cx, attr);
     }
@@ -542,6 +585,25 @@ fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::It
     }
 }
 
+fn check_allow_without_reason(cx: &EarlyContext<'_>, attr: &Attribute) {
+    if_chain! {
+        if attr.has_name(sym::allow);
+        if let Some(items) = attr.meta_item_list();
+        if items.iter().any(|item| extract_clippy_lint(item).is_some());
+        if !items.iter().any(|item| item.has_name(sym::reason));
+        then {
+            span_lint_and_help(
+                cx,
+                ALLOW_ATTRIBUTES_WITHOUT_REASON,
+                attr.span,
+                "`allow` attribute without specifying a reason",
+                None,
+                "try adding a reason at the end with `, reason = \"..\"`",
+            );
+        }
+    }
+}
+
 fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute) {
     if_chain! {
         // check cfg_attr
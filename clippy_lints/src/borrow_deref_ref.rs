@@ -0,0 +1,89 @@
+use crate::reference::DEREF_ADDROF;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{get_parent_expr, is_lint_allowed};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `&*x` where `x` is already an immutable reference.
+    ///
+    /// **Why is this bad?** Dereferencing a reference and borrowing the result again neither
+    /// changes the mutability nor calls a `Deref` implementation, it's the same reference as
+    /// `x`, which can be used directly.
+    ///
+    /// **Known problems:** If a `Deref` implementation of the referenced type was meant to be
+    /// called, `&**x` is needed instead.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn len(s: &str) -> usize {
+    ///     s.len()
+    /// }
+    ///
+    /// let s: &str = "foo";
+    /// let n = len(&*s);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn len(s: &str) -> usize {
+    /// #     s.len()
+    /// # }
+    /// let s: &str = "foo";
+    /// let n = len(s);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub BORROW_DEREF_REF,
+    complexity,
+    "borrowing a dereferenced immutable reference, like `&*x`"
+}
+
+declare_lint_pass!(BorrowDerefRef => [BORROW_DEREF_REF]);
+
+impl<'tcx> LateLintPass<'tcx> for BorrowDerefRef {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, addrof_target) = expr.kind;
+            if let ExprKind::Unary(UnOp::Deref, deref_target) = addrof_target.kind;
+            if !addrof_target.span.from_expansion() && !deref_target.span.from_expansion();
+            // `&**x` derefs `*x`, which may call a `Deref` implementation
+            if !matches!(deref_target.kind, ExprKind::Unary(UnOp::Deref, _));
+            let typeck = cx.typeck_results();
+            // Without adjustments, the dereference is the built-in one of `&T`, and not of an
+            // autoderefed or reborrowed value, so `&*x` has the type and mutability of `x`
+            if typeck.expr_adjustments(deref_target).is_empty();
+            if let ty::Ref(_, _, Mutability::Not) = typeck.expr_ty(deref_target).kind();
+            then {
+                if let Some(parent) = get_parent_expr(cx, expr) {
+                    // `*&*x` is linted by `deref_addrof`
+                    if matches!(parent.kind, ExprKind::Unary(UnOp::Deref, _))
+                        && !is_lint_allowed(cx, DEREF_ADDROF, parent.hir_id)
+                    {
+                        return;
+                    }
+                    // `&mut &*x` borrows a temporary, while `&mut x` would allow changing `x`
+                    if matches!(parent.kind, ExprKind::AddrOf(_, Mutability::Mut, _)) {
+                        return;
+                    }
+                }
+
+                let mut applicability = Applicability::MachineApplicable;
+                let sugg = snippet_with_applicability(cx, deref_target.span, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    BORROW_DEREF_REF,
+                    expr.span,
+                    "deref on an immutable reference",
+                    "if you would like to reborrow, try removing `&*`",
+                    sugg.to_string(),
+                    applicability,
+                );
+            }
+        }
+    }
+}
@@ -1,12 +1,26 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_isize_or_usize;
-use rustc_hir::Expr;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_isize_or_usize, is_type_diagnostic_item};
+use clippy_utils::{path_to_res, paths, return_ty};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BodyOwnerKind, Expr, TyKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_span::sym;
 
 use super::{utils, CAST_POSSIBLE_TRUNCATION};
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_to_hir: &rustc_hir::Ty<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+) {
     let msg = match (cast_from.is_integral(), cast_to.is_integral()) {
         (true, true) => {
             let from_nbits = utils::int_ty_to_nbits(cast_from, cx.tcx);
@@ -50,5 +64,56 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, ca
         },
     };
 
-    span_lint(cx, CAST_POSSIBLE_TRUNCATION, expr.span, &msg);
+    span_lint_and_then(cx, CAST_POSSIBLE_TRUNCATION, expr.span, &msg, |diag| {
+        // Only integers can be converted with `TryFrom`
+        if !(cast_from.is_integral() && cast_to.is_integral()) {
+            return;
+        }
+
+        let cast_expr_sugg = Sugg::hir(cx, cast_expr, "..");
+        let conversion = if let TyKind::Infer = cast_to_hir.kind {
+            format!("{}.try_into()", cast_expr_sugg.maybe_par())
+        } else {
+            format!("{}::try_from({})", snippet(cx, cast_to_hir.span, ".."), cast_expr_sugg)
+        };
+        if can_propagate_try_from_int_error(cx, expr) {
+            diag.span_suggestion(
+                expr.span,
+                "use `try_from` and propagate the error",
+                format!("{}?", conversion),
+                Applicability::MaybeIncorrect,
+            );
+        } else {
+            diag.span_suggestion(
+                expr.span,
+                "use `try_from` and handle the error",
+                conversion,
+                Applicability::Unspecified,
+            );
+        }
+        diag.help("if the truncation is intended, keep the cast and allow this lint with a comment explaining why");
+    });
+}
+
+/// Checks if `expr` is in a function (not a closure) returning `Result<_, E>`, where `E`
+/// implements `From<TryFromIntError>`, so that the error of a conversion can be returned with `?`.
+fn can_propagate_try_from_int_error(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let body_owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+    if !matches!(cx.tcx.hir().body_owner_kind(body_owner), BodyOwnerKind::Fn) {
+        return false;
+    }
+
+    let ret_ty = return_ty(cx, body_owner);
+    if_chain! {
+        if is_type_diagnostic_item(cx, ret_ty, sym::result_type);
+        if let ty::Adt(_, substs) = ret_ty.kind();
+        if let Res::Def(DefKind::Struct, error_def_id) = path_to_res(cx, &paths::TRY_FROM_INT_ERROR);
+        if let Some(from_trait_id) = cx.tcx.get_diagnostic_item(sym::from_trait);
+        then {
+            let try_from_int_error = cx.tcx.type_of(error_def_id);
+            implements_trait(cx, substs.type_at(1), from_trait_id, &[try_from_int_error.into()])
+        } else {
+            false
+        }
+    }
 }
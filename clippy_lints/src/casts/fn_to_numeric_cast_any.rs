@@ -0,0 +1,110 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::get_parent_expr;
+use clippy_utils::source::snippet_with_applicability;
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::FN_TO_NUMERIC_CAST_ANY;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    cast_expr: &'tcx Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if !is_fn_to_int_cast(cast_from, cast_to) || is_explicit_fn_ptr_cast(cx, cast_expr) {
+        return;
+    }
+
+    if_chain! {
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::Binary(op, left, right) = parent.kind;
+        if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne);
+        if is_fn_address_cast(cx, left) && is_fn_address_cast(cx, right);
+        then {
+            // both operands are checked, only lint the comparison once
+            if left.hir_id == expr.hir_id {
+                lint_comparison(cx, parent, op.node, left, right);
+            }
+            return;
+        }
+    }
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let from_snippet = snippet_with_applicability(cx, cast_expr.span, "x", &mut applicability);
+    let msg = format!("casting function pointer `{}` to `{}`", from_snippet, cast_to);
+    if let ty::FnDef(..) = cast_from.kind() {
+        let fn_ptr = cx.tcx.mk_fn_ptr(cast_from.fn_sig(cx.tcx));
+        span_lint_and_sugg(
+            cx,
+            FN_TO_NUMERIC_CAST_ANY,
+            expr.span,
+            &msg,
+            "cast to a function pointer first",
+            format!("{} as {} as {}", from_snippet, fn_ptr, cast_to),
+            applicability,
+        );
+    } else {
+        span_lint_and_help(
+            cx,
+            FN_TO_NUMERIC_CAST_ANY,
+            expr.span,
+            &msg,
+            None,
+            "if the address is needed, add a comment explaining what it is used for",
+        );
+    }
+}
+
+fn is_fn_to_int_cast(cast_from: Ty<'_>, cast_to: Ty<'_>) -> bool {
+    matches!(cast_from.kind(), ty::FnDef(..) | ty::FnPtr(_)) && matches!(cast_to.kind(), ty::Uint(_) | ty::Int(_))
+}
+
+/// Checks if `expr` casts a function item to a function pointer, e.g. `f as fn()`, which is the
+/// suggested way to state that the address of the function is needed.
+fn is_explicit_fn_ptr_cast(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Cast(inner, _) = expr.kind {
+        let typeck = cx.typeck_results();
+        matches!(typeck.expr_ty(inner).kind(), ty::FnDef(..)) && matches!(typeck.expr_ty(expr).kind(), ty::FnPtr(_))
+    } else {
+        false
+    }
+}
+
+/// Checks if `expr` is a cast of a function to an integer, e.g. `f as usize`.
+fn is_fn_address_cast(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Cast(cast_expr, _) = expr.kind {
+        let typeck = cx.typeck_results();
+        is_fn_to_int_cast(typeck.expr_ty(cast_expr), typeck.expr_ty(expr))
+    } else {
+        false
+    }
+}
+
+fn lint_comparison(cx: &LateContext<'_>, expr: &Expr<'_>, op: BinOpKind, left: &Expr<'_>, right: &Expr<'_>) {
+    let (left, right) = match (&left.kind, &right.kind) {
+        (ExprKind::Cast(left, _), ExprKind::Cast(right, _)) => (left, right),
+        _ => return,
+    };
+    let mut applicability = Applicability::MaybeIncorrect;
+    let left_snippet = snippet_with_applicability(cx, left.span, "..", &mut applicability);
+    let right_snippet = snippet_with_applicability(cx, right.span, "..", &mut applicability);
+    span_lint_and_sugg(
+        cx,
+        FN_TO_NUMERIC_CAST_ANY,
+        expr.span,
+        "comparing the addresses of functions by casting them to integers",
+        "compare the pointers instead",
+        format!(
+            "{}std::ptr::eq({} as *const (), {} as *const ())",
+            if op == BinOpKind::Ne { "!" } else { "" },
+            left_snippet,
+            right_snippet
+        ),
+        applicability,
+    );
+}
@@ -7,15 +7,17 @@ mod cast_ref_to_mut;
 mod cast_sign_loss;
 mod char_lit_as_u8;
 mod fn_to_numeric_cast;
+mod fn_to_numeric_cast_any;
 mod fn_to_numeric_cast_with_truncation;
 mod ptr_as_ptr;
+mod ptr_cast_constness;
 mod unnecessary_cast;
 mod utils;
 
+use clippy_utils::in_external_macro;
 use clippy_utils::is_hir_ty_cfg_dependant;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 
@@ -39,6 +41,7 @@ declare_clippy_lint! {
     /// let x = u64::MAX;
     /// x as f64;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_PRECISION_LOSS,
     pedantic,
     "casts that cause loss of precision, e.g., `x as f32` where `x: u64`"
@@ -60,6 +63,7 @@ declare_clippy_lint! {
     /// let y: i8 = -1;
     /// y as u128; // will return 18446744073709551615
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_SIGN_LOSS,
     pedantic,
     "casts from signed types to unsigned types, e.g., `x as u32` where `x: i32`"
@@ -74,7 +78,8 @@ declare_clippy_lint! {
     /// truncation. This lint can be activated to help assess where additional
     /// checks could be beneficial.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** The suggested `try_from` and `try_into` calls need the
+    /// `TryFrom` and `TryInto` traits in scope before the 2021 edition.
     ///
     /// **Example:**
     /// ```rust
@@ -82,6 +87,15 @@ declare_clippy_lint! {
     ///     x as u8
     /// }
     /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # use std::num::TryFromIntError;
+    /// fn as_u8(x: u64) -> Result<u8, TryFromIntError> {
+    ///     Ok(u8::try_from(x)?)
+    /// }
+    /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_POSSIBLE_TRUNCATION,
     pedantic,
     "casts that may cause truncation of the value, e.g., `x as u8` where `x: u32`, or `x as i32` where `x: f32`"
@@ -105,6 +119,7 @@ declare_clippy_lint! {
     /// ```rust
     /// u32::MAX as i32; // will yield a value of `-1`
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_POSSIBLE_WRAP,
     pedantic,
     "casts that may cause wrapping around the value, e.g., `x as i32` where `x: u32` and `x > i32::MAX`"
@@ -137,6 +152,7 @@ declare_clippy_lint! {
     ///     u64::from(x)
     /// }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_LOSSLESS,
     pedantic,
     "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`"
@@ -162,6 +178,7 @@ declare_clippy_lint! {
     /// let _ = 2_i32;
     /// let _ = 0.5_f32;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub UNNECESSARY_CAST,
     complexity,
     "cast to the same type, e.g., `x as i32` where `x: i32`"
@@ -186,6 +203,7 @@ declare_clippy_lint! {
     /// (&1u8 as *const u8).cast::<u16>();
     /// (&mut 1u8 as *mut u8).cast::<u16>();
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CAST_PTR_ALIGNMENT,
     pedantic,
     "cast from a pointer to a more-strictly-aligned pointer"
@@ -213,6 +231,7 @@ declare_clippy_lint! {
     /// fn fun2() -> i32 { 1 }
     /// let a = fun2 as usize;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub FN_TO_NUMERIC_CAST,
     style,
     "casting a function pointer to a numeric type other than usize"
@@ -243,11 +262,53 @@ declare_clippy_lint! {
     /// let fn_ptr = fn2 as usize;
     /// let fn_ptr_truncated = fn_ptr as i32;
     /// ```
+    #[clippy::version = "1.29.0"]
     pub FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
     style,
     "casting a function pointer to a numeric type not wide enough to store the address"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a function item or pointer to any integer type,
+    /// including `usize`.
+    ///
+    /// **Why is this bad?** Such a cast is easily confused with calling the function, and a
+    /// function item silently loses its unique type. When the addresses are only compared,
+    /// `std::ptr::eq` states that intention more clearly. Casting to a function pointer first, or a
+    /// comment, documents why the address is needed, so `foo as fn() -> i32 as usize` isn't linted.
+    ///
+    /// **Known problems:** The address of a function is not guaranteed to be unique, see
+    /// [`fn_address_comparisons`](#fn_address_comparisons).
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo() -> i32 {
+    ///     1
+    /// }
+    /// fn bar() -> i32 {
+    ///     2
+    /// }
+    ///
+    /// let address = foo as usize;
+    /// let same = foo as usize == bar as usize;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn foo() -> i32 {
+    /// #     1
+    /// # }
+    /// # fn bar() -> i32 {
+    /// #     2
+    /// # }
+    /// let address = foo as fn() -> i32 as usize;
+    /// let same = std::ptr::eq(foo as *const (), bar as *const ());
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub FN_TO_NUMERIC_CAST_ANY,
+    restriction,
+    "casting a function pointer to any integer type"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for casts of `&T` to `&mut T` anywhere in the code.
     ///
@@ -277,6 +338,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "1.33.0"]
     pub CAST_REF_TO_MUT,
     correctness,
     "a cast of reference to a mutable pointer"
@@ -304,6 +366,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// b'x'
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub CHAR_LIT_AS_U8,
     complexity,
     "casting a character literal to `u8` truncates"
@@ -335,11 +398,41 @@ declare_clippy_lint! {
     /// let _ = ptr.cast::<i32>();
     /// let _ = mut_ptr.cast::<i32>();
     /// ```
+    #[clippy::version = "1.51.0"]
     pub PTR_AS_PTR,
     pedantic,
     "casting using `as` from and to raw pointers that doesn't change its mutability, where `pointer::cast` could take the place of `as`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:**
+    /// Checks for `as` casts between raw pointers which change both the mutability and the
+    /// pointee type, namely `*const T` to `*mut U` and `*mut T` to `*const U`.
+    ///
+    /// **Why is this bad?**
+    /// A single `as` hides that the cast does two things at once. Changing the pointee type with
+    /// `pointer::cast` leaves the `as` cast to only change the mutability, which is easier to
+    /// spot when reviewing `unsafe` code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let ptr: *const u32 = &42_u32;
+    /// let _ = ptr as *mut i32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let ptr: *const u32 = &42_u32;
+    /// let _ = ptr.cast::<i32>() as *mut i32;
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub PTR_CAST_CONSTNESS,
+    pedantic,
+    "casting using `as` between raw pointers that changes both the mutability and the pointee type"
+}
+
 pub struct Casts {
     msrv: Option<RustcVersion>,
 }
@@ -362,8 +455,10 @@ impl_lint_pass!(Casts => [
     UNNECESSARY_CAST,
     FN_TO_NUMERIC_CAST,
     FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+    FN_TO_NUMERIC_CAST_ANY,
     CHAR_LIT_AS_U8,
     PTR_AS_PTR,
+    PTR_CAST_CONSTNESS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -372,8 +467,8 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             return;
         }
 
-        if let ExprKind::Cast(cast_expr, cast_to) = expr.kind {
-            if is_hir_ty_cfg_dependant(cx, cast_to) {
+        if let ExprKind::Cast(cast_expr, cast_to_hir) = expr.kind {
+            if is_hir_ty_cfg_dependant(cx, cast_to_hir) {
                 return;
             }
             let (cast_from, cast_to) = (
@@ -387,8 +482,9 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
 
             fn_to_numeric_cast::check(cx, expr, cast_expr, cast_from, cast_to);
             fn_to_numeric_cast_with_truncation::check(cx, expr, cast_expr, cast_from, cast_to);
+            fn_to_numeric_cast_any::check(cx, expr, cast_expr, cast_from, cast_to);
             if cast_from.is_numeric() && cast_to.is_numeric() && !in_external_macro(cx.sess(), expr.span) {
-                cast_possible_truncation::check(cx, expr, cast_from, cast_to);
+                cast_possible_truncation::check(cx, expr, cast_expr, cast_to_hir, cast_from, cast_to);
                 cast_possible_wrap::check(cx, expr, cast_from, cast_to);
                 cast_precision_loss::check(cx, expr, cast_from, cast_to);
                 cast_lossless::check(cx, expr, cast_expr, cast_from, cast_to);
@@ -400,6 +496,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
         cast_ptr_alignment::check(cx, expr);
         char_lit_as_u8::check(cx, expr);
         ptr_as_ptr::check(cx, expr, &self.msrv);
+        ptr_cast_constness::check(cx, expr, &self.msrv);
     }
 
     extract_msrv_attr!(LateContext);
@@ -5,11 +5,12 @@ use clippy_utils::sugg::Sugg;
 use clippy_utils::{meets_msrv, msrvs};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Mutability, TyKind};
+use rustc_hir::{Expr, ExprKind, TyKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, TypeAndMut};
 use rustc_semver::RustcVersion;
 
+use super::utils::{ptr_cast_kind, PtrCastKind};
 use super::PTR_AS_PTR;
 
 pub(super) fn check(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: &Option<RustcVersion>) {
@@ -20,10 +21,8 @@ pub(super) fn check(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: &Option<
     if_chain! {
         if let ExprKind::Cast(cast_expr, cast_to_hir_ty) = expr.kind;
         let (cast_from, cast_to) = (cx.typeck_results().expr_ty(cast_expr), cx.typeck_results().expr_ty(expr));
-        if let ty::RawPtr(TypeAndMut { mutbl: from_mutbl, .. }) = cast_from.kind();
-        if let ty::RawPtr(TypeAndMut { ty: to_pointee_ty, mutbl: to_mutbl }) = cast_to.kind();
-        if matches!((from_mutbl, to_mutbl),
-            (Mutability::Not, Mutability::Not) | (Mutability::Mut, Mutability::Mut));
+        if ptr_cast_kind(cast_from, cast_to) == Some(PtrCastKind::Pointee);
+        if let ty::RawPtr(TypeAndMut { ty: to_pointee_ty, .. }) = cast_to.kind();
         // The `U` in `pointer::cast` have to be `Sized`
         // as explained here: https://github.com/rust-lang/rust/issues/60602.
         if to_pointee_ty.is_sized(cx.tcx.at(expr.span), cx.param_env);
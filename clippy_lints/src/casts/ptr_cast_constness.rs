@@ -0,0 +1,58 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{meets_msrv, msrvs};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, MutTy, TyKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TypeAndMut};
+use rustc_semver::RustcVersion;
+
+use super::utils::{ptr_cast_kind, PtrCastKind};
+use super::PTR_CAST_CONSTNESS;
+
+pub(super) fn check(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: &Option<RustcVersion>) {
+    if !meets_msrv(msrv.as_ref(), &msrvs::POINTER_CAST) {
+        return;
+    }
+
+    if_chain! {
+        if let ExprKind::Cast(cast_expr, cast_to_hir_ty) = expr.kind;
+        let (cast_from, cast_to) = (cx.typeck_results().expr_ty(cast_expr), cx.typeck_results().expr_ty(expr));
+        if ptr_cast_kind(cast_from, cast_to) == Some(PtrCastKind::MutabilityAndPointee);
+        if let ty::RawPtr(TypeAndMut { ty: to_pointee_ty, .. }) = cast_to.kind();
+        // The `U` in `pointer::cast` have to be `Sized`
+        if to_pointee_ty.is_sized(cx.tcx.at(expr.span), cx.param_env);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let cast_expr_sugg = Sugg::hir_with_applicability(cx, cast_expr, "_", &mut applicability);
+            let cast_to_snip = snippet_with_applicability(cx, cast_to_hir_ty.span, "_", &mut applicability);
+            // The pointee type is spelled out, as the final cast can't infer it anymore
+            let to_pointee_snip = match cast_to_hir_ty.kind {
+                TyKind::Ptr(MutTy { ty, .. }) if !matches!(ty.kind, TyKind::Infer) => {
+                    snippet_with_applicability(cx, ty.span, "_", &mut applicability).into_owned()
+                },
+                // The printed type may not be nameable here, e.g. if it isn't imported
+                _ => {
+                    applicability = Applicability::MaybeIncorrect;
+                    to_pointee_ty.to_string()
+                },
+            };
+            span_lint_and_sugg(
+                cx,
+                PTR_CAST_CONSTNESS,
+                expr.span,
+                "`as` casting between raw pointers while changing both its mutability and its pointee type",
+                "try `pointer::cast`, keeping `as` for the mutability",
+                format!(
+                    "{}.cast::<{}>() as {}",
+                    cast_expr_sugg.maybe_par(),
+                    to_pointee_snip,
+                    cast_to_snip
+                ),
+                applicability,
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use rustc_middle::ty::{self, IntTy, Ty, TyCtxt, UintTy};
+use rustc_middle::ty::{self, IntTy, Ty, TyCtxt, TyS, TypeAndMut, UintTy};
 
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
@@ -23,3 +23,39 @@ pub(super) fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
         _ => 0,
     }
 }
+
+/// What an `as` cast between two raw pointers changes
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum PtrCastKind {
+    /// `*const T as *const U` or `*mut T as *mut U`, `T` and `U` may be the same
+    Pointee,
+    /// `*const T as *mut T` or `*mut T as *const T`
+    Mutability,
+    /// `*const T as *mut U` or `*mut T as *const U`
+    MutabilityAndPointee,
+}
+
+/// Classifies a cast from `cast_from` to `cast_to`, returns `None` if they aren't both raw
+/// pointers.
+pub(super) fn ptr_cast_kind(cast_from: Ty<'_>, cast_to: Ty<'_>) -> Option<PtrCastKind> {
+    match (cast_from.kind(), cast_to.kind()) {
+        (
+            ty::RawPtr(TypeAndMut {
+                ty: from_pointee_ty,
+                mutbl: from_mutbl,
+            }),
+            ty::RawPtr(TypeAndMut {
+                ty: to_pointee_ty,
+                mutbl: to_mutbl,
+            }),
+        ) => {
+            let same_pointee = TyS::same_type(from_pointee_ty, to_pointee_ty);
+            Some(match (from_mutbl == to_mutbl, same_pointee) {
+                (true, _) => PtrCastKind::Pointee,
+                (false, true) => PtrCastKind::Mutability,
+                (false, false) => PtrCastKind::MutabilityAndPointee,
+            })
+        },
+        _ => None,
+    }
+}
@@ -1,12 +1,11 @@
 //! calculate cognitive complexity and warn about overly complex functions
 
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::LimitStack;
+use clippy_utils::{in_macro, is_body_too_large, LimitStack};
 use rustc_ast::ast::Attribute;
 use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
-use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_hir::{BinOpKind, Body, Destination, Expr, ExprKind, FnDecl, HirId, MatchSource};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -16,13 +15,21 @@ use rustc_span::{sym, BytePos};
 declare_clippy_lint! {
     /// **What it does:** Checks for methods with high cognitive complexity.
     ///
+    /// Each `if`, `match` and loop adds one to the score, and one more for every `if`,
+    /// `match` and loop it is nested in. `else if`, `else`, labeled `break`s and
+    /// `continue`s, and every sequence of the same boolean operator add one each. The
+    /// threshold can be set with the `cognitive-complexity-threshold` configuration option
+    /// or with the `#[clippy::cognitive_complexity = "N"]` attribute.
+    ///
     /// **Why is this bad?** Methods of high cognitive complexity tend to be hard to
     /// both read and maintain. Also LLVM will tend to optimize small methods better.
     ///
     /// **Known problems:** Sometimes it's hard to find a way to reduce the
-    /// complexity.
+    /// complexity. Closures are scored on their own, so they don't add to the score
+    /// of the enclosing function.
     ///
     /// **Example:** No. You'll see it when you get the warning.
+    #[clippy::version = "1.35.0"]
     pub COGNITIVE_COMPLEXITY,
     nursery,
     "functions that should be split up into multiple functions"
@@ -30,13 +37,15 @@ declare_clippy_lint! {
 
 pub struct CognitiveComplexity {
     limit: LimitStack,
+    body_node_limit: u64,
 }
 
 impl CognitiveComplexity {
     #[must_use]
-    pub fn new(limit: u64) -> Self {
+    pub fn new(limit: u64, body_node_limit: u64) -> Self {
         Self {
             limit: LimitStack::new(limit),
+            body_node_limit,
         }
     }
 }
@@ -53,30 +62,15 @@ impl CognitiveComplexity {
         body: &'tcx Body<'_>,
         body_span: Span,
     ) {
-        if body_span.from_expansion() {
+        if body_span.from_expansion() || is_body_too_large(cx, body.id(), self.body_node_limit) {
             return;
         }
 
-        let expr = &body.value;
-
-        let mut helper = CcHelper { cc: 1, returns: 0 };
-        helper.visit_expr(expr);
-        let CcHelper { cc, returns } = helper;
-        let ret_ty = cx.typeck_results().node_type(expr.hir_id);
-        let ret_adjust = if is_type_diagnostic_item(cx, ret_ty, sym::result_type) {
-            returns
-        } else {
-            #[allow(clippy::integer_division)]
-            (returns / 2)
-        };
-
-        let mut rust_cc = cc;
-        // prevent degenerate cases where unreachable code contains `return` statements
-        if rust_cc >= ret_adjust {
-            rust_cc -= ret_adjust;
-        }
+        let mut helper = CcHelper::default();
+        helper.visit_expr(&body.value);
+        let score = helper.score();
 
-        if rust_cc > self.limit.limit() {
+        if score > self.limit.limit() {
             let fn_span = match kind {
                 FnKind::ItemFn(ident, _, _, _) | FnKind::Method(ident, _, _) => ident.span,
                 FnKind::Closure => {
@@ -98,17 +92,19 @@ impl CognitiveComplexity {
                 },
             };
 
-            span_lint_and_help(
+            span_lint_and_then(
                 cx,
                 COGNITIVE_COMPLEXITY,
                 fn_span,
                 &format!(
                     "the function has a cognitive complexity of ({}/{})",
-                    rust_cc,
+                    score,
                     self.limit.limit()
                 ),
-                None,
-                "you could split it up into multiple smaller functions",
+                |diag| {
+                    diag.note(&format!("the score is made up of {}", helper.breakdown()));
+                    diag.help("you could split it up into multiple smaller functions");
+                },
             );
         }
     }
@@ -138,30 +134,135 @@ impl<'tcx> LateLintPass<'tcx> for CognitiveComplexity {
     }
 }
 
+/// Computes the cognitive complexity of a function body. Every `if`, `match` and loop adds one,
+/// plus one for each of the `if`s, `match`es and loops it is nested in. `else if`,
+/// `else`, labeled `break`s and `continue`s and every sequence of the same boolean operator add
+/// one, regardless of the nesting. Desugarings like `?` and code from macros are not counted.
+/// Closures are scored on their own.
+#[derive(Default)]
 struct CcHelper {
-    cc: u64,
-    returns: u64,
+    nesting: u64,
+    branches: u64,
+    loops: u64,
+    bool_ops: u64,
+    jumps: u64,
+    nesting_penalty: u64,
+}
+
+impl CcHelper {
+    fn score(&self) -> u64 {
+        self.branches + self.loops + self.bool_ops + self.jumps + self.nesting_penalty
+    }
+
+    fn breakdown(&self) -> String {
+        let parts = [
+            (self.branches, "`if`, `else` and `match`"),
+            (self.loops, "loops"),
+            (self.bool_ops, "boolean operators"),
+            (self.jumps, "labeled `break` and `continue`"),
+            (self.nesting_penalty, "nesting"),
+        ];
+        parts
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, what)| format!("{} for {}", count, what))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn visit_nested(&mut self, e: &'tcx Expr<'tcx>) {
+        self.nesting += 1;
+        self.visit_expr(e);
+        self.nesting -= 1;
+    }
+
+    /// Visits an `if` or `if let` with its `else if`s and `else`. Only the first `if` adds to
+    /// the nesting penalty.
+    fn visit_if(&mut self, mut cond: &'tcx Expr<'tcx>, mut then: &'tcx Expr<'tcx>, mut els: Option<&'tcx Expr<'tcx>>) {
+        self.branches += 1;
+        self.nesting_penalty += self.nesting;
+        loop {
+            self.visit_expr(cond);
+            self.visit_nested(then);
+            match els {
+                Some(e) => {
+                    self.branches += 1;
+                    if let Some((else_if_cond, else_if_then, else_if_els)) = if_parts(e) {
+                        cond = else_if_cond;
+                        then = else_if_then;
+                        els = else_if_els;
+                    } else {
+                        self.visit_nested(e);
+                        return;
+                    }
+                },
+                None => return,
+            }
+        }
+    }
+}
+
+/// Returns the condition (or scrutinee), `then` branch and `else` branch of an `if` or `if let`.
+fn if_parts<'tcx>(e: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, Option<&'tcx Expr<'tcx>>)> {
+    if in_macro(e.span) {
+        return None;
+    }
+    match e.kind {
+        ExprKind::If(cond, then, els) => Some((cond, then, els)),
+        ExprKind::Match(scrutinee, [then_arm, else_arm], MatchSource::IfLetDesugar { contains_else_clause }) => {
+            Some((scrutinee, then_arm.body, contains_else_clause.then(|| else_arm.body)))
+        },
+        _ => None,
+    }
 }
 
 impl<'tcx> Visitor<'tcx> for CcHelper {
     type Map = Map<'tcx>;
 
-    fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
-        walk_expr(self, e);
+    fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+        if in_macro(e.span) {
+            walk_expr(self, e);
+            return;
+        }
+        if let Some((cond, then, els)) = if_parts(e) {
+            self.visit_if(cond, then, els);
+            return;
+        }
+
         match e.kind {
-            ExprKind::If(_, _, _) => {
-                self.cc += 1;
+            ExprKind::Match(scrutinee, arms, MatchSource::Normal) => {
+                self.branches += 1;
+                self.nesting_penalty += self.nesting;
+                self.visit_expr(scrutinee);
+                self.nesting += 1;
+                for arm in arms {
+                    self.visit_arm(arm);
+                }
+                self.nesting -= 1;
             },
-            ExprKind::Match(_, arms, _) => {
-                if arms.len() > 1 {
-                    self.cc += 1;
+            ExprKind::Loop(block, ..) => {
+                self.loops += 1;
+                self.nesting_penalty += self.nesting;
+                self.nesting += 1;
+                self.visit_block(block);
+                self.nesting -= 1;
+            },
+            ExprKind::Binary(op, lhs, _) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {
+                // `a && b && c` is a single sequence
+                if !matches!(lhs.kind, ExprKind::Binary(lhs_op, ..) if lhs_op.node == op.node) {
+                    self.bool_ops += 1;
                 }
-                self.cc += arms.iter().filter(|arm| arm.guard.is_some()).count() as u64;
+                walk_expr(self, e);
+            },
+            ExprKind::Break(Destination { label: Some(_), .. }, _)
+            | ExprKind::Continue(Destination { label: Some(_), .. }) => {
+                self.jumps += 1;
+                walk_expr(self, e);
             },
-            ExprKind::Ret(_) => self.returns += 1,
-            _ => {},
+            _ => walk_expr(self, e),
         }
     }
+
     fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
         NestedVisitorMap::None
     }
@@ -1,16 +1,19 @@
-use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note, span_lint_and_then, span_lint_hir_and_then};
 use clippy_utils::paths;
-use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::ty::{implements_trait, implements_trait_with_env, is_copy};
 use clippy_utils::{get_trait_def_id, is_automatically_derived, is_lint_allowed, match_def_path};
 use if_chain::if_chain;
+use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_expr, walk_fn, walk_item, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BlockCheckMode, BodyId, Expr, ExprKind, FnDecl, HirId, Impl, Item, ItemKind, TraitRef, UnsafeSource, Unsafety,
+    BlockCheckMode, BodyId, Constness, Expr, ExprKind, FnDecl, HirId, Impl, Item, ItemKind, TraitRef, UnsafeSource,
+    Unsafety,
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::traits::Reveal;
+use rustc_middle::ty::{self, Binder, GenericParamDefKind, ParamEnv, PredicateKind, TraitPredicate, Ty, TyCtxt};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
 
@@ -38,6 +41,7 @@ declare_clippy_lint! {
     ///     ...
     /// }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub DERIVE_HASH_XOR_EQ,
     correctness,
     "deriving `Hash` but implementing `PartialEq` explicitly"
@@ -89,6 +93,7 @@ declare_clippy_lint! {
     /// #[derive(Ord, PartialOrd, PartialEq, Eq)]
     /// struct Foo;
     /// ```
+    #[clippy::version = "1.47.0"]
     pub DERIVE_ORD_XOR_PARTIAL_ORD,
     correctness,
     "deriving `Ord` but implementing `PartialOrd` explicitly"
@@ -115,6 +120,7 @@ declare_clippy_lint! {
     ///     // ..
     /// }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub EXPL_IMPL_CLONE_ON_COPY,
     pedantic,
     "implementing `Clone` explicitly on `Copy` types"
@@ -149,16 +155,51 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "1.45.0"]
     pub UNSAFE_DERIVE_DESERIALIZE,
     pedantic,
     "deriving `serde::Deserialize` on a type that has methods using `unsafe`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for types that derive `PartialEq` and could implement `Eq`.
+    ///
+    /// **Why is this bad?** If a type `T` derives `PartialEq` and all of its members implement `Eq`,
+    /// then `T` can always implement `Eq`. Implementing `Eq` allows `T` to be used
+    /// in APIs that require `Eq` types. It also allows structs containing `T` to derive
+    /// `Eq` themselves.
+    ///
+    /// **Known problems:** Adding `Eq` to a public type is a commitment, removing it later is a
+    /// breaking change.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(PartialEq)]
+    /// struct Foo {
+    ///     i_am_eq: i32,
+    ///     i_am_eq_too: Vec<String>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(PartialEq, Eq)]
+    /// struct Foo {
+    ///     i_am_eq: i32,
+    ///     i_am_eq_too: Vec<String>,
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub DERIVE_PARTIAL_EQ_WITHOUT_EQ,
+    nursery,
+    "deriving `PartialEq` on a type that can implement `Eq`, without implementing `Eq`"
+}
+
 declare_lint_pass!(Derive => [
     EXPL_IMPL_CLONE_ON_COPY,
     DERIVE_HASH_XOR_EQ,
     DERIVE_ORD_XOR_PARTIAL_ORD,
-    UNSAFE_DERIVE_DESERIALIZE
+    UNSAFE_DERIVE_DESERIALIZE,
+    DERIVE_PARTIAL_EQ_WITHOUT_EQ
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Derive {
@@ -177,6 +218,7 @@ impl<'tcx> LateLintPass<'tcx> for Derive {
 
             if is_automatically_derived {
                 check_unsafe_derive_deserialize(cx, item, trait_ref, ty);
+                check_partial_eq_without_eq(cx, item.span, trait_ref, ty);
             } else {
                 check_copy_clone(cx, item, trait_ref, ty);
             }
@@ -422,3 +464,80 @@ impl<'tcx> Visitor<'tcx> for UnsafeVisitor<'_, 'tcx> {
         NestedVisitorMap::All(self.cx.tcx.hir())
     }
 }
+
+/// Implementation of the `DERIVE_PARTIAL_EQ_WITHOUT_EQ` lint.
+fn check_partial_eq_without_eq<'tcx>(cx: &LateContext<'tcx>, span: Span, trait_ref: &TraitRef<'_>, ty: Ty<'tcx>) {
+    if_chain! {
+        if let ty::Adt(adt, substs) = *ty.kind();
+        if let Some(eq_trait_def_id) = get_trait_def_id(cx, &paths::EQ_TRAIT);
+        if let Some(def_id) = trait_ref.trait_def_id();
+        if Some(def_id) == cx.tcx.lang_items().eq_trait();
+        let param_env = param_env_for_derived_eq(cx.tcx, adt.did, eq_trait_def_id);
+        if !implements_trait_with_env(cx.tcx, param_env, ty, eq_trait_def_id, &[]);
+        // If all of our fields implement `Eq`, we can implement `Eq` too
+        if adt
+            .all_fields()
+            .map(|f| f.ty(cx.tcx, substs))
+            .all(|ty| implements_trait_with_env(cx.tcx, param_env, ty, eq_trait_def_id, &[]));
+        if let Some(local_did) = adt.did.as_local();
+        then {
+            // The derived impl doesn't have the attributes of the type, so the lint level is taken
+            // from the type
+            let span = span.ctxt().outer_expn_data().call_site;
+            span_lint_hir_and_then(
+                cx,
+                DERIVE_PARTIAL_EQ_WITHOUT_EQ,
+                cx.tcx.hir().local_def_id_to_hir_id(local_did),
+                span,
+                "you are deriving `PartialEq` and can implement `Eq`",
+                |diag| {
+                    diag.span_suggestion(
+                        span,
+                        "consider deriving `Eq` as well",
+                        "PartialEq, Eq".to_string(),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Creates the `ParamEnv` of the `Eq` implementation `#[derive(Eq)]` would generate for the type,
+/// i.e. the bounds of the type plus an `Eq` bound on each type parameter.
+fn param_env_for_derived_eq(tcx: TyCtxt<'_>, did: DefId, eq_trait_id: DefId) -> ParamEnv<'_> {
+    // The type parameters, and whether they still need an `Eq` bound
+    let mut params = tcx
+        .generics_of(did)
+        .params
+        .iter()
+        .map(|p| (p, matches!(p.kind, GenericParamDefKind::Type { .. })))
+        .collect::<Vec<_>>();
+
+    let ty_predicates = tcx.predicates_of(did).predicates;
+    for (p, _) in ty_predicates {
+        if_chain! {
+            if let PredicateKind::Trait(p, _) = p.kind().skip_binder();
+            if p.trait_ref.def_id == eq_trait_id;
+            if let ty::Param(self_ty) = p.trait_ref.self_ty().kind();
+            then {
+                params[self_ty.index as usize].1 = false;
+            }
+        }
+    }
+
+    let eq_bounds = params.iter().filter(|&&(_, needs_eq)| needs_eq).map(|&(param, _)| {
+        let trait_ref = ty::TraitRef::new(
+            eq_trait_id,
+            tcx.mk_substs(std::iter::once(tcx.mk_param_from_def(param))),
+        );
+        tcx.mk_predicate(Binder::dummy(PredicateKind::Trait(
+            TraitPredicate { trait_ref },
+            Constness::NotConst,
+        )))
+    });
+    ParamEnv::new(
+        tcx.mk_predicates(ty_predicates.iter().map(|&(p, _)| p).chain(eq_bounds)),
+        Reveal::UserFacing,
+    )
+}
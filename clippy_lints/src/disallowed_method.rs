@@ -1,11 +1,13 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::fn_def_id;
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_then};
+use clippy_utils::{fn_def_id, get_def_path, is_crate_loaded};
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::{def::Res, def_id::DefId, Crate, Expr};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::Symbol;
+use rustc_span::{Symbol, DUMMY_SP};
+
+use crate::utils::conf;
 
 declare_clippy_lint! {
     /// **What it does:** Denies the configured methods and functions in clippy.toml
@@ -20,7 +22,11 @@ declare_clippy_lint! {
     /// An example clippy.toml configuration:
     /// ```toml
     /// # clippy.toml
-    /// disallowed-methods = ["std::vec::Vec::leak", "std::time::Instant::now"]
+    /// disallowed-methods = [
+    ///     "std::vec::Vec::leak",
+    ///     # The reason is shown in the lint message
+    ///     { path = "std::time::Instant::now", reason = "use the mocked clock instead" },
+    /// ]
     /// ```
     ///
     /// ```rust,ignore
@@ -37,6 +43,7 @@ declare_clippy_lint! {
     /// let mut xs = Vec::new(); // Vec::new is _not_ disallowed in the config.
     /// xs.push(123); // Vec::push is _not_ disallowed in the config.
     /// ```
+    #[clippy::version = "1.49.0"]
     pub DISALLOWED_METHOD,
     nursery,
     "use of a disallowed method call"
@@ -44,18 +51,16 @@ declare_clippy_lint! {
 
 #[derive(Clone, Debug)]
 pub struct DisallowedMethod {
-    disallowed: FxHashSet<Vec<Symbol>>,
-    def_ids: FxHashSet<(DefId, Vec<Symbol>)>,
+    conf_disallowed: Vec<conf::DisallowedPath>,
+    /// Maps the resolved methods to their index in `conf_disallowed`.
+    disallowed: FxHashMap<DefId, usize>,
 }
 
 impl DisallowedMethod {
-    pub fn new(disallowed: &FxHashSet<String>) -> Self {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedPath>) -> Self {
         Self {
-            disallowed: disallowed
-                .iter()
-                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
-                .collect(),
-            def_ids: FxHashSet::default(),
+            conf_disallowed,
+            disallowed: FxHashMap::default(),
         }
     }
 }
@@ -64,30 +69,53 @@ impl_lint_pass!(DisallowedMethod => [DISALLOWED_METHOD]);
 
 impl<'tcx> LateLintPass<'tcx> for DisallowedMethod {
     fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
-        for path in &self.disallowed {
-            let segs = path.iter().map(ToString::to_string).collect::<Vec<_>>();
-            if let Res::Def(_, id) = clippy_utils::path_to_res(cx, &segs.iter().map(String::as_str).collect::<Vec<_>>())
-            {
-                self.def_ids.insert((id, path.clone()));
+        for (index, conf) in self.conf_disallowed.iter().enumerate() {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            match clippy_utils::path_to_res(cx, &segs) {
+                Res::Def(_, id) => {
+                    self.disallowed.insert(id, index);
+                },
+                // Crates that aren't a dependency of the linted crate can't be checked
+                _ if is_crate_loaded(cx, segs[0]) => {
+                    span_lint_and_note(
+                        cx,
+                        DISALLOWED_METHOD,
+                        DUMMY_SP,
+                        &format!(
+                            "`{}` in `disallowed-methods` does not refer to a function or method",
+                            conf.path()
+                        ),
+                        None,
+                        "the path has to be fully qualified, e.g. `std::vec::Vec::leak`",
+                    );
+                },
+                _ => {},
             }
         }
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some(def_id) = fn_def_id(cx, expr) {
-            if self.def_ids.iter().any(|(id, _)| def_id == *id) {
-                let func_path = cx.get_def_path(def_id);
+            if let Some(&index) = self.disallowed.get(&def_id) {
+                let conf = &self.conf_disallowed[index];
+                let func_path = get_def_path(cx, def_id);
                 let func_path_string = func_path
-                    .into_iter()
+                    .iter()
+                    .copied()
                     .map(Symbol::to_ident_string)
                     .collect::<Vec<_>>()
                     .join("::");
 
-                span_lint(
+                span_lint_and_then(
                     cx,
                     DISALLOWED_METHOD,
                     expr.span,
                     &format!("use of a disallowed method `{}`", func_path_string),
+                    |diag| {
+                        if let Some(reason) = conf.reason() {
+                            diag.note(&format!("{} (from clippy.toml)", reason));
+                        }
+                    },
                 );
             }
         }
@@ -1,12 +1,15 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_then};
+use clippy_utils::is_crate_loaded;
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::{
     def::Res, def_id::DefId, Crate, Item, ItemKind, PolyTraitRef, TraitBoundModifier, Ty, TyKind, UseKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::{Span, Symbol};
+use rustc_span::{Span, DUMMY_SP};
+
+use crate::utils::conf;
 
 declare_clippy_lint! {
     /// **What it does:** Denies the configured types in clippy.toml.
@@ -22,7 +25,11 @@ declare_clippy_lint! {
     /// An example clippy.toml configuration:
     /// ```toml
     /// # clippy.toml
-    /// disallowed-methods = ["std::collections::BTreeMap"]
+    /// disallowed-types = [
+    ///     "std::collections::BTreeMap",
+    ///     # The reason is shown in the lint message
+    ///     { path = "std::time::Instant", reason = "use the mocked clock instead" },
+    /// ]
     /// ```
     ///
     /// ```rust,ignore
@@ -35,24 +42,29 @@ declare_clippy_lint! {
     /// // A similar type that is allowed by the config
     /// use std::collections::HashMap;
     /// ```
+    #[clippy::version = "1.55.0"]
     pub DISALLOWED_TYPE,
     nursery,
     "use of a disallowed type"
 }
 #[derive(Clone, Debug)]
 pub struct DisallowedType {
-    disallowed: FxHashSet<Vec<Symbol>>,
-    def_ids: FxHashSet<(DefId, Vec<Symbol>)>,
+    conf_disallowed: Vec<conf::DisallowedPath>,
+    /// Maps the resolved types to their index in `conf_disallowed`.
+    disallowed: FxHashMap<DefId, usize>,
 }
 
 impl DisallowedType {
-    pub fn new(disallowed: &FxHashSet<String>) -> Self {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedPath>) -> Self {
         Self {
-            disallowed: disallowed
-                .iter()
-                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
-                .collect(),
-            def_ids: FxHashSet::default(),
+            conf_disallowed,
+            disallowed: FxHashMap::default(),
+        }
+    }
+
+    fn check_res(&self, cx: &LateContext<'_>, did: DefId, span: Span) {
+        if let Some(&index) = self.disallowed.get(&did) {
+            emit(cx, &self.conf_disallowed[index], span);
         }
     }
 }
@@ -61,11 +73,27 @@ impl_lint_pass!(DisallowedType => [DISALLOWED_TYPE]);
 
 impl<'tcx> LateLintPass<'tcx> for DisallowedType {
     fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
-        for path in &self.disallowed {
-            let segs = path.iter().map(ToString::to_string).collect::<Vec<_>>();
-            if let Res::Def(_, id) = clippy_utils::path_to_res(cx, &segs.iter().map(String::as_str).collect::<Vec<_>>())
-            {
-                self.def_ids.insert((id, path.clone()));
+        for (index, conf) in self.conf_disallowed.iter().enumerate() {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            match clippy_utils::path_to_res(cx, &segs) {
+                Res::Def(_, id) => {
+                    self.disallowed.insert(id, index);
+                },
+                // Crates that aren't a dependency of the linted crate can't be checked
+                _ if is_crate_loaded(cx, segs[0]) => {
+                    span_lint_and_note(
+                        cx,
+                        DISALLOWED_TYPE,
+                        DUMMY_SP,
+                        &format!(
+                            "`{}` in `disallowed-types` does not refer to a type or trait",
+                            conf.path()
+                        ),
+                        None,
+                        "the path has to be fully qualified, e.g. `std::collections::BTreeMap`",
+                    );
+                },
+                _ => {},
             }
         }
     }
@@ -74,9 +102,8 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedType {
         if_chain! {
             if let ItemKind::Use(path, UseKind::Single) = &item.kind;
             if let Res::Def(_, did) = path.res;
-            if let Some((_, name)) = self.def_ids.iter().find(|(id, _)| *id == did);
             then {
-                emit(cx, name, item.span,);
+                self.check_res(cx, did, item.span);
             }
         }
     }
@@ -85,20 +112,15 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedType {
         if_chain! {
             if let TyKind::Path(path) = &ty.kind;
             if let Some(did) = cx.qpath_res(path, ty.hir_id).opt_def_id();
-            if let Some((_, name)) = self.def_ids.iter().find(|(id, _)| *id == did);
             then {
-                emit(cx, name, path.span());
+                self.check_res(cx, did, path.span());
             }
         }
     }
 
     fn check_poly_trait_ref(&mut self, cx: &LateContext<'tcx>, poly: &'tcx PolyTraitRef<'tcx>, _: TraitBoundModifier) {
-        if_chain! {
-            if let Res::Def(_, did) = poly.trait_ref.path.res;
-            if let Some((_, name)) = self.def_ids.iter().find(|(id, _)| *id == did);
-            then {
-                emit(cx, name, poly.trait_ref.path.span);
-            }
+        if let Res::Def(_, did) = poly.trait_ref.path.res {
+            self.check_res(cx, did, poly.trait_ref.path.span);
         }
     }
 
@@ -115,12 +137,16 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedType {
     // }
 }
 
-fn emit(cx: &LateContext<'_>, name: &[Symbol], span: Span) {
-    let name = name.iter().map(|s| s.to_ident_string()).collect::<Vec<_>>().join("::");
-    span_lint(
+fn emit(cx: &LateContext<'_>, conf: &conf::DisallowedPath, span: Span) {
+    span_lint_and_then(
         cx,
         DISALLOWED_TYPE,
         span,
-        &format!("`{}` is not allowed according to config", name),
+        &format!("`{}` is not allowed according to config", conf.path()),
+        |diag| {
+            if let Some(reason) = conf.reason() {
+                diag.note(&format!("{} (from clippy.toml)", reason));
+            }
+        },
     );
 }
@@ -0,0 +1,33 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_ast::ast::Attribute;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::DOC_ON_PRIVATE_IMPORTS;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &hir::Item<'_>, attrs: &[Attribute]) {
+    if item.span.from_expansion()
+        || !matches!(item.kind, hir::ItemKind::Use(..) | hir::ItemKind::ExternCrate(_))
+        || !matches!(item.vis.node, hir::VisibilityKind::Inherited)
+    {
+        return;
+    }
+
+    let mut doc_spans = attrs.iter().filter(|attr| attr.is_doc_comment()).map(|attr| attr.span);
+    if let Some(first) = doc_spans.next() {
+        let span = doc_spans.last().map_or(first, |last| first.to(last));
+        let kind = if let hir::ItemKind::Use(..) = item.kind {
+            "use"
+        } else {
+            "extern crate"
+        };
+        span_lint_and_help(
+            cx,
+            DOC_ON_PRIVATE_IMPORTS,
+            span,
+            &format!("doc comment on a private `{}` item, which rustdoc ignores", kind),
+            None,
+            "use a regular comment (`//`) instead",
+        );
+    }
+}
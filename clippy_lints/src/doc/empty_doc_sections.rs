@@ -0,0 +1,46 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+
+use super::EMPTY_DOC_SECTIONS;
+
+/// The last heading of the docs, as long as no content followed it
+#[derive(Default)]
+pub(super) struct EmptySections {
+    heading: Option<(u32, Span, String)>,
+}
+
+impl EmptySections {
+    /// Called at the start of a heading of level `level`, which ends the section of the last
+    /// heading if it's not a subsection of it.
+    pub fn start_heading(&mut self, cx: &LateContext<'_>, level: u32) {
+        match self.heading {
+            Some((last_level, ..)) if level <= last_level => self.finish(cx),
+            _ => self.heading = None,
+        }
+    }
+
+    /// Called at the end of a heading with its span and title, which starts a new section
+    pub fn end_heading(&mut self, level: u32, span: Span, title: String) {
+        self.heading = Some((level, span, title));
+    }
+
+    /// Called for everything after the headings
+    pub fn content(&mut self) {
+        self.heading = None;
+    }
+
+    /// Called at the end of the docs and of empty sections
+    pub fn finish(&mut self, cx: &LateContext<'_>) {
+        if let Some((_, span, title)) = self.heading.take() {
+            span_lint_and_help(
+                cx,
+                EMPTY_DOC_SECTIONS,
+                span,
+                &format!("the `{}` section of the docs is empty", title.trim()),
+                None,
+                "write the section or remove its heading",
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_ast::ast::{AttrStyle, Attribute};
+use rustc_errors::Applicability;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::source_map::{BytePos, Span};
+use rustc_span::{Pos, SourceFileAndLine};
+
+use super::FOUR_FORWARD_SLASHES;
+
+/// Checks the comment lines in between the attributes `attrs` of the item with the span `span`,
+/// and the ones right before the attributes.
+pub(super) fn check(cx: &LateContext<'_>, span: Span, attrs: &[Attribute]) {
+    if span.from_expansion() {
+        return;
+    }
+    let sm = cx.sess().source_map();
+    let (file, item_line) = match sm.lookup_line(span.lo()) {
+        Ok(SourceFileAndLine { sf, line }) => (sf, line),
+        Err(_) => return,
+    };
+    // The outer attributes, including the doc comments, come before the span of the item
+    let attrs_line = attrs
+        .iter()
+        .filter(|attr| attr.style == AttrStyle::Outer)
+        .filter_map(|attr| sm.lookup_line(attr.span.lo()).ok())
+        .map(|line| line.line)
+        .fold(item_line, Ord::min);
+
+    for line in (0..item_line).rev() {
+        let text = match file.get_line(line) {
+            Some(text) => text,
+            None => return,
+        };
+        let comment = text.trim_start();
+        if !comment.starts_with("//") {
+            if line < attrs_line {
+                break;
+            }
+            continue;
+        }
+        // Comments like `/////////` are used as separators
+        if comment.starts_with("////") && !comment.starts_with("/////") {
+            let lo = file.line_bounds(line).start + BytePos::from_usize(text.len() - comment.len());
+            span_lint_and_sugg(
+                cx,
+                FOUR_FORWARD_SLASHES,
+                Span::new(lo, lo + BytePos(4), span.ctxt()),
+                "this comment with four forward slashes (`////`) looks like a doc comment, but isn't one",
+                "make it a doc comment by removing a slash",
+                "///".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
@@ -1,33 +1,26 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_note};
+mod doc_on_private_imports;
+mod empty_doc_sections;
+mod four_forward_slashes;
+mod markdown;
+mod missing_headers;
+mod needless_doctest_main;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::in_external_macro;
+use clippy_utils::is_entrypoint_fn;
 use clippy_utils::source::first_line_of_span;
-use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{is_entrypoint_fn, is_expn_of, match_panic_def_id, method_chain_args, return_ty};
-use if_chain::if_chain;
+use empty_doc_sections::EmptySections;
 use itertools::Itertools;
-use rustc_ast::ast::{Async, AttrKind, Attribute, FnKind, FnRetTy, ItemKind};
+use rustc_ast::ast::{AttrKind, Attribute};
 use rustc_ast::token::CommentKind;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_data_structures::sync::Lrc;
-use rustc_errors::emitter::EmitterWriter;
-use rustc_errors::Handler;
 use rustc_hir as hir;
-use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
-use rustc_hir::{AnonConst, Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::hir::map::Map;
-use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty;
-use rustc_parse::maybe_new_parser_from_source_str;
-use rustc_parse::parser::ForceCollect;
-use rustc_session::parse::ParseSess;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::edition::Edition;
-use rustc_span::source_map::{BytePos, FilePathMapping, MultiSpan, SourceMap, Span};
-use rustc_span::{sym, FileName, Pos};
-use std::io;
+use rustc_span::source_map::{BytePos, Span};
+use rustc_span::{sym, Pos};
 use std::ops::Range;
-use std::thread;
-use url::Url;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for the presence of `_`, `::` or camel-case words
@@ -62,6 +55,7 @@ declare_clippy_lint! {
     /// /// [SmallVec]: SmallVec
     /// fn main() {}
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub DOC_MARKDOWN,
     pedantic,
     "presence of `_`, `::` or camel-case outside backticks in documentation"
@@ -96,6 +90,7 @@ declare_clippy_lint! {
     ///     unimplemented!();
     /// }
     /// ```
+    #[clippy::version = "1.39.0"]
     pub MISSING_SAFETY_DOC,
     style,
     "`pub unsafe fn` without `# Safety` docs"
@@ -125,6 +120,7 @@ declare_clippy_lint! {
     ///     unimplemented!();
     /// }
     /// ```
+    #[clippy::version = "1.41.0"]
     pub MISSING_ERRORS_DOC,
     pedantic,
     "`pub fn` returns `Result` without `# Errors` in doc comment"
@@ -156,6 +152,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "1.51.0"]
     pub MISSING_PANICS_DOC,
     pedantic,
     "`pub fn` may panic without `# Panics` in doc comment"
@@ -184,30 +181,131 @@ declare_clippy_lint! {
     ///     unimplemented!();
     /// }
     /// ``````
+    #[clippy::version = "1.40.0"]
     pub NEEDLESS_DOCTEST_MAIN,
     style,
     "presence of `fn main() {` in code examples"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for comments starting with four forward slashes (`////`) right
+    /// before an item.
+    ///
+    /// **Why is this bad?** They look like doc comments, but are regular comments, which don't
+    /// show up in the documentation. A doc comment starts with three slashes.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// //// Returns the answer.
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Returns the answer.
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub FOUR_FORWARD_SLASHES,
+    suspicious,
+    "comments with four forward slashes (`////`) that look like doc comments"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for doc comments on private `use` and `extern crate` items.
+    ///
+    /// **Why is this bad?** Private imports don't show up in the documentation, so their doc
+    /// comments are ignored by rustdoc. A regular comment states that.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Used for the lookup table.
+    /// use std::collections::HashMap;
+    /// # fn main() { let _: HashMap<u8, u8> = HashMap::new(); }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// // Used for the lookup table.
+    /// use std::collections::HashMap;
+    /// # fn main() { let _: HashMap<u8, u8> = HashMap::new(); }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub DOC_ON_PRIVATE_IMPORTS,
+    suspicious,
+    "doc comments on private `use` and `extern crate` items, which rustdoc ignores"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for headings in doc comments which are directly followed by
+    /// another heading of the same or a higher level, or by the end of the docs.
+    ///
+    /// **Why is this bad?** The section of the heading is empty. Often its content was forgotten,
+    /// which is especially bad for sections like `# Safety`, as an empty section still keeps
+    /// `missing_safety_doc` quiet.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Reads the byte at `ptr`.
+    /// ///
+    /// /// # Safety
+    /// pub unsafe fn read(ptr: *const u8) -> u8 {
+    ///     *ptr
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Reads the byte at `ptr`.
+    /// ///
+    /// /// # Safety
+    /// ///
+    /// /// `ptr` must be valid for reads.
+    /// pub unsafe fn read(ptr: *const u8) -> u8 {
+    ///     *ptr
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub EMPTY_DOC_SECTIONS,
+    pedantic,
+    "headings in doc comments without content in their section"
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct DocMarkdown {
     valid_idents: FxHashSet<String>,
     in_trait_impl: bool,
+    allow_unwrap: bool,
 }
 
 impl DocMarkdown {
-    pub fn new(valid_idents: FxHashSet<String>) -> Self {
+    pub fn new(valid_idents: FxHashSet<String>, allow_unwrap: bool) -> Self {
         Self {
             valid_idents,
             in_trait_impl: false,
+            allow_unwrap,
         }
     }
 }
 
-impl_lint_pass!(DocMarkdown =>
-    [DOC_MARKDOWN, MISSING_SAFETY_DOC, MISSING_ERRORS_DOC, MISSING_PANICS_DOC, NEEDLESS_DOCTEST_MAIN]
-);
+impl_lint_pass!(DocMarkdown => [
+    DOC_MARKDOWN,
+    MISSING_SAFETY_DOC,
+    MISSING_ERRORS_DOC,
+    MISSING_PANICS_DOC,
+    NEEDLESS_DOCTEST_MAIN,
+    FOUR_FORWARD_SLASHES,
+    DOC_ON_PRIVATE_IMPORTS,
+    EMPTY_DOC_SECTIONS,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx hir::Crate<'_>) {
@@ -216,26 +314,24 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
+        if is_from_use_list(item) {
+            return;
+        }
         let attrs = cx.tcx.hir().attrs(item.hir_id());
         let headers = check_attrs(cx, &self.valid_idents, attrs);
+        four_forward_slashes::check(cx, item.span, attrs);
+        doc_on_private_imports::check(cx, item, attrs);
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, item.def_id.to_def_id()) || in_external_macro(cx.tcx.sess, item.span)) {
-                    let body = cx.tcx.hir().body(body_id);
-                    let mut fpu = FindPanicUnwrap {
-                        cx,
-                        typeck_results: cx.tcx.typeck(item.def_id),
-                        panic_span: None,
-                    };
-                    fpu.visit_expr(&body.value);
-                    lint_for_missing_headers(
+                    missing_headers::check(
                         cx,
                         item.hir_id(),
                         item.span,
                         sig,
                         headers,
                         Some(body_id),
-                        fpu.panic_span,
+                        self.allow_unwrap,
                     );
                 }
             },
@@ -255,9 +351,10 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
         let headers = check_attrs(cx, &self.valid_idents, attrs);
+        four_forward_slashes::check(cx, item.span, attrs);
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
             if !in_external_macro(cx.tcx.sess, item.span) {
-                lint_for_missing_headers(cx, item.hir_id(), item.span, sig, headers, None, None);
+                missing_headers::check(cx, item.hir_id(), item.span, sig, headers, None, self.allow_unwrap);
             }
         }
     }
@@ -265,91 +362,29 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
         let headers = check_attrs(cx, &self.valid_idents, attrs);
+        four_forward_slashes::check(cx, item.span, attrs);
         if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) {
             return;
         }
         if let hir::ImplItemKind::Fn(ref sig, body_id) = item.kind {
-            let body = cx.tcx.hir().body(body_id);
-            let mut fpu = FindPanicUnwrap {
-                cx,
-                typeck_results: cx.tcx.typeck(item.def_id),
-                panic_span: None,
-            };
-            fpu.visit_expr(&body.value);
-            lint_for_missing_headers(
+            missing_headers::check(
                 cx,
                 item.hir_id(),
                 item.span,
                 sig,
                 headers,
                 Some(body_id),
-                fpu.panic_span,
+                self.allow_unwrap,
             );
         }
     }
 }
 
-fn lint_for_missing_headers<'tcx>(
-    cx: &LateContext<'tcx>,
-    hir_id: hir::HirId,
-    span: impl Into<MultiSpan> + Copy,
-    sig: &hir::FnSig<'_>,
-    headers: DocHeaders,
-    body_id: Option<hir::BodyId>,
-    panic_span: Option<Span>,
-) {
-    if !cx.access_levels.is_exported(hir_id) {
-        return; // Private functions do not require doc comments
-    }
-    if !headers.safety && sig.header.unsafety == hir::Unsafety::Unsafe {
-        span_lint(
-            cx,
-            MISSING_SAFETY_DOC,
-            span,
-            "unsafe function's docs miss `# Safety` section",
-        );
-    }
-    if !headers.panics && panic_span.is_some() {
-        span_lint_and_note(
-            cx,
-            MISSING_PANICS_DOC,
-            span,
-            "docs for function which may panic missing `# Panics` section",
-            panic_span,
-            "first possible panic found here",
-        );
-    }
-    if !headers.errors {
-        if is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym::result_type) {
-            span_lint(
-                cx,
-                MISSING_ERRORS_DOC,
-                span,
-                "docs for function returning `Result` missing `# Errors` section",
-            );
-        } else {
-            if_chain! {
-                if let Some(body_id) = body_id;
-                if let Some(future) = cx.tcx.lang_items().future_trait();
-                let typeck = cx.tcx.typeck_body(body_id);
-                let body = cx.tcx.hir().body(body_id);
-                let ret_ty = typeck.expr_ty(&body.value);
-                if implements_trait(cx, ret_ty, future, &[]);
-                if let ty::Opaque(_, subs) = ret_ty.kind();
-                if let Some(gen) = subs.types().next();
-                if let ty::Generator(_, subs, _) = gen.kind();
-                if is_type_diagnostic_item(cx, subs.as_generator().return_ty(), sym::result_type);
-                then {
-                    span_lint(
-                        cx,
-                        MISSING_ERRORS_DOC,
-                        span,
-                        "docs for function returning `Result` missing `# Errors` section",
-                    );
-                }
-            }
-        }
-    }
+/// Checks if the item is one of the items a `use` with a list, like `use a::{b, c};`, is lowered
+/// to besides the item for the whole list. They have the attributes of the whole list, but their
+/// own span, which doesn't start at the visibility anymore.
+fn is_from_use_list(item: &hir::Item<'_>) -> bool {
+    matches!(item.kind, hir::ItemKind::Use(..)) && item.vis.span.lo() != item.span.lo()
 }
 
 /// Cleanup documentation decoration.
@@ -486,12 +521,20 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     let mut in_code = false;
     let mut in_link = None;
     let mut in_heading = false;
+    let mut heading_title = String::new();
+    let mut empty_sections = EmptySections::default();
     let mut is_rust = false;
     let mut edition = None;
     let mut ticks_unbalanced = false;
     let mut text_to_check: Vec<(CowStr<'_>, Span)> = Vec::new();
     let mut paragraph_span = spans.get(0).expect("function isn't called if doc comment is empty").1;
     for (event, range) in events {
+        match event {
+            Start(Heading(level)) => empty_sections.start_heading(cx, level),
+            End(Heading(_)) => {},
+            _ if !in_heading => empty_sections.content(),
+            _ => {},
+        }
         match event {
             Start(CodeBlock(ref kind)) => {
                 in_code = true;
@@ -525,8 +568,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 paragraph_span = first_line_of_span(cx, span);
             },
             End(Heading(_) | Paragraph | Item) => {
-                if let End(Heading(_)) = event {
+                if let End(Heading(level)) = event {
                     in_heading = false;
+                    empty_sections.end_heading(level, paragraph_span, std::mem::take(&mut heading_title));
                 }
                 if ticks_unbalanced {
                     span_lint_and_help(
@@ -539,7 +583,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                     );
                 } else {
                     for (text, span) in text_to_check {
-                        check_text(cx, valid_idents, &text, span);
+                        markdown::check(cx, valid_idents, &text, span);
                     }
                 }
                 text_to_check = Vec::new();
@@ -550,6 +594,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             FootnoteReference(text) | Text(text) => {
                 let (begin, span) = get_current_span(spans, range.start);
                 paragraph_span = paragraph_span.with_hi(span.hi());
+                if in_heading {
+                    heading_title.push_str(&text);
+                }
                 ticks_unbalanced |= text.contains('`') && !in_code;
                 if Some(&text) == in_link.as_ref() || ticks_unbalanced {
                     // Probably a link of the form `<http://example.com>`
@@ -563,7 +610,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 if in_code {
                     if is_rust {
                         let edition = edition.unwrap_or_else(|| cx.tcx.sess.edition());
-                        check_code(cx, &text, edition, span);
+                        needless_doctest_main::check(cx, &text, edition, span);
                     }
                 } else {
                     // Adjust for the beginning of the current `Event`
@@ -573,6 +620,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             },
         }
     }
+    empty_sections.finish(cx);
     headers
 }
 
@@ -583,215 +631,3 @@ fn get_current_span(spans: &[(usize, Span)], idx: usize) -> (usize, Span) {
     };
     spans[index]
 }
-
-fn check_code(cx: &LateContext<'_>, text: &str, edition: Edition, span: Span) {
-    fn has_needless_main(code: String, edition: Edition) -> bool {
-        rustc_driver::catch_fatal_errors(|| {
-            rustc_span::create_session_globals_then(edition, || {
-                let filename = FileName::anon_source_code(&code);
-
-                let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
-                let emitter = EmitterWriter::new(box io::sink(), None, false, false, false, None, false);
-                let handler = Handler::with_emitter(false, None, box emitter);
-                let sess = ParseSess::with_span_handler(handler, sm);
-
-                let mut parser = match maybe_new_parser_from_source_str(&sess, filename, code) {
-                    Ok(p) => p,
-                    Err(errs) => {
-                        for mut err in errs {
-                            err.cancel();
-                        }
-                        return false;
-                    },
-                };
-
-                let mut relevant_main_found = false;
-                loop {
-                    match parser.parse_item(ForceCollect::No) {
-                        Ok(Some(item)) => match &item.kind {
-                            // Tests with one of these items are ignored
-                            ItemKind::Static(..)
-                            | ItemKind::Const(..)
-                            | ItemKind::ExternCrate(..)
-                            | ItemKind::ForeignMod(..) => return false,
-                            // We found a main function ...
-                            ItemKind::Fn(box FnKind(_, sig, _, Some(block))) if item.ident.name == sym::main => {
-                                let is_async = matches!(sig.header.asyncness, Async::Yes { .. });
-                                let returns_nothing = match &sig.decl.output {
-                                    FnRetTy::Default(..) => true,
-                                    FnRetTy::Ty(ty) if ty.kind.is_unit() => true,
-                                    FnRetTy::Ty(_) => false,
-                                };
-
-                                if returns_nothing && !is_async && !block.stmts.is_empty() {
-                                    // This main function should be linted, but only if there are no other functions
-                                    relevant_main_found = true;
-                                } else {
-                                    // This main function should not be linted, we're done
-                                    return false;
-                                }
-                            },
-                            // Another function was found; this case is ignored too
-                            ItemKind::Fn(..) => return false,
-                            _ => {},
-                        },
-                        Ok(None) => break,
-                        Err(mut e) => {
-                            e.cancel();
-                            return false;
-                        },
-                    }
-                }
-
-                relevant_main_found
-            })
-        })
-        .ok()
-        .unwrap_or_default()
-    }
-
-    // Because of the global session, we need to create a new session in a different thread with
-    // the edition we need.
-    let text = text.to_owned();
-    if thread::spawn(move || has_needless_main(text, edition))
-        .join()
-        .expect("thread::spawn failed")
-    {
-        span_lint(cx, NEEDLESS_DOCTEST_MAIN, span, "needless `fn main` in doctest");
-    }
-}
-
-fn check_text(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, text: &str, span: Span) {
-    for word in text.split(|c: char| c.is_whitespace() || c == '\'') {
-        // Trim punctuation as in `some comment (see foo::bar).`
-        //                                                   ^^
-        // Or even as in `_foo bar_` which is emphasized.
-        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
-
-        if valid_idents.contains(word) {
-            continue;
-        }
-
-        // Adjust for the current word
-        let offset = word.as_ptr() as usize - text.as_ptr() as usize;
-        let span = Span::new(
-            span.lo() + BytePos::from_usize(offset),
-            span.lo() + BytePos::from_usize(offset + word.len()),
-            span.ctxt(),
-        );
-
-        check_word(cx, word, span);
-    }
-}
-
-fn check_word(cx: &LateContext<'_>, word: &str, span: Span) {
-    /// Checks if a string is camel-case, i.e., contains at least two uppercase
-    /// letters (`Clippy` is ok) and one lower-case letter (`NASA` is ok).
-    /// Plurals are also excluded (`IDs` is ok).
-    fn is_camel_case(s: &str) -> bool {
-        if s.starts_with(|c: char| c.is_digit(10)) {
-            return false;
-        }
-
-        let s = s.strip_suffix('s').unwrap_or(s);
-
-        s.chars().all(char::is_alphanumeric)
-            && s.chars().filter(|&c| c.is_uppercase()).take(2).count() > 1
-            && s.chars().filter(|&c| c.is_lowercase()).take(1).count() > 0
-    }
-
-    fn has_underscore(s: &str) -> bool {
-        s != "_" && !s.contains("\\_") && s.contains('_')
-    }
-
-    fn has_hyphen(s: &str) -> bool {
-        s != "-" && s.contains('-')
-    }
-
-    if let Ok(url) = Url::parse(word) {
-        // try to get around the fact that `foo::bar` parses as a valid URL
-        if !url.cannot_be_a_base() {
-            span_lint(
-                cx,
-                DOC_MARKDOWN,
-                span,
-                "you should put bare URLs between `<`/`>` or make a proper Markdown link",
-            );
-
-            return;
-        }
-    }
-
-    // We assume that mixed-case words are not meant to be put inside bacticks. (Issue #2343)
-    if has_underscore(word) && has_hyphen(word) {
-        return;
-    }
-
-    if has_underscore(word) || word.contains("::") || is_camel_case(word) {
-        span_lint(
-            cx,
-            DOC_MARKDOWN,
-            span,
-            &format!("you should put `{}` between ticks in the documentation", word),
-        );
-    }
-}
-
-struct FindPanicUnwrap<'a, 'tcx> {
-    cx: &'a LateContext<'tcx>,
-    panic_span: Option<Span>,
-    typeck_results: &'tcx ty::TypeckResults<'tcx>,
-}
-
-impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
-    type Map = Map<'tcx>;
-
-    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-        if self.panic_span.is_some() {
-            return;
-        }
-
-        // check for `begin_panic`
-        if_chain! {
-            if let ExprKind::Call(func_expr, _) = expr.kind;
-            if let ExprKind::Path(QPath::Resolved(_, path)) = func_expr.kind;
-            if let Some(path_def_id) = path.res.opt_def_id();
-            if match_panic_def_id(self.cx, path_def_id);
-            if is_expn_of(expr.span, "unreachable").is_none();
-            if !is_expn_of_debug_assertions(expr.span);
-            then {
-                self.panic_span = Some(expr.span);
-            }
-        }
-
-        // check for `assert_eq` or `assert_ne`
-        if is_expn_of(expr.span, "assert_eq").is_some() || is_expn_of(expr.span, "assert_ne").is_some() {
-            self.panic_span = Some(expr.span);
-        }
-
-        // check for `unwrap`
-        if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-            let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
-            if is_type_diagnostic_item(self.cx, reciever_ty, sym::option_type)
-                || is_type_diagnostic_item(self.cx, reciever_ty, sym::result_type)
-            {
-                self.panic_span = Some(expr.span);
-            }
-        }
-
-        // and check sub-expressions
-        intravisit::walk_expr(self, expr);
-    }
-
-    // Panics in const blocks will cause compilation to fail.
-    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
-
-    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
-        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
-    }
-}
-
-fn is_expn_of_debug_assertions(span: Span) -> bool {
-    const MACRO_NAMES: &[&str] = &["debug_assert", "debug_assert_eq", "debug_assert_ne"];
-    MACRO_NAMES.iter().any(|name| is_expn_of(span, name).is_some())
-}
@@ -1,8 +1,10 @@
 use clippy_utils::{
     can_move_expr_to_closure_no_visit,
     diagnostics::span_lint_and_sugg,
-    is_expr_final_block_expr, is_expr_used_or_unified, match_def_path, paths, peel_hir_expr_while,
+    is_expr_final_block_expr, is_expr_used_or_unified, match_def_path, path_to_local, paths, peel_hir_expr_while,
     source::{reindent_multiline, snippet_indent, snippet_with_applicability, snippet_with_context},
+    ty::is_copy,
+    visitors::is_expr_pure,
     SpanlessEq,
 };
 use rustc_errors::Applicability;
@@ -21,7 +23,9 @@ declare_clippy_lint! {
     ///
     /// **Why is this bad?** Using `entry` is more efficient.
     ///
-    /// **Known problems:** The suggestion may have type inference errors in some cases. e.g.
+    /// **Known problems:** The suggestion is only machine applicable if it doesn't use the key or
+    /// the map while the entry is alive. The suggestion may also have type inference errors in
+    /// some cases. e.g.
     /// ```rust
     /// let mut map = std::collections::HashMap::new();
     /// let _ = if !map.contains_key(&0) {
@@ -49,6 +53,7 @@ declare_clippy_lint! {
     /// # let v = 1;
     /// map.entry(k).or_insert(v);
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub MAP_ENTRY,
     perf,
     "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"
@@ -74,6 +79,10 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
         };
 
         let mut app = Applicability::MachineApplicable;
+        // `entry` moves the key, so it can't be used again in the branches
+        if then_search.is_key_used {
+            app = Applicability::MaybeIncorrect;
+        }
         let map_str = snippet_with_context(cx, contains_expr.map.span, contains_expr.call_ctxt, "..", &mut app).0;
         let key_str = snippet_with_context(cx, contains_expr.key.span, contains_expr.call_ctxt, "..", &mut app).0;
         let sugg = if let Some(else_expr) = else_expr {
@@ -81,12 +90,21 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                 Some(search) => search,
                 None => return,
             };
+            if else_search.is_key_used {
+                app = Applicability::MaybeIncorrect;
+            }
 
             if then_search.edits.is_empty() && else_search.edits.is_empty() {
                 // No insertions
                 return;
             } else if then_search.edits.is_empty() || else_search.edits.is_empty() {
                 // if .. { insert } else { .. } or if .. { .. } else { insert }
+                // The entry keeps borrowing the map in the branch without the insertion if it needs to be dropped
+                if (then_search.edits.is_empty() && then_search.is_map_used)
+                    || (else_search.edits.is_empty() && else_search.is_map_used)
+                {
+                    app = Applicability::MaybeIncorrect;
+                }
                 let ((then_str, entry_kind), else_str) = match (else_search.edits.is_empty(), contains_expr.negated) {
                     (true, true) => (
                         then_search.snippet_vacant(cx, then_expr.span, &mut app),
@@ -166,7 +184,7 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
             } else if let Some(insertion) = then_search.as_single_insertion() {
                 let value_str = snippet_with_context(cx, insertion.value.span, then_expr.span.ctxt(), "..", &mut app).0;
                 if contains_expr.negated {
-                    if insertion.value.can_have_side_effects() {
+                    if !is_expr_pure(cx, insertion.value) {
                         format!("{}.entry({}).or_insert_with(|| {});", map_str, key_str, value_str)
                     } else {
                         format!("{}.entry({}).or_insert({});", map_str, key_str, value_str)
@@ -329,6 +347,12 @@ struct InsertSearcher<'cx, 'tcx> {
     is_single_insert: bool,
     /// If the visitor has seen the map being used.
     is_map_used: bool,
+    /// If the visitor has seen the map being used after an insertion on the same code path.
+    is_map_used_after_insert: bool,
+    /// The local used as the key, if its type isn't `Copy`. It's moved into the entry.
+    key_local: Option<HirId>,
+    /// If the visitor has seen the key local being used outside of the insertions.
+    is_key_used: bool,
     /// The locations where changes need to be made for the suggestion.
     edits: Vec<Edit<'tcx>>,
     /// A stack of loops the visitor is currently in.
@@ -442,15 +466,21 @@ impl<'tcx> Visitor<'tcx> for InsertSearcher<'_, 'tcx> {
                     call: expr,
                     value: insert_expr.value,
                 }));
-                self.is_map_used = true;
                 self.allow_insert_closure &= self.in_tail_pos;
 
                 // The value doesn't affect whether there is only a single insert expression.
                 let is_single_insert = self.is_single_insert;
                 self.visit_non_tail_expr(insert_expr.value);
                 self.is_single_insert = is_single_insert;
+                // The value is evaluated while the entry borrows the map
+                if self.is_map_used {
+                    self.can_use_entry = false;
+                    return;
+                }
+                self.is_map_used = true;
             },
             _ if SpanlessEq::new(self.cx).eq_expr(self.map, expr) => {
+                self.is_map_used_after_insert |= self.is_map_used && !self.edits.is_empty();
                 self.is_map_used = true;
             },
             _ => match expr.kind {
@@ -492,6 +522,9 @@ impl<'tcx> Visitor<'tcx> for InsertSearcher<'_, 'tcx> {
                     self.can_use_entry = false;
                 },
                 _ => {
+                    if self.key_local.is_some() && path_to_local(expr) == self.key_local {
+                        self.is_key_used = true;
+                    }
                     self.allow_insert_closure &= !self.in_tail_pos;
                     self.allow_insert_closure &= can_move_expr_to_closure_no_visit(self.cx, expr, &self.loops);
                     // Sub expressions are no longer in the tail position.
@@ -508,6 +541,9 @@ struct InsertSearchResults<'tcx> {
     edits: Vec<Edit<'tcx>>,
     allow_insert_closure: bool,
     is_single_insert: bool,
+    is_map_used: bool,
+    is_map_used_after_insert: bool,
+    is_key_used: bool,
 }
 impl InsertSearchResults<'tcx> {
     fn as_single_insertion(&self) -> Option<Insertion<'tcx>> {
@@ -546,6 +582,11 @@ impl InsertSearchResults<'tcx> {
     }
 
     fn snippet_occupied(&self, cx: &LateContext<'_>, span: Span, app: &mut Applicability) -> (String, &'static str) {
+        // Unlike a vacant entry, an occupied entry isn't consumed by the insertion. It keeps borrowing
+        // the map until it's dropped if it needs to be dropped.
+        if self.is_map_used_after_insert {
+            *app = Applicability::MaybeIncorrect;
+        }
         (
             self.snippet(cx, span, app, |res, insertion, ctxt, app| {
                 // Insertion into a map would return `Some(&mut value)`, but the entry returns `&mut value`
@@ -622,6 +663,10 @@ fn find_insert_calls(
         ctxt: expr.span.ctxt(),
         edits: Vec::new(),
         is_map_used: false,
+        is_map_used_after_insert: false,
+        key_local: path_to_local(contains_expr.key)
+            .filter(|_| !is_copy(cx, cx.typeck_results().expr_ty(contains_expr.key))),
+        is_key_used: false,
         allow_insert_closure: true,
         can_use_entry: true,
         in_tail_pos: true,
@@ -631,10 +676,16 @@ fn find_insert_calls(
     s.visit_expr(expr);
     let allow_insert_closure = s.allow_insert_closure;
     let is_single_insert = s.is_single_insert;
+    let is_map_used = s.is_map_used;
+    let is_map_used_after_insert = s.is_map_used_after_insert;
+    let is_key_used = s.is_key_used;
     let edits = s.edits;
     s.can_use_entry.then(|| InsertSearchResults {
         edits,
         allow_insert_closure,
         is_single_insert,
+        is_map_used,
+        is_map_used_after_insert,
+        is_key_used,
     })
 }
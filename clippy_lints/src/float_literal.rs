@@ -11,7 +11,9 @@ use std::fmt;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for float literals with a precision greater
-    /// than that supported by the underlying type.
+    /// than that supported by the underlying type. Literals which are exactly
+    /// representable, or which only have more trailing zeros than the shortest
+    /// literal of their value, are fine.
     ///
     /// **Why is this bad?** Rust will truncate the literal silently.
     ///
@@ -28,6 +30,7 @@ declare_clippy_lint! {
     /// let v: f64 = 0.123_456_789_9;
     /// println!("{}", v); //  0.123_456_789_9
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub EXCESSIVE_PRECISION,
     style,
     "excessive precision for float literal"
@@ -52,6 +55,7 @@ declare_clippy_lint! {
     /// let _: f32 = 16_777_216.0;
     /// let _: f64 = 16_777_217.0;
     /// ```
+    #[clippy::version = "1.43.0"]
     pub LOSSY_FLOAT_LITERAL,
     restriction,
     "lossy whole number float literals"
@@ -61,11 +65,11 @@ declare_lint_pass!(FloatLiteral => [EXCESSIVE_PRECISION, LOSSY_FLOAT_LITERAL]);
 
 impl<'tcx> LateLintPass<'tcx> for FloatLiteral {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
-        let ty = cx.typeck_results().expr_ty(expr);
         if_chain! {
-            if let ty::Float(fty) = *ty.kind();
             if let hir::ExprKind::Lit(ref lit) = expr.kind;
             if let LitKind::Float(sym, lit_float_ty) = lit.node;
+            let ty = cx.typeck_results().expr_ty(expr);
+            if let ty::Float(fty) = *ty.kind();
             then {
                 let sym_str = sym.as_str();
                 let formatter = FloatFormat::new(&sym_str);
@@ -80,16 +84,17 @@ impl<'tcx> LateLintPass<'tcx> for FloatLiteral {
                     LitFloatType::Suffixed(ast::FloatTy::F64) => Some("f64"),
                     LitFloatType::Unsuffixed => None
                 };
-                let (is_whole, mut float_str) = match fty {
+                // `f32` values are converted to `f64` without loss
+                let (is_whole, mut float_str, value) = match fty {
                     FloatTy::F32 => {
                         let value = sym_str.parse::<f32>().unwrap();
 
-                        (value.fract() == 0.0, formatter.format(value))
+                        (value.fract() == 0.0, formatter.format(value), f64::from(value))
                     },
                     FloatTy::F64 => {
                         let value = sym_str.parse::<f64>().unwrap();
 
-                        (value.fract() == 0.0, formatter.format(value))
+                        (value.fract() == 0.0, formatter.format(value), value)
                     },
                 };
 
@@ -113,7 +118,7 @@ impl<'tcx> LateLintPass<'tcx> for FloatLiteral {
                             Applicability::MachineApplicable,
                         );
                     }
-                } else if digits > max as usize && sym_str != float_str {
+                } else if digits > max as usize && sym_str != float_str && !round_trips(&sym_str, &float_str, value) {
                     span_lint_and_sugg(
                         cx,
                         EXCESSIVE_PRECISION,
@@ -150,6 +155,107 @@ fn count_digits(s: &str) -> usize {
         })
 }
 
+/// Checks if the literal `lit` has the same value as `float_str`, the shortest representation of
+/// the float it's parsed to, or as the float `value` itself, i.e. no precision is lost.
+fn round_trips(lit: &str, float_str: &str, value: f64) -> bool {
+    match Decimal::parse(lit) {
+        Some(lit) => {
+            Decimal::parse(float_str).map_or(false, |shortest| lit == shortest) || lit == Decimal::exact(value)
+        },
+        None => false,
+    }
+}
+
+/// An arbitrary-precision, non-negative decimal number `0.d1d2d3... * 10^exp`. The digits don't
+/// have leading or trailing zeros, so every value has exactly one representation. Zero has no
+/// digits.
+#[derive(Debug, PartialEq)]
+struct Decimal {
+    digits: Vec<u8>,
+    exp: i64,
+}
+
+// The lengths of literals and the exponents of floats are far from overflowing an `i64`
+#[allow(clippy::cast_possible_wrap)]
+impl Decimal {
+    /// Parses a float literal without suffix and underscores, like `1.5`, `2.` or `1e-10`.
+    fn parse(s: &str) -> Option<Self> {
+        let (mantissa, exp) = match s.find(|c| c == 'e' || c == 'E') {
+            Some(pos) => (&s[..pos], s[pos + 1..].parse::<i64>().ok()?),
+            None => (s, 0),
+        };
+        let (int_part, fract_part) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        let digits = int_part
+            .bytes()
+            .chain(fract_part.bytes())
+            .map(|c| c.is_ascii_digit().then(|| c - b'0'))
+            .collect::<Option<Vec<u8>>>()?;
+        Some(Self::new(digits, int_part.len() as i64 + exp))
+    }
+
+    /// Returns the exact value of the finite float `value`, which always has a finite decimal
+    /// expansion, as it's an integer times a power of two.
+    fn exact(value: f64) -> Self {
+        let bits = value.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        // `value` is `mantissa * 2^exp2`, subnormals don't have the implicit leading one
+        let (mantissa, exp2) = if biased_exp == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exp - 1075)
+        };
+
+        // The digits of an integer, least significant first
+        let mut int: Vec<u8> = mantissa.to_string().bytes().rev().map(|c| c - b'0').collect();
+        let mut exp10 = 0;
+        if exp2 >= 0 {
+            for _ in 0..exp2 {
+                mul_small(&mut int, 2);
+            }
+        } else {
+            // `2^-n == 5^n * 10^-n`
+            for _ in 0..-exp2 {
+                mul_small(&mut int, 5);
+            }
+            exp10 = exp2;
+        }
+        int.reverse();
+        let len = int.len() as i64;
+        Self::new(int, len + exp10)
+    }
+
+    /// Creates the decimal `0.digits * 10^exp`, stripping leading and trailing zeros.
+    fn new(mut digits: Vec<u8>, mut exp: i64) -> Self {
+        let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+        digits.drain(..leading_zeros);
+        exp -= leading_zeros as i64;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            exp = 0;
+        }
+        Self { digits, exp }
+    }
+}
+
+/// Multiplies the integer with the decimal digits `digits`, least significant first, by `factor`.
+fn mul_small(digits: &mut Vec<u8>, factor: u8) {
+    let mut carry = 0;
+    for digit in digits.iter_mut() {
+        let product = *digit * factor + carry;
+        *digit = product % 10;
+        carry = product / 10;
+    }
+    if carry > 0 {
+        digits.push(carry);
+    }
+}
+
 enum FloatFormat {
     LowerExp,
     UpperExp,
@@ -177,3 +283,25 @@ impl FloatFormat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Decimal;
+
+    #[test]
+    fn exact_decimal() {
+        let parse = |s| Decimal::parse(s).unwrap();
+        assert_eq!(
+            Decimal::exact(0.1),
+            parse("0.1000000000000000055511151231257827021181583404541015625")
+        );
+        assert_eq!(
+            Decimal::exact(f64::from(0.1_f32)),
+            parse("0.100000001490116119384765625")
+        );
+        assert_eq!(Decimal::exact(1536.0), parse("1.536e3"));
+        assert_eq!(Decimal::exact(0.0), parse("0.000"));
+        assert_eq!(parse("001.2500"), parse("12.5e-1"));
+        assert_ne!(parse("0.1"), parse("0.01"));
+    }
+}
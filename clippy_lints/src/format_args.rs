@@ -0,0 +1,160 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::higher::{FormatArgsArg, FormatArgsExpn, FormatExpn};
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{get_trait_def_id, match_def_path, paths};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::hygiene::{ExpnData, ExpnKind, MacroKind};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects `format!` within the arguments of another macro that does
+    /// formatting such as `format!` itself, `write!` or `println!`. Suggests inlining the
+    /// `format!` call.
+    ///
+    /// **Why is this bad?** The recommended code is both shorter and avoids a temporary allocation.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::panic::Location;
+    /// println!("error: {}", format!("something failed at {}", Location::caller()));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::panic::Location;
+    /// println!("error: something failed at {}", Location::caller());
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub FORMAT_IN_FORMAT_ARGS,
+    perf,
+    "`format!` used in a macro that does formatting"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
+    /// applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+    /// in a macro that does formatting.
+    ///
+    /// **Why is this bad?** Since the type implements `Display`, the use of `to_string` is
+    /// unnecessary and only allocates a temporary `String`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::panic::Location;
+    /// println!("error: something failed at {}", Location::caller().to_string());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::panic::Location;
+    /// println!("error: something failed at {}", Location::caller());
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub TO_STRING_IN_FORMAT_ARGS,
+    perf,
+    "`to_string` applied to a type that implements `Display` in format args"
+}
+
+declare_lint_pass!(FormatArgs => [FORMAT_IN_FORMAT_ARGS, TO_STRING_IN_FORMAT_ARGS]);
+
+/// The macros whose arguments are checked, they all pass their arguments to `format_args!`
+const FORMAT_MACROS: [&str; 8] = [
+    "eprint",
+    "eprintln",
+    "format",
+    "format_args",
+    "print",
+    "println",
+    "write",
+    "writeln",
+];
+
+impl<'tcx> LateLintPass<'tcx> for FormatArgs {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let Some(format_args) = FormatArgsExpn::parse(expr);
+            let outermost_expn_data = outermost_expn_data(expr.span.ctxt().outer_expn_data());
+            if let ExpnKind::Macro(MacroKind::Bang, name) = outermost_expn_data.kind;
+            if FORMAT_MACROS.contains(&&*name.as_str());
+            if !outermost_expn_data.call_site.from_expansion();
+            if let Some(args) = format_args.args();
+            then {
+                for (i, arg) in args.iter().enumerate() {
+                    let is_display = arg.format_trait().map_or(false, |t| t.as_str() == "Display");
+                    if !is_display || arg.has_string_formatting() {
+                        continue;
+                    }
+                    // With e.g. `{0} {0:?}` the value is still needed as is for the other argument
+                    if is_aliased(&args, i) {
+                        continue;
+                    }
+                    check_format_in_format_args(cx, name, arg.value);
+                    check_to_string_in_format_args(cx, name, arg.value);
+                }
+            }
+        }
+    }
+}
+
+/// Walks up the macro backtrace to the outermost macro call, e.g. from the `format_args_nl!`
+/// expansion to the `println!` call.
+fn outermost_expn_data(expn_data: ExpnData) -> ExpnData {
+    if expn_data.call_site.from_expansion() {
+        outermost_expn_data(expn_data.call_site.ctxt().outer_expn_data())
+    } else {
+        expn_data
+    }
+}
+
+fn is_aliased(args: &[FormatArgsArg<'_>], i: usize) -> bool {
+    args.iter()
+        .enumerate()
+        .any(|(j, arg)| j != i && arg.value.hir_id == args[i].value.hir_id)
+}
+
+fn check_format_in_format_args(cx: &LateContext<'_>, name: Symbol, arg: &Expr<'_>) {
+    if let Some(FormatExpn { call_site, .. }) = FormatExpn::parse(arg) {
+        span_lint_and_then(
+            cx,
+            FORMAT_IN_FORMAT_ARGS,
+            call_site,
+            &format!("`format!` in `{}!` args", name),
+            |diag| {
+                diag.help(&format!(
+                    "combine the `format!(..)` arguments with the outer `{}!(..)` call",
+                    name
+                ));
+                diag.help("or consider changing `format!` to `format_args!`");
+            },
+        );
+    }
+}
+
+fn check_to_string_in_format_args(cx: &LateContext<'tcx>, name: Symbol, value: &Expr<'_>) {
+    if_chain! {
+        if !value.span.from_expansion();
+        if let ExprKind::MethodCall(_, _, [receiver], _) = value.kind;
+        if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(value.hir_id);
+        if match_def_path(cx, method_def_id, &paths::TO_STRING_METHOD);
+        if let Some(display_trait_id) = get_trait_def_id(cx, &paths::DISPLAY_TRAIT);
+        // Without an auto-deref, the receiver formats the same as the `String` it is turned into
+        if implements_trait(cx, cx.typeck_results().expr_ty(receiver), display_trait_id, &[]);
+        then {
+            span_lint_and_sugg(
+                cx,
+                TO_STRING_IN_FORMAT_ARGS,
+                value.span.with_lo(receiver.span.hi()),
+                &format!("`to_string` applied to a type that implements `Display` in `{}!` args", name),
+                "remove this",
+                String::new(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
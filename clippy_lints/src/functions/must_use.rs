@@ -5,13 +5,13 @@ use rustc_hir::{self as hir, def::Res, intravisit, QPath};
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::{
     hir::map::Map,
-    lint::in_external_macro,
     ty::{self, Ty},
 };
 use rustc_span::{sym, Span};
 
 use clippy_utils::attrs::is_proc_macro;
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::in_external_macro;
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_must_use_ty;
 use clippy_utils::{match_def_path, must_use_attr, return_ty, trait_ref_of_method};
@@ -110,7 +110,7 @@ fn check_needless_must_use(
                 diag.span_suggestion(
                     attr.span,
                     "remove the attribute",
-                    "".into(),
+                    String::new(),
                     Applicability::MachineApplicable,
                 );
             },
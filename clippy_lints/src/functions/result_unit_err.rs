@@ -1,6 +1,6 @@
+use clippy_utils::in_external_macro;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_span::{sym, Span};
 use rustc_typeck::hir_ty_to_ty;
@@ -1,14 +1,14 @@
+use clippy_utils::in_external_macro;
 use clippy_utils::{
     diagnostics::span_lint_and_sugg,
     get_async_fn_body, is_async_fn,
     source::{snippet_with_applicability, snippet_with_context, walk_span_to_context},
-    visitors::visit_break_exprs,
+    visitors::{expr_diverges, visit_break_exprs},
 };
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{Block, Body, Expr, ExprKind, FnDecl, FnRetTy, HirId};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::{Span, SyntaxContext};
 
@@ -35,6 +35,7 @@ declare_clippy_lint! {
     ///     return x;
     /// }
     /// ```
+    #[clippy::version = "1.33.0"]
     pub IMPLICIT_RETURN,
     restriction,
     "use a return statement like `return expr` instead of an expression"
@@ -184,7 +185,7 @@ fn lint_implicit_returns(
         | ExprKind::Binary(..)
         | ExprKind::Unary(..)
         | ExprKind::Index(..)
-            if cx.typeck_results().expr_ty(expr).is_never() =>
+            if expr_diverges(cx, expr) =>
         {
             LintLocation::Inner
         },
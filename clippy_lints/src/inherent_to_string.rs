@@ -1,9 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::ty::{implements_trait_with_env, is_type_diagnostic_item};
 use clippy_utils::{get_trait_def_id, paths, return_ty, trait_ref_of_method};
 use if_chain::if_chain;
-use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Constness, ImplItem, ImplItemKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::traits::Reveal;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, Binder, ParamEnv, PredicateKind, TraitPredicate, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::sym;
 
@@ -39,6 +43,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "1.38.0"]
     pub INHERENT_TO_STRING,
     style,
     "type implements inherent method `to_string()`, but should instead implement the `Display` trait"
@@ -84,6 +89,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "1.38.0"]
     pub INHERENT_TO_STRING_SHADOW_DISPLAY,
     correctness,
     "type implements inherent method `to_string()`, which gets shadowed by the implementation of the `Display` trait"
@@ -127,17 +133,17 @@ fn show_lint(cx: &LateContext<'_>, item: &ImplItem<'_>) {
     let self_type = self_type.skip_binder().peel_refs();
 
     // Emit either a warning or an error
-    if implements_trait(cx, self_type, display_trait_id, &[]) {
+    if implements_display(cx, self_type, display_trait_id) {
         span_lint_and_help(
             cx,
             INHERENT_TO_STRING_SHADOW_DISPLAY,
             item.span,
             &format!(
                 "type `{}` implements inherent method `to_string(&self) -> String` which shadows the implementation of `Display`",
-                self_type.to_string()
+                self_type
             ),
             None,
-            &format!("remove the inherent method from type `{}`", self_type.to_string()),
+            &format!("remove the inherent method from type `{}`", self_type),
         );
     } else {
         span_lint_and_help(
@@ -146,10 +152,32 @@ fn show_lint(cx: &LateContext<'_>, item: &ImplItem<'_>) {
             item.span,
             &format!(
                 "implementation of inherent method `to_string(&self) -> String` for type `{}`",
-                self_type.to_string()
+                self_type
             ),
             None,
-            &format!("implement trait `Display` for type `{}` instead", self_type.to_string()),
+            &format!("implement trait `Display` for type `{}` instead", self_type),
         );
     }
 }
+
+/// Checks if `self_ty` implements `Display` as soon as its type parameters do. The `impl` block of
+/// `to_string` usually doesn't have the bounds of the `Display` implementation, as in
+/// `impl<T: Display> Display for Wrapper<T>`.
+fn implements_display<'tcx>(cx: &LateContext<'tcx>, self_ty: Ty<'tcx>, display_trait_id: DefId) -> bool {
+    let tcx = cx.tcx;
+    let display_bounds = self_ty.walk().filter_map(|arg| match arg.unpack() {
+        GenericArgKind::Type(ty) if matches!(ty.kind(), ty::Param(_)) => {
+            let trait_ref = ty::TraitRef::new(display_trait_id, tcx.mk_substs(std::iter::once(ty.into())));
+            Some(tcx.mk_predicate(Binder::dummy(PredicateKind::Trait(
+                TraitPredicate { trait_ref },
+                Constness::NotConst,
+            ))))
+        },
+        _ => None,
+    });
+    let param_env = ParamEnv::new(
+        tcx.mk_predicates(cx.param_env.caller_bounds().iter().chain(display_bounds)),
+        Reveal::UserFacing,
+    );
+    implements_trait_with_env(tcx, param_env, self_ty, display_trait_id, &[])
+}
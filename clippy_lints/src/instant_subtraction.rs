@@ -0,0 +1,131 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::source::{snippet, snippet_with_applicability};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::match_type;
+use clippy_utils::{is_expr_path_def_path, is_in_test_module_or_function, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for subtractions of a `Duration` from an `Instant`, with `-` or
+    /// `-=`, and of two `Instant`s with the `-` operator.
+    ///
+    /// **Why is this bad?** `Instant - Duration` panics if the result can't be represented, e.g.
+    /// shortly after booting on platforms measuring the time since boot. `Instant - Instant`
+    /// panics if the second `Instant` is later than the first, which can happen for two calls to
+    /// `Instant::now()` on platforms where the clock isn't actually monotonic.
+    ///
+    /// **Known problems:** The subtraction is linted even if the values are known to be in
+    /// range. The `allow-unchecked-duration-subtraction-in-tests` option allows it in tests. The
+    /// `None` case of `checked_sub` has to be handled by hand, so no fix is suggested for it.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// let start = Instant::now();
+    /// let earlier = start - Duration::from_secs(5);
+    /// let end = Instant::now();
+    /// let elapsed = end - start;
+    /// let elapsed = Instant::now() - start;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// let start = Instant::now();
+    /// let earlier = start.checked_sub(Duration::from_secs(5)).unwrap_or(start);
+    /// let end = Instant::now();
+    /// let elapsed = end.saturating_duration_since(start);
+    /// let elapsed = start.elapsed();
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub UNCHECKED_DURATION_SUBTRACTION,
+    pedantic,
+    "subtracting from an `Instant` with the `-` operator, which can panic"
+}
+
+pub struct InstantSubtraction {
+    allow_in_tests: bool,
+}
+
+impl InstantSubtraction {
+    #[must_use]
+    pub fn new(allow_in_tests: bool) -> Self {
+        Self { allow_in_tests }
+    }
+}
+
+impl_lint_pass!(InstantSubtraction => [UNCHECKED_DURATION_SUBTRACTION]);
+
+impl<'tcx> LateLintPass<'tcx> for InstantSubtraction {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (lhs, rhs) = match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs)
+                if op.node == BinOpKind::Sub && !expr.span.from_expansion() =>
+            {
+                (lhs, rhs)
+            },
+            _ => return,
+        };
+        let typeck = cx.typeck_results();
+        if !match_type(cx, typeck.expr_ty(lhs), &paths::INSTANT) {
+            return;
+        }
+        let rhs_ty = typeck.expr_ty(rhs);
+        let is_duration = match_type(cx, rhs_ty, &paths::DURATION);
+        if !(is_duration || match_type(cx, rhs_ty, &paths::INSTANT)) {
+            return;
+        }
+        if self.allow_in_tests && is_in_test_module_or_function(cx.tcx, expr.hir_id) {
+            return;
+        }
+
+        if is_duration {
+            // `checked_sub(..).unwrap()` would still panic, the caller has to decide what to do instead
+            span_lint_and_help(
+                cx,
+                UNCHECKED_DURATION_SUBTRACTION,
+                expr.span,
+                "unchecked subtraction of a `Duration` from an `Instant`",
+                None,
+                &format!(
+                    "consider using `{}.checked_sub({})` and handling the `None` case",
+                    Sugg::hir(cx, lhs, "..").maybe_par(),
+                    snippet(cx, rhs.span, "..")
+                ),
+            );
+        } else if is_instant_now(cx, lhs) {
+            // `elapsed` reads the clock itself, which may not happen at the same point of the evaluation
+            let mut applicability = Applicability::MaybeIncorrect;
+            let rhs = Sugg::hir_with_applicability(cx, rhs, "..", &mut applicability).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                UNCHECKED_DURATION_SUBTRACTION,
+                expr.span,
+                "unchecked subtraction of two `Instant`s",
+                "try",
+                format!("{}.elapsed()", rhs),
+                applicability,
+            );
+        } else {
+            let mut applicability = Applicability::MachineApplicable;
+            let lhs = Sugg::hir_with_applicability(cx, lhs, "..", &mut applicability).maybe_par();
+            let rhs = snippet_with_applicability(cx, rhs.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                UNCHECKED_DURATION_SUBTRACTION,
+                expr.span,
+                "unchecked subtraction of two `Instant`s",
+                "try",
+                format!("{}.saturating_duration_since({})", lhs, rhs),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks if `expr` is a call to `Instant::now()`.
+fn is_instant_now(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Call(func, []) if is_expr_path_def_path(cx, func, &paths::INSTANT_NOW))
+}
@@ -0,0 +1,135 @@
+mod seek_from_current;
+mod unbuffered_bytes;
+mod unused_io_amount;
+
+use clippy_utils::in_external_macro;
+use rustc_hir::{Expr, ExprKind, Stmt};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for unused written/read amount.
+    ///
+    /// **Why is this bad?** `io::Write::write(_vectored)` and
+    /// `io::Read::read(_vectored)` are not guaranteed to
+    /// process the entire buffer. They return how many bytes were processed, which
+    /// might be smaller
+    /// than a given buffer's length. If you don't need to deal with
+    /// partial-write/read, use
+    /// `write_all`/`read_exact` instead.
+    ///
+    /// **Known problems:** Detects only common patterns.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::io;
+    /// fn foo<W: io::Write>(w: &mut W) -> io::Result<()> {
+    ///     // must be `w.write_all(b"foo")?;`
+    ///     w.write(b"foo")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "pre 1.29.0"]
+    pub UNUSED_IO_AMOUNT,
+    correctness,
+    "unused written/read amount"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Seek::seek` with `SeekFrom::Current(0)`.
+    ///
+    /// **Why is this bad?** It only queries the current position of the stream, which
+    /// `Seek::stream_position` expresses directly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// use std::io::{Seek, SeekFrom};
+    /// # fn f(mut file: std::fs::File) -> std::io::Result<u64> {
+    /// let position = file.seek(SeekFrom::Current(0))?;
+    /// # Ok(position) }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// use std::io::Seek;
+    /// # fn f(mut file: std::fs::File) -> std::io::Result<u64> {
+    /// let position = file.stream_position()?;
+    /// # Ok(position) }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub SEEK_FROM_CURRENT,
+    complexity,
+    "use of `seek(SeekFrom::Current(0))` instead of `stream_position()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Read::bytes` on a `std::fs::File`.
+    ///
+    /// **Why is this bad?** A `File` isn't buffered, so every byte yielded by the iterator is read
+    /// with its own system call. Wrapping the file in a `BufReader` first reads it in large chunks.
+    ///
+    /// Reading the whole file at once, e.g. with `read_to_string` or `read_to_end`, doesn't need a
+    /// `BufReader`, so these calls are not linted.
+    ///
+    /// **Known problems:** Other unbuffered readers, like `TcpStream`, are not detected.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// use std::io::Read;
+    /// # fn f(file: std::fs::File) {
+    /// for byte in file.bytes() {
+    ///     // ..
+    /// }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// use std::io::{BufReader, Read};
+    /// # fn f(file: std::fs::File) {
+    /// for byte in BufReader::new(file).bytes() {
+    ///     // ..
+    /// }
+    /// # }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub UNBUFFERED_BYTES,
+    perf,
+    "calling `bytes()` on an unbuffered `File`"
+}
+
+pub struct Io {
+    msrv: Option<RustcVersion>,
+}
+
+impl Io {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Io => [UNUSED_IO_AMOUNT, SEEK_FROM_CURRENT, UNBUFFERED_BYTES]);
+
+impl<'tcx> LateLintPass<'tcx> for Io {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        if let ExprKind::MethodCall(path, _, args, _) = expr.kind {
+            match (&*path.ident.as_str(), args) {
+                ("seek", [recv, arg]) => seek_from_current::check(cx, expr, recv, arg, &self.msrv),
+                ("bytes", [recv]) => unbuffered_bytes::check(cx, expr, recv),
+                _ => (),
+            }
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        unused_io_amount::check(cx, stmt);
+    }
+
+    extract_msrv_attr!(LateContext);
+}
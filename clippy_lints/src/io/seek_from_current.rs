@@ -0,0 +1,41 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{is_expr_path_def_path, match_trait_method, meets_msrv, msrvs, paths};
+use if_chain::if_chain;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_semver::RustcVersion;
+
+use super::SEEK_FROM_CURRENT;
+
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    arg: &Expr<'_>,
+    msrv: &Option<RustcVersion>,
+) {
+    if_chain! {
+        if meets_msrv(msrv.as_ref(), &msrvs::SEEK_STREAM_POSITION);
+        if match_trait_method(cx, expr, &paths::IO_SEEK);
+        if let ExprKind::Call(func, [offset]) = arg.kind;
+        if is_expr_path_def_path(cx, func, &paths::IO_SEEK_FROM_CURRENT);
+        if let ExprKind::Lit(ref lit) = offset.kind;
+        if let LitKind::Int(0, _) = lit.node;
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let recv = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                SEEK_FROM_CURRENT,
+                expr.span,
+                "using `SeekFrom::Current(0)` to get the current position of the stream",
+                "use `stream_position` instead",
+                format!("{}.stream_position()", recv),
+                applicability,
+            );
+        }
+    }
+}
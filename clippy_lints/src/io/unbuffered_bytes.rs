@@ -0,0 +1,22 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::match_type;
+use clippy_utils::{match_trait_method, paths};
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+
+use super::UNBUFFERED_BYTES;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    if match_trait_method(cx, expr, &paths::IO_READ)
+        && match_type(cx, cx.typeck_results().expr_ty(recv).peel_refs(), &paths::FILE)
+    {
+        span_lint_and_help(
+            cx,
+            UNBUFFERED_BYTES,
+            expr.span,
+            "calling `bytes()` on an unbuffered `File` reads every byte with a separate system call",
+            None,
+            "consider wrapping the file in a `std::io::BufReader` first",
+        );
+    }
+}
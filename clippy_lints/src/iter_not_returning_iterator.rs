@@ -0,0 +1,83 @@
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{return_ty, trait_ref_of_method};
+use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects inherent methods named `iter` or `iter_mut` whose return type
+    /// implements neither `Iterator` nor `IntoIterator`.
+    ///
+    /// **Why is this bad?** Methods named `iter` or `iter_mut` conventionally return an
+    /// `Iterator`, like those of the standard collections. Users of the API expect to be able to
+    /// loop over the result.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// struct Data {}
+    /// impl Data {
+    ///     fn iter(&self) -> &Data {
+    ///         self
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Data {
+    ///     values: Vec<u32>,
+    /// }
+    /// impl Data {
+    ///     fn iter(&self) -> std::slice::Iter<'_, u32> {
+    ///         self.values.iter()
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ITER_NOT_RETURNING_ITERATOR,
+    pedantic,
+    "methods named `iter` or `iter_mut` that do not return an `Iterator`"
+}
+
+declare_lint_pass!(IterNotReturningIterator => [ITER_NOT_RETURNING_ITERATOR]);
+
+impl<'tcx> LateLintPass<'tcx> for IterNotReturningIterator {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        let name = impl_item.ident.name.as_str();
+        if !matches!(&*name, "iter" | "iter_mut") || impl_item.span.from_expansion() {
+            return;
+        }
+        let sig = match &impl_item.kind {
+            ImplItemKind::Fn(sig, _) if sig.decl.implicit_self.has_implicit_self() => sig,
+            _ => return,
+        };
+        // The signature of trait methods is decided by the trait
+        if trait_ref_of_method(cx, impl_item.hir_id()).is_some() {
+            return;
+        }
+
+        // Type aliases are already resolved in the return type, `impl Trait` is checked with the
+        // bounds of the opaque type. Every `Iterator` is also `IntoIterator`.
+        let ret_ty = return_ty(cx, impl_item.hir_id());
+        let ret_ty = cx.tcx.normalize_erasing_regions(cx.param_env, ret_ty);
+        let is_iterable = cx
+            .tcx
+            .get_diagnostic_item(sym::IntoIterator)
+            .map_or(true, |trait_id| implements_trait(cx, ret_ty, trait_id, &[]));
+        if !is_iterable {
+            span_lint(
+                cx,
+                ITER_NOT_RETURNING_ITERATOR,
+                sig.span,
+                &format!(
+                    "this method is named `{}` but its return type does not implement `Iterator`",
+                    name
+                ),
+            );
+        }
+    }
+}
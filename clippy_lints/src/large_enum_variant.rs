@@ -1,13 +1,22 @@
 //! lint when there is a large size difference between variants on an enum
 
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::in_external_macro;
 use clippy_utils::source::snippet_opt;
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind, VariantData};
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_body, walk_crate, walk_expr, walk_pat, NestedVisitorMap, Visitor};
+use rustc_hir::pat_util::EnumerateAndAdjustIterator;
+use rustc_hir::{Body, Expr, ExprKind, HirId, Item, ItemKind, Pat, PatKind, QPath};
+use rustc_index::vec::Idx;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::lint::in_external_macro;
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::{TypeckResults, VariantDef};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_target::abi::LayoutOf;
+use rustc_span::symbol::Symbol;
+use rustc_span::Span;
+use rustc_target::abi::{LayoutOf, VariantIdx};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for large size differences between variants on
@@ -22,6 +31,9 @@ declare_clippy_lint! {
     /// the overhead is negligible and the boxing is counter-productive. Always
     /// measure the change this lint suggests.
     ///
+    /// The suggestion also wraps the fields in `Box::new` where the variant is constructed in the
+    /// same crate. Patterns binding the boxed fields have to be changed by hand.
+    ///
     /// **Example:**
     ///
     /// ```rust
@@ -37,6 +49,7 @@ declare_clippy_lint! {
     ///     B(Box<[i32; 8000]>),
     /// }
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub LARGE_ENUM_VARIANT,
     perf,
     "large size difference between variants on an enum"
@@ -58,81 +71,291 @@ impl LargeEnumVariant {
 
 impl_lint_pass!(LargeEnumVariant => [LARGE_ENUM_VARIANT]);
 
+struct VariantInfo {
+    ind: usize,
+    size: u64,
+    /// Whether the size is known exactly, which is not the case for fields whose layout depends on
+    /// generic parameters
+    exact: bool,
+    /// The indices and sizes of the fields with a known layout, the largest first
+    field_sizes: Vec<(usize, u64)>,
+}
+
 impl<'tcx> LateLintPass<'tcx> for LargeEnumVariant {
-    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         if in_external_macro(cx.tcx.sess, item.span) {
             return;
         }
         if let ItemKind::Enum(ref def, _) = item.kind {
             let ty = cx.tcx.type_of(item.def_id);
             let adt = ty.ty_adt_def().expect("already checked whether this is an enum");
+            if adt.variants.len() < 2 {
+                return;
+            }
+
+            // The layout of the enum includes the tag and padding of every variant, but it is only
+            // available if it doesn't depend on generic parameters
+            let enum_layout = cx.layout_of(ty).ok();
+            let mut variants: Vec<VariantInfo> = adt
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(ind, variant)| {
+                    let field_layouts: Vec<_> = variant
+                        .fields
+                        .iter()
+                        .map(|f| cx.layout_of(cx.tcx.type_of(f.did)).ok())
+                        .collect();
+                    let mut field_sizes: Vec<_> = field_layouts
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(i, layout)| layout.map(|l| (i, l.size.bytes())))
+                        .collect();
+                    field_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+
+                    let (size, exact) = match enum_layout {
+                        Some(layout) => (layout.for_variant(cx, VariantIdx::new(ind)).size.bytes(), true),
+                        None => (
+                            field_sizes.iter().map(|&(_, size)| size).sum(),
+                            field_layouts.iter().all(Option::is_some),
+                        ),
+                    };
+                    VariantInfo {
+                        ind,
+                        size,
+                        exact,
+                        field_sizes,
+                    }
+                })
+                .collect();
+            variants.sort_by(|a, b| b.size.cmp(&a.size));
 
-            let mut largest_variant: Option<(_, _)> = None;
-            let mut second_variant: Option<(_, _)> = None;
-
-            for (i, variant) in adt.variants.iter().enumerate() {
-                let size: u64 = variant
-                    .fields
-                    .iter()
-                    .filter_map(|f| {
-                        let ty = cx.tcx.type_of(f.did);
-                        // don't count generics by filtering out everything
-                        // that does not have a layout
-                        cx.layout_of(ty).ok().map(|l| l.size.bytes())
-                    })
-                    .sum();
-
-                let grouped = (size, (i, variant));
-
-                if grouped.0 >= largest_variant.map_or(0, |x| x.0) {
-                    second_variant = largest_variant;
-                    largest_variant = Some(grouped);
+            // If the size of any other variant is only a lower bound, it might be just as large
+            if variants[1..].iter().any(|v| !v.exact) {
+                return;
+            }
+            let (largest, second) = (&variants[0], &variants[1]);
+            let difference = largest.size - second.size;
+            if difference <= self.maximum_size_difference_allowed {
+                return;
+            }
+
+            // Box the largest fields until the variant is small enough
+            let pointer_size = cx.tcx.data_layout.pointer_size.bytes();
+            let mut remaining_size = largest.size;
+            let mut fields_to_box = Vec::new();
+            for &(i, size) in &largest.field_sizes {
+                if remaining_size.saturating_sub(second.size) <= self.maximum_size_difference_allowed
+                    || size <= pointer_size
+                {
+                    break;
                 }
+                fields_to_box.push(i);
+                remaining_size -= size - pointer_size;
             }
 
-            if let (Some(largest), Some(second)) = (largest_variant, second_variant) {
-                let difference = largest.0 - second.0;
-
-                if difference > self.maximum_size_difference_allowed {
-                    let (i, variant) = largest.1;
-
-                    let help_text = "consider boxing the large fields to reduce the total size of the enum";
-                    span_lint_and_then(
-                        cx,
-                        LARGE_ENUM_VARIANT,
-                        def.variants[i].span,
-                        "large size difference between variants",
-                        |diag| {
-                            diag.span_label(
-                                def.variants[(largest.1).0].span,
-                                &format!("this variant is {} bytes", largest.0),
-                            );
-                            diag.span_note(
-                                def.variants[(second.1).0].span,
-                                &format!("and the second-largest variant is {} bytes:", second.0),
-                            );
-                            if variant.fields.len() == 1 {
-                                let span = match def.variants[i].data {
-                                    VariantData::Struct(fields, ..) | VariantData::Tuple(fields, ..) => {
-                                        fields[0].ty.span
-                                    },
-                                    VariantData::Unit(..) => unreachable!(),
-                                };
-                                if let Some(snip) = snippet_opt(cx, span) {
-                                    diag.span_suggestion(
-                                        span,
-                                        help_text,
-                                        format!("Box<{}>", snip),
-                                        Applicability::MaybeIncorrect,
-                                    );
-                                    return;
-                                }
-                            }
-                            diag.span_help(def.variants[i].span, help_text);
-                        },
+            let hir_variant = &def.variants[largest.ind];
+            let help_text = "consider boxing the large fields to reduce the total size of the enum";
+            span_lint_and_then(
+                cx,
+                LARGE_ENUM_VARIANT,
+                hir_variant.span,
+                "large size difference between variants",
+                |diag| {
+                    diag.span_label(
+                        hir_variant.span,
+                        &format!(
+                            "this variant is {}{} bytes",
+                            if largest.exact { "" } else { "at least " },
+                            largest.size
+                        ),
                     );
+                    diag.span_note(
+                        def.variants[second.ind].span,
+                        &format!("and the second-largest variant is {} bytes:", second.size),
+                    );
+
+                    let hir_fields = hir_variant.data.fields();
+                    let mut suggestions = Vec::new();
+                    for &i in &fields_to_box {
+                        let span = hir_fields[i].ty.span;
+                        match snippet_opt(cx, span) {
+                            Some(snip) if !span.from_expansion() => suggestions.push((span, format!("Box<{}>", snip))),
+                            _ => {
+                                diag.span_help(hir_variant.span, help_text);
+                                return;
+                            },
+                        }
+                    }
+                    if suggestions.is_empty() {
+                        diag.span_help(hir_variant.span, help_text);
+                        return;
+                    }
+
+                    let (sites, mut applicability) =
+                        construction_sites(cx, &adt.variants[VariantIdx::new(largest.ind)], &fields_to_box);
+                    suggestions.extend(sites);
+                    // Changing the type of a field of a public enum is a breaking change
+                    if cx.access_levels.is_exported(item.hir_id()) {
+                        applicability = Applicability::MaybeIncorrect;
+                    }
+                    diag.multipart_suggestion(help_text, suggestions, applicability);
+                },
+            );
+        }
+    }
+}
+
+/// Finds the expressions for the `boxed_fields` wherever `variant` is constructed in the crate,
+/// and returns the suggestions to wrap them in `Box::new`. The suggestion is only machine
+/// applicable if there are no other uses of the fields which would need to change.
+fn construction_sites(
+    cx: &LateContext<'tcx>,
+    variant: &VariantDef,
+    boxed_fields: &[usize],
+) -> (Vec<(Span, String)>, Applicability) {
+    let mut visitor = ConstructionSiteVisitor {
+        cx,
+        maybe_typeck_results: None,
+        variant_id: variant.def_id,
+        ctor_id: variant.ctor_def_id,
+        field_count: variant.fields.len(),
+        boxed_fields,
+        boxed_field_names: boxed_fields.iter().map(|&i| variant.fields[i].ident.name).collect(),
+        sites: Vec::new(),
+        applicability: Applicability::MachineApplicable,
+    };
+    walk_crate(&mut visitor, cx.tcx.hir().krate());
+
+    let mut suggestions = Vec::new();
+    for (span, shorthand_field) in visitor.sites {
+        let snip = match snippet_opt(cx, span) {
+            Some(snip) => snip,
+            None => {
+                visitor.applicability = Applicability::MaybeIncorrect;
+                continue;
+            },
+        };
+        let sugg = match shorthand_field {
+            // `Variant { name }` becomes `Variant { name: Box::new(name) }`
+            Some(name) => format!("{}: Box::new({})", name, snip),
+            None => format!("Box::new({})", snip),
+        };
+        suggestions.push((span, sugg));
+    }
+    (suggestions, visitor.applicability)
+}
+
+struct ConstructionSiteVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    maybe_typeck_results: Option<&'tcx TypeckResults<'tcx>>,
+    variant_id: DefId,
+    /// The constructor function of tuple variants
+    ctor_id: Option<DefId>,
+    field_count: usize,
+    boxed_fields: &'a [usize],
+    boxed_field_names: Vec<Symbol>,
+    /// The expressions which have to be boxed, and the field name if they are written as a
+    /// shorthand field
+    sites: Vec<(Span, Option<Symbol>)>,
+    applicability: Applicability,
+}
+
+impl<'a, 'tcx> ConstructionSiteVisitor<'a, 'tcx> {
+    fn qpath_res(&self, qpath: &QPath<'_>, hir_id: HirId) -> Res {
+        match (qpath, self.maybe_typeck_results) {
+            (QPath::Resolved(_, path), _) => path.res,
+            (_, Some(typeck_results)) => typeck_results.qpath_res(qpath, hir_id),
+            (_, None) => Res::Err,
+        }
+    }
+
+    fn is_ctor(&self, res: Res) -> bool {
+        matches!(res, Res::Def(DefKind::Ctor(CtorOf::Variant, _), id) if Some(id) == self.ctor_id)
+    }
+
+    fn add_site(&mut self, expr: &Expr<'_>, shorthand_field: Option<Symbol>) {
+        if expr.span.from_expansion() {
+            self.applicability = Applicability::MaybeIncorrect;
+        } else {
+            self.sites.push((expr.span, shorthand_field));
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ConstructionSiteVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_body(&mut self, body: &'tcx Body<'_>) {
+        let old_maybe_typeck_results = self.maybe_typeck_results.replace(self.cx.tcx.typeck_body(body.id()));
+        walk_body(self, body);
+        self.maybe_typeck_results = old_maybe_typeck_results;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Call(func, args) => {
+                if let ExprKind::Path(ref qpath) = func.kind {
+                    if self.is_ctor(self.qpath_res(qpath, func.hir_id)) {
+                        for &i in self.boxed_fields {
+                            self.add_site(&args[i], None);
+                        }
+                        for arg in args {
+                            self.visit_expr(arg);
+                        }
+                        return;
+                    }
                 }
-            }
+            },
+            ExprKind::Struct(qpath, fields, _) => {
+                if self.qpath_res(qpath, expr.hir_id) == Res::Def(DefKind::Variant, self.variant_id) {
+                    for field in fields {
+                        if self.boxed_field_names.contains(&field.ident.name) {
+                            self.add_site(field.expr, field.is_shorthand.then(|| field.ident.name));
+                        }
+                    }
+                }
+            },
+            // The constructor is used as a function, e.g. `.map(Enum::Variant)`
+            ExprKind::Path(ref qpath) => {
+                if self.is_ctor(self.qpath_res(qpath, expr.hir_id)) {
+                    self.applicability = Applicability::MaybeIncorrect;
+                }
+            },
+            _ => {},
         }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'_>) {
+        // Bindings of the boxed fields would get a different type
+        match pat.kind {
+            PatKind::TupleStruct(ref qpath, pats, dotdot) => {
+                if self.is_ctor(self.qpath_res(qpath, pat.hir_id)) {
+                    if pats
+                        .iter()
+                        .enumerate_and_adjust(self.field_count, dotdot)
+                        .any(|(i, p)| self.boxed_fields.contains(&i) && !matches!(p.kind, PatKind::Wild))
+                    {
+                        self.applicability = Applicability::MaybeIncorrect;
+                    }
+                }
+            },
+            PatKind::Struct(ref qpath, fields, _) => {
+                if self.qpath_res(qpath, pat.hir_id) == Res::Def(DefKind::Variant, self.variant_id)
+                    && fields
+                        .iter()
+                        .any(|f| self.boxed_field_names.contains(&f.ident.name) && !matches!(f.pat.kind, PatKind::Wild))
+                {
+                    self.applicability = Applicability::MaybeIncorrect;
+                }
+            },
+            _ => {},
+        }
+        walk_pat(self, pat);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::All(self.cx.tcx.hir())
     }
 }
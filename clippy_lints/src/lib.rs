@@ -46,8 +46,13 @@ extern crate clippy_utils;
 
 use clippy_utils::parse_msrv;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::LintId;
+use rustc_lint::{Level, LintId};
+use rustc_middle::lint::LintSet;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::lint::{Lint, LintArray, LintPass};
 use rustc_session::Session;
+use std::env;
+use std::path::{Path, PathBuf};
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -159,12 +164,15 @@ mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod absurd_extreme_comparisons;
+mod allowed_clippy_lint;
 mod approx_const;
 mod arithmetic;
+mod arithmetic_side_effects;
 mod as_conversions;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assign_ops;
+mod async_blocking_call;
 mod async_yields_async;
 mod atomic_ordering;
 mod attrs;
@@ -174,6 +182,7 @@ mod blacklisted_name;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod booleans;
+mod borrow_deref_ref;
 mod bytecount;
 mod cargo_common_metadata;
 mod case_sensitive_file_extension_comparisons;
@@ -218,6 +227,7 @@ mod float_equality_without_abs;
 mod float_literal;
 mod floating_point_arithmetic;
 mod format;
+mod format_args;
 mod formatting;
 mod from_over_into;
 mod from_str_radix_10;
@@ -238,10 +248,13 @@ mod infinite_iter;
 mod inherent_impl;
 mod inherent_to_string;
 mod inline_fn_without_body;
+mod instant_subtraction;
 mod int_plus_one;
 mod integer_division;
 mod invalid_upcast_comparisons;
+mod io;
 mod items_after_statements;
+mod iter_not_returning_iterator;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_stack_arrays;
@@ -253,10 +266,15 @@ mod literal_representation;
 mod loops;
 mod macro_use;
 mod main_recursion;
+mod manual_assert;
 mod manual_async_fn;
+mod manual_let_else;
 mod manual_map;
 mod manual_non_exhaustive;
 mod manual_ok_or;
+mod manual_retain;
+mod manual_std;
+mod manual_string_new;
 mod manual_strip;
 mod manual_unwrap_or;
 mod map_clone;
@@ -271,10 +289,12 @@ mod methods;
 mod minmax;
 mod misc;
 mod misc_early;
+mod missing_asserts_for_indexing;
 mod missing_const_for_fn;
 mod missing_doc;
 mod missing_enforced_import_rename;
 mod missing_inline;
+mod module_style;
 mod modulo_arithmetic;
 mod multiple_crate_versions;
 mod mut_key;
@@ -288,8 +308,12 @@ mod needless_bitwise_bool;
 mod needless_bool;
 mod needless_borrow;
 mod needless_borrowed_ref;
+mod needless_borrows_for_generic_args;
 mod needless_continue;
 mod needless_for_each;
+mod needless_impls;
+mod needless_late_init;
+mod needless_pass_by_ref_mut;
 mod needless_pass_by_value;
 mod needless_question_mark;
 mod needless_update;
@@ -300,7 +324,9 @@ mod no_effect;
 mod non_copy_const;
 mod non_expressive_names;
 mod non_octal_unix_permissions;
+mod non_send_fields_in_send_ty;
 mod nonstandard_macro_braces;
+mod only_used_in_recursion;
 mod open_options;
 mod option_env_unwrap;
 mod option_if_let_else;
@@ -317,10 +343,13 @@ mod ptr_eq;
 mod ptr_offset_with_cast;
 mod question_mark;
 mod ranges;
+mod rc_clone_in_vec_init;
+mod recursive_format_impl;
 mod redundant_clone;
 mod redundant_closure_call;
 mod redundant_else;
 mod redundant_field_names;
+mod redundant_locals;
 mod redundant_pub_crate;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
@@ -334,6 +363,7 @@ mod self_named_constructor;
 mod semicolon_if_nothing_returned;
 mod serde_api;
 mod shadow;
+mod significant_drop_in_scrutinee;
 mod single_component_path_imports;
 mod size_of_in_element_count;
 mod slow_vector_initialization;
@@ -343,15 +373,16 @@ mod strlen_on_c_strings;
 mod suspicious_operation_groupings;
 mod suspicious_trait_impl;
 mod swap;
+mod swap_ptr_to_ref;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
 mod to_digit_is_some;
-mod to_string_in_display;
 mod trait_bounds;
 mod transmute;
 mod transmuting_null;
 mod try_err;
 mod types;
+mod undocumented_unsafe_blocks;
 mod undropped_manually_drops;
 mod unicode;
 mod unit_return_expecting_ord;
@@ -363,7 +394,6 @@ mod unnecessary_wraps;
 mod unnested_or_patterns;
 mod unsafe_removed_from_name;
 mod unused_async;
-mod unused_io_amount;
 mod unused_self;
 mod unused_unit;
 mod unwrap;
@@ -383,7 +413,6 @@ mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 pub use crate::utils::conf::Conf;
-use crate::utils::conf::TryConf;
 
 /// Register all pre expansion lints
 ///
@@ -400,30 +429,88 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore) {
     store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro);
 }
 
+/// Returns the lint levels set by the `allow`, `warn`, `deny` and `forbid` keys of the
+/// configuration, as if they were passed with `--allow`, `--warn`, etc. in this order. They are
+/// passed to rustc before the arguments of the command line, which override them.
+///
+/// Lint groups come first, so that the levels of single lints override those of their groups,
+/// like in `warn = ["clippy::pedantic"]` and `allow = ["clippy::module_name_repetitions"]`.
 #[doc(hidden)]
-pub fn read_conf(sess: &Session) -> Conf {
-    let file_name = match utils::conf::lookup_conf_file() {
-        Ok(Some(path)) => path,
-        Ok(None) => return Conf::default(),
-        Err(error) => {
-            sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
-                .emit();
-            return Conf::default();
-        },
-    };
+pub fn conf_lint_levels(conf: &Conf) -> Vec<(String, Level)> {
+    // The groups of Clippy are registered by `register_plugins`, which needs the session that
+    // doesn't exist yet when the levels are passed to rustc
+    const CLIPPY_GROUPS: [&str; 11] = [
+        "clippy::all",
+        "clippy::cargo",
+        "clippy::complexity",
+        "clippy::correctness",
+        "clippy::internal",
+        "clippy::nursery",
+        "clippy::pedantic",
+        "clippy::perf",
+        "clippy::restriction",
+        "clippy::style",
+        "clippy::suspicious",
+    ];
 
-    let TryConf { conf, errors } = utils::conf::read(&file_name);
-    // all conf errors are non-fatal, we just use the default conf in case of error
-    for error in errors {
-        sess.struct_err(&format!(
-            "error reading Clippy's configuration file `{}`: {}",
-            file_name.display(),
-            error
-        ))
-        .emit();
+    let mut levels: Vec<(String, Level)> = [
+        (&conf.allow, Level::Allow),
+        (&conf.warn, Level::Warn),
+        (&conf.deny, Level::Deny),
+        (&conf.forbid, Level::Forbid),
+    ]
+    .iter()
+    .flat_map(|&(names, level)| names.iter().map(move |name| (name.replace('-', "_"), level)))
+    .collect();
+    if levels.is_empty() {
+        return levels;
     }
 
-    conf
+    // The lint store of rustc knows its groups, like `unused` or `rust_2018_idioms`
+    let rustc_groups: Vec<&str> = rustc_lint::new_lint_store(false, false)
+        .get_lint_groups()
+        .into_iter()
+        .map(|(name, ..)| name)
+        .collect();
+    let is_group = |name: &str| name == "warnings" || CLIPPY_GROUPS.contains(&name) || rustc_groups.contains(&name);
+    // the sort is stable, the order of the keys is kept otherwise
+    levels.sort_by_key(|(name, _)| !is_group(name));
+    levels
+}
+
+/// Looks up and parses the configuration files and serializes the result.
+///
+/// `cargo clippy` calls this once through `clippy-driver --resolve-conf` and passes the result to
+/// every crate in the `CLIPPY_CONF` environment variable. This way the configuration files above
+/// the workspace aren't looked up and parsed again for every crate of a workspace and errors are
+/// only reported once. The directory the configuration was resolved in is passed in
+/// `CLIPPY_CONF_ROOT`, only the configuration files below it are read for each crate.
+///
+/// # Errors
+///
+/// Returns the error messages if the configuration files can't be found or read.
+#[doc(hidden)]
+pub fn resolve_conf(inherit: bool) -> Result<String, Vec<String>> {
+    let (conf, errors) = read_conf(None, inherit);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    utils::conf::to_string(&conf).map_err(|error| vec![format!("error serializing Clippy's configuration: {}", error)])
+}
+
+/// Reads the configuration files from the crate directory up, with the keys of nearer files
+/// overriding those of files further up, unless `inherit` is `false`. `source_file` is the crate
+/// root, see [`utils::conf::conf_dir`].
+///
+/// The configuration is read before the `Session` is created, for the lint levels set in it. All
+/// errors are non-fatal, they are returned to be reported later, and the default value is used
+/// for the keys which can't be read.
+#[doc(hidden)]
+pub fn read_conf(source_file: Option<&Path>, inherit: bool) -> (Conf, Vec<String>) {
+    let dir = utils::conf::conf_dir(source_file);
+    let resolved = env::var("CLIPPY_CONF").ok();
+    let root = env::var_os("CLIPPY_CONF_ROOT").map(PathBuf::from);
+    utils::conf::read_conf_files(&dir, resolved.as_deref().zip(root.as_deref()), inherit)
 }
 
 /// Register all lints and lint groups with the rustc plugin registry
@@ -432,6 +519,7 @@ pub fn read_conf(sess: &Session) -> Conf {
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
 pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+    let first_late_pass = store.late_passes.len();
     register_removed_non_tool_lints(store);
 
     // begin deprecated lints, do not remove this comment, it’s used in `update_lints`
@@ -512,6 +600,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         #[cfg(feature = "internal-lints")]
         utils::internal_lints::DEFAULT_LINT,
         #[cfg(feature = "internal-lints")]
+        utils::internal_lints::DEF_PATH_LITERAL,
+        #[cfg(feature = "internal-lints")]
         utils::internal_lints::IF_CHAIN_STYLE,
         #[cfg(feature = "internal-lints")]
         utils::internal_lints::INTERNING_DEFINED_SYMBOL,
@@ -528,17 +618,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         #[cfg(feature = "internal-lints")]
         utils::internal_lints::UNNECESSARY_SYMBOL_STR,
         absurd_extreme_comparisons::ABSURD_EXTREME_COMPARISONS,
+        allowed_clippy_lint::ALLOWED_CLIPPY_LINT,
         approx_const::APPROX_CONSTANT,
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
+        arithmetic_side_effects::ARITHMETIC_SIDE_EFFECTS,
         as_conversions::AS_CONVERSIONS,
         asm_syntax::INLINE_ASM_X86_ATT_SYNTAX,
         asm_syntax::INLINE_ASM_X86_INTEL_SYNTAX,
         assertions_on_constants::ASSERTIONS_ON_CONSTANTS,
         assign_ops::ASSIGN_OP_PATTERN,
         assign_ops::MISREFACTORED_ASSIGN_OP,
+        async_blocking_call::ASYNC_BLOCKING_CALL,
         async_yields_async::ASYNC_YIELDS_ASYNC,
         atomic_ordering::INVALID_ATOMIC_ORDERING,
+        attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON,
         attrs::BLANKET_CLIPPY_RESTRICTION_LINTS,
         attrs::DEPRECATED_CFG_ATTR,
         attrs::DEPRECATED_SEMVER,
@@ -556,6 +650,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         bool_assert_comparison::BOOL_ASSERT_COMPARISON,
         booleans::LOGIC_BUG,
         booleans::NONMINIMAL_BOOL,
+        borrow_deref_ref::BORROW_DEREF_REF,
         bytecount::NAIVE_BYTECOUNT,
         cargo_common_metadata::CARGO_COMMON_METADATA,
         case_sensitive_file_extension_comparisons::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
@@ -568,8 +663,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         casts::CAST_SIGN_LOSS,
         casts::CHAR_LIT_AS_U8,
         casts::FN_TO_NUMERIC_CAST,
+        casts::FN_TO_NUMERIC_CAST_ANY,
         casts::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         casts::PTR_AS_PTR,
+        casts::PTR_CAST_CONSTNESS,
         casts::UNNECESSARY_CAST,
         checked_conversions::CHECKED_CONVERSIONS,
         cognitive_complexity::COGNITIVE_COMPLEXITY,
@@ -590,12 +687,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         dereference::EXPLICIT_DEREF_METHODS,
         derive::DERIVE_HASH_XOR_EQ,
         derive::DERIVE_ORD_XOR_PARTIAL_ORD,
+        derive::DERIVE_PARTIAL_EQ_WITHOUT_EQ,
         derive::EXPL_IMPL_CLONE_ON_COPY,
         derive::UNSAFE_DERIVE_DESERIALIZE,
         disallowed_method::DISALLOWED_METHOD,
         disallowed_script_idents::DISALLOWED_SCRIPT_IDENTS,
         disallowed_type::DISALLOWED_TYPE,
         doc::DOC_MARKDOWN,
+        doc::DOC_ON_PRIVATE_IMPORTS,
+        doc::EMPTY_DOC_SECTIONS,
+        doc::FOUR_FORWARD_SLASHES,
         doc::MISSING_ERRORS_DOC,
         doc::MISSING_PANICS_DOC,
         doc::MISSING_SAFETY_DOC,
@@ -635,6 +736,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         floating_point_arithmetic::IMPRECISE_FLOPS,
         floating_point_arithmetic::SUBOPTIMAL_FLOPS,
         format::USELESS_FORMAT,
+        format_args::FORMAT_IN_FORMAT_ARGS,
+        format_args::TO_STRING_IN_FORMAT_ARGS,
         formatting::POSSIBLE_MISSING_COMMA,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
@@ -667,10 +770,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         inherent_to_string::INHERENT_TO_STRING,
         inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
+        instant_subtraction::UNCHECKED_DURATION_SUBTRACTION,
         int_plus_one::INT_PLUS_ONE,
         integer_division::INTEGER_DIVISION,
         invalid_upcast_comparisons::INVALID_UPCAST_COMPARISONS,
+        io::SEEK_FROM_CURRENT,
+        io::UNBUFFERED_BYTES,
+        io::UNUSED_IO_AMOUNT,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        iter_not_returning_iterator::ITER_NOT_RETURNING_ITERATOR,
         large_const_arrays::LARGE_CONST_ARRAYS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         large_stack_arrays::LARGE_STACK_ARRAYS,
@@ -709,10 +817,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         loops::WHILE_LET_ON_ITERATOR,
         macro_use::MACRO_USE_IMPORTS,
         main_recursion::MAIN_RECURSION,
+        manual_assert::MANUAL_ASSERT,
         manual_async_fn::MANUAL_ASYNC_FN,
+        manual_let_else::MANUAL_LET_ELSE,
         manual_map::MANUAL_MAP,
         manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         manual_ok_or::MANUAL_OK_OR,
+        manual_retain::MANUAL_RETAIN,
+        manual_std::MANUAL_BITS,
+        manual_std::MANUAL_CLAMP,
+        manual_string_new::MANUAL_STRING_NEW,
         manual_strip::MANUAL_STRIP,
         manual_unwrap_or::MANUAL_UNWRAP_OR,
         map_clone::MAP_CLONE,
@@ -750,9 +864,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
         methods::CLONE_ON_REF_PTR,
+        methods::COLLAPSIBLE_STR_REPLACE,
+        methods::DOUBLE_ENDED_ITERATOR_LAST,
         methods::EXPECT_FUN_CALL,
         methods::EXPECT_USED,
         methods::FILETYPE_IS_FILE,
+        methods::FILTER_MAP_BOOL_THEN,
         methods::FILTER_MAP_IDENTITY,
         methods::FILTER_MAP_NEXT,
         methods::FILTER_NEXT,
@@ -770,10 +887,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::ITER_NEXT_SLICE,
         methods::ITER_NTH,
         methods::ITER_NTH_ZERO,
+        methods::ITER_OVEREAGER_CLONED,
         methods::ITER_SKIP_NEXT,
         methods::MANUAL_FILTER_MAP,
         methods::MANUAL_FIND_MAP,
         methods::MANUAL_SATURATING_ARITHMETIC,
+        methods::MANUAL_SPLIT_ONCE,
         methods::MANUAL_STR_REPEAT,
         methods::MAP_COLLECT_RESULT_UNIT,
         methods::MAP_FLATTEN,
@@ -798,6 +917,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::UNNECESSARY_FILTER_MAP,
         methods::UNNECESSARY_FOLD,
         methods::UNNECESSARY_LAZY_EVALUATIONS,
+        methods::UNNECESSARY_TO_OWNED,
         methods::UNWRAP_USED,
         methods::USELESS_ASREF,
         methods::WRONG_SELF_CONVENTION,
@@ -821,10 +941,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         misc_early::UNNEEDED_WILDCARD_PATTERN,
         misc_early::UNSEPARATED_LITERAL_SUFFIX,
         misc_early::ZERO_PREFIXED_LITERAL,
+        missing_asserts_for_indexing::MISSING_ASSERTS_FOR_INDEXING,
         missing_const_for_fn::MISSING_CONST_FOR_FN,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_enforced_import_rename::MISSING_ENFORCED_IMPORT_RENAMES,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        module_style::MOD_MODULE_FILES,
+        module_style::SELF_NAMED_MODULE_FILES,
         modulo_arithmetic::MODULO_ARITHMETIC,
         multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
         mut_key::MUTABLE_KEY_TYPE,
@@ -834,6 +957,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL,
         mutex_atomic::MUTEX_ATOMIC,
         mutex_atomic::MUTEX_INTEGER,
+        mutex_atomic::MUTEX_UNIT,
         needless_arbitrary_self_type::NEEDLESS_ARBITRARY_SELF_TYPE,
         needless_bitwise_bool::NEEDLESS_BITWISE_BOOL,
         needless_bool::BOOL_COMPARISON,
@@ -841,8 +965,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         needless_borrow::NEEDLESS_BORROW,
         needless_borrow::REF_BINDING_TO_REFERENCE,
         needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        needless_borrows_for_generic_args::NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
         needless_continue::NEEDLESS_CONTINUE,
         needless_for_each::NEEDLESS_FOR_EACH,
+        needless_impls::EMPTY_DROP,
+        needless_impls::EMPTY_IMPL,
+        needless_impls::NEEDLESS_DEFAULT_IMPL,
+        needless_late_init::NEEDLESS_LATE_INIT,
+        needless_pass_by_ref_mut::NEEDLESS_PASS_BY_REF_MUT,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         needless_question_mark::NEEDLESS_QUESTION_MARK,
         needless_update::NEEDLESS_UPDATE,
@@ -857,7 +987,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
         non_expressive_names::SIMILAR_NAMES,
         non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
+        non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY,
         nonstandard_macro_braces::NONSTANDARD_MACRO_BRACES,
+        only_used_in_recursion::ONLY_USED_IN_RECURSION,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         option_env_unwrap::OPTION_ENV_UNWRAP,
         option_if_let_else::OPTION_IF_LET_ELSE,
@@ -885,10 +1017,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         ranges::RANGE_PLUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
         ranges::REVERSED_EMPTY_RANGES,
+        rc_clone_in_vec_init::RC_CLONE_IN_VEC_INIT,
+        recursive_format_impl::RECURSIVE_FORMAT_IMPL,
         redundant_clone::REDUNDANT_CLONE,
         redundant_closure_call::REDUNDANT_CLOSURE_CALL,
         redundant_else::REDUNDANT_ELSE,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
+        redundant_locals::REDUNDANT_LOCALS,
         redundant_pub_crate::REDUNDANT_PUB_CRATE,
         redundant_slicing::REDUNDANT_SLICING,
         redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES,
@@ -907,6 +1042,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         shadow::SHADOW_UNRELATED,
+        significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE,
         single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
         size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
@@ -915,6 +1051,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         strings::STRING_ADD_ASSIGN,
         strings::STRING_FROM_UTF8_AS_BYTES,
         strings::STRING_LIT_AS_BYTES,
+        strings::STRING_SLICE,
         strings::STRING_TO_STRING,
         strings::STR_TO_STRING,
         strlen_on_c_strings::STRLEN_ON_C_STRINGS,
@@ -923,10 +1060,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
+        swap_ptr_to_ref::SWAP_PTR_TO_REF,
         tabs_in_doc_comments::TABS_IN_DOC_COMMENTS,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
         to_digit_is_some::TO_DIGIT_IS_SOME,
-        to_string_in_display::TO_STRING_IN_DISPLAY,
         trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS,
         trait_bounds::TYPE_REPETITION_IN_BOUNDS,
         transmute::CROSSPOINTER_TRANSMUTE,
@@ -938,6 +1075,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         transmute::TRANSMUTE_INT_TO_FLOAT,
         transmute::TRANSMUTE_PTR_TO_PTR,
         transmute::TRANSMUTE_PTR_TO_REF,
+        transmute::TRANSMUTE_UNDEFINED_REPR,
         transmute::UNSOUND_COLLECTION_TRANSMUTE,
         transmute::USELESS_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
@@ -952,6 +1090,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         types::REDUNDANT_ALLOCATION,
         types::TYPE_COMPLEXITY,
         types::VEC_BOX,
+        undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS,
+        undocumented_unsafe_blocks::UNNECESSARY_SAFETY_COMMENT,
         undropped_manually_drops::UNDROPPED_MANUALLY_DROPS,
         unicode::INVISIBLE_CHARACTERS,
         unicode::NON_ASCII_LITERAL,
@@ -968,7 +1108,6 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         unnested_or_patterns::UNNESTED_OR_PATTERNS,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_async::UNUSED_ASYNC,
-        unused_io_amount::UNUSED_IO_AMOUNT,
         unused_self::UNUSED_SELF,
         unused_unit::UNUSED_UNIT,
         unwrap::PANICKING_UNWRAP,
@@ -999,11 +1138,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     // end register lints, do not remove this comment, it’s used in `update_lints`
 
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
+        LintId::of(allowed_clippy_lint::ALLOWED_CLIPPY_LINT),
         LintId::of(arithmetic::FLOAT_ARITHMETIC),
         LintId::of(arithmetic::INTEGER_ARITHMETIC),
+        LintId::of(arithmetic_side_effects::ARITHMETIC_SIDE_EFFECTS),
         LintId::of(as_conversions::AS_CONVERSIONS),
         LintId::of(asm_syntax::INLINE_ASM_X86_ATT_SYNTAX),
         LintId::of(asm_syntax::INLINE_ASM_X86_INTEL_SYNTAX),
+        LintId::of(attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON),
+        LintId::of(casts::FN_TO_NUMERIC_CAST_ANY),
         LintId::of(create_dir::CREATE_DIR),
         LintId::of(dbg_macro::DBG_MACRO),
         LintId::of(default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK),
@@ -1028,13 +1171,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::EXPECT_USED),
         LintId::of(methods::FILETYPE_IS_FILE),
         LintId::of(methods::GET_UNWRAP),
+        LintId::of(methods::MANUAL_SPLIT_ONCE),
         LintId::of(methods::UNWRAP_USED),
         LintId::of(misc::FLOAT_CMP_CONST),
         LintId::of(misc_early::UNNEEDED_FIELD_PATTERN),
+        LintId::of(missing_asserts_for_indexing::MISSING_ASSERTS_FOR_INDEXING),
         LintId::of(missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS),
         LintId::of(missing_enforced_import_rename::MISSING_ENFORCED_IMPORT_RENAMES),
         LintId::of(missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS),
+        LintId::of(module_style::MOD_MODULE_FILES),
+        LintId::of(module_style::SELF_NAMED_MODULE_FILES),
         LintId::of(modulo_arithmetic::MODULO_ARITHMETIC),
+        LintId::of(needless_impls::EMPTY_DROP),
         LintId::of(panic_in_result_fn::PANIC_IN_RESULT_FN),
         LintId::of(panic_unimplemented::PANIC),
         LintId::of(panic_unimplemented::TODO),
@@ -1044,10 +1192,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(shadow::SHADOW_REUSE),
         LintId::of(shadow::SHADOW_SAME),
         LintId::of(strings::STRING_ADD),
+        LintId::of(strings::STRING_SLICE),
         LintId::of(strings::STRING_TO_STRING),
         LintId::of(strings::STR_TO_STRING),
         LintId::of(types::RC_BUFFER),
         LintId::of(types::RC_MUTEX),
+        LintId::of(undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS),
+        LintId::of(undocumented_unsafe_blocks::UNNECESSARY_SAFETY_COMMENT),
         LintId::of(unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS),
         LintId::of(unwrap_in_result::UNWRAP_IN_RESULT),
         LintId::of(verbose_file_reads::VERBOSE_FILE_READS),
@@ -1057,6 +1208,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     ]);
 
     store.register_group(true, "clippy::pedantic", Some("clippy_pedantic"), vec![
+        LintId::of(async_blocking_call::ASYNC_BLOCKING_CALL),
         LintId::of(attrs::INLINE_ALWAYS),
         LintId::of(await_holding_invalid::AWAIT_HOLDING_LOCK),
         LintId::of(await_holding_invalid::AWAIT_HOLDING_REFCELL_REF),
@@ -1070,6 +1222,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(casts::CAST_PTR_ALIGNMENT),
         LintId::of(casts::CAST_SIGN_LOSS),
         LintId::of(casts::PTR_AS_PTR),
+        LintId::of(casts::PTR_CAST_CONSTNESS),
         LintId::of(checked_conversions::CHECKED_CONVERSIONS),
         LintId::of(copies::SAME_FUNCTIONS_IN_IF_CONDITION),
         LintId::of(copy_iterator::COPY_ITERATOR),
@@ -1078,6 +1231,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(derive::EXPL_IMPL_CLONE_ON_COPY),
         LintId::of(derive::UNSAFE_DERIVE_DESERIALIZE),
         LintId::of(doc::DOC_MARKDOWN),
+        LintId::of(doc::EMPTY_DOC_SECTIONS),
         LintId::of(doc::MISSING_ERRORS_DOC),
         LintId::of(doc::MISSING_PANICS_DOC),
         LintId::of(empty_enum::EMPTY_ENUM),
@@ -1092,8 +1246,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(implicit_saturating_sub::IMPLICIT_SATURATING_SUB),
         LintId::of(inconsistent_struct_constructor::INCONSISTENT_STRUCT_CONSTRUCTOR),
         LintId::of(infinite_iter::MAYBE_INFINITE_ITER),
+        LintId::of(instant_subtraction::UNCHECKED_DURATION_SUBTRACTION),
         LintId::of(invalid_upcast_comparisons::INVALID_UPCAST_COMPARISONS),
         LintId::of(items_after_statements::ITEMS_AFTER_STATEMENTS),
+        LintId::of(iter_not_returning_iterator::ITER_NOT_RETURNING_ITERATOR),
         LintId::of(large_stack_arrays::LARGE_STACK_ARRAYS),
         LintId::of(let_underscore::LET_UNDERSCORE_DROP),
         LintId::of(literal_representation::LARGE_DIGIT_GROUPS),
@@ -1101,7 +1257,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(loops::EXPLICIT_INTO_ITER_LOOP),
         LintId::of(loops::EXPLICIT_ITER_LOOP),
         LintId::of(macro_use::MACRO_USE_IMPORTS),
+        LintId::of(manual_assert::MANUAL_ASSERT),
         LintId::of(manual_ok_or::MANUAL_OK_OR),
+        LintId::of(manual_string_new::MANUAL_STRING_NEW),
         LintId::of(match_on_vec_items::MATCH_ON_VEC_ITEMS),
         LintId::of(matches::MATCH_BOOL),
         LintId::of(matches::MATCH_SAME_ARMS),
@@ -1158,6 +1316,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(utils::internal_lints::COLLAPSIBLE_SPAN_LINT_CALLS),
         LintId::of(utils::internal_lints::COMPILER_LINT_FUNCTIONS),
         LintId::of(utils::internal_lints::DEFAULT_LINT),
+        LintId::of(utils::internal_lints::DEF_PATH_LITERAL),
         LintId::of(utils::internal_lints::IF_CHAIN_STYLE),
         LintId::of(utils::internal_lints::INTERNING_DEFINED_SYMBOL),
         LintId::of(utils::internal_lints::INVALID_PATHS),
@@ -1188,6 +1347,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(bool_assert_comparison::BOOL_ASSERT_COMPARISON),
         LintId::of(booleans::LOGIC_BUG),
         LintId::of(booleans::NONMINIMAL_BOOL),
+        LintId::of(borrow_deref_ref::BORROW_DEREF_REF),
         LintId::of(casts::CAST_REF_TO_MUT),
         LintId::of(casts::CHAR_LIT_AS_U8),
         LintId::of(casts::FN_TO_NUMERIC_CAST),
@@ -1203,6 +1363,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(default::FIELD_REASSIGN_WITH_DEFAULT),
         LintId::of(derive::DERIVE_HASH_XOR_EQ),
         LintId::of(derive::DERIVE_ORD_XOR_PARTIAL_ORD),
+        LintId::of(doc::DOC_ON_PRIVATE_IMPORTS),
+        LintId::of(doc::FOUR_FORWARD_SLASHES),
         LintId::of(doc::MISSING_SAFETY_DOC),
         LintId::of(doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(double_comparison::DOUBLE_COMPARISONS),
@@ -1227,6 +1389,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS),
         LintId::of(float_literal::EXCESSIVE_PRECISION),
         LintId::of(format::USELESS_FORMAT),
+        LintId::of(format_args::FORMAT_IN_FORMAT_ARGS),
+        LintId::of(format_args::TO_STRING_IN_FORMAT_ARGS),
         LintId::of(formatting::POSSIBLE_MISSING_COMMA),
         LintId::of(formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING),
         LintId::of(formatting::SUSPICIOUS_ELSE_FORMATTING),
@@ -1248,6 +1412,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
         LintId::of(inline_fn_without_body::INLINE_FN_WITHOUT_BODY),
         LintId::of(int_plus_one::INT_PLUS_ONE),
+        LintId::of(io::SEEK_FROM_CURRENT),
+        LintId::of(io::UNBUFFERED_BYTES),
+        LintId::of(io::UNUSED_IO_AMOUNT),
         LintId::of(large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(large_enum_variant::LARGE_ENUM_VARIANT),
         LintId::of(len_zero::COMPARISON_TO_EMPTY),
@@ -1279,6 +1446,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(manual_async_fn::MANUAL_ASYNC_FN),
         LintId::of(manual_map::MANUAL_MAP),
         LintId::of(manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
+        LintId::of(manual_retain::MANUAL_RETAIN),
+        LintId::of(manual_std::MANUAL_BITS),
+        LintId::of(manual_std::MANUAL_CLAMP),
         LintId::of(manual_strip::MANUAL_STRIP),
         LintId::of(manual_unwrap_or::MANUAL_UNWRAP_OR),
         LintId::of(map_clone::MAP_CLONE),
@@ -1304,7 +1474,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::CHARS_NEXT_CMP),
         LintId::of(methods::CLONE_DOUBLE_REF),
         LintId::of(methods::CLONE_ON_COPY),
+        LintId::of(methods::COLLAPSIBLE_STR_REPLACE),
+        LintId::of(methods::DOUBLE_ENDED_ITERATOR_LAST),
         LintId::of(methods::EXPECT_FUN_CALL),
+        LintId::of(methods::FILTER_MAP_BOOL_THEN),
         LintId::of(methods::FILTER_MAP_IDENTITY),
         LintId::of(methods::FILTER_NEXT),
         LintId::of(methods::FLAT_MAP_IDENTITY),
@@ -1316,6 +1489,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::ITER_NEXT_SLICE),
         LintId::of(methods::ITER_NTH),
         LintId::of(methods::ITER_NTH_ZERO),
+        LintId::of(methods::ITER_OVEREAGER_CLONED),
         LintId::of(methods::ITER_SKIP_NEXT),
         LintId::of(methods::MANUAL_FILTER_MAP),
         LintId::of(methods::MANUAL_FIND_MAP),
@@ -1342,6 +1516,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::UNNECESSARY_FILTER_MAP),
         LintId::of(methods::UNNECESSARY_FOLD),
         LintId::of(methods::UNNECESSARY_LAZY_EVALUATIONS),
+        LintId::of(methods::UNNECESSARY_TO_OWNED),
         LintId::of(methods::USELESS_ASREF),
         LintId::of(methods::WRONG_SELF_CONVENTION),
         LintId::of(methods::ZST_OFFSET),
@@ -1369,6 +1544,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(needless_bool::NEEDLESS_BOOL),
         LintId::of(needless_borrow::NEEDLESS_BORROW),
         LintId::of(needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE),
+        LintId::of(needless_borrows_for_generic_args::NEEDLESS_BORROWS_FOR_GENERIC_ARGS),
+        LintId::of(needless_impls::EMPTY_IMPL),
+        LintId::of(needless_impls::NEEDLESS_DEFAULT_IMPL),
+        LintId::of(needless_late_init::NEEDLESS_LATE_INIT),
         LintId::of(needless_question_mark::NEEDLESS_QUESTION_MARK),
         LintId::of(needless_update::NEEDLESS_UPDATE),
         LintId::of(neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD),
@@ -1381,6 +1560,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(non_expressive_names::JUST_UNDERSCORES_AND_DIGITS),
         LintId::of(non_expressive_names::MANY_SINGLE_CHAR_NAMES),
         LintId::of(non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS),
+        LintId::of(only_used_in_recursion::ONLY_USED_IN_RECURSION),
         LintId::of(open_options::NONSENSICAL_OPEN_OPTIONS),
         LintId::of(option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
@@ -1396,9 +1576,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(ranges::MANUAL_RANGE_CONTAINS),
         LintId::of(ranges::RANGE_ZIP_WITH_LEN),
         LintId::of(ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(rc_clone_in_vec_init::RC_CLONE_IN_VEC_INIT),
+        LintId::of(recursive_format_impl::RECURSIVE_FORMAT_IMPL),
         LintId::of(redundant_clone::REDUNDANT_CLONE),
         LintId::of(redundant_closure_call::REDUNDANT_CLOSURE_CALL),
         LintId::of(redundant_field_names::REDUNDANT_FIELD_NAMES),
+        LintId::of(redundant_locals::REDUNDANT_LOCALS),
         LintId::of(redundant_slicing::REDUNDANT_SLICING),
         LintId::of(redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES),
         LintId::of(reference::DEREF_ADDROF),
@@ -1420,10 +1603,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL),
         LintId::of(swap::ALMOST_SWAPPED),
         LintId::of(swap::MANUAL_SWAP),
+        LintId::of(swap_ptr_to_ref::SWAP_PTR_TO_REF),
         LintId::of(tabs_in_doc_comments::TABS_IN_DOC_COMMENTS),
         LintId::of(temporary_assignment::TEMPORARY_ASSIGNMENT),
         LintId::of(to_digit_is_some::TO_DIGIT_IS_SOME),
-        LintId::of(to_string_in_display::TO_STRING_IN_DISPLAY),
         LintId::of(transmute::CROSSPOINTER_TRANSMUTE),
         LintId::of(transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS),
         LintId::of(transmute::TRANSMUTE_BYTES_TO_STR),
@@ -1450,7 +1633,6 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(unnamed_address::VTABLE_ADDRESS_COMPARISONS),
         LintId::of(unnecessary_sort_by::UNNECESSARY_SORT_BY),
         LintId::of(unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME),
-        LintId::of(unused_io_amount::UNUSED_IO_AMOUNT),
         LintId::of(unused_unit::UNUSED_UNIT),
         LintId::of(unwrap::PANICKING_UNWRAP),
         LintId::of(unwrap::UNNECESSARY_UNWRAP),
@@ -1508,6 +1690,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(manual_async_fn::MANUAL_ASYNC_FN),
         LintId::of(manual_map::MANUAL_MAP),
         LintId::of(manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
+        LintId::of(manual_std::MANUAL_BITS),
         LintId::of(map_clone::MAP_CLONE),
         LintId::of(matches::INFALLIBLE_DESTRUCTURING_MATCH),
         LintId::of(matches::MATCH_LIKE_MATCHES_MACRO),
@@ -1520,6 +1703,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::BYTES_NTH),
         LintId::of(methods::CHARS_LAST_CMP),
         LintId::of(methods::CHARS_NEXT_CMP),
+        LintId::of(methods::COLLAPSIBLE_STR_REPLACE),
+        LintId::of(methods::FILTER_MAP_BOOL_THEN),
         LintId::of(methods::INTO_ITER_ON_REF),
         LintId::of(methods::ITER_CLONED_COLLECT),
         LintId::of(methods::ITER_NEXT_SLICE),
@@ -1547,6 +1732,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(mut_mutex_lock::MUT_MUTEX_LOCK),
         LintId::of(mut_reference::UNNECESSARY_MUT_PASSED),
         LintId::of(needless_borrow::NEEDLESS_BORROW),
+        LintId::of(needless_borrows_for_generic_args::NEEDLESS_BORROWS_FOR_GENERIC_ARGS),
+        LintId::of(needless_late_init::NEEDLESS_LATE_INIT),
         LintId::of(neg_multiply::NEG_MULTIPLY),
         LintId::of(new_without_default::NEW_WITHOUT_DEFAULT),
         LintId::of(non_copy_const::BORROW_INTERIOR_MUTABLE_CONST),
@@ -1581,6 +1768,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_group(true, "clippy::complexity", Some("clippy_complexity"), vec![
         LintId::of(attrs::DEPRECATED_CFG_ATTR),
         LintId::of(booleans::NONMINIMAL_BOOL),
+        LintId::of(borrow_deref_ref::BORROW_DEREF_REF),
         LintId::of(casts::CHAR_LIT_AS_U8),
         LintId::of(casts::UNNECESSARY_CAST),
         LintId::of(copies::BRANCHES_SHARING_CODE),
@@ -1594,12 +1782,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(get_last_with_len::GET_LAST_WITH_LEN),
         LintId::of(identity_op::IDENTITY_OP),
         LintId::of(int_plus_one::INT_PLUS_ONE),
+        LintId::of(io::SEEK_FROM_CURRENT),
         LintId::of(lifetimes::EXTRA_UNUSED_LIFETIMES),
         LintId::of(lifetimes::NEEDLESS_LIFETIMES),
         LintId::of(loops::EXPLICIT_COUNTER_LOOP),
         LintId::of(loops::MANUAL_FLATTEN),
         LintId::of(loops::SINGLE_ELEMENT_LOOP),
         LintId::of(loops::WHILE_LET_LOOP),
+        LintId::of(manual_std::MANUAL_CLAMP),
         LintId::of(manual_strip::MANUAL_STRIP),
         LintId::of(manual_unwrap_or::MANUAL_UNWRAP_OR),
         LintId::of(map_unit_fn::OPTION_MAP_UNIT_FN),
@@ -1630,17 +1820,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(needless_bool::BOOL_COMPARISON),
         LintId::of(needless_bool::NEEDLESS_BOOL),
         LintId::of(needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE),
+        LintId::of(needless_impls::EMPTY_IMPL),
+        LintId::of(needless_impls::NEEDLESS_DEFAULT_IMPL),
         LintId::of(needless_question_mark::NEEDLESS_QUESTION_MARK),
         LintId::of(needless_update::NEEDLESS_UPDATE),
         LintId::of(neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD),
         LintId::of(no_effect::NO_EFFECT),
         LintId::of(no_effect::UNNECESSARY_OPERATION),
+        LintId::of(only_used_in_recursion::ONLY_USED_IN_RECURSION),
         LintId::of(overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
         LintId::of(partialeq_ne_impl::PARTIALEQ_NE_IMPL),
         LintId::of(precedence::PRECEDENCE),
         LintId::of(ptr_offset_with_cast::PTR_OFFSET_WITH_CAST),
         LintId::of(ranges::RANGE_ZIP_WITH_LEN),
         LintId::of(redundant_closure_call::REDUNDANT_CLOSURE_CALL),
+        LintId::of(redundant_locals::REDUNDANT_LOCALS),
         LintId::of(redundant_slicing::REDUNDANT_SLICING),
         LintId::of(reference::DEREF_ADDROF),
         LintId::of(reference::REF_IN_DEREF),
@@ -1697,6 +1891,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(infinite_iter::INFINITE_ITER),
         LintId::of(inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
         LintId::of(inline_fn_without_body::INLINE_FN_WITHOUT_BODY),
+        LintId::of(io::UNUSED_IO_AMOUNT),
         LintId::of(let_underscore::LET_UNDERSCORE_LOCK),
         LintId::of(literal_representation::MISTYPED_LITERAL_SUFFIXES),
         LintId::of(loops::ITER_NEXT_LOOP),
@@ -1719,12 +1914,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(ptr::INVALID_NULL_PTR_USAGE),
         LintId::of(ptr::MUT_FROM_REF),
         LintId::of(ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(recursive_format_impl::RECURSIVE_FORMAT_IMPL),
         LintId::of(regex::INVALID_REGEX),
         LintId::of(self_assignment::SELF_ASSIGNMENT),
         LintId::of(serde_api::SERDE_API_MISUSE),
         LintId::of(size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT),
         LintId::of(swap::ALMOST_SWAPPED),
-        LintId::of(to_string_in_display::TO_STRING_IN_DISPLAY),
         LintId::of(transmute::UNSOUND_COLLECTION_TRANSMUTE),
         LintId::of(transmute::WRONG_TRANSMUTE),
         LintId::of(transmuting_null::TRANSMUTING_NULL),
@@ -1734,7 +1929,6 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(unit_types::UNIT_CMP),
         LintId::of(unnamed_address::FN_ADDRESS_COMPARISONS),
         LintId::of(unnamed_address::VTABLE_ADDRESS_COMPARISONS),
-        LintId::of(unused_io_amount::UNUSED_IO_AMOUNT),
         LintId::of(unwrap::PANICKING_UNWRAP),
         LintId::of(vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
     ]);
@@ -1742,6 +1936,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_group(true, "clippy::suspicious", None, vec![
         LintId::of(assign_ops::MISREFACTORED_ASSIGN_OP),
         LintId::of(attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
+        LintId::of(doc::DOC_ON_PRIVATE_IMPORTS),
+        LintId::of(doc::FOUR_FORWARD_SLASHES),
         LintId::of(eval_order_dependence::EVAL_ORDER_DEPENDENCE),
         LintId::of(float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS),
         LintId::of(formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING),
@@ -1752,23 +1948,32 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(loops::MUT_RANGE_BOUND),
         LintId::of(methods::SUSPICIOUS_MAP),
         LintId::of(mut_key::MUTABLE_KEY_TYPE),
+        LintId::of(rc_clone_in_vec_init::RC_CLONE_IN_VEC_INIT),
         LintId::of(suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL),
         LintId::of(suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL),
+        LintId::of(swap_ptr_to_ref::SWAP_PTR_TO_REF),
     ]);
 
     store.register_group(true, "clippy::perf", Some("clippy_perf"), vec![
         LintId::of(entry::MAP_ENTRY),
         LintId::of(escape::BOXED_LOCAL),
+        LintId::of(format_args::FORMAT_IN_FORMAT_ARGS),
+        LintId::of(format_args::TO_STRING_IN_FORMAT_ARGS),
+        LintId::of(io::UNBUFFERED_BYTES),
         LintId::of(large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(large_enum_variant::LARGE_ENUM_VARIANT),
         LintId::of(loops::MANUAL_MEMCPY),
         LintId::of(loops::NEEDLESS_COLLECT),
+        LintId::of(manual_retain::MANUAL_RETAIN),
         LintId::of(methods::APPEND_INSTEAD_OF_EXTEND),
+        LintId::of(methods::DOUBLE_ENDED_ITERATOR_LAST),
         LintId::of(methods::EXPECT_FUN_CALL),
         LintId::of(methods::ITER_NTH),
+        LintId::of(methods::ITER_OVEREAGER_CLONED),
         LintId::of(methods::MANUAL_STR_REPEAT),
         LintId::of(methods::OR_FUN_CALL),
         LintId::of(methods::SINGLE_CHAR_PATTERN),
+        LintId::of(methods::UNNECESSARY_TO_OWNED),
         LintId::of(misc::CMP_OWNED),
         LintId::of(mutex_atomic::MUTEX_ATOMIC),
         LintId::of(redundant_clone::REDUNDANT_CLONE),
@@ -1789,6 +1994,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
         LintId::of(attrs::EMPTY_LINE_AFTER_OUTER_ATTR),
         LintId::of(cognitive_complexity::COGNITIVE_COMPLEXITY),
+        LintId::of(derive::DERIVE_PARTIAL_EQ_WITHOUT_EQ),
         LintId::of(disallowed_method::DISALLOWED_METHOD),
         LintId::of(disallowed_type::DISALLOWED_TYPE),
         LintId::of(fallible_impl_from::FALLIBLE_IMPL_FROM),
@@ -1796,15 +2002,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(future_not_send::FUTURE_NOT_SEND),
         LintId::of(let_if_seq::USELESS_LET_IF_SEQ),
+        LintId::of(manual_let_else::MANUAL_LET_ELSE),
         LintId::of(missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
         LintId::of(mutex_atomic::MUTEX_INTEGER),
+        LintId::of(mutex_atomic::MUTEX_UNIT),
+        LintId::of(needless_pass_by_ref_mut::NEEDLESS_PASS_BY_REF_MUT),
+        LintId::of(non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY),
         LintId::of(nonstandard_macro_braces::NONSTANDARD_MACRO_BRACES),
         LintId::of(path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE),
         LintId::of(redundant_pub_crate::REDUNDANT_PUB_CRATE),
         LintId::of(regex::TRIVIAL_REGEX),
+        LintId::of(significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE),
         LintId::of(strings::STRING_LIT_AS_BYTES),
         LintId::of(suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS),
+        LintId::of(transmute::TRANSMUTE_UNDEFINED_REPR),
         LintId::of(transmute::USELESS_TRANSMUTE),
         LintId::of(use_self::USE_SELF),
     ]);
@@ -1825,6 +2037,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         store.register_late_pass(|| box utils::inspector::DeepCodeInspector);
         store.register_late_pass(|| box utils::internal_lints::CollapsibleCalls);
         store.register_late_pass(|| box utils::internal_lints::CompilerLintFunctions::new());
+        store.register_late_pass(|| box utils::internal_lints::DefPathLiteral);
         store.register_late_pass(|| box utils::internal_lints::IfChainStyle);
         store.register_late_pass(|| box utils::internal_lints::InvalidPaths);
         store.register_late_pass(|| box utils::internal_lints::InterningDefinedSymbol::default());
@@ -1881,7 +2094,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     store.register_late_pass(move || box methods::Methods::new(avoid_breaking_exported_api, msrv));
-    store.register_late_pass(move || box matches::Matches::new(msrv));
+    let body_node_limit = conf.body_node_limit;
+    store.register_late_pass(move || box matches::Matches::new(msrv, body_node_limit));
     store.register_early_pass(move || box manual_non_exhaustive::ManualNonExhaustive::new(msrv));
     store.register_late_pass(move || box manual_strip::ManualStrip::new(msrv));
     store.register_early_pass(move || box redundant_static_lifetimes::RedundantStaticLifetimes::new(msrv));
@@ -1916,7 +2130,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box temporary_assignment::TemporaryAssignment);
     store.register_late_pass(|| box transmute::Transmute);
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
-    store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
+    store.register_late_pass(move || {
+        box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold, body_node_limit)
+    });
     let too_large_for_stack = conf.too_large_for_stack;
     store.register_late_pass(move || box escape::BoxedLocal{too_large_for_stack});
     store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
@@ -1929,7 +2145,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box absurd_extreme_comparisons::AbsurdExtremeComparisons);
     store.register_late_pass(|| box invalid_upcast_comparisons::InvalidUpcastComparisons);
     store.register_late_pass(|| box regex::Regex::default());
-    store.register_late_pass(|| box copies::CopyAndPaste);
+    store.register_late_pass(move || box copies::CopyAndPaste::new(body_node_limit));
     store.register_late_pass(|| box copy_iterator::CopyIterator);
     store.register_late_pass(|| box format::UselessFormat);
     store.register_late_pass(|| box swap::Swap);
@@ -1941,7 +2157,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let too_many_lines_threshold = conf.too_many_lines_threshold;
     store.register_late_pass(move || box functions::Functions::new(too_many_arguments_threshold, too_many_lines_threshold));
     let doc_valid_idents = conf.doc_valid_idents.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box doc::DocMarkdown::new(doc_valid_idents.clone()));
+    let allow_unwrap_without_panics_doc = conf.allow_unwrap_without_panics_doc;
+    store.register_late_pass(move || box doc::DocMarkdown::new(doc_valid_idents.clone(), allow_unwrap_without_panics_doc));
     store.register_late_pass(|| box neg_multiply::NegMultiply);
     store.register_late_pass(|| box mem_discriminant::MemDiscriminant);
     store.register_late_pass(|| box mem_forget::MemForget);
@@ -1954,7 +2171,6 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box exhaustive_items::ExhaustiveItems);
     store.register_late_pass(|| box if_let_some_result::OkIfLet);
     store.register_late_pass(|| box partialeq_ne_impl::PartialEqNeImpl);
-    store.register_late_pass(|| box unused_io_amount::UnusedIoAmount);
     let enum_variant_size_threshold = conf.enum_variant_size_threshold;
     store.register_late_pass(move || box large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold));
     store.register_late_pass(|| box explicit_write::ExplicitWrite);
@@ -2003,7 +2219,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| box reference::DerefAddrOf);
     store.register_early_pass(|| box reference::RefInDeref);
     store.register_early_pass(|| box double_parens::DoubleParens);
-    store.register_late_pass(|| box to_string_in_display::ToStringInDisplay::new());
+    store.register_late_pass(|| box recursive_format_impl::RecursiveFormatImpl::new());
     store.register_early_pass(|| box unsafe_removed_from_name::UnsafeNameRemoval);
     store.register_early_pass(|| box if_not_else::IfNotElse);
     store.register_early_pass(|| box else_if_without_else::ElseIfWithoutElse);
@@ -2082,8 +2298,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box float_equality_without_abs::FloatEqualityWithoutAbs);
     store.register_late_pass(|| box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
     store.register_late_pass(|| box async_yields_async::AsyncYieldsAsync);
-    let disallowed_methods = conf.disallowed_methods.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box disallowed_method::DisallowedMethod::new(&disallowed_methods));
+    let disallowed_methods = conf.disallowed_methods.clone();
+    store.register_late_pass(move || box disallowed_method::DisallowedMethod::new(disallowed_methods.clone()));
     store.register_early_pass(|| box asm_syntax::InlineAsmX86AttSyntax);
     store.register_early_pass(|| box asm_syntax::InlineAsmX86IntelSyntax);
     store.register_late_pass(|| box undropped_manually_drops::UndroppedManuallyDrops);
@@ -2098,15 +2314,368 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box if_then_some_else_none::IfThenSomeElseNone::new(msrv));
     store.register_early_pass(|| box bool_assert_comparison::BoolAssertComparison);
     store.register_late_pass(|| box unused_async::UnusedAsync);
-    let disallowed_types = conf.disallowed_types.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box disallowed_type::DisallowedType::new(&disallowed_types));
+    let disallowed_types = conf.disallowed_types.clone();
+    store.register_late_pass(move || box disallowed_type::DisallowedType::new(disallowed_types.clone()));
     let import_renames = conf.enforced_import_renames.clone();
     store.register_late_pass(move || box missing_enforced_import_rename::ImportRename::new(import_renames.clone()));
     let scripts = conf.allowed_scripts.clone();
     store.register_early_pass(move || box disallowed_script_idents::DisallowedScriptIdents::new(&scripts));
+    store.register_early_pass(|| box module_style::ModStyle);
     store.register_late_pass(|| box strlen_on_c_strings::StrlenOnCStrings);
     store.register_late_pass(move || box self_named_constructor::SelfNamedConstructor);
+    let disallowed_blocking_paths = conf.disallowed_blocking_paths.clone();
+    store.register_late_pass(move || box async_blocking_call::AsyncBlockingCall::new(disallowed_blocking_paths.clone()));
+    store.register_late_pass(|| box manual_let_else::ManualLetElse);
+    let significant_drop_types = conf.significant_drop_types.clone();
+    store.register_late_pass(move || box significant_drop_in_scrutinee::SignificantDropInScrutinee::new(&significant_drop_types));
+    store.register_late_pass(|| box manual_assert::ManualAssert);
+    store.register_late_pass(|| box rc_clone_in_vec_init::RcCloneInVecInit);
+    store.register_late_pass(move || box needless_pass_by_ref_mut::NeedlessPassByRefMut::new(avoid_breaking_exported_api));
+    store.register_late_pass(|| box format_args::FormatArgs);
+    let undocumented_unsafe_impls = conf.undocumented_unsafe_impls;
+    store.register_late_pass(move || box undocumented_unsafe_blocks::UndocumentedUnsafeBlocks::new(undocumented_unsafe_impls));
+    store.register_late_pass(move || box io::Io::new(msrv));
+    store.register_late_pass(move || box manual_retain::ManualRetain::new(msrv));
+    store.register_late_pass(|| box needless_impls::NeedlessImpls);
+    let allowed_non_send_types = conf.allowed_non_send_types.clone();
+    store.register_late_pass(move || box non_send_fields_in_send_ty::NonSendFieldInSendTy::new(&allowed_non_send_types));
+    store.register_late_pass(|| box iter_not_returning_iterator::IterNotReturningIterator);
+    store.register_late_pass(|| box needless_late_init::NeedlessLateInit);
+    store.register_late_pass(move || box manual_std::ManualStd::new(msrv));
+    store.register_late_pass(move || box only_used_in_recursion::OnlyUsedInRecursion::new(avoid_breaking_exported_api));
+    let allow_unchecked_duration_subtraction_in_tests = conf.allow_unchecked_duration_subtraction_in_tests;
+    store.register_late_pass(move || box instant_subtraction::InstantSubtraction::new(allow_unchecked_duration_subtraction_in_tests));
+    store.register_late_pass(|| box manual_string_new::ManualStringNew);
+    store.register_late_pass(|| box borrow_deref_ref::BorrowDerefRef);
+    store.register_late_pass(|| box redundant_locals::RedundantLocals);
+    let arithmetic_side_effects_allowed = conf.arithmetic_side_effects_allowed.clone();
+    store.register_late_pass(move || box arithmetic_side_effects::ArithmeticSideEffects::new(&arithmetic_side_effects_allowed));
+    store.register_late_pass(|| box needless_borrows_for_generic_args::NeedlessBorrowsForGenericArgs);
+    store.register_late_pass(|| box swap_ptr_to_ref::SwapPtrToRef);
+    store.register_late_pass(|| box missing_asserts_for_indexing::MissingAssertsForIndexing);
+    store.register_early_pass(|| box allowed_clippy_lint::AllowAudit::default());
+
+    utils::timings::time_late_passes(store, sess, first_late_pass);
+    skip_allowed_late_passes(store, first_late_pass);
+}
 
+/// Replaces a late pass whose lints are all allowed, see `skip_allowed_late_passes`.
+///
+/// This isn't declared with `declare_lint_pass!`, which would make `cargo dev update_lints` add it
+/// to `lint_pass_lints`.
+struct SkippedPass;
+
+impl LintPass for SkippedPass {
+    fn name(&self) -> &'static str {
+        "SkippedPass"
+    }
+}
+
+impl<'tcx> rustc_lint::LateLintPass<'tcx> for SkippedPass {}
+
+/// Replaces the late passes registered from `first_pass` on with `SkippedPass` if all of their
+/// lints are `allow`ed in the whole crate, e.g. the restriction lints if none of them is enabled.
+///
+/// The lint levels from attributes are only known after expansion, so this is decided when the
+/// passes are created, right before the late lint passes run.
+fn skip_allowed_late_passes(store: &mut rustc_lint::LintStore, first_pass: usize) {
+    for pass_factory in store.late_passes.split_off(first_pass) {
+        store.register_late_pass(move || {
+            let pass = pass_factory();
+            let skip = lint_pass_lints(pass.name()).map_or(false, |lints| {
+                ty::tls::with_opt(|tcx| {
+                    tcx.map_or(false, |tcx| lints.iter().all(|&lint| is_allowed_in_crate(tcx, lint)))
+                })
+            });
+            if skip { box SkippedPass } else { pass }
+        });
+    }
+}
+
+/// Checks if `lint` is `allow`ed by its default level, the command line and every lint attribute
+/// in the crate.
+fn is_allowed_in_crate(tcx: TyCtxt<'_>, lint: &'static Lint) -> bool {
+    let sets = &tcx.lint_levels(()).sets;
+    let id = LintId::of(lint);
+    sets.lint_cap == Level::Allow
+        || sets.list.iter().all(|set| match set {
+            LintSet::CommandLine { specs } => {
+                specs
+                    .get(&id)
+                    .map_or_else(|| lint.default_level(tcx.sess.edition()), |&(level, _)| level)
+                    == Level::Allow
+            },
+            LintSet::Node { specs, .. } => specs.get(&id).map_or(true, |&(level, _)| level == Level::Allow),
+        })
+}
+
+/// Returns the lints of the lint pass with the given `LintPass::name`. The passes in `utils` are
+/// not listed and therefore never skipped.
+///
+/// Used in `skip_allowed_late_passes`.
+#[allow(clippy::too_many_lines)]
+#[rustfmt::skip]
+fn lint_pass_lints(name: &str) -> Option<LintArray> {
+    Some(match name {
+        // begin lint passes, do not remove this comment, it’s used in `update_lints`
+        "AbsurdExtremeComparisons" => absurd_extreme_comparisons::AbsurdExtremeComparisons::get_lints(),
+        "AllowAudit" => allowed_clippy_lint::AllowAudit::get_lints(),
+        "ApproxConstant" => approx_const::ApproxConstant::get_lints(),
+        "Arithmetic" => arithmetic::Arithmetic::get_lints(),
+        "ArithmeticSideEffects" => arithmetic_side_effects::ArithmeticSideEffects::get_lints(),
+        "AsConversions" => as_conversions::AsConversions::get_lints(),
+        "AssertionsOnConstants" => assertions_on_constants::AssertionsOnConstants::get_lints(),
+        "AssignOps" => assign_ops::AssignOps::get_lints(),
+        "AsyncBlockingCall" => async_blocking_call::AsyncBlockingCall::get_lints(),
+        "AsyncYieldsAsync" => async_yields_async::AsyncYieldsAsync::get_lints(),
+        "AtomicOrdering" => atomic_ordering::AtomicOrdering::get_lints(),
+        "Attributes" => attrs::Attributes::get_lints(),
+        "AwaitHolding" => await_holding_invalid::AwaitHolding::get_lints(),
+        "BitMask" => bit_mask::BitMask::get_lints(),
+        "BlacklistedName" => blacklisted_name::BlacklistedName::get_lints(),
+        "BlocksInIfConditions" => blocks_in_if_conditions::BlocksInIfConditions::get_lints(),
+        "BoolAssertComparison" => bool_assert_comparison::BoolAssertComparison::get_lints(),
+        "BoolComparison" => needless_bool::BoolComparison::get_lints(),
+        "BorrowDerefRef" => borrow_deref_ref::BorrowDerefRef::get_lints(),
+        "BoxedLocal" => escape::BoxedLocal::get_lints(),
+        "ByteCount" => bytecount::ByteCount::get_lints(),
+        "CargoCommonMetadata" => cargo_common_metadata::CargoCommonMetadata::get_lints(),
+        "CaseSensitiveFileExtensionComparisons" => case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons::get_lints(),
+        "Casts" => casts::Casts::get_lints(),
+        "CheckedConversions" => checked_conversions::CheckedConversions::get_lints(),
+        "CognitiveComplexity" => cognitive_complexity::CognitiveComplexity::get_lints(),
+        "CollapsibleIf" => collapsible_if::CollapsibleIf::get_lints(),
+        "CollapsibleMatch" => collapsible_match::CollapsibleMatch::get_lints(),
+        "ComparisonChain" => comparison_chain::ComparisonChain::get_lints(),
+        "CopyAndPaste" => copies::CopyAndPaste::get_lints(),
+        "CopyIterator" => copy_iterator::CopyIterator::get_lints(),
+        "CreateDir" => create_dir::CreateDir::get_lints(),
+        "DbgMacro" => dbg_macro::DbgMacro::get_lints(),
+        "DebugAssertWithMutCall" => mutable_debug_assertion::DebugAssertWithMutCall::get_lints(),
+        "DecimalLiteralRepresentation" => literal_representation::DecimalLiteralRepresentation::get_lints(),
+        "Default" => default::Default::get_lints(),
+        "DefaultNumericFallback" => default_numeric_fallback::DefaultNumericFallback::get_lints(),
+        "DerefAddrOf" => reference::DerefAddrOf::get_lints(),
+        "Dereferencing" => dereference::Dereferencing::get_lints(),
+        "Derive" => derive::Derive::get_lints(),
+        "DisallowedMethod" => disallowed_method::DisallowedMethod::get_lints(),
+        "DisallowedScriptIdents" => disallowed_script_idents::DisallowedScriptIdents::get_lints(),
+        "DisallowedType" => disallowed_type::DisallowedType::get_lints(),
+        "DocMarkdown" => doc::DocMarkdown::get_lints(),
+        "DoubleComparisons" => double_comparison::DoubleComparisons::get_lints(),
+        "DoubleParens" => double_parens::DoubleParens::get_lints(),
+        "DropForgetRef" => drop_forget_ref::DropForgetRef::get_lints(),
+        "DurationSubsec" => duration_subsec::DurationSubsec::get_lints(),
+        "EarlyAttributes" => attrs::EarlyAttributes::get_lints(),
+        "ElseIfWithoutElse" => else_if_without_else::ElseIfWithoutElse::get_lints(),
+        "EmptyEnum" => empty_enum::EmptyEnum::get_lints(),
+        "EnumVariantNames" => enum_variants::EnumVariantNames::get_lints(),
+        "EqOp" => eq_op::EqOp::get_lints(),
+        "ErasingOp" => erasing_op::ErasingOp::get_lints(),
+        "EtaReduction" => eta_reduction::EtaReduction::get_lints(),
+        "EvalOrderDependence" => eval_order_dependence::EvalOrderDependence::get_lints(),
+        "ExcessiveBools" => excessive_bools::ExcessiveBools::get_lints(),
+        "ExhaustiveItems" => exhaustive_items::ExhaustiveItems::get_lints(),
+        "Exit" => exit::Exit::get_lints(),
+        "ExplicitWrite" => explicit_write::ExplicitWrite::get_lints(),
+        "FallibleImplFrom" => fallible_impl_from::FallibleImplFrom::get_lints(),
+        "FloatEqualityWithoutAbs" => float_equality_without_abs::FloatEqualityWithoutAbs::get_lints(),
+        "FloatLiteral" => float_literal::FloatLiteral::get_lints(),
+        "FloatingPointArithmetic" => floating_point_arithmetic::FloatingPointArithmetic::get_lints(),
+        "FormatArgs" => format_args::FormatArgs::get_lints(),
+        "Formatting" => formatting::Formatting::get_lints(),
+        "FromOverInto" => from_over_into::FromOverInto::get_lints(),
+        "FromStrRadix10" => from_str_radix_10::FromStrRadix10::get_lints(),
+        "Functions" => functions::Functions::get_lints(),
+        "FutureNotSend" => future_not_send::FutureNotSend::get_lints(),
+        "GetLastWithLen" => get_last_with_len::GetLastWithLen::get_lints(),
+        "HashMapPass" => entry::HashMapPass::get_lints(),
+        "IdentityOp" => identity_op::IdentityOp::get_lints(),
+        "IfLetMutex" => if_let_mutex::IfLetMutex::get_lints(),
+        "IfNotElse" => if_not_else::IfNotElse::get_lints(),
+        "IfThenSomeElseNone" => if_then_some_else_none::IfThenSomeElseNone::get_lints(),
+        "ImplicitHasher" => implicit_hasher::ImplicitHasher::get_lints(),
+        "ImplicitReturn" => implicit_return::ImplicitReturn::get_lints(),
+        "ImplicitSaturatingSub" => implicit_saturating_sub::ImplicitSaturatingSub::get_lints(),
+        "ImportRename" => missing_enforced_import_rename::ImportRename::get_lints(),
+        "InconsistentStructConstructor" => inconsistent_struct_constructor::InconsistentStructConstructor::get_lints(),
+        "IndexingSlicing" => indexing_slicing::IndexingSlicing::get_lints(),
+        "InfiniteIter" => infinite_iter::InfiniteIter::get_lints(),
+        "InherentToString" => inherent_to_string::InherentToString::get_lints(),
+        "InlineAsmX86AttSyntax" => asm_syntax::InlineAsmX86AttSyntax::get_lints(),
+        "InlineAsmX86IntelSyntax" => asm_syntax::InlineAsmX86IntelSyntax::get_lints(),
+        "InlineFnWithoutBody" => inline_fn_without_body::InlineFnWithoutBody::get_lints(),
+        "InstantSubtraction" => instant_subtraction::InstantSubtraction::get_lints(),
+        "IntPlusOne" => int_plus_one::IntPlusOne::get_lints(),
+        "IntegerDivision" => integer_division::IntegerDivision::get_lints(),
+        "InvalidUpcastComparisons" => invalid_upcast_comparisons::InvalidUpcastComparisons::get_lints(),
+        "Io" => io::Io::get_lints(),
+        "ItemsAfterStatements" => items_after_statements::ItemsAfterStatements::get_lints(),
+        "IterNotReturningIterator" => iter_not_returning_iterator::IterNotReturningIterator::get_lints(),
+        "LargeConstArrays" => large_const_arrays::LargeConstArrays::get_lints(),
+        "LargeEnumVariant" => large_enum_variant::LargeEnumVariant::get_lints(),
+        "LargeStackArrays" => large_stack_arrays::LargeStackArrays::get_lints(),
+        "LenZero" => len_zero::LenZero::get_lints(),
+        "LetIfSeq" => let_if_seq::LetIfSeq::get_lints(),
+        "LetUnderscore" => let_underscore::LetUnderscore::get_lints(),
+        "Lifetimes" => lifetimes::Lifetimes::get_lints(),
+        "LiteralDigitGrouping" => literal_representation::LiteralDigitGrouping::get_lints(),
+        "Loops" => loops::Loops::get_lints(),
+        "MacroBraces" => nonstandard_macro_braces::MacroBraces::get_lints(),
+        "MacroUseImports" => macro_use::MacroUseImports::get_lints(),
+        "MainRecursion" => main_recursion::MainRecursion::get_lints(),
+        "ManualAssert" => manual_assert::ManualAssert::get_lints(),
+        "ManualAsyncFn" => manual_async_fn::ManualAsyncFn::get_lints(),
+        "ManualLetElse" => manual_let_else::ManualLetElse::get_lints(),
+        "ManualMap" => manual_map::ManualMap::get_lints(),
+        "ManualNonExhaustive" => manual_non_exhaustive::ManualNonExhaustive::get_lints(),
+        "ManualOkOr" => manual_ok_or::ManualOkOr::get_lints(),
+        "ManualRetain" => manual_retain::ManualRetain::get_lints(),
+        "ManualStd" => manual_std::ManualStd::get_lints(),
+        "ManualStringNew" => manual_string_new::ManualStringNew::get_lints(),
+        "ManualStrip" => manual_strip::ManualStrip::get_lints(),
+        "ManualUnwrapOr" => manual_unwrap_or::ManualUnwrapOr::get_lints(),
+        "MapClone" => map_clone::MapClone::get_lints(),
+        "MapErrIgnore" => map_err_ignore::MapErrIgnore::get_lints(),
+        "MapUnit" => map_unit_fn::MapUnit::get_lints(),
+        "MatchOnVecItems" => match_on_vec_items::MatchOnVecItems::get_lints(),
+        "Matches" => matches::Matches::get_lints(),
+        "MemDiscriminant" => mem_discriminant::MemDiscriminant::get_lints(),
+        "MemForget" => mem_forget::MemForget::get_lints(),
+        "MemReplace" => mem_replace::MemReplace::get_lints(),
+        "Methods" => methods::Methods::get_lints(),
+        "MinMaxPass" => minmax::MinMaxPass::get_lints(),
+        "MiscEarlyLints" => misc_early::MiscEarlyLints::get_lints(),
+        "MiscLints" => misc::MiscLints::get_lints(),
+        "MissingAssertsForIndexing" => missing_asserts_for_indexing::MissingAssertsForIndexing::get_lints(),
+        "MissingConstForFn" => missing_const_for_fn::MissingConstForFn::get_lints(),
+        "MissingDoc" => missing_doc::MissingDoc::get_lints(),
+        "MissingInline" => missing_inline::MissingInline::get_lints(),
+        "ModStyle" => module_style::ModStyle::get_lints(),
+        "ModuloArithmetic" => modulo_arithmetic::ModuloArithmetic::get_lints(),
+        "MultipleCrateVersions" => multiple_crate_versions::MultipleCrateVersions::get_lints(),
+        "MultipleInherentImpl" => inherent_impl::MultipleInherentImpl::get_lints(),
+        "MutMut" => mut_mut::MutMut::get_lints(),
+        "MutMutexLock" => mut_mutex_lock::MutMutexLock::get_lints(),
+        "MutableKeyType" => mut_key::MutableKeyType::get_lints(),
+        "Mutex" => mutex_atomic::Mutex::get_lints(),
+        "NeedlessArbitrarySelfType" => needless_arbitrary_self_type::NeedlessArbitrarySelfType::get_lints(),
+        "NeedlessBitwiseBool" => needless_bitwise_bool::NeedlessBitwiseBool::get_lints(),
+        "NeedlessBool" => needless_bool::NeedlessBool::get_lints(),
+        "NeedlessBorrow" => needless_borrow::NeedlessBorrow::get_lints(),
+        "NeedlessBorrowedRef" => needless_borrowed_ref::NeedlessBorrowedRef::get_lints(),
+        "NeedlessBorrowsForGenericArgs" => needless_borrows_for_generic_args::NeedlessBorrowsForGenericArgs::get_lints(),
+        "NeedlessContinue" => needless_continue::NeedlessContinue::get_lints(),
+        "NeedlessForEach" => needless_for_each::NeedlessForEach::get_lints(),
+        "NeedlessImpls" => needless_impls::NeedlessImpls::get_lints(),
+        "NeedlessLateInit" => needless_late_init::NeedlessLateInit::get_lints(),
+        "NeedlessPassByRefMut" => needless_pass_by_ref_mut::NeedlessPassByRefMut::get_lints(),
+        "NeedlessPassByValue" => needless_pass_by_value::NeedlessPassByValue::get_lints(),
+        "NeedlessQuestionMark" => needless_question_mark::NeedlessQuestionMark::get_lints(),
+        "NeedlessUpdate" => needless_update::NeedlessUpdate::get_lints(),
+        "NegMultiply" => neg_multiply::NegMultiply::get_lints(),
+        "NewWithoutDefault" => new_without_default::NewWithoutDefault::get_lints(),
+        "NoEffect" => no_effect::NoEffect::get_lints(),
+        "NoNegCompOpForPartialOrd" => neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd::get_lints(),
+        "NonCopyConst" => non_copy_const::NonCopyConst::get_lints(),
+        "NonExpressiveNames" => non_expressive_names::NonExpressiveNames::get_lints(),
+        "NonOctalUnixPermissions" => non_octal_unix_permissions::NonOctalUnixPermissions::get_lints(),
+        "NonSendFieldInSendTy" => non_send_fields_in_send_ty::NonSendFieldInSendTy::get_lints(),
+        "NonminimalBool" => booleans::NonminimalBool::get_lints(),
+        "OkIfLet" => if_let_some_result::OkIfLet::get_lints(),
+        "OnlyUsedInRecursion" => only_used_in_recursion::OnlyUsedInRecursion::get_lints(),
+        "OpenOptions" => open_options::OpenOptions::get_lints(),
+        "OptionEnvUnwrap" => option_env_unwrap::OptionEnvUnwrap::get_lints(),
+        "OptionIfLetElse" => option_if_let_else::OptionIfLetElse::get_lints(),
+        "OverflowCheckConditional" => overflow_check_conditional::OverflowCheckConditional::get_lints(),
+        "PanicInResultFn" => panic_in_result_fn::PanicInResultFn::get_lints(),
+        "PanicUnimplemented" => panic_unimplemented::PanicUnimplemented::get_lints(),
+        "PartialEqNeImpl" => partialeq_ne_impl::PartialEqNeImpl::get_lints(),
+        "PassByRefOrValue" => pass_by_ref_or_value::PassByRefOrValue::get_lints(),
+        "PathBufPushOverwrite" => path_buf_push_overwrite::PathBufPushOverwrite::get_lints(),
+        "PatternTypeMismatch" => pattern_type_mismatch::PatternTypeMismatch::get_lints(),
+        "Precedence" => precedence::Precedence::get_lints(),
+        "Ptr" => ptr::Ptr::get_lints(),
+        "PtrEq" => ptr_eq::PtrEq::get_lints(),
+        "PtrOffsetWithCast" => ptr_offset_with_cast::PtrOffsetWithCast::get_lints(),
+        "QuestionMark" => question_mark::QuestionMark::get_lints(),
+        "Ranges" => ranges::Ranges::get_lints(),
+        "RcCloneInVecInit" => rc_clone_in_vec_init::RcCloneInVecInit::get_lints(),
+        "RecursiveFormatImpl" => recursive_format_impl::RecursiveFormatImpl::get_lints(),
+        "RedundantClone" => redundant_clone::RedundantClone::get_lints(),
+        "RedundantClosureCall" => redundant_closure_call::RedundantClosureCall::get_lints(),
+        "RedundantElse" => redundant_else::RedundantElse::get_lints(),
+        "RedundantFieldNames" => redundant_field_names::RedundantFieldNames::get_lints(),
+        "RedundantLocals" => redundant_locals::RedundantLocals::get_lints(),
+        "RedundantPubCrate" => redundant_pub_crate::RedundantPubCrate::get_lints(),
+        "RedundantSlicing" => redundant_slicing::RedundantSlicing::get_lints(),
+        "RedundantStaticLifetimes" => redundant_static_lifetimes::RedundantStaticLifetimes::get_lints(),
+        "RefInDeref" => reference::RefInDeref::get_lints(),
+        "RefOptionRef" => ref_option_ref::RefOptionRef::get_lints(),
+        "Regex" => regex::Regex::get_lints(),
+        "RepeatOnce" => repeat_once::RepeatOnce::get_lints(),
+        "Return" => returns::Return::get_lints(),
+        "SelfAssignment" => self_assignment::SelfAssignment::get_lints(),
+        "SelfNamedConstructor" => self_named_constructor::SelfNamedConstructor::get_lints(),
+        "SemicolonIfNothingReturned" => semicolon_if_nothing_returned::SemicolonIfNothingReturned::get_lints(),
+        "SerdeApi" => serde_api::SerdeApi::get_lints(),
+        "Shadow" => shadow::Shadow::get_lints(),
+        "SignificantDropInScrutinee" => significant_drop_in_scrutinee::SignificantDropInScrutinee::get_lints(),
+        "SingleComponentPathImports" => single_component_path_imports::SingleComponentPathImports::get_lints(),
+        "SizeOfInElementCount" => size_of_in_element_count::SizeOfInElementCount::get_lints(),
+        "SlowVectorInit" => slow_vector_initialization::SlowVectorInit::get_lints(),
+        "StableSortPrimitive" => stable_sort_primitive::StableSortPrimitive::get_lints(),
+        "StrToString" => strings::StrToString::get_lints(),
+        "StringAdd" => strings::StringAdd::get_lints(),
+        "StringLitAsBytes" => strings::StringLitAsBytes::get_lints(),
+        "StringToString" => strings::StringToString::get_lints(),
+        "StrlenOnCStrings" => strlen_on_c_strings::StrlenOnCStrings::get_lints(),
+        "SuspiciousImpl" => suspicious_trait_impl::SuspiciousImpl::get_lints(),
+        "SuspiciousOperationGroupings" => suspicious_operation_groupings::SuspiciousOperationGroupings::get_lints(),
+        "Swap" => swap::Swap::get_lints(),
+        "SwapPtrToRef" => swap_ptr_to_ref::SwapPtrToRef::get_lints(),
+        "TabsInDocComments" => tabs_in_doc_comments::TabsInDocComments::get_lints(),
+        "TemporaryAssignment" => temporary_assignment::TemporaryAssignment::get_lints(),
+        "ToDigitIsSome" => to_digit_is_some::ToDigitIsSome::get_lints(),
+        "TraitBounds" => trait_bounds::TraitBounds::get_lints(),
+        "Transmute" => transmute::Transmute::get_lints(),
+        "TransmutingNull" => transmuting_null::TransmutingNull::get_lints(),
+        "TryErr" => try_err::TryErr::get_lints(),
+        "Types" => types::Types::get_lints(),
+        "UndocumentedUnsafeBlocks" => undocumented_unsafe_blocks::UndocumentedUnsafeBlocks::get_lints(),
+        "UndroppedManuallyDrops" => undropped_manually_drops::UndroppedManuallyDrops::get_lints(),
+        "Unicode" => unicode::Unicode::get_lints(),
+        "UnitReturnExpectingOrd" => unit_return_expecting_ord::UnitReturnExpectingOrd::get_lints(),
+        "UnitTypes" => unit_types::UnitTypes::get_lints(),
+        "UnnamedAddress" => unnamed_address::UnnamedAddress::get_lints(),
+        "UnnecessaryMutPassed" => mut_reference::UnnecessaryMutPassed::get_lints(),
+        "UnnecessarySelfImports" => unnecessary_self_imports::UnnecessarySelfImports::get_lints(),
+        "UnnecessarySortBy" => unnecessary_sort_by::UnnecessarySortBy::get_lints(),
+        "UnnecessaryWraps" => unnecessary_wraps::UnnecessaryWraps::get_lints(),
+        "UnnestedOrPatterns" => unnested_or_patterns::UnnestedOrPatterns::get_lints(),
+        "UnportableVariant" => enum_clike::UnportableVariant::get_lints(),
+        "UnsafeNameRemoval" => unsafe_removed_from_name::UnsafeNameRemoval::get_lints(),
+        "UnusedAsync" => unused_async::UnusedAsync::get_lints(),
+        "UnusedSelf" => unused_self::UnusedSelf::get_lints(),
+        "UnusedUnit" => unused_unit::UnusedUnit::get_lints(),
+        "Unwrap" => unwrap::Unwrap::get_lints(),
+        "UnwrapInResult" => unwrap_in_result::UnwrapInResult::get_lints(),
+        "UpperCaseAcronyms" => upper_case_acronyms::UpperCaseAcronyms::get_lints(),
+        "UseSelf" => use_self::UseSelf::get_lints(),
+        "UselessConversion" => useless_conversion::UselessConversion::get_lints(),
+        "UselessFormat" => format::UselessFormat::get_lints(),
+        "UselessVec" => vec::UselessVec::get_lints(),
+        "VecInitThenPush" => vec_init_then_push::VecInitThenPush::get_lints(),
+        "VecResizeToZero" => vec_resize_to_zero::VecResizeToZero::get_lints(),
+        "VerboseFileReads" => verbose_file_reads::VerboseFileReads::get_lints(),
+        "WildcardDependencies" => wildcard_dependencies::WildcardDependencies::get_lints(),
+        "WildcardImports" => wildcard_imports::WildcardImports::get_lints(),
+        "Write" => write::Write::get_lints(),
+        "ZeroDiv" => zero_div_zero::ZeroDiv::get_lints(),
+        "ZeroSizedMapValues" => zero_sized_map_values::ZeroSizedMapValues::get_lints(),
+        // end lint passes, do not remove this comment, it’s used in `update_lints`
+        _ => return None,
+    })
 }
 
 #[rustfmt::skip]
@@ -2176,6 +2745,7 @@ pub fn register_renamed(ls: &mut rustc_lint::LintStore) {
     ls.register_renamed("clippy::identity_conversion", "clippy::useless_conversion");
     ls.register_renamed("clippy::zero_width_space", "clippy::invisible_characters");
     ls.register_renamed("clippy::single_char_push_str", "clippy::single_char_add_str");
+    ls.register_renamed("clippy::to_string_in_display", "clippy::recursive_format_impl");
 
     // uplifted lints
     ls.register_renamed("clippy::invalid_ref", "invalid_value");
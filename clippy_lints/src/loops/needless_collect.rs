@@ -3,11 +3,11 @@ use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_trait_method, path_to_local_id};
+use clippy_utils::{is_trait_method, path_to_local, path_to_local_id};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::intravisit::{walk_block, walk_expr, NestedVisitorMap, Visitor};
-use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_hir::intravisit::{walk_block, walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
 use rustc_span::sym;
@@ -79,12 +79,19 @@ fn check_needless_collect_indirect_usage<'tcx>(expr: &'tcx Expr<'_>, cx: &LateCo
                 if let ExprKind::MethodCall(method_name, collect_span, &[ref iter_source], ..) = init_expr.kind;
                 if method_name.ident.name == sym!(collect) && is_trait_method(cx, init_expr, sym::Iterator);
                 let ty = cx.typeck_results().expr_ty(init_expr);
-                if is_type_diagnostic_item(cx, ty, sym::vec_type) ||
+                let is_sequence = is_type_diagnostic_item(cx, ty, sym::vec_type) ||
                     is_type_diagnostic_item(cx, ty, sym::vecdeque_type) ||
                     is_type_diagnostic_item(cx, ty, sym::BinaryHeap) ||
                     is_type_diagnostic_item(cx, ty, sym::LinkedList);
-                if let Some(iter_calls) = detect_iter_and_into_iters(block, id);
+                // Maps and sets drop duplicates, so only `is_empty` is the same for the iterator
+                let is_map_or_set = is_type_diagnostic_item(cx, ty, sym::hashmap_type) ||
+                    is_type_diagnostic_item(cx, ty, sym::hashset_type) ||
+                    is_type_diagnostic_item(cx, ty, sym::BTreeMap) ||
+                    is_type_diagnostic_item(cx, ty, sym::BTreeSet);
+                if is_sequence || is_map_or_set;
+                if let Some(iter_calls) = detect_iter_and_into_iters(block, id, local_ids(iter_source));
                 if let [iter_call] = &*iter_calls;
+                if is_sequence || matches!(iter_call.func, IterFunctionKind::IsEmpty);
                 then {
                     let mut used_count_visitor = UsedCountVisitor {
                         cx,
@@ -171,12 +178,40 @@ struct IterFunctionVisitor {
     uses: Vec<IterFunction>,
     seen_other: bool,
     target: HirId,
+    /// The locals used by the iterator which is collected
+    source_locals: Vec<HirId>,
+    /// Whether the statement binding the collection has been visited
+    seen_let: bool,
+    loop_depth: usize,
 }
 impl<'tcx> Visitor<'tcx> for IterFunctionVisitor {
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'tcx>) {
+        if let StmtKind::Local(local) = stmt.kind {
+            if let PatKind::Binding(_, id, ..) = local.pat.kind {
+                if id == self.target {
+                    self.seen_let = true;
+                    return;
+                }
+            }
+        }
+        walk_stmt(self, stmt);
+    }
+
     fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Loop(..) = expr.kind {
+            self.loop_depth += 1;
+            walk_expr(self, expr);
+            self.loop_depth -= 1;
+            return;
+        }
         // Check function calls on our collection
         if let ExprKind::MethodCall(method_name, _, [recv, args @ ..], _) = &expr.kind {
             if path_to_local_id(recv, self.target) {
+                // The iterator can only be consumed once
+                if self.loop_depth > 0 {
+                    self.seen_other = true;
+                    return;
+                }
                 match &*method_name.ident.name.as_str() {
                     "into_iter" => self.uses.push(IterFunction {
                         func: IterFunctionKind::IntoIter,
@@ -202,6 +237,12 @@ impl<'tcx> Visitor<'tcx> for IterFunctionVisitor {
         // Check if the collection is used for anything else
         if path_to_local_id(expr, self.target) {
             self.seen_other = true;
+        } else if self.seen_let
+            && self.uses.is_empty()
+            && self.source_locals.iter().any(|&id| path_to_local_id(expr, id))
+        {
+            // Moving the iterator past other uses of its locals could change what it yields
+            self.seen_other = true;
         } else {
             walk_expr(self, expr);
         }
@@ -237,12 +278,45 @@ impl<'a, 'tcx> Visitor<'tcx> for UsedCountVisitor<'a, 'tcx> {
 
 /// Detect the occurrences of calls to `iter` or `into_iter` for the
 /// given identifier
-fn detect_iter_and_into_iters<'tcx>(block: &'tcx Block<'tcx>, id: HirId) -> Option<Vec<IterFunction>> {
+fn detect_iter_and_into_iters<'tcx>(
+    block: &'tcx Block<'tcx>,
+    id: HirId,
+    source_locals: Vec<HirId>,
+) -> Option<Vec<IterFunction>> {
     let mut visitor = IterFunctionVisitor {
         uses: Vec::new(),
         target: id,
         seen_other: false,
+        source_locals,
+        seen_let: false,
+        loop_depth: 0,
     };
     visitor.visit_block(block);
     if visitor.seen_other { None } else { Some(visitor.uses) }
 }
+
+/// Collects the locals used in `expr`.
+fn local_ids<'tcx>(expr: &'tcx Expr<'tcx>) -> Vec<HirId> {
+    struct LocalVisitor {
+        ids: Vec<HirId>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for LocalVisitor {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if let Some(id) = path_to_local(expr) {
+                self.ids.push(id);
+            }
+            walk_expr(self, expr);
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = LocalVisitor { ids: Vec::new() };
+    visitor.visit_expr(expr);
+    visitor.ids
+}
@@ -2,7 +2,8 @@ use super::WHILE_LET_ON_ITERATOR;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{
-    get_enclosing_loop_or_closure, is_refutable, is_trait_method, match_def_path, paths, visitors::is_res_used,
+    get_enclosing_loop_or_closure, is_refutable, is_trait_method, match_def_path, paths,
+    visitors::{is_expr_pure, is_res_used},
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -97,7 +98,7 @@ fn try_parse_iter_expr(cx: &LateContext<'_>, mut e: &Expr<'_>) -> Option<IterExp
 
             // Shouldn't have side effects, but there's no way to trace which field is used. So forget which fields have
             // already been seen.
-            ExprKind::Index(base, idx) if !idx.can_have_side_effects() => {
+            ExprKind::Index(base, idx) if is_expr_pure(cx, idx) => {
                 fields.clear();
                 e = base;
             },
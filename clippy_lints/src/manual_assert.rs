@@ -0,0 +1,102 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{is_expn_of, match_panic_call, sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Detects `if`-then-`panic!` that can be replaced with `assert!`.
+    ///
+    /// **Why is this bad?** `assert!` is simpler than `if`-then-`panic!`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let sad_people: Vec<&str> = vec![];
+    /// if !sad_people.is_empty() {
+    ///     panic!("there are sad people: {:?}", sad_people);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let sad_people: Vec<&str> = vec![];
+    /// assert!(sad_people.is_empty(), "there are sad people: {:?}", sad_people);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_ASSERT,
+    pedantic,
+    "`panic!` and only a `panic!` in `if`-then statement"
+}
+
+declare_lint_pass!(ManualAssert => [MANUAL_ASSERT]);
+
+impl LateLintPass<'_> for ManualAssert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::If(cond, then, None) = expr.kind;
+            // `assert!` itself expands to this
+            if !expr.span.from_expansion();
+            if let ExprKind::Block(block, _) = then.kind;
+            if let Some(panic_expr) = single_expr(block);
+            if match_panic_call(cx, panic_expr).is_some();
+            if let Some(panic_span) = is_expn_of(panic_expr.span, "panic");
+            if let Some(panic_snip) = snippet_opt(cx, panic_span);
+            if let Some(args) = macro_args(&panic_snip);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let cond = match cond.kind {
+                    ExprKind::DropTemps(cond) => cond,
+                    _ => cond,
+                };
+                let cond_sugg = match cond.kind {
+                    ExprKind::Unary(UnOp::Not, inner) => {
+                        sugg::Sugg::hir_with_applicability(cx, inner, "..", &mut applicability)
+                    },
+                    _ => !sugg::Sugg::hir_with_applicability(cx, cond, "..", &mut applicability),
+                };
+                let sugg = if args.is_empty() {
+                    format!("assert!({});", cond_sugg)
+                } else {
+                    format!("assert!({}, {});", cond_sugg, args)
+                };
+
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_ASSERT,
+                    expr.span,
+                    "only a `panic!` in `if`-then statement",
+                    "try",
+                    sugg,
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the only expression of the block, either as its trailing expression or as its only
+/// statement. Nested blocks are peeled.
+fn single_expr<'tcx>(block: &'tcx Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let expr = match (block.stmts, block.expr) {
+        ([], Some(expr)) => expr,
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    match expr.kind {
+        ExprKind::Block(block, _) => single_expr(block),
+        _ => Some(expr),
+    }
+}
+
+/// Returns the arguments of a macro call given its source, e.g. `"{}", x` for `panic!("{}", x)`.
+fn macro_args(snip: &str) -> Option<&str> {
+    let args = snip.get(snip.find('!')? + 1..)?.trim_start();
+    Some(args.get(1..args.len().checked_sub(1)?)?.trim())
+}
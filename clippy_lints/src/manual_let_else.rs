@@ -0,0 +1,246 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{
+    indent_of, snippet, snippet_opt, snippet_with_applicability, snippet_with_context, walk_span_to_context,
+};
+use clippy_utils::{in_macro, path_to_local};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, ExprKind, Local, MatchSource, Pat, PatKind};
+use rustc_lexer::{tokenize, TokenKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+use std::ops::Range;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `let` statements initialized by a `match` or `if let`, which
+    /// only returns the binding of one pattern and diverges (e.g. with `return`, `break`,
+    /// `continue` or `panic!`) otherwise.
+    ///
+    /// **Why is this bad?** A `let ... else` statement does the same with less nesting and
+    /// without renaming the binding.
+    ///
+    /// **Known problems:** `let ... else` isn't implemented by the nightly compiler Clippy is
+    /// pinned to, so the lint only runs in crates enabling `#![feature(let_else)]` and is silent
+    /// until a compiler supporting that feature is used. Comments outside of the diverging arm are
+    /// moved to the top of the `else` block.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # fn f(opt: Option<u32>) {
+    /// let x = match opt {
+    ///     Some(v) => v,
+    ///     None => return,
+    /// };
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// # fn f(opt: Option<u32>) {
+    /// let Some(x) = opt else { return };
+    /// # }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_LET_ELSE,
+    nursery,
+    "manual implementation of a `let ... else` statement"
+}
+
+declare_lint_pass!(ManualLetElse => [MANUAL_LET_ELSE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualLetElse {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'_>) {
+        if_chain! {
+            if !in_macro(local.span);
+            if let_else_enabled(cx);
+            if local.ty.is_none();
+            if let PatKind::Binding(BindingAnnotation::Unannotated | BindingAnnotation::Mutable, ..) = local.pat.kind;
+            if let Some(init) = local.init;
+            if let ExprKind::Match(scrutinee, [arm1, arm2], MatchSource::Normal | MatchSource::IfLetDesugar { .. }) =
+                init.kind;
+            if let Some((binding_span, kept_arm, diverging_arm)) = kept_binding(arm1)
+                .map(|binding| (binding, arm1, arm2))
+                .or_else(|| kept_binding(arm2).map(|binding| (binding, arm2, arm1)));
+            if diverging_arm.guard.is_none();
+            if cx.typeck_results().expr_ty(diverging_arm.body).is_never();
+            if !binds_anything(diverging_arm.pat);
+            if !kept_arm.pat.span.from_expansion();
+            let local_snip = snippet(cx, local.pat.span, "..");
+            if let Some(pat_snip) = snippet_opt(cx, kept_arm.pat.span)
+                .and_then(|snip| replace_span(&snip, kept_arm.pat.span, binding_span, &local_snip));
+            then {
+                let mut applicability = Applicability::MaybeIncorrect;
+                let ctxt = local.span.ctxt();
+                let (scrutinee_snip, _) = snippet_with_context(cx, scrutinee.span, ctxt, "..", &mut applicability);
+                // `let ... else` doesn't allow the initializer to end with a `}` or to be a lazy
+                // boolean expression
+                let needs_parens = scrutinee_snip.ends_with('}') || matches!(scrutinee.kind, ExprKind::Binary(..));
+                let scrutinee_snip = if needs_parens {
+                    format!("({})", scrutinee_snip)
+                } else {
+                    scrutinee_snip.into_owned()
+                };
+
+                // e.g. the call site of `panic!`
+                let else_span =
+                    walk_span_to_context(diverging_arm.body.span, ctxt).unwrap_or(diverging_arm.body.span);
+                let else_snip = snippet_with_applicability(cx, else_span, "..", &mut applicability);
+                let else_block = !diverging_arm.body.span.from_expansion()
+                    && matches!(diverging_arm.body.kind, ExprKind::Block(..));
+
+                // The comments of the other arm and around the `match` would be lost otherwise
+                let init_snip = snippet(cx, init.span, "");
+                let else_range = if init.span.contains(else_span) {
+                    (else_span.lo() - init.span.lo()).0 as usize..(else_span.hi() - init.span.lo()).0 as usize
+                } else {
+                    0..0
+                };
+                let comments = comments_outside(&init_snip, else_range);
+                let else_snip = if !comments.is_empty() {
+                    let body = if else_block {
+                        strip_braces(&else_snip)
+                    } else {
+                        else_snip.trim()
+                    };
+                    block_with_comments(body, &comments, indent_of(cx, local.span).unwrap_or(0))
+                } else if else_block {
+                    else_snip.into_owned()
+                } else {
+                    format!("{{ {} }}", else_snip)
+                };
+
+                let span = local.span.with_hi(init.span.hi());
+                let sugg = format!("let {} = {} else {}", pat_snip, scrutinee_snip, else_snip);
+                span_lint_and_then(
+                    cx,
+                    MANUAL_LET_ELSE,
+                    span,
+                    "this could be rewritten as `let ... else`",
+                    |diag| {
+                        diag.span_suggestion(span, "consider writing", sugg, applicability);
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the span of the binding if `arm` only returns the single binding of its pattern.
+fn kept_binding(arm: &Arm<'_>) -> Option<Span> {
+    let body = match arm.body.kind {
+        ExprKind::Block(block, None) if block.stmts.is_empty() => block.expr?,
+        _ => arm.body,
+    };
+    let local_id = path_to_local(body)?;
+
+    let mut binding = None;
+    let mut binding_count = 0;
+    arm.pat.walk_always(|pat| {
+        if let PatKind::Binding(annotation, id, _, sub_pat) = pat.kind {
+            binding_count += 1;
+            if id == local_id
+                && sub_pat.is_none()
+                && matches!(annotation, BindingAnnotation::Unannotated | BindingAnnotation::Mutable)
+            {
+                binding = Some(pat.span);
+            }
+        }
+    });
+    if arm.guard.is_none() && binding_count == 1 {
+        binding
+    } else {
+        None
+    }
+}
+
+fn binds_anything(pat: &Pat<'_>) -> bool {
+    let mut binds = false;
+    pat.walk_always(|pat| binds |= matches!(pat.kind, PatKind::Binding(..)));
+    binds
+}
+
+/// Replaces the part of `snip` (the source of `span`) which corresponds to `inner` with
+/// `replacement`.
+fn replace_span(snip: &str, span: Span, inner: Span, replacement: &str) -> Option<String> {
+    if !span.contains(inner) {
+        return None;
+    }
+    let start = (inner.lo() - span.lo()).0 as usize;
+    let end = (inner.hi() - span.lo()).0 as usize;
+    Some(format!("{}{}{}", snip.get(..start)?, replacement, snip.get(end..)?))
+}
+
+/// Checks if the crate enables the `let_else` feature, which the compiler doesn't know yet.
+fn let_else_enabled(cx: &LateContext<'_>) -> bool {
+    cx.tcx
+        .features()
+        .declared_lang_features
+        .iter()
+        .any(|&(name, ..)| name == sym!(let_else))
+}
+
+/// Returns the comments of `snip` which aren't inside the byte range `inner`.
+fn comments_outside(snip: &str, inner: Range<usize>) -> Vec<&str> {
+    let mut pos = 0;
+    tokenize(snip)
+        .filter_map(|token| {
+            let range = pos..pos + token.len;
+            pos = range.end;
+            let is_comment = matches!(
+                token.kind,
+                TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            );
+            (is_comment && (range.start < inner.start || range.end > inner.end)).then(|| &snip[range])
+        })
+        .collect()
+}
+
+/// Returns the content of the block `snip` without the surrounding braces.
+fn strip_braces(snip: &str) -> &str {
+    snip.trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .unwrap_or(snip)
+        .trim()
+}
+
+/// Builds a block with the `comments` followed by `body`, each on its own line, for a statement
+/// indented by `indent` spaces.
+fn block_with_comments(body: &str, comments: &[&str], indent: usize) -> String {
+    let indent = " ".repeat(indent);
+    let mut block = String::from("{\n");
+    for line in comments.iter().copied().chain((!body.is_empty()).then(|| body)) {
+        block.push_str(&indent);
+        block.push_str("    ");
+        block.push_str(line);
+        block.push('\n');
+    }
+    block.push_str(&indent);
+    block.push('}');
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{block_with_comments, comments_outside, strip_braces};
+
+    #[test]
+    fn test_comments_outside() {
+        let snip = "match opt {\n    // keep\n    Some(v) => v,\n    None => { /* none */ return },\n}";
+        let start = snip.find("{ /*").unwrap();
+        let end = snip.find("},\n}").unwrap() + 1;
+        assert_eq!(comments_outside(snip, start..end), vec!["// keep"]);
+        assert_eq!(comments_outside(snip, 0..0), vec!["// keep", "/* none */"]);
+        assert!(comments_outside("match opt { _ => \"//\" }", 0..0).is_empty());
+    }
+
+    #[test]
+    fn test_block_with_comments() {
+        assert_eq!(strip_braces("{ /* none */ return }"), "/* none */ return");
+        assert_eq!(
+            block_with_comments("return", &["// keep"], 4),
+            "{\n        // keep\n        return\n    }"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{eq_expr_value, is_trait_method, meets_msrv, msrvs};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for collections which are rebuilt from a filtered iterator over
+    /// themselves, like `v = v.into_iter().filter(f).collect()`, where `retain` can be used
+    /// instead. `Vec`, `VecDeque`, `String`, `HashMap`, `HashSet`, `BTreeMap` and `BTreeSet` are
+    /// checked.
+    ///
+    /// **Why is this bad?** `retain` removes the elements in place, without allocating a new
+    /// collection.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec = vec.into_iter().filter(|&x| x % 2 == 0).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec.retain(|&x| x % 2 == 0);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_RETAIN,
+    perf,
+    "rebuilding a collection from a filtered iterator over itself instead of using `retain`"
+}
+
+pub struct ManualRetain {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualRetain {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualRetain => [MANUAL_RETAIN]);
+
+#[derive(Clone, Copy, PartialEq)]
+enum CollectionKind {
+    /// `Vec`, `VecDeque`, `HashSet` and `BTreeSet`, whose `retain` takes the same closure as
+    /// `filter` on `into_iter`
+    Sequence,
+    /// `HashMap` and `BTreeMap`, whose `retain` takes the key and the value separately
+    Map,
+    /// `String`, whose `retain` takes the `char` by value
+    String,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Assign(target, value, _) = expr.kind;
+            if let ExprKind::MethodCall(path, _, [iter], _) = value.kind;
+            if path.ident.name == sym!(collect);
+            if is_trait_method(cx, value, sym::Iterator);
+            if let Some((method, recv, filter_arg, adapted)) = parse_filter_chain(iter);
+            if eq_expr_value(cx, target, recv);
+            if let Some(kind) = collection_kind(cx, target, &self.msrv);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let closure = match (kind, &*method.as_str(), adapted) {
+                    (CollectionKind::Sequence, "into_iter", false) => {
+                        snippet_with_applicability(cx, filter_arg.span, "..", &mut applicability).into_owned()
+                    },
+                    // The closure of `filter` takes one more reference than the one of `retain`
+                    (CollectionKind::Sequence, "iter", true) => {
+                        applicability = Applicability::MaybeIncorrect;
+                        snippet_with_applicability(cx, filter_arg.span, "..", &mut applicability).into_owned()
+                    },
+                    (CollectionKind::Map, "into_iter", false) | (CollectionKind::String, "chars", false) => {
+                        match rewrite_closure(cx, kind, filter_arg, &mut applicability) {
+                            Some(closure) => closure,
+                            None => return,
+                        }
+                    },
+                    _ => return,
+                };
+
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    "consider calling `.retain()` instead",
+                    format!(
+                        "{}.retain({})",
+                        snippet_with_applicability(cx, target.span, "..", &mut applicability),
+                        closure
+                    ),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Matches `<recv>.<method>().filter(<filter_arg>)`, optionally followed by `.cloned()` or
+/// `.copied()`, which is returned as the last element.
+fn parse_filter_chain<'tcx>(iter: &'tcx Expr<'tcx>) -> Option<(Symbol, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>, bool)> {
+    let (filter, adapted) = match iter.kind {
+        ExprKind::MethodCall(path, _, [filter], _) if matches!(&*path.ident.as_str(), "cloned" | "copied") => {
+            (filter, true)
+        },
+        _ => (iter, false),
+    };
+    if_chain! {
+        if let ExprKind::MethodCall(filter_path, _, [filter_recv, filter_arg], _) = filter.kind;
+        if filter_path.ident.name == sym!(filter);
+        if let ExprKind::MethodCall(path, _, [recv], _) = filter_recv.kind;
+        then {
+            Some((path.ident.name, recv, filter_arg, adapted))
+        } else {
+            None
+        }
+    }
+}
+
+fn collection_kind(cx: &LateContext<'_>, expr: &Expr<'_>, msrv: &Option<RustcVersion>) -> Option<CollectionKind> {
+    let ty = cx.typeck_results().expr_ty(expr);
+    if is_type_diagnostic_item(cx, ty, sym::vec_type)
+        || is_type_diagnostic_item(cx, ty, sym::vecdeque_type)
+        || is_type_diagnostic_item(cx, ty, sym::hashset_type)
+        || (is_type_diagnostic_item(cx, ty, sym::BTreeSet) && meets_msrv(msrv.as_ref(), &msrvs::BTREE_RETAIN))
+    {
+        Some(CollectionKind::Sequence)
+    } else if is_type_diagnostic_item(cx, ty, sym::hashmap_type)
+        || (is_type_diagnostic_item(cx, ty, sym::BTreeMap) && meets_msrv(msrv.as_ref(), &msrvs::BTREE_RETAIN))
+    {
+        Some(CollectionKind::Map)
+    } else if is_type_diagnostic_item(cx, ty, sym::string_type) && meets_msrv(msrv.as_ref(), &msrvs::STRING_RETAIN) {
+        Some(CollectionKind::String)
+    } else {
+        None
+    }
+}
+
+/// Rewrites the closure passed to `filter` to the arguments of `retain`: `|&c|` becomes `|c|` for
+/// a `String`, and `|(k, v)|` becomes `|k, v|` for a map.
+fn rewrite_closure(
+    cx: &LateContext<'_>,
+    kind: CollectionKind,
+    filter_arg: &Expr<'_>,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Closure(_, _, body_id, ..) = filter_arg.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        then {
+            let mut snip = |pat: &Pat<'_>| snippet_with_applicability(cx, pat.span, "..", applicability).into_owned();
+            let params = match (kind, param.pat.kind) {
+                (CollectionKind::String, PatKind::Ref(pat, _)) => snip(pat),
+                (CollectionKind::Map, PatKind::Tuple([key, value], None)) => format!("{}, {}", snip(key), snip(value)),
+                (CollectionKind::Map, PatKind::Ref(Pat { kind: PatKind::Tuple([key, value], None), .. }, _)) => {
+                    format!("&{}, &mut {}", snip(key), snip(value))
+                },
+                _ => return None,
+            };
+            let body = snippet_with_applicability(cx, body.value.span, "..", applicability);
+            Some(format!("|{}| {}", params, body))
+        } else {
+            None
+        }
+    }
+}
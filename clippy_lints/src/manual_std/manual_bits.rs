@@ -0,0 +1,54 @@
+use clippy_utils::{get_parent_expr, match_def_path, meets_msrv, msrvs, paths};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_semver::RustcVersion;
+
+use super::{lint_std_replacement, MANUAL_BITS};
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, msrv: &Option<RustcVersion>) {
+    if_chain! {
+        if let ExprKind::Binary(op, left, right) = expr.kind;
+        if op.node == BinOpKind::Mul;
+        if let Some(ty) = size_of_ty(cx, left, right).or_else(|| size_of_ty(cx, right, left));
+        if meets_msrv(msrv.as_ref(), &msrvs::INTEGER_BITS);
+        then {
+            let bits = format!("{}::BITS", ty);
+            // `x as usize < y` and `x as usize << y` are parsed as the start of generic arguments
+            let needs_paren = matches!(
+                get_parent_expr(cx, expr),
+                Some(Expr { kind: ExprKind::Binary(op, left, _), .. })
+                    if left.hir_id == expr.hir_id && matches!(op.node, BinOpKind::Lt | BinOpKind::Shl)
+            );
+            let sugg = if needs_paren {
+                format!("({} as usize)", bits)
+            } else {
+                format!("{} as usize", bits)
+            };
+            lint_std_replacement(cx, MANUAL_BITS, expr, &bits, sugg, Applicability::MachineApplicable);
+        }
+    }
+}
+
+/// Returns the integer type `T` if `size_of` is `std::mem::size_of::<T>()` and `eight` is the
+/// literal `8`.
+fn size_of_ty<'tcx>(cx: &LateContext<'tcx>, size_of: &Expr<'_>, eight: &Expr<'_>) -> Option<Ty<'tcx>> {
+    if_chain! {
+        if let ExprKind::Lit(lit) = &eight.kind;
+        if let LitKind::Int(8, _) = lit.node;
+        if let ExprKind::Call(callee, []) = size_of.kind;
+        if let ExprKind::Path(ref qpath) = callee.kind;
+        if let Some(def_id) = cx.qpath_res(qpath, callee.hir_id).opt_def_id();
+        if match_def_path(cx, def_id, &paths::MEM_SIZE_OF);
+        let ty = cx.typeck_results().node_substs(callee.hir_id).type_at(0);
+        if matches!(ty.kind(), ty::Int(_) | ty::Uint(_));
+        then {
+            Some(ty)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{eq_expr_value, get_trait_def_id, match_def_path, match_trait_method, meets_msrv, msrvs, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_semver::RustcVersion;
+use std::cmp::Ordering;
+
+use super::{lint_std_replacement, MANUAL_CLAMP};
+
+/// A value restricted to the range `min..=max`.
+struct Clamp<'tcx> {
+    value: &'tcx Expr<'tcx>,
+    min: Bound<'tcx>,
+    max: Bound<'tcx>,
+}
+
+struct Bound<'tcx> {
+    expr: &'tcx Expr<'tcx>,
+    value: Constant,
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: &Option<RustcVersion>) {
+    if !meets_msrv(msrv.as_ref(), &msrvs::CLAMP) {
+        return;
+    }
+    let clamp = match min_max_clamp(cx, expr).or_else(|| if_clamp(cx, expr)) {
+        Some(clamp) => clamp,
+        None => return,
+    };
+
+    // `clamp` panics if `min > max`, and the result doesn't depend on the value if `min == max`
+    let ty = cx.typeck_results().expr_ty(clamp.value);
+    if Constant::partial_cmp(cx.tcx, ty, &clamp.min.value, &clamp.max.value) != Some(Ordering::Less) {
+        return;
+    }
+    let is_ord = get_trait_def_id(cx, &paths::ORD).map_or(false, |id| implements_trait(cx, ty, id, &[]));
+    if !is_ord {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    let sugg = format!(
+        "{}.clamp({}, {})",
+        Sugg::hir_with_applicability(cx, clamp.value, "..", &mut applicability).maybe_par(),
+        snippet_with_applicability(cx, clamp.min.expr.span, "..", &mut applicability),
+        snippet_with_applicability(cx, clamp.max.expr.span, "..", &mut applicability),
+    );
+    lint_std_replacement(cx, MANUAL_CLAMP, expr, "clamp", sugg, applicability);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MinMax {
+    Min,
+    Max,
+}
+
+/// `max(min(x, max), min)` and `min(max(x, min), max)`, as functions or methods, with the
+/// arguments in any order.
+fn min_max_clamp<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Clamp<'tcx>> {
+    let (outer, outer_bound, inner) = min_max(cx, expr)?;
+    let (inner, inner_bound, value) = min_max(cx, inner)?;
+    match (outer, inner) {
+        (MinMax::Max, MinMax::Min) => Some(Clamp {
+            value,
+            min: outer_bound,
+            max: inner_bound,
+        }),
+        (MinMax::Min, MinMax::Max) => Some(Clamp {
+            value,
+            min: inner_bound,
+            max: outer_bound,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the constant and the other argument of a call to `std::cmp::min`, `std::cmp::max` or
+/// `Ord::min`, `Ord::max`.
+fn min_max<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(MinMax, Bound<'tcx>, &'tcx Expr<'tcx>)> {
+    let (kind, args) = match expr.kind {
+        ExprKind::Call(callee, args @ [_, _]) => {
+            let def_id = match callee.kind {
+                ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id()?,
+                _ => return None,
+            };
+            if match_def_path(cx, def_id, &paths::CMP_MIN) {
+                (MinMax::Min, args)
+            } else if match_def_path(cx, def_id, &paths::CMP_MAX) {
+                (MinMax::Max, args)
+            } else {
+                return None;
+            }
+        },
+        ExprKind::MethodCall(path, _, args @ [_, _], _) if match_trait_method(cx, expr, &paths::ORD) => {
+            if path.ident.name == sym!(min) {
+                (MinMax::Min, args)
+            } else if path.ident.name == sym!(max) {
+                (MinMax::Max, args)
+            } else {
+                return None;
+            }
+        },
+        _ => return None,
+    };
+
+    let (first, second) = (&args[0], &args[1]);
+    match (constant_value(cx, first), constant_value(cx, second)) {
+        (Some(value), None) => Some((kind, Bound { expr: first, value }, second)),
+        (None, Some(value)) => Some((kind, Bound { expr: second, value }, first)),
+        _ => None,
+    }
+}
+
+/// `if x < min { min } else if x > max { max } else { x }`, with the comparisons in any order
+/// and direction.
+fn if_clamp<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Clamp<'tcx>> {
+    let (first_cond, first_then, els) = if_else(expr)?;
+    let (second_cond, second_then, value) = if_else(els)?;
+    let value = peel_block(value);
+
+    let (first_kind, first_value, first_bound) = bound_check(cx, first_cond)?;
+    let (second_kind, second_value, second_bound) = bound_check(cx, second_cond)?;
+    if !(eq_expr_value(cx, first_value, value)
+        && eq_expr_value(cx, second_value, value)
+        && eq_expr_value(cx, first_bound.expr, first_then)
+        && eq_expr_value(cx, second_bound.expr, second_then))
+    {
+        return None;
+    }
+
+    match (first_kind, second_kind) {
+        (MinMax::Min, MinMax::Max) => Some(Clamp {
+            value,
+            min: first_bound,
+            max: second_bound,
+        }),
+        (MinMax::Max, MinMax::Min) => Some(Clamp {
+            value,
+            min: second_bound,
+            max: first_bound,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the condition and the branches of an `if` with an `else` branch. The `then` branch has
+/// to be a block with only a trailing expression, which is returned.
+fn if_else<'tcx>(expr: &'tcx Expr<'_>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    match expr.kind {
+        ExprKind::If(
+            Expr {
+                kind: ExprKind::DropTemps(cond),
+                ..
+            },
+            then,
+            Some(els),
+        ) => Some((cond, peel_block(then), els)),
+        _ => None,
+    }
+}
+
+fn peel_block<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match expr.kind {
+        ExprKind::Block(
+            Block {
+                stmts: [],
+                expr: Some(inner),
+                ..
+            },
+            None,
+        ) => inner,
+        _ => expr,
+    }
+}
+
+/// Parses a comparison of a value with a constant bound. Returns `MinMax::Min` if the value is
+/// compared to the lower bound, like `x < min`, and `MinMax::Max` for the upper bound.
+fn bound_check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>) -> Option<(MinMax, &'tcx Expr<'tcx>, Bound<'tcx>)> {
+    let (op, left, right) = match cond.kind {
+        ExprKind::Binary(op, left, right) => (op.node, left, right),
+        _ => return None,
+    };
+    let (value, bound, value_is_left) = match (constant_value(cx, left), constant_value(cx, right)) {
+        (None, Some(value)) => (left, Bound { expr: right, value }, true),
+        (Some(value), None) => (right, Bound { expr: left, value }, false),
+        _ => return None,
+    };
+    let is_less = match op {
+        BinOpKind::Lt | BinOpKind::Le => value_is_left,
+        BinOpKind::Gt | BinOpKind::Ge => !value_is_left,
+        _ => return None,
+    };
+    Some((if is_less { MinMax::Min } else { MinMax::Max }, value, bound))
+}
+
+/// Evaluates `expr` if it's a constant, including named constants.
+fn constant_value(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Constant> {
+    constant(cx, cx.typeck_results(), expr).map(|(value, _)| value)
+}
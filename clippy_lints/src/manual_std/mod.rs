@@ -0,0 +1,116 @@
+mod manual_bits;
+mod manual_clamp;
+
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass, Lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the size of integer types in bits computed as
+    /// `std::mem::size_of::<T>() * 8`.
+    ///
+    /// **Why is this bad?** The associated constant `BITS` of the integer types is shorter and
+    /// states the intent.
+    ///
+    /// **Known problems:** `BITS` is a `u32`, the suggestion casts it to `usize` to keep the type
+    /// of the expression.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bits = std::mem::size_of::<u32>() * 8;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bits = u32::BITS as usize;
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_BITS,
+    style,
+    "computing the size of an integer type in bits with `size_of::<T>() * 8` instead of using `T::BITS`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for values restricted to a range between two constants with
+    /// nested `min` and `max` calls or an `if` chain, where `clamp` can be used.
+    ///
+    /// **Why is this bad?** `clamp` is shorter and states the intent, without having to figure out
+    /// which bound each call or branch applies.
+    ///
+    /// **Known problems:** Only types implementing `Ord` are linted. The bounds have to be
+    /// constants, as `clamp` panics if the lower bound is greater than the upper bound.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 42;
+    /// let a = std::cmp::max(std::cmp::min(x, 100), 0);
+    /// let b = x.max(0).min(100);
+    /// let c = if x > 100 {
+    ///     100
+    /// } else if x < 0 {
+    ///     0
+    /// } else {
+    ///     x
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 42;
+    /// let a = x.clamp(0, 100);
+    /// let b = x.clamp(0, 100);
+    /// let c = x.clamp(0, 100);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_CLAMP,
+    complexity,
+    "restricting a value to a range with `min` and `max` or an `if` chain instead of using `clamp`"
+}
+
+/// Lints for hand-written versions of items of the standard library.
+pub struct ManualStd {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualStd {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualStd => [MANUAL_BITS, MANUAL_CLAMP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualStd {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        manual_bits::check(cx, expr, &self.msrv);
+        manual_clamp::check(cx, expr, &self.msrv);
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Lints `expr`, a hand-written version of `std_item`, suggesting to replace it with `sugg`.
+fn lint_std_replacement(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    expr: &Expr<'_>,
+    std_item: &str,
+    sugg: String,
+    applicability: Applicability,
+) {
+    span_lint_and_sugg(
+        cx,
+        lint,
+        expr.span,
+        &format!("manual implementation of `{}`", std_item),
+        "try",
+        sugg,
+        applicability,
+    );
+}
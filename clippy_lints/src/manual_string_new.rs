@@ -0,0 +1,109 @@
+use crate::format::USELESS_FORMAT;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::higher::FormatExpn;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{in_external_macro, is_lint_allowed, match_def_path, paths};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for empty `String`s created from an empty string literal, like
+    /// `"".to_string()`, `"".into()`, `String::from("")` or `format!("")`.
+    ///
+    /// **Why is this bad?** `String::new()` is the canonical way to create an empty `String`, it
+    /// states the intent without going through a conversion.
+    ///
+    /// **Known problems:** `format!("")` is only linted if `useless_format` is allowed, which
+    /// reports it otherwise.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = "".to_string();
+    /// let b: String = "".into();
+    /// let c = String::from("");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = String::new();
+    /// let b = String::new();
+    /// let c = String::new();
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MANUAL_STRING_NEW,
+    pedantic,
+    "empty `String` created from an empty string literal instead of with `String::new()`"
+}
+
+declare_lint_pass!(ManualStringNew => [MANUAL_STRING_NEW]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualStringNew {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        if let Some(FormatExpn { call_site, format_args }) = FormatExpn::parse(expr) {
+            if !call_site.from_expansion()
+                && format_args.value_args.is_empty()
+                && format_args.format_string_symbols.iter().all(|s| *s == kw::Empty)
+                && is_lint_allowed(cx, USELESS_FORMAT, expr.hir_id)
+            {
+                lint_string_new(cx, call_site);
+            }
+            return;
+        }
+
+        if expr.span.from_expansion()
+            || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::string_type)
+        {
+            return;
+        }
+        let is_manual = match expr.kind {
+            ExprKind::MethodCall(path, _, [recv], _) => {
+                matches!(&*path.ident.as_str(), "to_string" | "to_owned" | "into") && is_empty_str_lit(recv)
+            },
+            ExprKind::Call(callee, [arg]) => {
+                is_empty_str_lit(arg)
+                    && match callee.kind {
+                        ExprKind::Path(ref qpath) => cx
+                            .qpath_res(qpath, callee.hir_id)
+                            .opt_def_id()
+                            .map_or(false, |def_id| match_def_path(cx, def_id, &paths::FROM_FROM)),
+                        _ => false,
+                    }
+            },
+            _ => false,
+        };
+        if is_manual {
+            lint_string_new(cx, expr.span);
+        }
+    }
+}
+
+fn is_empty_str_lit(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Str(s, _) if s == kw::Empty))
+}
+
+fn lint_string_new(cx: &LateContext<'_>, span: Span) {
+    // The call site of a `format!` statement includes the semicolon
+    let sugg = if snippet(cx, span, "").ends_with(';') {
+        "String::new();"
+    } else {
+        "String::new()"
+    };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_STRING_NEW,
+        span,
+        "empty `String` is being created manually",
+        "consider using",
+        sugg.to_string(),
+        Applicability::MachineApplicable,
+    );
+}
@@ -23,6 +23,7 @@ declare_clippy_lint! {
     /// mem::discriminant(&"hello");
     /// mem::discriminant(&&Some(2));
     /// ```
+    #[clippy::version = "1.32.0"]
     pub MEM_DISCRIMINANT_NON_ENUM,
     correctness,
     "calling `mem::descriminant` on non-enum type"
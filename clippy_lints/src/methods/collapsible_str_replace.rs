@@ -0,0 +1,95 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{eq_expr_value, get_parent_expr};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+
+use super::COLLAPSIBLE_STR_REPLACE;
+
+/// A call to `str::replace` with a `char` literal as the pattern.
+struct CharReplace<'a> {
+    recv: &'a Expr<'a>,
+    pat: &'a Expr<'a>,
+    to: &'a Expr<'a>,
+}
+
+/// Lints a chain of `replace` calls ending with `expr`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    let last = match char_replace(cx, expr) {
+        Some(replace) => replace,
+        None => return,
+    };
+    // The whole chain is linted at its last call
+    if let Some(parent) = get_parent_expr(cx, expr).and_then(|parent| char_replace(cx, parent)) {
+        if parent.recv.hir_id == expr.hir_id && eq_expr_value(cx, parent.to, last.to) {
+            return;
+        }
+    }
+
+    let (recv, pats) = collect_chain(cx, &last);
+    if pats.len() < 2 {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    // `[char; N]` only implements `Pattern` since Rust 1.58, a closure works with every version
+    let pats = pats
+        .iter()
+        .map(|pat| {
+            format!(
+                "c == {}",
+                snippet_with_applicability(cx, pat.span, "..", &mut applicability)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" || ");
+    let sugg = format!(
+        "{}.replace(|c| {}, {})",
+        snippet_with_applicability(cx, recv.span, "..", &mut applicability),
+        pats,
+        snippet_with_applicability(cx, last.to.span, "..", &mut applicability),
+    );
+    span_lint_and_sugg(
+        cx,
+        COLLAPSIBLE_STR_REPLACE,
+        expr.span,
+        "used consecutive `str::replace` calls with the same replacement",
+        "replace with",
+        sugg,
+        applicability,
+    );
+}
+
+/// Follows the receivers of `last` while they are `replace` calls with the same replacement.
+/// Returns the receiver of the first call of the chain and the patterns in the order of the
+/// calls.
+fn collect_chain<'a>(cx: &LateContext<'_>, last: &CharReplace<'a>) -> (&'a Expr<'a>, Vec<&'a Expr<'a>>) {
+    let mut pats = vec![last.pat];
+    let mut recv = last.recv;
+    while let Some(replace) = char_replace(cx, recv) {
+        if !eq_expr_value(cx, replace.to, last.to) {
+            break;
+        }
+        pats.push(replace.pat);
+        recv = replace.recv;
+    }
+    pats.reverse();
+    (recv, pats)
+}
+
+fn char_replace<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<CharReplace<'a>> {
+    match expr.kind {
+        ExprKind::MethodCall(path, _, [recv, pat, to], _)
+            if path.ident.name == sym!(replace)
+                && matches!(pat.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Char(_)))
+                && !expr.span.from_expansion() =>
+        {
+            let def_id = cx.typeck_results().type_dependent_def_id(expr.hir_id)?;
+            let impl_id = cx.tcx.impl_of_method(def_id)?;
+            (cx.tcx.lang_items().str_alloc_impl() == Some(impl_id)).then(|| CharReplace { recv, pat, to })
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{get_trait_def_id, is_trait_method, path_to_local, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Instance};
+use rustc_span::{sym, Span};
+
+use super::DOUBLE_ENDED_ITERATOR_LAST;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, span: Span) {
+    let typeck = cx.typeck_results();
+    if !is_trait_method(cx, expr, sym::Iterator) {
+        return;
+    }
+    let recv_ty = typeck.expr_ty(recv);
+    match get_trait_def_id(cx, &paths::DOUBLE_ENDED_ITERATOR) {
+        Some(id) if implements_trait(cx, recv_ty, id, &[]) => {},
+        _ => return,
+    }
+    // Iterators overriding `last`, like `slice::Iter`, already use `next_back`
+    let last_id = match typeck.type_dependent_def_id(expr.hir_id) {
+        Some(id) => id,
+        None => return,
+    };
+    match Instance::resolve(cx.tcx, cx.param_env, last_id, typeck.node_substs(expr.hir_id)) {
+        Ok(Some(instance)) if instance.def_id() == last_id => {},
+        _ => return,
+    }
+    // `last` calls the closures of the adapters for every item, `next_back` only for the last one
+    if has_closure_adapter(cx, recv) {
+        return;
+    }
+
+    // `next_back` takes `&mut self`, which needs a `mut` binding
+    let applicability = match path_to_local(recv).map(|id| cx.tcx.hir().get(id)) {
+        Some(Node::Binding(pat)) if !matches!(pat.kind, PatKind::Binding(BindingAnnotation::Mutable, ..)) => {
+            Applicability::MaybeIncorrect
+        },
+        _ => Applicability::MachineApplicable,
+    };
+    span_lint_and_sugg(
+        cx,
+        DOUBLE_ENDED_ITERATOR_LAST,
+        span,
+        "called `Iterator::last` on a `DoubleEndedIterator`, which iterates over all the items",
+        "try",
+        "next_back".to_string(),
+        applicability,
+    );
+}
+
+/// Checks if a method in the call chain of `recv` takes a closure or function, like `map` or
+/// `inspect`.
+fn has_closure_adapter(cx: &LateContext<'_>, mut recv: &Expr<'_>) -> bool {
+    while let ExprKind::MethodCall(_, _, [inner, args @ ..], _) = recv.kind {
+        if args.iter().any(|arg| {
+            matches!(
+                cx.typeck_results().expr_ty(arg).kind(),
+                ty::Closure(..) | ty::FnDef(..) | ty::FnPtr(_)
+            )
+        }) {
+            return true;
+        }
+        recv = inner;
+    }
+    false
+}
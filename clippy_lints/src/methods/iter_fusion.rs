@@ -0,0 +1,425 @@
+//! Chains of iterator adaptors which are a single adaptor: `filter(..).map(..)` is
+//! `filter_map(..)`, `map(..).flatten()` is `flat_map(..)` and `filter_map(..).next()` is
+//! `find_map(..)`. A `filter_map` closure returning `bool::then` is split into `filter` and `map`
+//! instead, unless the result would be fused again.
+
+use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use clippy_utils::source::{indent_of, reindent_multiline, snippet, snippet_with_applicability};
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::{is_trait_method, meets_msrv, msrvs, path_to_local_id, remove_blocks, SpanlessEq};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, HirId, PatKind, PathSegment, QPath, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TyS};
+use rustc_semver::RustcVersion;
+use rustc_span::source_map::Span;
+use rustc_span::symbol::{sym, Ident, Symbol};
+use std::borrow::Cow;
+
+use super::{
+    method_call, FILTER_MAP_BOOL_THEN, FILTER_MAP_NEXT, MANUAL_FILTER_MAP, MANUAL_FIND_MAP, MAP_FLATTEN,
+    OPTION_FILTER_MAP,
+};
+
+/// Checks the method call `expr` of `name` and the adaptor it's called on.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    name: &str,
+    recv: &'tcx Expr<'tcx>,
+    args: &'tcx [Expr<'tcx>],
+    span: Span,
+    msrv: Option<&RustcVersion>,
+) {
+    let prev = method_call(recv);
+    let prev = prev.as_ref().map(|&(ref name, args, span)| (&**name, args, span));
+    match (name, args, prev) {
+        ("filter_map", [arg], _) => check_filter_map_bool_then(cx, expr, arg, span),
+        ("flatten", [], Some(("map", [map_recv, map_arg], _))) => check_map_flatten(cx, expr, map_recv, map_arg),
+        ("map", [map_arg], Some((filter_name @ ("filter" | "find"), [filter_recv, filter_arg], filter_span))) => {
+            check_filter_map(
+                cx,
+                expr,
+                filter_recv,
+                filter_arg,
+                filter_span,
+                recv,
+                map_arg,
+                span,
+                filter_name == "find",
+            );
+        },
+        ("next", [], Some(("filter_map", [filter_map_recv, filter_map_arg], _))) => {
+            check_filter_map_next(cx, expr, filter_map_recv, filter_map_arg, msrv);
+        },
+        _ => {},
+    }
+}
+
+/// The parameter of a closure with a single parameter bound to a name, like `|x| ..` or `|&x| ..`.
+struct ClosureParam {
+    id: HirId,
+    ident: Ident,
+    /// Whether the pattern dereferences the item, like `|&x| ..`
+    is_ref: bool,
+}
+
+/// Returns the parameter and the body of the closure `expr`.
+fn closure_with_param<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<(ClosureParam, &'tcx Expr<'tcx>)> {
+    if_chain! {
+        if let ExprKind::Closure(_, _, body_id, ..) = expr.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        let (pat, is_ref) = if let PatKind::Ref(ref_pat, _) = param.pat.kind {
+            (ref_pat, true)
+        } else {
+            (param.pat, false)
+        };
+        if let PatKind::Binding(_, id, ident, None) = pat.kind;
+        then {
+            Some((ClosureParam { id, ident, is_ref }, &body.value))
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks if `check` calls `is_some` or `is_ok` on the same expression which `unwrap` unwraps,
+/// with the parameter of the closure of `check` in place of the one of `unwrap`, like
+/// `x.checked_add(1).is_some()` and `y.checked_add(1).unwrap()`. Returns the unwrapped expression,
+/// and whether it's a `Result`.
+fn checked_unwrap<'tcx>(
+    cx: &LateContext<'tcx>,
+    check_param: &ClosureParam,
+    check: &Expr<'_>,
+    unwrap_param: &ClosureParam,
+    unwrap: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    if_chain! {
+        if let ExprKind::MethodCall(path, _, [checked], _) = check.kind;
+        if let Some(opt_ty) = cx.typeck_results().expr_ty(checked).ty_adt_def();
+        if let Some(is_result) = if cx.tcx.is_diagnostic_item(sym::option_type, opt_ty.did) {
+            Some(false)
+        } else if cx.tcx.is_diagnostic_item(sym::result_type, opt_ty.did) {
+            Some(true)
+        } else {
+            None
+        };
+        if path.ident.name.as_str() == if is_result { "is_ok" } else { "is_some" };
+
+        if !unwrap_param.is_ref;
+        if let ExprKind::MethodCall(seg, _, [unwrapped, ..], _) = unwrap.kind;
+        if matches!(seg.ident.name, sym::expect | sym::unwrap | sym::unwrap_or);
+
+        let eq_fallback = |a: &Expr<'_>, b: &Expr<'_>| {
+            // in `filter(|x| ..)`, replace `*x` with `x`
+            let a_path = if_chain! {
+                if !check_param.is_ref;
+                if let ExprKind::Unary(UnOp::Deref, expr_path) = a.kind;
+                then { expr_path } else { a }
+            };
+            // let the filter closure arg and the map closure arg be equal
+            path_to_local_id(a_path, check_param.id)
+                && path_to_local_id(b, unwrap_param.id)
+                && TyS::same_type(cx.typeck_results().expr_ty_adjusted(a), cx.typeck_results().expr_ty_adjusted(b))
+        };
+        if SpanlessEq::new(cx).expr_fallback(eq_fallback).eq_expr(checked, unwrapped);
+        then {
+            Some((unwrapped, is_result))
+        } else {
+            None
+        }
+    }
+}
+
+fn is_method<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, method_name: Symbol) -> bool {
+    match &expr.kind {
+        ExprKind::Path(QPath::TypeRelative(_, mname)) => mname.ident.name == method_name,
+        ExprKind::Path(QPath::Resolved(_, segments)) => segments.segments.last().unwrap().ident.name == method_name,
+        ExprKind::Closure(_, _, c, _, _) => {
+            let body = cx.tcx.hir().body(*c);
+            let closure_expr = remove_blocks(&body.value);
+            let arg_id = body.params[0].pat.hir_id;
+            match closure_expr.kind {
+                ExprKind::MethodCall(PathSegment { ident, .. }, _, args, _) => {
+                    if_chain! {
+                    if ident.name == method_name;
+                    if let ExprKind::Path(path) = &args[0].kind;
+                    if let Res::Local(ref local) = cx.qpath_res(path, args[0].hir_id);
+                    then {
+                        return arg_id == *local
+                    }
+                    }
+                    false
+                },
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn is_option_filter_map<'tcx>(cx: &LateContext<'tcx>, filter_arg: &Expr<'_>, map_arg: &Expr<'_>) -> bool {
+    is_method(cx, map_arg, sym::unwrap) && is_method(cx, filter_arg, sym!(is_some))
+}
+
+/// lint use of `filter().map()` for `Iterators`
+fn lint_filter_some_map_unwrap(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    filter_recv: &Expr<'_>,
+    filter_arg: &Expr<'_>,
+    map_arg: &Expr<'_>,
+    target_span: Span,
+    methods_span: Span,
+) {
+    let iterator = is_trait_method(cx, expr, sym::Iterator);
+    let option = is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(filter_recv), sym::option_type);
+    if (iterator || option) && is_option_filter_map(cx, filter_arg, map_arg) {
+        let msg = "`filter` for `Some` followed by `unwrap`";
+        let help = "consider using `flatten` instead";
+        let sugg = format!(
+            "{}",
+            reindent_multiline(Cow::Borrowed("flatten()"), true, indent_of(cx, target_span),)
+        );
+        span_lint_and_sugg(
+            cx,
+            OPTION_FILTER_MAP,
+            methods_span,
+            msg,
+            help,
+            sugg,
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// lint use of `filter().map()` or `find().map()` for `Iterators`
+#[allow(clippy::too_many_arguments)]
+fn check_filter_map<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    filter_recv: &Expr<'_>,
+    filter_arg: &Expr<'_>,
+    filter_span: Span,
+    map_recv: &Expr<'_>,
+    map_arg: &Expr<'_>,
+    map_span: Span,
+    is_find: bool,
+) {
+    lint_filter_some_map_unwrap(
+        cx,
+        expr,
+        filter_recv,
+        filter_arg,
+        map_arg,
+        map_span,
+        filter_span.with_hi(expr.span.hi()),
+    );
+    if_chain! {
+        if is_trait_method(cx, map_recv, sym::Iterator);
+        // filter(|x| ..is_some())..
+        if let Some((filter_param, filter_value)) = closure_with_param(cx, filter_arg);
+        // ..map(|x| ..unwrap())
+        if let Some((map_param, map_value)) = closure_with_param(cx, map_arg);
+        if let Some((unwrapped, is_result)) = checked_unwrap(cx, &filter_param, filter_value, &map_param, map_value);
+        then {
+            let span = filter_span.with_hi(expr.span.hi());
+            let (filter_name, lint) = if is_find {
+                ("find", MANUAL_FIND_MAP)
+            } else {
+                ("filter", MANUAL_FILTER_MAP)
+            };
+            let msg = format!("`{}(..).map(..)` can be simplified as `{0}_map(..)`", filter_name);
+            let to_opt = if is_result { ".ok()" } else { "" };
+            let sugg = format!(
+                "{}_map(|{}| {}{})",
+                filter_name,
+                map_param.ident,
+                snippet(cx, unwrapped.span, ".."),
+                to_opt
+            );
+            span_lint_and_sugg(cx, lint, span, &msg, "try", sugg, Applicability::MachineApplicable);
+        }
+    }
+}
+
+/// lint use of `map().flatten()` for `Iterators` and 'Options'
+fn check_map_flatten<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    map_arg: &'tcx Expr<'_>,
+) {
+    // lint if caller of `.map().flatten()` is an Iterator
+    if is_trait_method(cx, expr, sym::Iterator) {
+        let map_closure_ty = cx.typeck_results().expr_ty(map_arg);
+        let is_map_to_option = match map_closure_ty.kind() {
+            ty::Closure(_, _) | ty::FnDef(_, _) | ty::FnPtr(_) => {
+                let map_closure_sig = match map_closure_ty.kind() {
+                    ty::Closure(_, substs) => substs.as_closure().sig(),
+                    _ => map_closure_ty.fn_sig(cx.tcx),
+                };
+                let map_closure_return_ty = cx.tcx.erase_late_bound_regions(map_closure_sig.output());
+                is_type_diagnostic_item(cx, map_closure_return_ty, sym::option_type)
+            },
+            _ => false,
+        };
+
+        let method_to_use = if is_map_to_option {
+            // `(...).map(...)` has type `impl Iterator<Item=Option<...>>
+            "filter_map"
+        } else {
+            // `(...).map(...)` has type `impl Iterator<Item=impl Iterator<...>>
+            "flat_map"
+        };
+        let func_snippet = snippet(cx, map_arg.span, "..");
+        let hint = format!(".{0}({1})", method_to_use, func_snippet);
+        span_lint_and_sugg(
+            cx,
+            MAP_FLATTEN,
+            expr.span.with_lo(recv.span.hi()),
+            "called `map(..).flatten()` on an `Iterator`",
+            &format!("try using `{}` instead", method_to_use),
+            hint,
+            Applicability::MachineApplicable,
+        );
+    }
+
+    // lint if caller of `.map().flatten()` is an Option
+    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::option_type) {
+        let func_snippet = snippet(cx, map_arg.span, "..");
+        let hint = format!(".and_then({})", func_snippet);
+        span_lint_and_sugg(
+            cx,
+            MAP_FLATTEN,
+            expr.span.with_lo(recv.span.hi()),
+            "called `map(..).flatten()` on an `Option`",
+            "try using `and_then` instead",
+            hint,
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// lint use of `filter_map().next()` for `Iterators`
+fn check_filter_map_next<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    msrv: Option<&RustcVersion>,
+) {
+    if is_trait_method(cx, expr, sym::Iterator) {
+        if !meets_msrv(msrv, &msrvs::ITERATOR_FIND_MAP) {
+            return;
+        }
+
+        let msg = "called `filter_map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
+                   `.find_map(..)` instead";
+        let filter_snippet = snippet(cx, arg.span, "..");
+        if filter_snippet.lines().count() <= 1 {
+            let iter_snippet = snippet(cx, recv.span, "..");
+            span_lint_and_sugg(
+                cx,
+                FILTER_MAP_NEXT,
+                expr.span,
+                msg,
+                "try this",
+                format!("{}.find_map({})", iter_snippet, filter_snippet),
+                Applicability::MachineApplicable,
+            );
+        } else {
+            span_lint(cx, FILTER_MAP_NEXT, expr.span, msg);
+        }
+    }
+}
+
+/// lint use of `bool::then` in the closure of `filter_map()` for `Iterators`
+fn check_filter_map_bool_then<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>, span: Span) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if is_trait_method(cx, expr, sym::Iterator);
+        if let ExprKind::Closure(_, _, body_id, ..) = arg.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, param_id, ident, None) = param.pat.kind;
+        if let ExprKind::MethodCall(path, _, [cond, then_arg], _) = remove_blocks(&body.value).kind;
+        if path.ident.name == sym!(then);
+        if cx.typeck_results().expr_ty(cond).is_bool();
+        if let ExprKind::Closure(_, _, then_body_id, ..) = then_arg.kind;
+        let then_body = cx.tcx.hir().body(then_body_id);
+        if then_body.params.is_empty();
+        if !cond.span.from_expansion() && !then_body.value.span.from_expansion();
+        then {
+            let span = span.with_hi(expr.span.hi());
+            let msg = "usage of `bool::then` in `filter_map`";
+
+            // `filter(|x| x.is_some()).map(|x| x.unwrap())` would be fused into `filter_map` again
+            let param = ClosureParam {
+                id: param_id,
+                ident,
+                is_ref: false,
+            };
+            if let Some((unwrapped, is_result)) =
+                checked_unwrap(cx, &param, cond, &param, remove_blocks(&then_body.value))
+            {
+                let to_opt = if is_result { ".ok()" } else { "" };
+                span_lint_and_sugg(
+                    cx,
+                    FILTER_MAP_BOOL_THEN,
+                    span,
+                    msg,
+                    "return the checked value instead",
+                    format!("filter_map(|{}| {}{})", ident, snippet(cx, unwrapped.span, ".."), to_opt),
+                    Applicability::MachineApplicable,
+                );
+                return;
+            }
+
+            // `filter` passes a reference to the item, which can only be dereferenced in the
+            // pattern if it's `Copy`
+            let (filter_param, mut applicability) = if is_copy(cx, cx.typeck_results().pat_ty(param.pat)) {
+                (format!("&{}", ident), Applicability::MachineApplicable)
+            } else {
+                (ident.to_string(), Applicability::MaybeIncorrect)
+            };
+            let cond_snip = snippet_with_applicability(cx, cond.span, "..", &mut applicability);
+            let then_snip = snippet_with_applicability(cx, then_body.value.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                FILTER_MAP_BOOL_THEN,
+                span,
+                msg,
+                "use `filter` then `map` instead",
+                format!(
+                    "filter(|{}| {}).map(|{}| {})",
+                    filter_param,
+                    strip_parens(cond, &cond_snip),
+                    ident,
+                    then_snip
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Removes the parentheses around the condition, which are needed for calling `then` on it.
+fn strip_parens<'a>(cond: &Expr<'_>, snip: &'a str) -> &'a str {
+    let is_parenthesized = match cond.kind {
+        // The span of `(a) && b` starts with the parenthesis of the operand
+        ExprKind::Binary(_, lhs, _) => lhs.span.lo() != cond.span.lo(),
+        ExprKind::Unary(..) => true,
+        _ => false,
+    };
+    if is_parenthesized {
+        snip.strip_prefix('(')
+            .and_then(|snip| snip.strip_suffix(')'))
+            .unwrap_or(snip)
+    } else {
+        snip
+    }
+}
@@ -0,0 +1,52 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::source::snippet_with_applicability;
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::ITER_OVEREAGER_CLONED;
+
+/// Checks `recv.cloned().name(args)`, where `cloned_expr` is `recv.cloned()`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    cloned_expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    name: &str,
+    args: &'tcx [Expr<'_>],
+) {
+    if_chain! {
+        if is_trait_method(cx, cloned_expr, sym::Iterator);
+        if is_trait_method(cx, expr, sym::Iterator);
+        if !expr.span.from_expansion();
+        then {
+            // The closures of `filter` and `find` get `&&T` instead of `&T` afterwards
+            let mut applicability = if matches!(name, "filter" | "find") {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let recv_snip = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+            let (msg, sugg) = if name == "count" {
+                (
+                    "unneeded cloning of iterator items",
+                    format!("{}.count()", recv_snip),
+                )
+            } else {
+                let args_snip = args
+                    .iter()
+                    .map(|arg| snippet_with_applicability(cx, arg.span, "..", &mut applicability))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                (
+                    "unnecessarily eager cloning of iterator items",
+                    format!("{}.{}({}).cloned()", recv_snip, name, args_snip),
+                )
+            };
+            span_lint_and_sugg(cx, ITER_OVEREAGER_CLONED, expr.span, msg, "try", sugg, applicability);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use if_chain::if_chain;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+
+use super::MANUAL_SPLIT_ONCE;
+
+/// Checks for `split_recv.split(pat).next().unwrap()` on strings, `split_expr` being the call to
+/// `split`.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    split_expr: &Expr<'_>,
+    split_recv: &Expr<'_>,
+    pat: &Expr<'_>,
+) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if let Some(split_id) = cx.typeck_results().type_dependent_def_id(split_expr.hir_id);
+        if let Some(impl_id) = cx.tcx.impl_of_method(split_id);
+        if cx.tcx.lang_items().str_impl() == Some(impl_id);
+        then {
+            span_lint_and_help(
+                cx,
+                MANUAL_SPLIT_ONCE,
+                expr.span,
+                "this `unwrap` never panics, the whole string is returned if the pattern isn't found",
+                None,
+                &format!(
+                    "to handle a missing pattern, use `{}.split_once({})`",
+                    snippet(cx, split_recv.span, ".."),
+                    snippet(cx, pat.span, ".."),
+                ),
+            );
+        }
+    }
+}
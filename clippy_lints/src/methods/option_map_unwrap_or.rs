@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{span_lint_and_sugg_multipart, SuggestionBuilder};
 use clippy_utils::differing_macro_contexts;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_copy;
@@ -71,23 +71,21 @@ pub(super) fn check<'tcx>(
             arg, suggest
         );
 
-        span_lint_and_then(cx, MAP_UNWRAP_OR, expr.span, msg, |diag| {
-            let map_arg_span = map_arg.span;
-
-            let mut suggestion = vec![
-                (
-                    map_span,
-                    String::from(if unwrap_snippet_none { "and_then" } else { "map_or" }),
-                ),
-                (expr.span.with_lo(unwrap_recv.span.hi()), String::from("")),
-            ];
-
-            if !unwrap_snippet_none {
-                suggestion.push((map_arg_span.with_hi(map_arg_span.lo()), format!("{}, ", unwrap_snippet)));
-            }
+        let mut sugg = SuggestionBuilder::new(applicability);
+        sugg.replace(map_span, if unwrap_snippet_none { "and_then" } else { "map_or" })
+            .remove(expr.span.with_lo(unwrap_recv.span.hi()));
+        if !unwrap_snippet_none {
+            sugg.insert_before(map_arg.span, format!("{}, ", unwrap_snippet));
+        }
 
-            diag.multipart_suggestion(&format!("use `{}` instead", suggest), suggestion, applicability);
-        });
+        span_lint_and_sugg_multipart(
+            cx,
+            MAP_UNWRAP_OR,
+            expr.span,
+            msg,
+            &format!("use `{}` instead", suggest),
+            sugg,
+        );
     }
 }
 
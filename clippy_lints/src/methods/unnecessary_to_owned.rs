@@ -0,0 +1,175 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{contains_ty, implements_trait, is_type_diagnostic_item};
+use clippy_utils::{get_parent_expr, is_diag_item_method, is_diag_trait_item, match_def_path, paths};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, PredicateKind, Ty, TyS, TypeFoldable};
+use rustc_span::symbol::kw;
+use rustc_span::{sym, Span};
+
+use super::UNNECESSARY_TO_OWNED;
+
+pub fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, method_name: &str, recv: &'tcx Expr<'_>) {
+    if_chain! {
+        if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        if is_to_owned_like(cx, method_name, method_def_id);
+        // All of the methods above take `&self`, so the adjusted receiver is `&Self`.
+        if let ty::Ref(_, self_ty, _) = *cx.typeck_results().expr_ty_adjusted(recv).kind();
+        // `to_string` is only a plain copy of the receiver for string types
+        if method_name != "to_string" || self_ty.is_str() || is_type_diagnostic_item(cx, self_ty, sym::string_type);
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if !parent.span.from_expansion();
+        then {
+            if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) = parent.kind {
+                check_addr_of(cx, parent, expr, method_name, recv, self_ty);
+            } else {
+                check_generic_arg(cx, parent, expr, method_name, recv);
+            }
+        }
+    }
+}
+
+fn is_to_owned_like(cx: &LateContext<'_>, method_name: &str, method_def_id: DefId) -> bool {
+    match method_name {
+        "to_os_string" => is_diag_item_method(cx, method_def_id, sym::OsStr),
+        "to_owned" => is_diag_trait_item(cx, method_def_id, sym::ToOwned),
+        "to_path_buf" => is_diag_item_method(cx, method_def_id, sym::Path),
+        "to_string" => match_def_path(cx, method_def_id, &paths::TO_STRING_METHOD),
+        "to_vec" => cx.tcx.impl_of_method(method_def_id).map_or(false, |impl_did| {
+            Some(impl_did) == cx.tcx.lang_items().slice_alloc_impl()
+        }),
+        _ => false,
+    }
+}
+
+/// Checks `&x.to_owned()` passed as an argument where a borrow of `x` would do.
+fn check_addr_of<'tcx>(
+    cx: &LateContext<'tcx>,
+    addr_of_expr: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    method_name: &str,
+    recv: &'tcx Expr<'_>,
+    self_ty: Ty<'tcx>,
+) {
+    let typeck = cx.typeck_results();
+    if_chain! {
+        if let Some(call_expr) = get_parent_expr(cx, addr_of_expr);
+        if is_argument(call_expr, addr_of_expr);
+        let addr_of_ty = typeck.expr_ty(addr_of_expr);
+        let adjusted_ty = typeck.expr_ty_adjusted(addr_of_expr);
+        if let ty::Ref(_, target_ty, Mutability::Not) = *adjusted_ty.kind();
+        let owned_ty = typeck.expr_ty(expr);
+        let recv_ty = typeck.expr_ty(recv);
+        let new_ty = if recv_ty.is_ref() { recv_ty } else { replacement_ty(cx, recv_ty) };
+        // If the borrow was coerced, the receiver will be coerced to the same type. Otherwise the
+        // receiver has to be borrowed as the exact same type.
+        if TyS::same_type(new_ty, adjusted_ty)
+            || (!TyS::same_type(addr_of_ty, adjusted_ty)
+                && (TyS::same_type(target_ty, self_ty) || TyS::same_type(owned_ty, self_ty)));
+        then {
+            lint(cx, addr_of_expr.span, method_name, recv, recv_ty.is_ref());
+        }
+    }
+}
+
+/// Checks `x.to_owned()` passed as an argument of a generic type, where a borrow of `x` satisfies
+/// the same bounds.
+fn check_generic_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    call_expr: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    method_name: &str,
+    recv: &'tcx Expr<'_>,
+) {
+    let typeck = cx.typeck_results();
+    let (callee_def_id, args) = match call_expr.kind {
+        ExprKind::Call(callee, args) => match *typeck.expr_ty(callee).kind() {
+            ty::FnDef(def_id, _) => (def_id, args),
+            _ => return,
+        },
+        ExprKind::MethodCall(_, _, args, _) => match typeck.type_dependent_def_id(call_expr.hir_id) {
+            Some(def_id) => (def_id, args),
+            None => return,
+        },
+        _ => return,
+    };
+
+    let fn_sig = cx.tcx.fn_sig(callee_def_id).skip_binder();
+    if_chain! {
+        if is_argument(call_expr, expr);
+        if let Some(arg_index) = args.iter().position(|arg| arg.hir_id == expr.hir_id);
+        if let Some(&param_ty) = fn_sig.inputs().get(arg_index);
+        if let ty::Param(param) = *param_ty.kind();
+        if param.name != kw::SelfUpper;
+        // Changing the type must not affect any other argument or the return type
+        if fn_sig.inputs().iter().filter(|&&ty| contains_ty(ty, param_ty)).count() == 1;
+        if !contains_ty(fn_sig.output(), param_ty);
+        let recv_ty = typeck.expr_ty(recv);
+        let recv_is_ref = matches!(recv_ty.kind(), ty::Ref(_, _, Mutability::Not));
+        let new_ty = if recv_is_ref { recv_ty } else { replacement_ty(cx, recv_ty) };
+        if bounds_hold_for(cx, callee_def_id, param_ty, new_ty);
+        then {
+            lint(cx, expr.span, method_name, recv, recv_is_ref);
+        }
+    }
+}
+
+/// Returns `true` if `arg` is an argument of the call `call_expr`, excluding the receiver of a
+/// method call.
+fn is_argument(call_expr: &Expr<'_>, arg: &Expr<'_>) -> bool {
+    match call_expr.kind {
+        ExprKind::Call(_, args) => args.iter().any(|a| a.hir_id == arg.hir_id),
+        ExprKind::MethodCall(_, _, args, _) => args.iter().skip(1).any(|a| a.hir_id == arg.hir_id),
+        _ => false,
+    }
+}
+
+/// Checks whether all the bounds the callee puts on `param_ty` also hold for `new_ty`.
+fn bounds_hold_for<'tcx>(cx: &LateContext<'tcx>, callee_def_id: DefId, param_ty: Ty<'tcx>, new_ty: Ty<'tcx>) -> bool {
+    cx.tcx
+        .param_env(callee_def_id)
+        .caller_bounds()
+        .iter()
+        .all(|predicate| match predicate.kind().skip_binder() {
+            PredicateKind::Trait(trait_predicate, _) if TyS::same_type(trait_predicate.self_ty(), param_ty) => {
+                let trait_substs = &trait_predicate.trait_ref.substs[1..];
+                // Bounds like `P: AsRef<[T]>` would need `T` to be inferred again
+                !trait_substs
+                    .iter()
+                    .any(|arg| arg.needs_subst() || arg.has_escaping_bound_vars())
+                    && implements_trait(cx, new_ty, trait_predicate.def_id(), trait_substs)
+            },
+            PredicateKind::Projection(projection_predicate) => {
+                !TyS::same_type(projection_predicate.projection_ty.self_ty(), param_ty)
+            },
+            PredicateKind::TypeOutlives(outlives_predicate) => !TyS::same_type(outlives_predicate.0, param_ty),
+            _ => true,
+        })
+}
+
+/// The type of `&recv`.
+fn replacement_ty<'tcx>(cx: &LateContext<'tcx>, recv_ty: Ty<'tcx>) -> Ty<'tcx> {
+    cx.tcx.mk_imm_ref(cx.tcx.lifetimes.re_erased, recv_ty)
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, method_name: &str, recv: &Expr<'_>, recv_is_ref: bool) {
+    let mut applicability = Applicability::MachineApplicable;
+    let recv_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+    span_lint_and_sugg(
+        cx,
+        UNNECESSARY_TO_OWNED,
+        span,
+        &format!("unnecessary use of `{}`", method_name),
+        "use",
+        if recv_is_ref {
+            recv_snippet.to_string()
+        } else {
+            format!("&{}", recv_snippet)
+        },
+        applicability,
+    );
+}
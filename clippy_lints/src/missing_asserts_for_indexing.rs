@@ -0,0 +1,245 @@
+use clippy_utils::consts::{constant_simple, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{eq_expr_value, higher, is_direct_expn_of};
+use if_chain::if_chain;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for blocks which index a slice or `Vec` with several constant
+    /// indices, like `s[0]`, `s[1]` and `s[2]`, without asserting its length first.
+    ///
+    /// **Why is this bad?** Every indexing checks the length on its own. After an
+    /// `assert!(s.len() > 2)`, the compiler knows that none of the indices are out of bounds, and
+    /// can remove the checks. Only the statements of one block are considered together.
+    ///
+    /// **Known problems:** The assert panics before code between the indexing expressions runs,
+    /// which changes the behavior if that code returns or panics on its own.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn sum(s: &[u32]) -> u32 {
+    ///     s[0] + s[1] + s[2]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn sum(s: &[u32]) -> u32 {
+    ///     assert!(s.len() > 2);
+    ///     s[0] + s[1] + s[2]
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MISSING_ASSERTS_FOR_INDEXING,
+    restriction,
+    "indexing a slice multiple times without asserting its length first"
+}
+
+declare_lint_pass!(MissingAssertsForIndexing => [MISSING_ASSERTS_FOR_INDEXING]);
+
+/// The constant indices into one slice in a block.
+struct IndexEntry<'tcx> {
+    slice: &'tcx Expr<'tcx>,
+    /// The length asserted before the first index, and the span of the assert
+    asserted_len: Option<(u128, Span)>,
+    indices: Vec<(u128, Span)>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for MissingAssertsForIndexing {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        let mut visitor = IndexVisitor {
+            cx,
+            entries: Vec::new(),
+        };
+        for stmt in block.stmts {
+            match stmt.kind {
+                StmtKind::Local(local) => {
+                    if let Some(init) = local.init {
+                        visitor.visit_expr(init);
+                    }
+                },
+                StmtKind::Expr(e) | StmtKind::Semi(e) => {
+                    if let Some((slice, len)) = asserted_len(cx, e) {
+                        visitor.assert_len(slice, len, e.span.source_callsite());
+                    } else {
+                        visitor.visit_expr(e);
+                    }
+                },
+                StmtKind::Item(_) => {},
+            }
+        }
+        if let Some(e) = block.expr {
+            visitor.visit_expr(e);
+        }
+
+        for entry in visitor.entries {
+            check_entry(cx, &entry);
+        }
+    }
+}
+
+fn check_entry(cx: &LateContext<'_>, entry: &IndexEntry<'_>) {
+    let max_index = match entry.indices.iter().map(|&(index, _)| index).max() {
+        Some(max_index) => max_index,
+        None => return,
+    };
+    // A single index is checked once anyway
+    if entry.indices.iter().all(|&(index, _)| index == entry.indices[0].0) {
+        return;
+    }
+    if entry.asserted_len.map_or(false, |(len, _)| len > max_index) {
+        return;
+    }
+
+    let spans: Vec<_> = entry.indices.iter().map(|&(_, span)| span).collect();
+    let assert = format!(
+        "assert!({}.len() > {});",
+        snippet(cx, entry.slice.span, ".."),
+        max_index
+    );
+    span_lint_and_then(
+        cx,
+        MISSING_ASSERTS_FOR_INDEXING,
+        spans,
+        "indexing into a slice multiple times without an `assert`",
+        |diag| {
+            if let Some((_, assert_span)) = entry.asserted_len {
+                diag.span_help(
+                    assert_span,
+                    &format!("this doesn't cover the highest index, consider asserting `{}`", assert),
+                );
+            } else {
+                diag.help(&format!("consider asserting the length before indexing: `{}`", assert));
+            }
+            diag.note("asserting the length before indexing will elide bounds checks");
+        },
+    );
+}
+
+/// Parses `assert!(s.len() > N)` and similar asserts into the slice and the asserted minimum
+/// length.
+fn asserted_len<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, u128)> {
+    if is_direct_expn_of(e.span, "assert_eq").is_some() {
+        if let [a, b] = *higher::extract_assert_macro_args(e)? {
+            return len_of(cx, a)
+                .zip(const_int(cx, b))
+                .or_else(|| len_of(cx, b).zip(const_int(cx, a)));
+        }
+        return None;
+    }
+    is_direct_expn_of(e.span, "assert")?;
+    let cond = match *higher::extract_assert_macro_args(e)? {
+        [cond] => cond,
+        _ => return None,
+    };
+    let (op, lhs, rhs) = match cond.kind {
+        ExprKind::Binary(op, lhs, rhs) => (op.node, lhs, rhs),
+        _ => return None,
+    };
+    match op {
+        BinOpKind::Gt => len_of(cx, lhs).zip(const_int(cx, rhs).map(|n| n + 1)),
+        BinOpKind::Ge | BinOpKind::Eq => len_of(cx, lhs).zip(const_int(cx, rhs)),
+        BinOpKind::Lt => len_of(cx, rhs).zip(const_int(cx, lhs).map(|n| n + 1)),
+        BinOpKind::Le => len_of(cx, rhs).zip(const_int(cx, lhs)),
+        _ => None,
+    }
+}
+
+/// Returns `s` if `e` is `s.len()`, where `s` is a slice or a `Vec`.
+fn len_of<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if_chain! {
+        if let ExprKind::MethodCall(path, _, [recv], _) = e.kind;
+        if path.ident.name == sym::len;
+        if is_indexable(cx, recv);
+        then {
+            Some(recv)
+        } else {
+            None
+        }
+    }
+}
+
+fn const_int(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match constant_simple(cx, cx.typeck_results(), e)? {
+        Constant::Int(n) => Some(n),
+        _ => None,
+    }
+}
+
+/// Checks if indexing `e` is bounds checked at runtime, which isn't the case for arrays indexed
+/// with constants.
+fn is_indexable(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty_adjusted(e).peel_refs();
+    matches!(ty.kind(), ty::Slice(_)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
+
+struct IndexVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    entries: Vec<IndexEntry<'tcx>>,
+}
+
+impl<'a, 'tcx> IndexVisitor<'a, 'tcx> {
+    fn entry(&mut self, slice: &'tcx Expr<'tcx>) -> Option<&mut IndexEntry<'tcx>> {
+        let cx = self.cx;
+        match self
+            .entries
+            .iter()
+            .position(|entry| eq_expr_value(cx, entry.slice, slice))
+        {
+            Some(i) => Some(&mut self.entries[i]),
+            // Slices which aren't the same for every use, like `f()[0]`, aren't tracked
+            None if eq_expr_value(cx, slice, slice) => {
+                self.entries.push(IndexEntry {
+                    slice,
+                    asserted_len: None,
+                    indices: Vec::new(),
+                });
+                self.entries.last_mut()
+            },
+            None => None,
+        }
+    }
+
+    fn assert_len(&mut self, slice: &'tcx Expr<'tcx>, len: u128, span: Span) {
+        if let Some(entry) = self.entry(slice) {
+            // An assert after indexing doesn't help the indexing before it
+            if entry.indices.is_empty() && entry.asserted_len.map_or(true, |(asserted, _)| asserted < len) {
+                entry.asserted_len = Some((len, span));
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for IndexVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            // Nested blocks are checked on their own, and closures may not be called
+            ExprKind::Block(..) | ExprKind::Closure(..) => return,
+            ExprKind::Index(slice, index) if !expr.span.from_expansion() && is_indexable(self.cx, slice) => {
+                if let Some(index) = const_int(self.cx, index) {
+                    if let Some(entry) = self.entry(slice) {
+                        entry.indices.push((index, expr.span));
+                    }
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
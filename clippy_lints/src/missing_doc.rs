@@ -26,6 +26,7 @@ declare_clippy_lint! {
     /// This lint fixes that.
     ///
     /// **Known problems:** None.
+    #[clippy::version = "pre 1.29.0"]
     pub MISSING_DOCS_IN_PRIVATE_ITEMS,
     restriction,
     "detects missing documentation for public and private members"
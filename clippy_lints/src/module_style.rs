@@ -0,0 +1,138 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_ast::ast;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync::Lrc;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::{EarlyContext, EarlyLintPass, Level};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{FileName, RealFileName, SourceFile, Span};
+use std::path::{Path, PathBuf};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that module layout uses only self named module files, bans
+    /// `mod.rs` files.
+    ///
+    /// **Why is this bad?** Having multiple module layout styles in a project can be confusing.
+    /// Enable either this lint or [`self_named_module_files`](#self_named_module_files), not both.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```text
+    /// src/
+    ///   stuff/
+    ///     stuff_files.rs
+    ///     mod.rs
+    ///   lib.rs
+    /// ```
+    /// Use instead:
+    /// ```text
+    /// src/
+    ///   stuff/
+    ///     stuff_files.rs
+    ///   stuff.rs
+    ///   lib.rs
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MOD_MODULE_FILES,
+    restriction,
+    "checks that module layout doesn't use `mod.rs` files"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that module layout uses only `mod.rs` files, bans self named
+    /// module files next to a directory of the same name.
+    ///
+    /// **Why is this bad?** Having multiple module layout styles in a project can be confusing.
+    /// Enable either this lint or [`mod_module_files`](#mod_module_files), not both.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```text
+    /// src/
+    ///   stuff/
+    ///     stuff_files.rs
+    ///   stuff.rs
+    ///   lib.rs
+    /// ```
+    /// Use instead:
+    /// ```text
+    /// src/
+    ///   stuff/
+    ///     stuff_files.rs
+    ///     mod.rs
+    ///   lib.rs
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub SELF_NAMED_MODULE_FILES,
+    restriction,
+    "checks that module layout uses `mod.rs` files"
+}
+
+declare_lint_pass!(ModStyle => [MOD_MODULE_FILES, SELF_NAMED_MODULE_FILES]);
+
+impl EarlyLintPass for ModStyle {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        if cx.builder.lint_level(MOD_MODULE_FILES).0 == Level::Allow
+            && cx.builder.lint_level(SELF_NAMED_MODULE_FILES).0 == Level::Allow
+        {
+            return;
+        }
+
+        let working_dir = match &cx.sess.opts.working_dir {
+            RealFileName::LocalPath(dir) => dir,
+            RealFileName::Remapped { .. } => return,
+        };
+
+        // The files of this crate with their paths relative to the working directory. The module
+        // layout is decided by the file names, so the AST doesn't help here. Files outside of the
+        // working directory, like those of the standard library, are ignored.
+        let files: Vec<(Lrc<SourceFile>, PathBuf)> = cx
+            .sess
+            .source_map()
+            .files()
+            .iter()
+            .filter(|file| file.cnum == LOCAL_CRATE)
+            .filter_map(|file| match &file.name {
+                FileName::Real(RealFileName::LocalPath(path)) => {
+                    Some((Lrc::clone(file), path.strip_prefix(working_dir).ok()?.to_path_buf()))
+                },
+                _ => None,
+            })
+            .collect();
+        // The directories containing the files, e.g. `foo` and `foo/bar` for `foo/bar/baz.rs`
+        let dirs: FxHashSet<&Path> = files.iter().flat_map(|(_, path)| path.ancestors().skip(1)).collect();
+
+        for (file, path) in &files {
+            let span = Span::with_root_ctxt(file.start_pos, file.start_pos);
+            if path.file_name() == Some("mod.rs".as_ref()) {
+                let dir = match path.parent() {
+                    Some(dir) if dir != Path::new("") => dir,
+                    _ => continue,
+                };
+                span_lint_and_help(
+                    cx,
+                    MOD_MODULE_FILES,
+                    span,
+                    &format!("`mod.rs` files are not allowed, found `{}`", path.display()),
+                    None,
+                    &format!("move `{}` to `{}`", path.display(), dir.with_extension("rs").display()),
+                );
+            } else if dirs.contains(path.with_extension("").as_path()) {
+                span_lint_and_help(
+                    cx,
+                    SELF_NAMED_MODULE_FILES,
+                    span,
+                    &format!("`mod.rs` files are required, found `{}`", path.display()),
+                    None,
+                    &format!(
+                        "move `{}` to `{}`",
+                        path.display(),
+                        path.with_extension("").join("mod.rs").display()
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -2,15 +2,19 @@
 //!
 //! This lint is **warn** by default
 
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_type_diagnostic_item;
-use rustc_hir::Expr;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{get_parent_expr, paths};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind, FieldDef};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for usages of `Mutex<X>` where an atomic will do.
+    /// **What it does:** Checks for usages of `Mutex<X>` or `RwLock<X>` where an atomic will
+    /// do, both in expressions and in the fields of structs.
     ///
     /// **Why is this bad?** Using a mutex just to make access to a plain bool or
     /// reference sequential is shooting flies with cannons.
@@ -32,14 +36,15 @@ declare_clippy_lint! {
     /// # use std::sync::atomic::AtomicBool;
     /// let x = AtomicBool::new(y);
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub MUTEX_ATOMIC,
     perf,
     "using a mutex where an atomic value could be used instead"
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for usages of `Mutex<X>` where `X` is an integral
-    /// type.
+    /// **What it does:** Checks for usages of `Mutex<X>` or `RwLock<X>` where `X` is an
+    /// integral type.
     ///
     /// **Why is this bad?** Using a mutex just to make access to a plain integer
     /// sequential is
@@ -57,32 +62,105 @@ declare_clippy_lint! {
     /// # use std::sync::atomic::AtomicUsize;
     /// let x = AtomicUsize::new(0usize);
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub MUTEX_INTEGER,
     nursery,
     "using a mutex for an integer type"
 }
 
-declare_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for usages of `Mutex<()>` and `RwLock<()>`.
+    ///
+    /// **Why is this bad?** A lock without data is often only used to signal between threads,
+    /// which `std::sync::atomic::AtomicBool` or `std::sync::Condvar` do better.
+    ///
+    /// **Known problems:** This lint cannot detect if the lock is actually used to guard a
+    /// critical section, e.g. access to a file or some other resource.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// struct Worker {
+    ///     stopped: Mutex<()>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::AtomicBool;
+    /// struct Worker {
+    ///     stopped: AtomicBool,
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub MUTEX_UNIT,
+    nursery,
+    "using a `Mutex<()>` or `RwLock<()>`, which may only be used to signal between threads"
+}
+
+declare_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER, MUTEX_UNIT]);
 
 impl<'tcx> LateLintPass<'tcx> for Mutex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let ty = cx.typeck_results().expr_ty(expr);
-        if let ty::Adt(_, subst) = ty.kind() {
-            if is_type_diagnostic_item(cx, ty, sym!(mutex_type)) {
-                let mutex_param = subst.type_at(0);
-                if let Some(atomic_name) = get_atomic_name(mutex_param) {
-                    let msg = format!(
-                        "consider using an `{}` instead of a `Mutex` here; if you just want the locking \
-                         behavior and not the internal type, consider using `Mutex<()>`",
-                        atomic_name
-                    );
-                    match *mutex_param.kind() {
-                        ty::Uint(t) if t != ty::UintTy::Usize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        ty::Int(t) if t != ty::IntTy::Isize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        _ => span_lint(cx, MUTEX_ATOMIC, expr.span, &msg),
-                    };
-                }
-            }
+        if !is_local_field_value(cx, expr) {
+            check_lock_ty(cx, cx.typeck_results().expr_ty(expr), expr.span);
+        }
+    }
+
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx FieldDef<'_>) {
+        let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(field.hir_id));
+        check_lock_ty(cx, ty, field.ty.span);
+    }
+}
+
+/// Checks if `expr` is a field of a struct of this crate, or the value such a field is initialized
+/// with. The type of the field is linted in `check_field_def` already.
+fn is_local_field_value(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let is_local_adt = |ty: Ty<'_>| matches!(ty.kind(), ty::Adt(def, _) if def.did.is_local());
+    if let ExprKind::Field(base, _) = expr.kind {
+        return is_local_adt(cx.typeck_results().expr_ty_adjusted(base).peel_refs());
+    }
+    if_chain! {
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::Struct(_, fields, _) = parent.kind;
+        if fields.iter().any(|field| field.expr.hir_id == expr.hir_id);
+        then {
+            is_local_adt(cx.typeck_results().expr_ty(parent))
+        } else {
+            false
+        }
+    }
+}
+
+fn check_lock_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, span: Span) {
+    if let ty::Adt(_, subst) = ty.kind() {
+        let lock_name = if is_type_diagnostic_item(cx, ty, sym!(mutex_type)) {
+            "Mutex"
+        } else if match_type(cx, ty, &paths::RWLOCK) {
+            "RwLock"
+        } else {
+            return;
+        };
+        let lock_param = subst.type_at(0);
+        if lock_param.is_unit() {
+            span_lint_and_help(
+                cx,
+                MUTEX_UNIT,
+                span,
+                &format!("`{}<()>` doesn't guard any data", lock_name),
+                None,
+                "if it is only used to signal between threads, consider using an `AtomicBool` or a `Condvar`",
+            );
+        } else if let Some(atomic_name) = get_atomic_name(lock_param) {
+            let msg = format!(
+                "consider using an `{}` instead of a `{}` here; if the lock guards more than this \
+                 value, consider moving that data into the `{}` as well",
+                atomic_name, lock_name, lock_name
+            );
+            match *lock_param.kind() {
+                ty::Uint(t) if t != ty::UintTy::Usize => span_lint(cx, MUTEX_INTEGER, span, &msg),
+                ty::Int(t) if t != ty::IntTy::Isize => span_lint(cx, MUTEX_INTEGER, span, &msg),
+                _ => span_lint(cx, MUTEX_ATOMIC, span, &msg),
+            };
         }
     }
 }
@@ -0,0 +1,165 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::{in_macro, match_def_path, paths};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::subst::{GenericArg, GenericArgKind, Subst, SubstsRef};
+use rustc_middle::ty::{self, OutlivesPredicate, PredicateKind, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for borrowed arguments like `f(&x)`, where the parameter is a
+    /// generic type bounded by `AsRef` or `Borrow`, and `x` satisfies the bounds itself.
+    ///
+    /// **Why is this bad?** The borrow doesn't change what the function does with its argument,
+    /// it only adds noise, and may instantiate the function once more for the reference type.
+    ///
+    /// **Known problems:** Passing `x` moves it, so only values which are `Copy` or temporaries
+    /// are linted. A borrowed local which isn't used afterwards could be moved as well. Parameters
+    /// bounded by `Deref` aren't linted, as a reference doesn't deref to the same type as the
+    /// value.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn exists<P: AsRef<std::path::Path>>(path: P) -> bool {
+    ///     path.as_ref().exists()
+    /// }
+    ///
+    /// let _ = exists(&"Cargo.toml");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn exists<P: AsRef<std::path::Path>>(path: P) -> bool {
+    /// #     path.as_ref().exists()
+    /// # }
+    /// let _ = exists("Cargo.toml");
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+    style,
+    "taking a reference that is not needed by a generic parameter"
+}
+
+declare_lint_pass!(NeedlessBorrowsForGenericArgs => [NEEDLESS_BORROWS_FOR_GENERIC_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessBorrowsForGenericArgs {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let typeck = cx.typeck_results();
+        let (fn_id, substs, args) = match expr.kind {
+            ExprKind::Call(callee, args) => match *typeck.expr_ty(callee).kind() {
+                ty::FnDef(fn_id, substs) => (fn_id, substs, args),
+                _ => return,
+            },
+            // The receiver is borrowed by autoref, and can't be linted
+            ExprKind::MethodCall(_, _, [_, args @ ..], _) => match typeck.type_dependent_def_id(expr.hir_id) {
+                Some(fn_id) => (fn_id, typeck.node_substs(expr.hir_id), args),
+                None => return,
+            },
+            _ => return,
+        };
+
+        let fn_sig = cx.tcx.fn_sig(fn_id).skip_binder();
+        // C-variadic functions take more arguments than they have parameters
+        let offset = match fn_sig.inputs().len().checked_sub(args.len()) {
+            Some(offset) => offset,
+            None => return,
+        };
+        for (i, arg) in args.iter().enumerate() {
+            if_chain! {
+                if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) = arg.kind;
+                if !in_macro(arg.span) && typeck.expr_adjustments(arg).is_empty();
+                if let ty::Param(param) = *fn_sig.inputs()[offset + i].kind();
+                // Another parameter or the return type would be inferred differently
+                if !fn_sig
+                    .inputs_and_output
+                    .iter()
+                    .enumerate()
+                    .any(|(j, ty)| j != offset + i && contains_param(ty, param));
+                let inner_ty = typeck.expr_ty(inner);
+                // Passing a place by value moves it
+                if is_copy(cx, inner_ty) || !is_place_expr(inner);
+                if bounds_hold_for(cx, fn_id, substs, param, inner_ty);
+                then {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let sugg = snippet_with_applicability(cx, inner.span, "..", &mut applicability);
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+                        arg.span,
+                        "the borrowed expression implements the required traits",
+                        "change this to",
+                        sugg.to_string(),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn contains_param(ty: Ty<'_>, param: ty::ParamTy) -> bool {
+    ty.walk().any(|arg| match arg.unpack() {
+        GenericArgKind::Type(ty) => matches!(*ty.kind(), ty::Param(p) if p == param),
+        _ => false,
+    })
+}
+
+fn is_place_expr(expr: &Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _)
+    )
+}
+
+/// Checks if the bounds on `param` of the function `fn_id` only consist of `AsRef`, `Borrow` and
+/// `Sized`, and hold for `ty` as well.
+fn bounds_hold_for<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_id: DefId,
+    substs: SubstsRef<'tcx>,
+    param: ty::ParamTy,
+    ty: Ty<'tcx>,
+) -> bool {
+    let new_substs = cx.tcx.mk_substs(substs.iter().enumerate().map(|(i, arg)| {
+        if i == param.index as usize {
+            GenericArg::from(ty)
+        } else {
+            arg
+        }
+    }));
+    let lang_items = cx.tcx.lang_items();
+    cx.tcx
+        .param_env(fn_id)
+        .caller_bounds()
+        .iter()
+        .all(|pred| match pred.kind().skip_binder() {
+            PredicateKind::Trait(pred, _) if matches!(*pred.self_ty().kind(), ty::Param(p) if p == param) => {
+                let trait_id = pred.def_id();
+                if Some(trait_id) == lang_items.sized_trait() {
+                    return true;
+                }
+                // `Deref::Target` of `&T` is `T`, it differs from the target of `T` itself
+                if !match_def_path(cx, trait_id, &paths::ASREF_TRAIT)
+                    && !match_def_path(cx, trait_id, &paths::BORROW_TRAIT)
+                {
+                    return false;
+                }
+                let trait_ref = pred.trait_ref.subst(cx.tcx, new_substs);
+                implements_trait(cx, ty, trait_id, &trait_ref.substs[1..])
+            },
+            PredicateKind::Trait(pred, _) => !pred.trait_ref.substs.types().any(|ty| contains_param(ty, param)),
+            PredicateKind::Projection(pred) => {
+                !pred.projection_ty.substs.types().any(|ty| contains_param(ty, param))
+                    && !contains_param(pred.ty, param)
+            },
+            PredicateKind::TypeOutlives(OutlivesPredicate(ty, _)) => !contains_param(ty, param),
+            _ => true,
+        })
+}
@@ -0,0 +1,249 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::{indent_of, snippet_opt};
+use clippy_utils::{in_external_macro, is_default_equivalent, is_lint_allowed};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Body, Expr, ExprKind, Impl, ImplItem, ImplItemKind, Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Drop` implementations with an empty `drop` method.
+    ///
+    /// **Why is this bad?** The implementation doesn't do anything, the fields of the type are
+    /// dropped anyway.
+    ///
+    /// **Known problems:** Implementing `Drop` also forbids moving fields out of the type and
+    /// makes the borrow checker assume the value is used when it is dropped. Removing the
+    /// implementation can allow code which didn't compile before, which is rarely a problem.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct S;
+    ///
+    /// impl Drop for S {
+    ///     fn drop(&mut self) {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct S;
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub EMPTY_DROP,
+    restriction,
+    "empty `Drop` implementations"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for inherent `impl` blocks without any items.
+    ///
+    /// **Why is this bad?** The block doesn't do anything and only adds noise.
+    ///
+    /// **Known problems:** None. Trait implementations without items are not linted, and neither
+    /// are blocks whose items are all removed by `#[cfg(..)]`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct S;
+    ///
+    /// impl S {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct S;
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub EMPTY_IMPL,
+    complexity,
+    "inherent `impl` blocks without any items"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Default` implementations which only call `Self::new()`, when
+    /// `new` only fills the fields of the struct with their default values.
+    ///
+    /// **Why is this bad?** The implementation can be derived, which is shorter and doesn't need
+    /// to be kept in sync with `new`.
+    ///
+    /// **Known problems:** Only `new` functions which return a struct literal are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct S {
+    ///     names: Vec<String>,
+    ///     count: usize,
+    /// }
+    ///
+    /// impl S {
+    ///     fn new() -> Self {
+    ///         Self { names: Vec::new(), count: 0 }
+    ///     }
+    /// }
+    ///
+    /// impl Default for S {
+    ///     fn default() -> Self {
+    ///         Self::new()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Default)]
+    /// struct S {
+    ///     names: Vec<String>,
+    ///     count: usize,
+    /// }
+    ///
+    /// impl S {
+    ///     fn new() -> Self {
+    ///         Self { names: Vec::new(), count: 0 }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub NEEDLESS_DEFAULT_IMPL,
+    complexity,
+    "`Default` implementations which can be derived as they only call an equivalent `new`"
+}
+
+declare_lint_pass!(NeedlessImpls => [EMPTY_DROP, EMPTY_IMPL, NEEDLESS_DEFAULT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessImpls {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if item.span.from_expansion() || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+
+        if let ItemKind::Impl(impl_) = &item.kind {
+            let trait_id = match &impl_.of_trait {
+                Some(trait_ref) => trait_ref.trait_def_id(),
+                None => {
+                    if !is_lint_allowed(cx, EMPTY_IMPL, item.hir_id()) {
+                        check_empty_impl(cx, item, impl_);
+                    }
+                    return;
+                },
+            };
+
+            if trait_id.is_some() && trait_id == cx.tcx.lang_items().drop_trait() {
+                if !is_lint_allowed(cx, EMPTY_DROP, item.hir_id()) {
+                    check_empty_drop(cx, item, impl_);
+                }
+            } else if trait_id.map_or(false, |id| cx.tcx.is_diagnostic_item(sym::Default, id))
+                && !is_lint_allowed(cx, NEEDLESS_DEFAULT_IMPL, item.hir_id())
+            {
+                check_default_calling_new(cx, item, impl_);
+            }
+        }
+    }
+}
+
+fn check_empty_impl(cx: &LateContext<'_>, item: &Item<'_>, impl_: &Impl<'_>) {
+    if !impl_.items.is_empty() {
+        return;
+    }
+    // Items removed by `#[cfg(..)]` are still in the source
+    let is_empty_in_source = snippet_opt(cx, item.span)
+        .and_then(|snippet| snippet.rfind('{').map(|pos| snippet[pos + 1..].trim() == "}"))
+        .unwrap_or(false);
+    if is_empty_in_source {
+        span_lint_and_sugg(
+            cx,
+            EMPTY_IMPL,
+            item.span,
+            "empty `impl` block",
+            "try removing this impl",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+fn check_empty_drop(cx: &LateContext<'_>, item: &Item<'_>, impl_: &Impl<'_>) {
+    if_chain! {
+        if let [item_ref] = impl_.items;
+        let impl_item = cx.tcx.hir().impl_item(item_ref.id);
+        if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+        if let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind;
+        if block.stmts.is_empty() && block.expr.is_none();
+        then {
+            span_lint_and_sugg(
+                cx,
+                EMPTY_DROP,
+                item.span,
+                "empty drop implementation",
+                "try removing this impl",
+                String::new(),
+                Applicability::MaybeIncorrect,
+            );
+        }
+    }
+}
+
+fn check_default_calling_new(cx: &LateContext<'_>, item: &Item<'_>, impl_: &Impl<'_>) {
+    if_chain! {
+        if let [item_ref] = impl_.items;
+        let impl_item = cx.tcx.hir().impl_item(item_ref.id);
+        if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+        if let ExprKind::Call(func, []) = returned_expr(cx.tcx.hir().body(body_id)).kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Res::Def(DefKind::AssocFn, new_id) = cx.qpath_res(qpath, func.hir_id);
+        if cx.tcx.item_name(new_id) == sym::new;
+
+        // Generic structs could need different bounds on the derived implementation
+        let self_ty = cx.tcx.type_of(item.def_id.to_def_id());
+        if let ty::Adt(adt, substs) = self_ty.kind();
+        if adt.is_struct() && substs.is_empty();
+        if let Some(Node::Item(struct_item)) = cx.tcx.hir().get_if_local(adt.did);
+        if !struct_item.span.from_expansion();
+
+        if let Some(new_impl_id) = cx.tcx.impl_of_method(new_id);
+        if cx.tcx.type_of(new_impl_id) == self_ty;
+        if let Some(Node::ImplItem(new_item)) = cx.tcx.hir().get_if_local(new_id);
+        if is_default_equivalent_new(cx, new_item);
+        then {
+            span_lint_and_then(
+                cx,
+                NEEDLESS_DEFAULT_IMPL,
+                item.span,
+                "this `impl` can be derived, as `new` only uses the default value of each field",
+                |diag| {
+                    let indent = " ".repeat(indent_of(cx, struct_item.span).unwrap_or(0));
+                    diag.multipart_suggestion(
+                        "derive `Default` instead",
+                        vec![
+                            (struct_item.span.shrink_to_lo(), format!("#[derive(Default)]\n{}", indent)),
+                            (item.span, String::new()),
+                        ],
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Checks if `new` takes no arguments and returns a struct literal which only contains default
+/// values, e.g. `Self { names: Vec::new(), count: 0 }`.
+fn is_default_equivalent_new(cx: &LateContext<'_>, new_item: &ImplItem<'_>) -> bool {
+    if let ImplItemKind::Fn(ref sig, body_id) = new_item.kind {
+        if sig.decl.inputs.is_empty() {
+            if let ExprKind::Struct(_, fields, None) = returned_expr(cx.tcx.hir().body(body_id)).kind {
+                return fields.iter().all(|field| is_default_equivalent(cx, field.expr));
+            }
+        }
+    }
+    false
+}
+
+/// Returns the final expression of a function body which consists of a single expression.
+fn returned_expr<'tcx>(body: &'tcx Body<'tcx>) -> &'tcx Expr<'tcx> {
+    match body.value.kind {
+        ExprKind::Block(block, _) if block.stmts.is_empty() => block.expr.unwrap_or(&body.value),
+        _ => &body.value,
+    }
+}
@@ -0,0 +1,333 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::visitors::{expr_diverges, LocalUsedVisitor};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    BindingAnnotation, Block, Expr, ExprKind, HirId, Local, LocalSource, MatchSource, Node, Pat, PatKind, Stmt,
+    StmtKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for late initializations that can be replaced by a `let`
+    /// statement with an initializer.
+    ///
+    /// **Why is this bad?** Assigning in the `let` statement is less repetitive, and the binding
+    /// can't be used before it's initialized.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a;
+    /// a = 1;
+    ///
+    /// let b;
+    /// match 3 {
+    ///     0 => b = "zero",
+    ///     1 => b = "one",
+    ///     _ => b = "many",
+    /// }
+    ///
+    /// let c;
+    /// if true {
+    ///     c = 1;
+    /// } else {
+    ///     c = -1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = 1;
+    ///
+    /// let b = match 3 {
+    ///     0 => "zero",
+    ///     1 => "one",
+    ///     _ => "many",
+    /// };
+    ///
+    /// let c = if true {
+    ///     1
+    /// } else {
+    ///     -1
+    /// };
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub NEEDLESS_LATE_INIT,
+    style,
+    "late initializations that can be replaced by a `let` statement with an initializer"
+}
+
+declare_lint_pass!(NeedlessLateInit => [NEEDLESS_LATE_INIT]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessLateInit {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        let binding_id = match *local {
+            Local {
+                init: None,
+                pat:
+                    &Pat {
+                        kind: PatKind::Binding(BindingAnnotation::Unannotated, binding_id, _, None),
+                        ..
+                    },
+                source: LocalSource::Normal,
+                ..
+            } => binding_id,
+            _ => return,
+        };
+        if local.span.from_expansion() {
+            return;
+        }
+
+        let mut parents = cx.tcx.hir().parent_iter(local.hir_id).map(|(_, node)| node);
+        if let (Some(Node::Stmt(local_stmt)), Some(Node::Block(block))) = (parents.next(), parents.next()) {
+            check(cx, local, local_stmt, block, binding_id);
+        }
+    }
+}
+
+/// An assignment `binding = rhs`, possibly as the last statement of a block.
+struct LocalAssign {
+    lhs_span: Span,
+    rhs_span: Span,
+    /// The span of the assignment, including the trailing semicolon of its statement
+    span: Span,
+}
+
+impl LocalAssign {
+    fn from_expr(expr: &Expr<'_>, binding_id: HirId, span: Span) -> Option<Self> {
+        match expr.kind {
+            ExprKind::Assign(lhs, rhs, _) if !expr.span.from_expansion() && path_to_local(lhs) == Some(binding_id) => {
+                Some(Self {
+                    lhs_span: lhs.span,
+                    rhs_span: rhs.span.source_callsite(),
+                    span,
+                })
+            },
+            _ => None,
+        }
+    }
+
+    /// Finds the assignment to the binding a branch of an `if` or `match` ends with. The statements
+    /// before it may not assign other bindings, which would be initialized as a side effect of the
+    /// new `let` statement.
+    fn from_branch<'tcx>(binding_id: HirId, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        let block = match expr.kind {
+            ExprKind::Block(block, None) => block,
+            _ => return Self::from_expr(expr, binding_id, expr.span),
+        };
+        let (assign, other_stmts) = match (block.expr, block.stmts.split_last()) {
+            (Some(tail), _) => (Self::from_expr(tail, binding_id, tail.span)?, block.stmts),
+            (None, Some((last, other_stmts))) => match last.kind {
+                StmtKind::Expr(expr) | StmtKind::Semi(expr) => {
+                    (Self::from_expr(expr, binding_id, last.span)?, other_stmts)
+                },
+                _ => return None,
+            },
+            (None, None) => return None,
+        };
+
+        if other_stmts.iter().any(contains_assign_expr) {
+            None
+        } else {
+            Some(assign)
+        }
+    }
+}
+
+fn contains_assign_expr<'tcx>(stmt: &'tcx Stmt<'tcx>) -> bool {
+    struct AssignVisitor {
+        found: bool,
+    }
+
+    impl<'tcx> Visitor<'tcx> for AssignVisitor {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if let ExprKind::Assign(..) = expr.kind {
+                self.found = true;
+            } else if !self.found {
+                walk_expr(self, expr);
+            }
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = AssignVisitor { found: false };
+    visitor.visit_stmt(stmt);
+    visitor.found
+}
+
+/// Returns the suggestions removing the assignments from the branches, so that each branch
+/// evaluates to the assigned value instead. Branches which diverge are left as they are.
+fn assignment_suggestions<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding_id: HirId,
+    branches: impl IntoIterator<Item = &'tcx Expr<'tcx>>,
+) -> Option<(Applicability, Vec<(Span, String)>)> {
+    let mut suggestions = Vec::new();
+    for branch in branches {
+        if expr_diverges(cx, branch) {
+            continue;
+        }
+        if !cx.typeck_results().expr_ty(branch).is_unit() {
+            return None;
+        }
+
+        let assign = LocalAssign::from_branch(binding_id, branch)?;
+        suggestions.push((assign.lhs_span.until(assign.rhs_span), String::new()));
+        // the trailing semicolon, if any
+        let after_rhs = assign.rhs_span.between(assign.span.shrink_to_hi());
+        if !after_rhs.is_empty() {
+            suggestions.push((after_rhs, String::new()));
+        }
+    }
+
+    if suggestions.is_empty() {
+        return None;
+    }
+    // rustfix can't apply several parts of a multipart suggestion in one pass yet
+    let applicability = if suggestions.len() > 1 {
+        Applicability::Unspecified
+    } else {
+        Applicability::MachineApplicable
+    };
+    Some((applicability, suggestions))
+}
+
+/// The first statement after the `let` using the binding.
+struct Usage<'tcx> {
+    stmt: &'tcx Stmt<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    /// Whether the statement has no trailing semicolon, like an `if` or `match` statement
+    needs_semi: bool,
+}
+
+fn first_usage<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding_id: HirId,
+    local_stmt: &'tcx Stmt<'tcx>,
+    block: &'tcx Block<'tcx>,
+) -> Option<Usage<'tcx>> {
+    // Moving the declaration past other `let` statements changes the order in which the locals
+    // are dropped
+    let ty = cx.typeck_results().node_type(binding_id);
+    let needs_drop = ty.needs_drop(cx.tcx, cx.param_env);
+    let mut visitor = LocalUsedVisitor::new(cx, binding_id);
+
+    let stmt = block
+        .stmts
+        .iter()
+        .skip_while(|stmt| stmt.hir_id != local_stmt.hir_id)
+        .skip(1)
+        .take_while(|stmt| !needs_drop || !matches!(stmt.kind, StmtKind::Local(_)))
+        .find(|stmt| visitor.check_stmt(stmt))?;
+    match stmt.kind {
+        StmtKind::Expr(expr) => Some(Usage {
+            stmt,
+            expr,
+            needs_semi: true,
+        }),
+        StmtKind::Semi(expr) => Some(Usage {
+            stmt,
+            expr,
+            needs_semi: false,
+        }),
+        _ => None,
+    }
+}
+
+/// The source of `let <pat>: <ty>` without the semicolon.
+fn local_snippet_without_semicolon(cx: &LateContext<'_>, local: &Local<'_>) -> Option<String> {
+    let end = local.ty.map_or(local.pat.span, |ty| ty.span);
+    snippet_opt(cx, local.span.with_hi(end.hi()))
+}
+
+fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: &'tcx Local<'tcx>,
+    local_stmt: &'tcx Stmt<'tcx>,
+    block: &'tcx Block<'tcx>,
+    binding_id: HirId,
+) -> Option<()> {
+    let usage = first_usage(cx, binding_id, local_stmt, block)?;
+    if usage.stmt.span.from_expansion() {
+        return None;
+    }
+    let binding_name = cx.tcx.hir().name(binding_id);
+    let let_snippet = local_snippet_without_semicolon(cx, local)?;
+
+    let (branch_kind, (applicability, suggestions)) = match usage.expr.kind {
+        ExprKind::Assign(..) => {
+            let assign = LocalAssign::from_expr(usage.expr, binding_id, usage.expr.span)?;
+            span_lint_and_then(
+                cx,
+                NEEDLESS_LATE_INIT,
+                local_stmt.span,
+                "unneeded late initialization",
+                |diag| {
+                    diag.tool_only_span_suggestion(
+                        local_stmt.span,
+                        "remove the local",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                    diag.span_suggestion(
+                        assign.lhs_span,
+                        &format!("declare `{}` here", binding_name),
+                        let_snippet,
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+            return Some(());
+        },
+        ExprKind::If(_, then_expr, Some(else_expr)) => (
+            "the branches",
+            assignment_suggestions(cx, binding_id, [then_expr, else_expr].iter().copied())?,
+        ),
+        ExprKind::Match(_, arms, MatchSource::Normal) => (
+            "the `match` arms",
+            assignment_suggestions(cx, binding_id, arms.iter().map(|arm| arm.body))?,
+        ),
+        _ => return None,
+    };
+
+    span_lint_and_then(
+        cx,
+        NEEDLESS_LATE_INIT,
+        local_stmt.span,
+        "unneeded late initialization",
+        |diag| {
+            diag.tool_only_span_suggestion(local_stmt.span, "remove the local", String::new(), applicability);
+            diag.span_suggestion_verbose(
+                usage.stmt.span.shrink_to_lo(),
+                &format!("declare `{}` here", binding_name),
+                format!("{} = ", let_snippet),
+                applicability,
+            );
+            diag.multipart_suggestion(
+                &format!("remove the assignments from {}", branch_kind),
+                suggestions,
+                applicability,
+            );
+            if usage.needs_semi {
+                diag.span_suggestion(
+                    usage.stmt.span.shrink_to_hi(),
+                    "add a semicolon after the expression",
+                    ";".to_string(),
+                    applicability,
+                );
+            }
+        },
+    );
+    Some(())
+}
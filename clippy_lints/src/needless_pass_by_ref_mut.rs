@@ -0,0 +1,193 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    BindingAnnotation, Body, FnDecl, HirId, HirIdSet, Impl, ItemKind, Mutability, MutTy, Node, PatKind, TyKind,
+};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::kw;
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+use rustc_typeck::expr_use_visitor as euv;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions taking arguments by mutable reference, but never
+    /// using them mutably, neither in the body nor in the closures it contains.
+    ///
+    /// **Why is this bad?** A mutable reference is more restrictive for the caller, as it requires
+    /// exclusive access to the value, and it suggests that the value is changed by the function.
+    ///
+    /// **Known problems:** Functions which have to match a signature, e.g. because they are
+    /// passed as a `fn(&mut T)` pointer, are linted as well. Trait methods and their
+    /// implementations are not checked. Exported functions are only checked if the
+    /// `avoid-breaking-exported-api` configuration option is set to `false`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn sum(v: &mut Vec<u32>) -> u32 {
+    ///     v.iter().sum()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn sum(v: &Vec<u32>) -> u32 {
+    ///     v.iter().sum()
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub NEEDLESS_PASS_BY_REF_MUT,
+    nursery,
+    "taking an argument by `&mut` which is never used mutably"
+}
+
+pub struct NeedlessPassByRefMut {
+    avoid_breaking_exported_api: bool,
+}
+
+impl NeedlessPassByRefMut {
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(NeedlessPassByRefMut => [NEEDLESS_PASS_BY_REF_MUT]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessPassByRefMut {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if span.from_expansion() {
+            return;
+        }
+
+        let header = match kind {
+            FnKind::ItemFn(.., header, _) => header,
+            FnKind::Method(_, sig, ..) => sig.header,
+            FnKind::Closure => return,
+        };
+        if header.abi != Abi::Rust {
+            return;
+        }
+
+        // The signature of trait methods is given by the trait
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(hir_id)) {
+            if matches!(
+                item.kind,
+                ItemKind::Impl(Impl { of_trait: Some(_), .. }) | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+
+        if self.avoid_breaking_exported_api && cx.access_levels.is_exported(hir_id) {
+            return;
+        }
+
+        let mut_ref_params: Vec<_> = decl
+            .inputs
+            .iter()
+            .zip(body.params)
+            .filter_map(|(input, param)| match (&input.kind, param.pat.kind) {
+                (
+                    TyKind::Rptr(
+                        lifetime,
+                        MutTy {
+                            ty: inner_ty,
+                            mutbl: Mutability::Mut,
+                        },
+                    ),
+                    PatKind::Binding(BindingAnnotation::Unannotated, binding_id, ident, None),
+                ) if !ident.as_str().starts_with('_') && !input.span.from_expansion() => {
+                    Some((binding_id, ident.name, input.span, lifetime, inner_ty, param.span))
+                },
+                _ => None,
+            })
+            .collect();
+        if mut_ref_params.is_empty() {
+            return;
+        }
+
+        let mut ctx = MutablyUsedVariables::default();
+        let fn_def_id = cx.tcx.hir().local_def_id(hir_id);
+        cx.tcx.infer_ctxt().enter(|infcx| {
+            euv::ExprUseVisitor::new(&mut ctx, &infcx, fn_def_id, cx.param_env, cx.typeck_results()).consume_body(body);
+        });
+
+        for (binding_id, name, input_span, lifetime, inner_ty, param_span) in mut_ref_params {
+            if ctx.mutably_used.contains(&binding_id) {
+                continue;
+            }
+
+            let mut applicability = Applicability::MaybeIncorrect;
+            let (span, sugg) = if name == kw::SelfLower {
+                (param_span, "&self".to_string())
+            } else {
+                let inner_snip = snippet_with_applicability(cx, inner_ty.span, "_", &mut applicability);
+                let sugg = if lifetime.is_elided() {
+                    format!("&{}", inner_snip)
+                } else {
+                    format!("&{} {}", lifetime.name.ident(), inner_snip)
+                };
+                (input_span, sugg)
+            };
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_PASS_BY_REF_MUT,
+                span,
+                "this argument is a mutable reference, but is never used mutably",
+                "consider changing to",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Collects the variables which are mutated, borrowed mutably or moved, which includes
+/// reborrowing them, e.g. with `&mut *x` or by calling a `&mut self` method on them.
+#[derive(Default)]
+struct MutablyUsedVariables {
+    mutably_used: HirIdSet,
+}
+
+impl MutablyUsedVariables {
+    fn add(&mut self, cmt: &euv::PlaceWithHirId<'_>) {
+        if let euv::PlaceBase::Local(id) = cmt.place.base {
+            self.mutably_used.insert(id);
+        }
+    }
+}
+
+impl<'tcx> euv::Delegate<'tcx> for MutablyUsedVariables {
+    fn consume(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId, mode: euv::ConsumeMode) {
+        // Moving the reference out, e.g. returning it, keeps it usable mutably
+        if let euv::ConsumeMode::Move = mode {
+            self.add(cmt);
+        }
+    }
+
+    fn borrow(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId, kind: ty::BorrowKind) {
+        if matches!(kind, ty::BorrowKind::MutBorrow | ty::BorrowKind::UniqueImmBorrow) {
+            self.add(cmt);
+        }
+    }
+
+    fn mutate(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId) {
+        self.add(cmt);
+    }
+
+    fn fake_read(&mut self, _: rustc_typeck::expr_use_visitor::Place<'tcx>, _: FakeReadCause, _: HirId) {}
+}
@@ -1,10 +1,10 @@
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::in_external_macro;
 use clippy_utils::ty::implements_trait;
 use clippy_utils::{self, get_trait_def_id, paths};
 use if_chain::if_chain;
 use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
@@ -39,6 +39,7 @@ declare_clippy_lint! {
     ///     _ => false,
     /// };
     /// ```
+    #[clippy::version = "1.29.0"]
     pub NEG_CMP_OP_ON_PARTIAL_ORD,
     complexity,
     "The use of negated comparison operators on partially ordered types may produce confusing code."
@@ -59,6 +60,11 @@ impl<'tcx> LateLintPass<'tcx> for NoNegCompOpForPartialOrd {
 
                 let ty = cx.typeck_results().expr_ty(left);
 
+                // Avoid the trait queries for primitive types, which are the common case here.
+                if ty.is_integral() || ty.is_char() || ty.is_bool() {
+                    return;
+                }
+
                 let implements_ord = {
                     if let Some(id) = get_trait_def_id(cx, &paths::ORD) {
                         implements_trait(cx, ty, id, &[])
@@ -0,0 +1,180 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{in_external_macro, match_def_path, paths};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Impl, Item, ItemKind, Unsafety};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe impl Send` on types which contain fields that are not
+    /// `Send`, like `Rc` or raw pointers to types which are not `Send`.
+    ///
+    /// **Why is this bad?** Sending such a value to another thread sends its fields as well,
+    /// which is unsound unless the type makes sure they are never accessed from two threads, e.g.
+    /// the clones of an `Rc`.
+    ///
+    /// Raw pointers and types containing them, like `NonNull<T>` or `Vec<*const T>`, are treated
+    /// as `Send` if the types they point to are, since they are usually used to implement owning
+    /// data structures. Fields of a generic type need a `Send` bound on the `impl`.
+    ///
+    /// **Known problems:** A type may rely on invariants which make it sound, like only ever
+    /// accessing a field from the thread which created it. The wrapper types which are sound to
+    /// send can be added to the `allowed-non-send-types` configuration, written as fully
+    /// qualified paths.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// struct Handle {
+    ///     shared: Rc<String>,
+    /// }
+    ///
+    /// unsafe impl Send for Handle {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// // `Handle` is `Send` without an `impl`
+    /// struct Handle {
+    ///     shared: Arc<String>,
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub NON_SEND_FIELDS_IN_SEND_TY,
+    nursery,
+    "a type containing fields which are not `Send` implements `Send`"
+}
+
+pub struct NonSendFieldInSendTy {
+    allowed_paths: Vec<Vec<String>>,
+}
+
+impl NonSendFieldInSendTy {
+    pub fn new(allowed_non_send_types: &[String]) -> Self {
+        Self {
+            allowed_paths: allowed_non_send_types
+                .iter()
+                .map(|path| path.split("::").map(ToString::to_string).collect())
+                .collect(),
+        }
+    }
+
+    fn is_allowed_adt(&self, cx: &LateContext<'_>, did: DefId) -> bool {
+        if self.allowed_paths.is_empty() {
+            return false;
+        }
+        let def_path = cx.get_def_path(did);
+        self.allowed_paths.iter().any(|path| {
+            path.len() == def_path.len()
+                && def_path
+                    .iter()
+                    .zip(path)
+                    .all(|(sym, segment)| sym.as_str() == segment.as_str())
+        })
+    }
+
+    /// Checks if `ty` is not `Send`, even with the raw pointer heuristic described in the lint
+    /// documentation and the configured types.
+    fn is_non_send<'tcx>(&self, cx: &LateContext<'tcx>, send_trait: DefId, ty: Ty<'tcx>) -> bool {
+        if implements_trait(cx, ty, send_trait, &[]) {
+            return false;
+        }
+        match *ty.kind() {
+            ty::Adt(adt, _) if self.is_allowed_adt(cx, adt.did) => false,
+            ty::RawPtr(TypeAndMut { ty, .. }) | ty::Array(ty, _) | ty::Slice(ty) => {
+                self.is_non_send(cx, send_trait, ty)
+            },
+            ty::Tuple(_) => ty.tuple_fields().any(|ty| self.is_non_send(cx, send_trait, ty)),
+            ty::Adt(_, substs) if contains_pointer_like(cx, ty) => {
+                substs.types().any(|ty| self.is_non_send(cx, send_trait, ty))
+            },
+            _ => true,
+        }
+    }
+}
+
+impl_lint_pass!(NonSendFieldInSendTy => [NON_SEND_FIELDS_IN_SEND_TY]);
+
+impl<'tcx> LateLintPass<'tcx> for NonSendFieldInSendTy {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if item.span.from_expansion() || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+
+        let send_trait = match cx.tcx.get_diagnostic_item(sym::send_trait) {
+            Some(send_trait) => send_trait,
+            None => return,
+        };
+        let is_send_impl = matches!(
+            item.kind,
+            ItemKind::Impl(Impl {
+                unsafety: Unsafety::Unsafe,
+                of_trait: Some(ref trait_ref),
+                ..
+            }) if trait_ref.trait_def_id() == Some(send_trait)
+        );
+        if !is_send_impl {
+            return;
+        }
+
+        // The field types refer to the generic parameters of the `impl`, which can have `Send`
+        // bounds
+        let self_ty = cx.tcx.type_of(item.def_id.to_def_id());
+        let (adt, substs) = match *self_ty.kind() {
+            ty::Adt(adt, substs) => (adt, substs),
+            _ => return,
+        };
+        let non_send_fields = adt
+            .all_fields()
+            .filter(|field| self.is_non_send(cx, send_trait, field.ty(cx.tcx, substs)))
+            .collect::<Vec<_>>();
+        if non_send_fields.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            NON_SEND_FIELDS_IN_SEND_TY,
+            item.span,
+            &format!(
+                "this implementation is unsound, as some fields in `{}` are not `Send`",
+                self_ty
+            ),
+            |diag| {
+                for field in non_send_fields {
+                    let field_ty = field.ty(cx.tcx, substs);
+                    diag.span_note(
+                        cx.tcx.def_span(field.did),
+                        &format!(
+                            "the type of field `{}` is `{}`, which is not `Send`",
+                            field.ident, field_ty
+                        ),
+                    );
+                    if let ty::Param(_) = field_ty.kind() {
+                        diag.help(&format!("add a `{}: Send` bound to the `impl`", field_ty));
+                    } else {
+                        diag.help("use a thread-safe type which implements `Send`");
+                    }
+                }
+            },
+        );
+    }
+}
+
+/// Checks if the type is or has generic arguments which are raw pointers or `NonNull`.
+fn contains_pointer_like<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|arg| match arg.unpack() {
+        GenericArgKind::Type(ty) => match *ty.kind() {
+            ty::RawPtr(_) => true,
+            ty::Adt(adt, _) => match_def_path(cx, adt.did, &paths::PTR_NON_NULL),
+            _ => false,
+        },
+        _ => false,
+    })
+}
@@ -10,7 +10,8 @@ use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::Span;
-use serde::{de, Deserialize};
+use serde::ser::SerializeStruct;
+use serde::{de, ser, Deserialize, Serialize};
 
 declare_clippy_lint! {
     /// **What it does:** Checks that common macros are used with consistent bracing.
@@ -30,6 +31,7 @@ declare_clippy_lint! {
     /// ```rust
     /// vec![1, 2, 3];
     /// ```
+    #[clippy::version = "1.55.0"]
     pub NONSTANDARD_MACRO_BRACES,
     nursery,
     "check consistent use of braces in macro"
@@ -278,3 +280,15 @@ impl<'de> Deserialize<'de> for MacroMatcher {
         deser.deserialize_struct("MacroMatcher", FIELDS, MacVisitor)
     }
 }
+
+impl Serialize for MacroMatcher {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut state = ser.serialize_struct("MacroMatcher", 2)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("brace", &self.braces.0)?;
+        state.end()
+    }
+}
@@ -0,0 +1,187 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, Impl, ItemKind, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::kw;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for function parameters which are only passed on to recursive
+    /// calls of the same function, at the same position.
+    ///
+    /// **Why is this bad?** The parameter doesn't affect the result of the function, it can be
+    /// removed from the signature and from the recursive calls.
+    ///
+    /// **Known problems:** Only direct recursion is detected. A parameter which is only passed
+    /// around between mutually recursive functions isn't linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn count(n: u32, unused: &str) -> u32 {
+    ///     if n == 0 { 0 } else { 1 + count(n - 1, unused) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn count(n: u32) -> u32 {
+    ///     if n == 0 { 0 } else { 1 + count(n - 1) }
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub ONLY_USED_IN_RECURSION,
+    complexity,
+    "function parameters which are only passed on to recursive calls"
+}
+
+pub struct OnlyUsedInRecursion {
+    avoid_breaking_exported_api: bool,
+}
+
+impl OnlyUsedInRecursion {
+    #[must_use]
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(OnlyUsedInRecursion => [ONLY_USED_IN_RECURSION]);
+
+impl<'tcx> LateLintPass<'tcx> for OnlyUsedInRecursion {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if matches!(kind, FnKind::Closure) || span.from_expansion() {
+            return;
+        }
+        if self.avoid_breaking_exported_api && cx.access_levels.is_exported(hir_id) {
+            return;
+        }
+        // The signature of trait methods and their implementations can't be changed
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(hir_id)) {
+            if matches!(
+                item.kind,
+                ItemKind::Impl(Impl { of_trait: Some(_), .. }) | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+
+        let params = body
+            .params
+            .iter()
+            .map(|param| match param.pat.kind {
+                // `self` can't be removed without changing the method calls, and `_x` is unused
+                // on purpose
+                PatKind::Binding(_, id, ident, None)
+                    if ident.name != kw::SelfLower && !ident.as_str().starts_with('_') =>
+                {
+                    Some(Param {
+                        id,
+                        span: param.span,
+                        recursive_uses: Vec::new(),
+                        used: false,
+                    })
+                },
+                _ => None,
+            })
+            .collect();
+        let mut visitor = RecursionVisitor {
+            cx,
+            fn_def_id: cx.tcx.hir().local_def_id(hir_id).to_def_id(),
+            params,
+        };
+        visitor.visit_expr(&body.value);
+
+        for param in visitor.params.into_iter().flatten() {
+            if param.used || param.recursive_uses.is_empty() {
+                continue;
+            }
+            span_lint_and_then(
+                cx,
+                ONLY_USED_IN_RECURSION,
+                param.span,
+                "parameter is only used in recursion",
+                |diag| {
+                    diag.span_note(param.recursive_uses, "parameter passed on here");
+                    diag.help("remove the parameter from the function and its recursive calls");
+                },
+            );
+        }
+    }
+}
+
+struct Param {
+    /// The `HirId` of the binding
+    id: HirId,
+    span: Span,
+    /// The arguments of the recursive calls passing on the parameter at the same position
+    recursive_uses: Vec<Span>,
+    /// Whether the parameter is used anywhere else
+    used: bool,
+}
+
+struct RecursionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    fn_def_id: DefId,
+    /// The parameters of the function by position, `None` for parameters which aren't checked
+    params: Vec<Option<Param>>,
+}
+
+impl<'tcx> RecursionVisitor<'_, 'tcx> {
+    /// Returns the arguments of `expr` if it calls the checked function.
+    fn recursive_call_args(&self, expr: &'tcx Expr<'tcx>) -> Option<&'tcx [Expr<'tcx>]> {
+        let (def_id, args) = match expr.kind {
+            ExprKind::Call(callee, args) => match callee.kind {
+                ExprKind::Path(ref qpath) => (self.cx.qpath_res(qpath, callee.hir_id).opt_def_id()?, args),
+                _ => return None,
+            },
+            ExprKind::MethodCall(_, _, args, _) => (self.cx.typeck_results().type_dependent_def_id(expr.hir_id)?, args),
+            _ => return None,
+        };
+        if def_id == self.fn_def_id { Some(args) } else { None }
+    }
+
+    fn param_mut(&mut self, id: HirId) -> Option<&mut Param> {
+        self.params.iter_mut().flatten().find(|param| param.id == id)
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for RecursionVisitor<'_, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let Some(args) = self.recursive_call_args(expr) {
+            for (pos, arg) in args.iter().enumerate() {
+                match (path_to_local(arg), self.params.get_mut(pos)) {
+                    (Some(id), Some(Some(param))) if param.id == id => param.recursive_uses.push(arg.span),
+                    _ => self.visit_expr(arg),
+                }
+            }
+            if let ExprKind::Call(callee, _) = expr.kind {
+                self.visit_expr(callee);
+            }
+        } else if let Some(id) = path_to_local(expr) {
+            if let Some(param) = self.param_mut(id) {
+                param.used = true;
+            }
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
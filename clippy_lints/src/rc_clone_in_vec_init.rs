@@ -0,0 +1,100 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::{self, VecArgs};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{in_external_macro, is_expn_of, paths};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `vec![elem; len]` where `elem` creates a new `Rc`, `Arc`
+    /// or `Weak`.
+    ///
+    /// **Why is this bad?** `vec![elem; len]` clones `elem` for each element, so all the
+    /// elements point to the same allocation. This is rarely what is intended when a new pointer
+    /// is created in place, e.g. with `vec![Rc::new(RefCell::new(0)); 3]` a change through one
+    /// element is seen through all of them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// let v = vec![Rc::new("some data".to_string()); 100];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// // Each element has its own allocation
+    /// let v = (0..100).map(|_| Rc::new("some data".to_string())).collect::<Vec<_>>();
+    ///
+    /// // Or make the sharing explicit
+    /// let data = Rc::new("some data".to_string());
+    /// let v = vec![data; 100];
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub RC_CLONE_IN_VEC_INIT,
+    suspicious,
+    "initializing a reference-counted pointer in `vec![elem; len]`"
+}
+
+declare_lint_pass!(RcCloneInVecInit => [RC_CLONE_IN_VEC_INIT]);
+
+impl LateLintPass<'_> for RcCloneInVecInit {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let Some(VecArgs::Repeat(elem, len)) = higher::vec_macro(cx, expr);
+            if let Some(pointer_name) = ref_counted_pointer_ctor(cx, elem);
+            if let Some(macro_call_span) = is_expn_of(expr.span, "vec");
+            if !in_external_macro(cx.sess(), macro_call_span);
+            then {
+                span_lint_and_then(
+                    cx,
+                    RC_CLONE_IN_VEC_INIT,
+                    macro_call_span,
+                    "initializing a reference-counted pointer in `vec![elem; len]`",
+                    |diag| {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let elem_snip = snippet_with_applicability(cx, elem.span, "..", &mut applicability);
+                        let len_snip = snippet_with_applicability(cx, len.span, "..", &mut applicability);
+                        diag.note(&format!("each element will point to the same `{}` instance", pointer_name));
+                        diag.span_suggestion(
+                            macro_call_span,
+                            &format!("consider initializing each `{}` element individually", pointer_name),
+                            format!("(0..{}).map(|_| {}).collect::<Vec<_>>()", len_snip, elem_snip),
+                            applicability,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the pointer type if `expr` is a call to `Rc::new`, `Arc::new` or the `new`
+/// function of their `Weak` pointers.
+fn ref_counted_pointer_ctor(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if_chain! {
+        if let ExprKind::Call(func, _) = expr.kind;
+        if let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind;
+        if segment.ident.name == sym::new;
+        then {
+            let ty = cx.typeck_results().expr_ty(expr);
+            if is_type_diagnostic_item(cx, ty, sym::Rc) {
+                Some("Rc")
+            } else if is_type_diagnostic_item(cx, ty, sym::Arc) {
+                Some("Arc")
+            } else if match_type(cx, ty, &paths::WEAK_RC) || match_type(cx, ty, &paths::WEAK_ARC) {
+                Some("Weak")
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::higher::FormatArgsExpn;
+use clippy_utils::ty::same_type_and_consts;
+use clippy_utils::{is_diag_trait_item, match_def_path, path_to_local_id, paths};
+use if_chain::if_chain;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Impl, ImplItem, ImplItemKind, Item, ItemKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Display` and `Debug` implementations which format `self`
+    /// with the trait they implement, either by calling `self.to_string()` in `Display`, or by
+    /// passing `self` to a formatting macro, e.g. `write!(f, "{}", self)`.
+    ///
+    /// **Why is this bad?** `to_string` is usually implemented via `Display`, and formatting
+    /// `self` calls the implementation again. Either way it recurses infinitely and overflows the
+    /// stack at runtime.
+    ///
+    /// **Known problems:** Only `self`, references to it and its clones are recognized, other
+    /// values of the same type are not linted.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.to_string())
+    ///     }
+    /// }
+    ///
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub RECURSIVE_FORMAT_IMPL,
+    correctness,
+    "formatting `self` in its own `Display` or `Debug` implementation"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FormatTrait {
+    Debug,
+    Display,
+}
+
+impl FormatTrait {
+    fn name(self) -> Symbol {
+        match self {
+            Self::Debug => sym::Debug,
+            Self::Display => sym::Display,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RecursiveFormatImpl {
+    format_trait_impl: Option<FormatTrait>,
+    self_hir_id: Option<HirId>,
+}
+
+impl RecursiveFormatImpl {
+    pub fn new() -> Self {
+        Self {
+            format_trait_impl: None,
+            self_hir_id: None,
+        }
+    }
+}
+
+impl_lint_pass!(RecursiveFormatImpl => [RECURSIVE_FORMAT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for RecursiveFormatImpl {
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if let Some(format_trait) = format_trait_impl(cx, item) {
+            self.format_trait_impl = Some(format_trait);
+        }
+    }
+
+    fn check_item_post(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if format_trait_impl(cx, item).is_some() {
+            self.format_trait_impl = None;
+            self.self_hir_id = None;
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'_>, impl_item: &ImplItem<'_>) {
+        if_chain! {
+            if self.format_trait_impl.is_some();
+            if let ImplItemKind::Fn(.., body_id) = &impl_item.kind;
+            let body = cx.tcx.hir().body(*body_id);
+            if !body.params.is_empty();
+            then {
+                let self_param = &body.params[0];
+                self.self_hir_id = Some(self_param.pat.hir_id);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (format_trait, self_hir_id) = match (self.format_trait_impl, self.self_hir_id) {
+            (Some(format_trait), Some(self_hir_id)) => (format_trait, self_hir_id),
+            _ => return,
+        };
+
+        if_chain! {
+            if format_trait == FormatTrait::Display;
+            if let ExprKind::MethodCall(path, _, [receiver], _) = expr.kind;
+            if path.ident.name == sym!(to_string);
+            if let Some(expr_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            if is_diag_trait_item(cx, expr_def_id, sym::ToString);
+            if is_self(cx, receiver, self_hir_id);
+            then {
+                span_lint(
+                    cx,
+                    RECURSIVE_FORMAT_IMPL,
+                    expr.span,
+                    "using `self.to_string` in `fmt::Display` implementation will cause infinite recursion",
+                );
+                return;
+            }
+        }
+
+        if let Some(format_args) = FormatArgsExpn::parse(expr) {
+            for arg in format_args.args().into_iter().flatten() {
+                if arg.format_trait() == Some(format_trait.name()) && is_self(cx, arg.value, self_hir_id) {
+                    span_lint(
+                        cx,
+                        RECURSIVE_FORMAT_IMPL,
+                        arg.value.span,
+                        &format!(
+                            "using `self` as `{0}` in `impl {0}` will cause infinite recursion",
+                            format_trait.name()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn format_trait_impl(cx: &LateContext<'_>, item: &Item<'_>) -> Option<FormatTrait> {
+    if_chain! {
+        if let ItemKind::Impl(Impl { of_trait: Some(trait_ref), .. }) = &item.kind;
+        if let Some(did) = trait_ref.trait_def_id();
+        then {
+            if match_def_path(cx, did, &paths::DISPLAY_TRAIT) {
+                Some(FormatTrait::Display)
+            } else if cx.tcx.is_diagnostic_item(sym::debug_trait, did) {
+                Some(FormatTrait::Debug)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks if `expr` evaluates to `self`, looking through references, dereferences and method
+/// calls which return the receiver unchanged, like `(&*self).clone()`.
+fn is_self(cx: &LateContext<'_>, expr: &Expr<'_>, self_hir_id: HirId) -> bool {
+    match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => {
+            is_self(cx, inner, self_hir_id)
+        },
+        ExprKind::MethodCall(path, _, [receiver], _)
+            if matches!(
+                &*path.ident.as_str(),
+                "clone" | "to_owned" | "borrow" | "as_ref" | "deref"
+            ) =>
+        {
+            // `as_ref` and the like can also return a field, which is formatted by another impl
+            let typeck = cx.typeck_results();
+            same_type_and_consts(typeck.expr_ty(expr).peel_refs(), typeck.expr_ty(receiver).peel_refs())
+                && is_self(cx, receiver, self_hir_id)
+        },
+        _ => path_to_local_id(expr, self_hir_id),
+    }
+}
@@ -0,0 +1,103 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, HirId, Local, Node, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for redefinitions of a binding with itself, like `let x = x;`,
+    /// which change neither its type nor its mutability.
+    ///
+    /// **Why is this bad?** The redefinition doesn't do anything, it's usually left over from
+    /// refactoring.
+    ///
+    /// **Known problems:** The following redefinitions are never linted, because they can have an
+    /// effect, even where they are redundant:
+    /// - a `mut` binding or parameter redefined in an inner scope, where assignments to the
+    ///   redefinition don't change the outer binding,
+    /// - a binding redefined inside of a closure, which moves it into the closure instead of
+    ///   borrowing it,
+    /// - a binding whose type needs to be dropped, as the redefinition is dropped at the end of its
+    ///   own scope and before the locals declared in between.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn greet(name: &str) {
+    ///     let name = name;
+    ///     println!("Hello, {}!", name);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn greet(name: &str) {
+    ///     println!("Hello, {}!", name);
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub REDUNDANT_LOCALS,
+    complexity,
+    "redefining a binding with itself, like `let x = x;`"
+}
+
+declare_lint_pass!(RedundantLocals => [REDUNDANT_LOCALS]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantLocals {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'_>) {
+        if_chain! {
+            // Also skips the rebinding of the parameters of `async fn`s
+            if !local.span.from_expansion();
+            if local.ty.is_none();
+            if let PatKind::Binding(annotation, _, ident, None) = local.pat.kind;
+            if matches!(annotation, BindingAnnotation::Unannotated | BindingAnnotation::Mutable);
+            if let Some(init) = local.init;
+            if let ExprKind::Path(ref qpath @ QPath::Resolved(None, path)) = init.kind;
+            if let [segment] = path.segments;
+            if segment.ident.name == ident.name;
+            if let Res::Local(binding_id) = cx.qpath_res(qpath, init.hir_id);
+            if let Some(Node::Binding(binding_pat)) = cx.tcx.hir().find(binding_id);
+            if let PatKind::Binding(binding_annotation, ..) = binding_pat.kind;
+            if binding_annotation == annotation;
+            if !changes_scope(cx, annotation, local.hir_id, binding_id);
+            // The redefinition is dropped in its own scope and before the locals declared in between
+            if !cx.typeck_results().expr_ty(init).needs_drop(cx.tcx, cx.param_env);
+            if !is_closure_capture(cx, local.hir_id, binding_id);
+            then {
+                span_lint_and_note(
+                    cx,
+                    REDUNDANT_LOCALS,
+                    local.span,
+                    &format!("redundant redefinition of a binding `{}`", ident),
+                    Some(binding_pat.span),
+                    &format!("`{}` is initially defined here", ident),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the redefinition of a `mut` binding is in another scope than the binding, where it
+/// keeps assignments from affecting the original binding.
+fn changes_scope(cx: &LateContext<'_>, annotation: BindingAnnotation, local_id: HirId, binding_id: HirId) -> bool {
+    let hir = cx.tcx.hir();
+    annotation == BindingAnnotation::Mutable && hir.get_enclosing_scope(local_id) != hir.get_enclosing_scope(binding_id)
+}
+
+/// Checks if the redefinition is in a closure which the binding is declared outside of. The
+/// redefinition moves the binding into the closure instead of borrowing it.
+fn is_closure_capture(cx: &LateContext<'_>, local_id: HirId, binding_id: HirId) -> bool {
+    let hir = cx.tcx.hir();
+    hir.parent_iter(local_id)
+        .find(|(_, node)| {
+            matches!(
+                node,
+                Node::Expr(Expr {
+                    kind: ExprKind::Closure(..),
+                    ..
+                })
+            )
+        })
+        .map_or(false, |(closure_id, _)| {
+            !hir.parent_iter(binding_id).any(|(id, _)| id == closure_id)
+        })
+}
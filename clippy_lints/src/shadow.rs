@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::in_external_macro;
 use clippy_utils::source::snippet;
 use clippy_utils::{contains_name, higher, iter_input_pats};
 use rustc_hir::intravisit::FnKind;
@@ -7,7 +8,6 @@ use rustc_hir::{
     UnOp,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
@@ -33,6 +33,7 @@ declare_clippy_lint! {
     /// // Good
     /// let y = &x; // use different variable name
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub SHADOW_SAME,
     restriction,
     "rebinding a name to itself, e.g., `let mut x = &mut x`"
@@ -60,6 +61,7 @@ declare_clippy_lint! {
     /// let x = 2;
     /// let y = x + 1;
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub SHADOW_REUSE,
     restriction,
     "rebinding a name to an expression that re-uses the original value, e.g., `let x = x + 1`"
@@ -92,6 +94,7 @@ declare_clippy_lint! {
     /// // Good
     /// let w = z; // use different variable name
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub SHADOW_UNRELATED,
     pedantic,
     "rebinding a name without even using the original value"
@@ -207,7 +210,7 @@ fn check_pat<'tcx>(
                         let name = field.ident.name;
                         let efield = efields
                             .iter()
-                            .find_map(|f| if f.ident.name == name { Some(&*f.expr) } else { None });
+                            .find_map(|f| if f.ident.name == name { Some(f.expr) } else { None });
                         check_pat(cx, field.pat, efield, span, bindings);
                     }
                 } else {
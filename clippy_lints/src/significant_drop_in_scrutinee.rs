@@ -0,0 +1,151 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::get_parent_expr;
+use clippy_utils::in_external_macro;
+use clippy_utils::ty::{has_significant_drop, significant_drop_paths};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, MatchSource, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for temporaries in the scrutinee of a `match`, `if let` or
+    /// `while let` whose `Drop` implementation has a significant effect, like releasing a lock
+    /// or a `RefCell` borrow.
+    ///
+    /// **Why is this bad?** Temporaries in the scrutinee live until the end of the whole
+    /// expression, so the lock is still held (or the `RefCell` still borrowed) in the arms. Locking
+    /// the same `Mutex` again in an arm deadlocks, borrowing the `RefCell` mutably panics.
+    ///
+    /// **Known problems:** Only the guards of `std` and `parking_lot` are known. More types can be
+    /// added with the `significant-drop-types` configuration option.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// match mutex.lock().unwrap().len() {
+    ///     // The lock is still held here, so this deadlocks
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => {},
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let len = mutex.lock().unwrap().len();
+    /// match len {
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => {},
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub SIGNIFICANT_DROP_IN_SCRUTINEE,
+    nursery,
+    "temporary with significant `Drop` living until the end of a `match`, `if let` or `while let`"
+}
+
+pub struct SignificantDropInScrutinee {
+    paths: Vec<Vec<Symbol>>,
+}
+
+impl SignificantDropInScrutinee {
+    pub fn new(significant_drop_types: &[String]) -> Self {
+        Self {
+            paths: significant_drop_paths(significant_drop_types),
+        }
+    }
+}
+
+impl_lint_pass!(SignificantDropInScrutinee => [SIGNIFICANT_DROP_IN_SCRUTINEE]);
+
+impl<'tcx> LateLintPass<'tcx> for SignificantDropInScrutinee {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (scrutinee, kind) = match expr.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::Normal) => (scrutinee, "match"),
+            ExprKind::Match(scrutinee, _, MatchSource::IfLetDesugar { .. }) => (scrutinee, "if let"),
+            ExprKind::Match(scrutinee, _, MatchSource::WhileLetDesugar) => (scrutinee, "while let"),
+            _ => return,
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        let mut visitor = TemporaryVisitor {
+            cx,
+            paths: &self.paths,
+            scrutinee,
+            temporaries: Vec::new(),
+        };
+        visitor.visit_expr(scrutinee);
+
+        for temporary in visitor.temporaries {
+            span_lint_and_help(
+                cx,
+                SIGNIFICANT_DROP_IN_SCRUTINEE,
+                temporary.span,
+                &format!(
+                    "temporary with significant `Drop` in `{0}` scrutinee will live until the end of the `{0}` expression",
+                    kind
+                ),
+                None,
+                &format!(
+                    "consider computing the scrutinee in a `let` statement before the `{}`, so that the temporary is dropped at the end of that statement",
+                    kind
+                ),
+            );
+        }
+    }
+}
+
+/// Collects the temporaries with a significant `Drop` which are borrowed in the scrutinee. A
+/// temporary moved into a call is dropped by the callee, so those are not collected.
+struct TemporaryVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    paths: &'a [Vec<Symbol>],
+    scrutinee: &'tcx Expr<'tcx>,
+    temporaries: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> TemporaryVisitor<'a, 'tcx> {
+    fn is_borrowed(&self, expr: &'tcx Expr<'tcx>) -> bool {
+        let borrowed_by_adjustment = self
+            .cx
+            .typeck_results()
+            .expr_adjustments(expr)
+            .first()
+            .map_or(false, |adjustment| {
+                matches!(adjustment.kind, Adjust::Deref(_) | Adjust::Borrow(_))
+            });
+        borrowed_by_adjustment
+            || get_parent_expr(self.cx, expr).map_or(false, |parent| match parent.kind {
+                ExprKind::AddrOf(..) | ExprKind::Unary(UnOp::Deref, _) => true,
+                ExprKind::Field(base, _) | ExprKind::Index(base, _) => base.hir_id == expr.hir_id,
+                _ => false,
+            })
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for TemporaryVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            // Temporaries in closures and blocks are dropped before the end of the scrutinee
+            ExprKind::Closure(..) | ExprKind::Block(..) => return,
+            ExprKind::Call(..) | ExprKind::MethodCall(..)
+                if expr.hir_id != self.scrutinee.hir_id
+                    && has_significant_drop(self.cx, self.cx.typeck_results().expr_ty(expr), self.paths)
+                    && self.is_borrowed(expr) =>
+            {
+                self.temporaries.push(expr);
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,13 +1,17 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::in_external_macro;
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::SpanlessEq;
-use clippy_utils::{get_parent_expr, is_lint_allowed, match_function_call, method_calls, paths};
+use clippy_utils::{
+    expr_or_init, get_parent_expr, higher, is_lang_ctor, is_lint_allowed, match_function_call, method_calls,
+    path_to_local, paths,
+};
 use if_chain::if_chain;
+use rustc_ast::{LitKind, RangeLimits};
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, LangItem, QPath};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, LangItem, Node, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Spanned;
@@ -32,6 +36,7 @@ declare_clippy_lint! {
     /// x += ", World";
     /// x.push_str(", World");
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub STRING_ADD_ASSIGN,
     pedantic,
     "using `x = x + ..` where x is a `String` instead of `push_str()`"
@@ -60,11 +65,41 @@ declare_clippy_lint! {
     /// let x = "Hello".to_owned();
     /// x + ", World";
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub STRING_ADD,
     restriction,
     "using `x + ..` where x is a `String` instead of `push_str()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing expressions like `&s[a..b]` on a `str` or `String`,
+    /// unless both bounds are known to lie on a char boundary: omitted, `0`, a `len()`, or the
+    /// result of `find`/`rfind`, optionally plus the length of the literal it searched for.
+    ///
+    /// **Why is this bad?** Slicing a string panics if one of the bounds lies within a UTF-8
+    /// encoded character, which is easy to overlook when the code is only tested with ASCII text.
+    /// `str::get` returns `None` instead.
+    ///
+    /// **Known problems:** The bounds are only recognized syntactically, so a `len()` or `find`
+    /// result of another string is also accepted.
+    ///
+    /// **Example:**
+    /// ```rust,should_panic
+    /// let s = "Ölkanne";
+    /// let first = &s[0..1];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "Ölkanne";
+    /// let first = s.get(0..1);
+    /// assert_eq!(first, None);
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub STRING_SLICE,
+    restriction,
+    "slicing a string, which panics if a bound isn't on a char boundary"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for the `as_bytes` method called on string literals
     /// that contain only ASCII characters.
@@ -102,12 +137,13 @@ declare_clippy_lint! {
     /// // Good
     /// let bs = b"a byte string";
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub STRING_LIT_AS_BYTES,
     nursery,
     "calling `as_bytes` on a string literal instead of using a byte string literal"
 }
 
-declare_lint_pass!(StringAdd => [STRING_ADD, STRING_ADD_ASSIGN]);
+declare_lint_pass!(StringAdd => [STRING_ADD, STRING_ADD_ASSIGN, STRING_SLICE]);
 
 impl<'tcx> LateLintPass<'tcx> for StringAdd {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
@@ -152,10 +188,121 @@ impl<'tcx> LateLintPass<'tcx> for StringAdd {
                      `String::push_str()` instead",
                 );
             }
+        } else if let ExprKind::Index(target, idx) = e.kind {
+            check_string_slice(cx, e, target, idx);
+        }
+    }
+}
+
+fn check_string_slice<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>, target: &Expr<'_>, idx: &'tcx Expr<'tcx>) {
+    if_chain! {
+        if is_str_or_string(cx, target);
+        if let Some(range) = higher::range(idx);
+        if !(range.start.map_or(true, |start| is_known_char_boundary(cx, start))
+            // The end of an inclusive range is the start of the last included char
+            && range.end.map_or(true, |end| {
+                range.limits == RangeLimits::HalfOpen && is_known_char_boundary(cx, end)
+            }));
+        then {
+            span_lint_and_help(
+                cx,
+                STRING_SLICE,
+                e.span,
+                "slicing a string may panic if a bound is not on a char boundary",
+                None,
+                &format!(
+                    "consider using `{}.get({})`, which returns `None` instead",
+                    snippet(cx, target.span, ".."),
+                    snippet(cx, idx.span, ".."),
+                ),
+            );
         }
     }
 }
 
+fn is_str_or_string(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    cx.typeck_results().expr_ty(e).peel_refs().is_str() || is_string(cx, e)
+}
+
+/// Checks if `bound` is `0`, a `len()` of a string, or the result of `find`/`rfind` on a string,
+/// optionally plus the length of the literal it searched for.
+fn is_known_char_boundary<'tcx>(cx: &LateContext<'tcx>, bound: &'tcx Expr<'tcx>) -> bool {
+    let bound = expr_or_init(cx, bound);
+    match bound.kind {
+        ExprKind::Lit(ref lit) => matches!(lit.node, LitKind::Int(0, _)),
+        ExprKind::MethodCall(path, _, [recv], _) if path.ident.name == sym::len => is_str_or_string(cx, recv),
+        ExprKind::Binary(
+            Spanned {
+                node: BinOpKind::Add, ..
+            },
+            left,
+            right,
+        ) => {
+            let pattern_len = find_result_pattern(cx, left).and_then(literal_str_len);
+            matches!(
+                (pattern_len, &right.kind),
+                (Some(len), ExprKind::Lit(lit)) if matches!(lit.node, LitKind::Int(n, _) if n == len as u128)
+            )
+        },
+        _ => find_result_pattern(cx, bound).is_some(),
+    }
+}
+
+/// If `expr` is the unwrapped result of `find`/`rfind` on a string, either directly or bound by
+/// `if let Some(..)` or `let`, returns the pattern searched for.
+fn find_result_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let expr = expr_or_init(cx, expr);
+    let option = match expr.kind {
+        ExprKind::MethodCall(path, _, [recv, ..], _) if matches!(&*path.ident.name.as_str(), "unwrap" | "expect") => {
+            recv
+        },
+        _ => option_some_scrutinee(cx, expr)?,
+    };
+    if_chain! {
+        if let ExprKind::MethodCall(path, _, [recv, pattern], _) = option.kind;
+        if matches!(&*path.ident.name.as_str(), "find" | "rfind");
+        if is_str_or_string(cx, recv);
+        then {
+            Some(pattern)
+        } else {
+            None
+        }
+    }
+}
+
+/// If `expr` is a local bound by a `Some(..)` pattern of a `match`, `if let` or `while let`,
+/// returns the matched expression.
+fn option_some_scrutinee<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    let mut parents = cx.tcx.hir().parent_iter(path_to_local(expr)?).map(|(_, node)| node);
+    if_chain! {
+        if let Some(Node::Pat(pat)) = parents.next();
+        if let PatKind::TupleStruct(ref qpath, [_], _) = pat.kind;
+        if is_lang_ctor(cx, qpath, LangItem::OptionSome);
+        if let Some(Node::Arm(_)) = parents.next();
+        if let Some(Node::Expr(Expr {
+            kind: ExprKind::Match(scrutinee, ..),
+            ..
+        })) = parents.next();
+        then {
+            Some(scrutinee)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the UTF-8 length of a `char` or string literal.
+fn literal_str_len(expr: &Expr<'_>) -> Option<usize> {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Char(c) => Some(c.len_utf8()),
+            LitKind::Str(s, _) => Some(s.as_str().len()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn is_string(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(e).peel_refs(), sym::string_type)
 }
@@ -191,6 +338,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = &"Hello World!"[6..11];
     /// ```
+    #[clippy::version = "1.50.0"]
     pub STRING_FROM_UTF8_AS_BYTES,
     complexity,
     "casting string slices to byte slices and back"
@@ -203,8 +351,6 @@ declare_lint_pass!(StringLitAsBytes => [STRING_LIT_AS_BYTES, STRING_FROM_UTF8_AS
 
 impl<'tcx> LateLintPass<'tcx> for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        use rustc_ast::LitKind;
-
         if_chain! {
             // Find std::str::converts::from_utf8
             if let Some(args) = match_function_call(cx, e, &paths::STR_FROM_UTF8);
@@ -336,6 +482,7 @@ declare_clippy_lint! {
     /// // example code which does not raise clippy warning
     /// let _ = "str".to_owned();
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub STR_TO_STRING,
     restriction,
     "using `to_string()` on a `&str`, which should be `to_owned()`"
@@ -385,6 +532,7 @@ declare_clippy_lint! {
     /// let msg = String::from("Hello World");
     /// let _ = msg.clone();
     /// ```
+    #[clippy::version = "pre 1.29.0"]
     pub STRING_TO_STRING,
     restriction,
     "using `to_string()` on a `String`, which should be `clone()`"
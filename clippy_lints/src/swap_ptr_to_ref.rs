@@ -0,0 +1,136 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_no_std_crate, match_def_path, paths, SpanlessEq};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::SyntaxContext;
+use std::convert::TryFrom;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `core::mem::swap` where both mutable references are
+    /// created from raw pointers, like `mem::swap(&mut *a, &mut *b)`.
+    ///
+    /// **Why is this bad?** The references have to be unique, which the pointers don't
+    /// guarantee. If they point to the same or overlapping values, creating the references is
+    /// undefined behavior. `core::ptr::swap` takes the pointers directly and allows them to alias.
+    ///
+    /// **Known problems:** Pointers to distinct values are linted as well, unless they are offset
+    /// from the same pointer by different constants with `add` or `sub`, like `p` and `p.add(1)`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn swap(x: *mut u32, y: *mut u32) {
+    ///     unsafe {
+    ///         core::mem::swap(&mut *x, &mut *y);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn swap(x: *mut u32, y: *mut u32) {
+    ///     unsafe {
+    ///         core::ptr::swap(x, y);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub SWAP_PTR_TO_REF,
+    suspicious,
+    "call to `mem::swap` using references created from raw pointers"
+}
+
+declare_lint_pass!(SwapPtrToRef => [SWAP_PTR_TO_REF]);
+
+impl<'tcx> LateLintPass<'tcx> for SwapPtrToRef {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if_chain! {
+            if !e.span.from_expansion();
+            if let ExprKind::Call(func, [arg1, arg2]) = e.kind;
+            if let ExprKind::Path(ref func_qpath) = func.kind;
+            if let Some(def_id) = cx.qpath_res(func_qpath, func.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::MEM_SWAP);
+            if let Some(ptr1) = ptr_of_ref(cx, arg1);
+            if let Some(ptr2) = ptr_of_ref(cx, arg2);
+            let (base1, offset1) = ptr_and_offset(cx, ptr1);
+            let (base2, offset2) = ptr_and_offset(cx, ptr2);
+            let same_base = SpanlessEq::new(cx).eq_expr(base1, base2);
+            // e.g. `p` and `p.add(1)`, which can't alias
+            if !same_base || offset1 == offset2;
+            then {
+                let ctxt = e.span.ctxt();
+                let mut applicability = Applicability::MachineApplicable;
+                let ptr1_snip = arg_snippet(cx, ptr1, ctxt, &mut applicability);
+                let ptr2_snip = arg_snippet(cx, ptr2, ctxt, &mut applicability);
+                span_lint_and_then(
+                    cx,
+                    SWAP_PTR_TO_REF,
+                    e.span,
+                    "call to `core::mem::swap` with references derived from raw pointers",
+                    |diag| {
+                        if same_base {
+                            diag.note("both references point to the same value, which is undefined behavior");
+                        }
+                        diag.span_suggestion(
+                            e.span,
+                            "use ptr::swap",
+                            format!(
+                                "{}::ptr::swap({}, {})",
+                                if is_no_std_crate(cx) { "core" } else { "std" },
+                                ptr1_snip,
+                                ptr2_snip
+                            ),
+                            applicability,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the raw pointer `ptr` if `e` is `&mut *ptr`.
+fn ptr_of_ref<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if_chain! {
+        if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, borrowed) = e.kind;
+        if let ExprKind::Unary(UnOp::Deref, ptr) = borrowed.kind;
+        if let ty::RawPtr(_) = cx.typeck_results().expr_ty(ptr).kind();
+        then {
+            Some(ptr)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the pointer which `ptr` is offset from by constants with `add` and `sub`, and the
+/// offset, e.g. `p` and `1` for `p.add(2).sub(1)`.
+fn ptr_and_offset<'tcx>(cx: &LateContext<'_>, ptr: &'tcx Expr<'tcx>) -> (&'tcx Expr<'tcx>, i128) {
+    if_chain! {
+        if let ExprKind::MethodCall(path, _, [base, count], _) = ptr.kind;
+        if let ty::RawPtr(_) = cx.typeck_results().expr_ty(base).kind();
+        if let Some((Constant::Int(count), _)) = constant(cx, cx.typeck_results(), count);
+        if let Ok(count) = i128::try_from(count);
+        let (base, base_offset) = ptr_and_offset(cx, base);
+        if let Some(offset) = match &*path.ident.as_str() {
+            "add" => base_offset.checked_add(count),
+            "sub" => base_offset.checked_sub(count),
+            _ => None,
+        };
+        then {
+            (base, offset)
+        } else {
+            (ptr, 0)
+        }
+    }
+}
+
+fn arg_snippet(cx: &LateContext<'_>, e: &Expr<'_>, ctxt: SyntaxContext, applicability: &mut Applicability) -> String {
+    snippet_with_context(cx, e.span, ctxt, "..", applicability)
+        .0
+        .into_owned()
+}
@@ -1,14 +1,17 @@
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::source::{snippet, snippet_with_applicability};
-use clippy_utils::{in_macro, SpanlessHash};
+use clippy_utils::{in_macro, SpanlessEq, SpanlessHash};
 use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_errors::Applicability;
-use rustc_hir::{def::Res, GenericBound, Generics, ParamName, Path, QPath, TyKind, WherePredicate};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    GenericBound, GenericParamKind, Generics, PolyTraitRef, QPath, TraitBoundModifier, TyKind, WherePredicate,
+};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::Span;
 
 declare_clippy_lint! {
     /// **What it does:** This lint warns about unnecessary type repetitions in trait bounds
@@ -28,6 +31,7 @@ declare_clippy_lint! {
     /// ```rust
     /// pub fn foo<T>(t: T) where T: Copy + Clone {}
     /// ```
+    #[clippy::version = "1.38.0"]
     pub TYPE_REPETITION_IN_BOUNDS,
     pedantic,
     "Types are repeated unnecessary in trait bounds use `+` instead of using `T: _, T: _`"
@@ -35,7 +39,10 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for cases where generics are being used and multiple
-    /// syntax specifications for trait bounds are used simultaneously.
+    /// syntax specifications for trait bounds are used simultaneously, and for trait bounds
+    /// which are repeated in the where clause or in the generic parameters. Bounds on the
+    /// same trait with different generic arguments, like `From<u8>` and `From<u16>`, are
+    /// different bounds.
     ///
     /// **Why is this bad?** Duplicate bounds makes the code
     /// less readable than specifing them only once.
@@ -57,6 +64,7 @@ declare_clippy_lint! {
     /// ```rust
     /// fn func<T>(arg: T) where T: Clone + Default {}
     /// ```
+    #[clippy::version = "1.47.0"]
     pub TRAIT_DUPLICATION_IN_BOUNDS,
     pedantic,
     "Check if the same trait bounds are specified twice during a function declaration"
@@ -83,14 +91,6 @@ impl<'tcx> LateLintPass<'tcx> for TraitBounds {
     }
 }
 
-fn get_trait_res_span_from_bound(bound: &GenericBound<'_>) -> Option<(Res, Span)> {
-    if let GenericBound::Trait(t, _) = bound {
-        Some((t.trait_ref.path.res, t.span))
-    } else {
-        None
-    }
-}
-
 impl TraitBounds {
     fn check_type_repetition(self, cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
         if in_macro(gen.span) {
@@ -108,34 +108,24 @@ impl TraitBounds {
                 if let WherePredicate::BoundPredicate(ref p) = bound;
                 if p.bounds.len() as u64 <= self.max_trait_bounds;
                 if !in_macro(p.span);
-                let h = hash(p.bounded_ty);
-                if let Some(ref v) = map.insert(h, p.bounds.iter().collect::<Vec<_>>());
-
                 then {
-                    let mut hint_string = format!(
-                        "consider combining the bounds: `{}:",
-                        snippet(cx, p.bounded_ty.span, "_")
-                    );
-                    for b in v.iter() {
-                        if let GenericBound::Trait(ref poly_trait_ref, _) = b {
-                            let path = &poly_trait_ref.trait_ref.path;
-                            hint_string.push_str(&format!(
-                                " {} +",
-                                snippet_with_applicability(cx, path.span, "..", &mut applicability)
-                            ));
-                        }
-                    }
-                    for b in p.bounds.iter() {
-                        if let GenericBound::Trait(ref poly_trait_ref, _) = b {
-                            let path = &poly_trait_ref.trait_ref.path;
-                            hint_string.push_str(&format!(
-                                " {} +",
-                                snippet_with_applicability(cx, path.span, "..", &mut applicability)
-                            ));
-                        }
+                    let bounds = map.entry(hash(p.bounded_ty)).or_default();
+                    let is_repeated = !bounds.is_empty();
+                    // The hint combines all bounds of the type so far, including lifetimes and `?Sized`
+                    bounds.extend(p.bounds);
+                    if !is_repeated {
+                        continue;
                     }
-                    hint_string.truncate(hint_string.len() - 2);
-                    hint_string.push('`');
+
+                    let bounds = bounds
+                        .iter()
+                        .map(|b| snippet_with_applicability(cx, b.span(), "..", &mut applicability))
+                        .collect::<Vec<_>>();
+                    let hint_string = format!(
+                        "consider combining the bounds: `{}: {}`",
+                        snippet(cx, p.bounded_ty.span, "_"),
+                        bounds.join(" + ")
+                    );
                     span_lint_and_help(
                         cx,
                         TYPE_REPETITION_IN_BOUNDS,
@@ -150,46 +140,83 @@ impl TraitBounds {
     }
 }
 
+/// Where a trait bound is written.
+#[derive(Copy, Clone)]
+enum BoundLocation {
+    GenericParam,
+    WhereClause,
+}
+
 fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
-    if in_macro(gen.span) || gen.params.is_empty() || gen.where_clause.predicates.is_empty() {
+    if in_macro(gen.span) || gen.params.is_empty() {
         return;
     }
 
-    let mut map = FxHashMap::default();
-    for param in gen.params {
-        if let ParamName::Plain(ref ident) = param.name {
-            let res = param
-                .bounds
-                .iter()
-                .filter_map(get_trait_res_span_from_bound)
-                .collect::<Vec<_>>();
-            map.insert(*ident, res);
-        }
-    }
-
-    for predicate in gen.where_clause.predicates {
+    // The where clause comes first, so that a bound which is also specified in the generic
+    // parameters is reported there
+    let where_bounds = gen.where_clause.predicates.iter().filter_map(|predicate| {
         if_chain! {
             if let WherePredicate::BoundPredicate(ref bound_predicate) = predicate;
             if !in_macro(bound_predicate.span);
-            if let TyKind::Path(QPath::Resolved(_, Path { segments, .. })) = bound_predicate.bounded_ty.kind;
-            if let Some(segment) = segments.first();
-            if let Some(trait_resolutions_direct) = map.get(&segment.ident);
+            if let TyKind::Path(QPath::Resolved(None, path)) = bound_predicate.bounded_ty.kind;
+            if let [_] = path.segments;
+            if let Res::Def(DefKind::TyParam, param_id) = path.res;
             then {
-                for (res_where, _) in bound_predicate.bounds.iter().filter_map(get_trait_res_span_from_bound) {
-                    if let Some((_, span_direct)) = trait_resolutions_direct
-                                                .iter()
-                                                .find(|(res_direct, _)| *res_direct == res_where) {
-                        span_lint_and_help(
-                            cx,
-                            TRAIT_DUPLICATION_IN_BOUNDS,
-                            *span_direct,
-                            "this trait bound is already specified in the where clause",
-                            None,
-                            "consider removing this trait bound",
-                        );
-                    }
-                }
+                Some((param_id, BoundLocation::WhereClause, bound_predicate.bounds))
+            } else {
+                None
+            }
+        }
+    });
+    let param_bounds = gen.params.iter().filter_map(|param| {
+        if let GenericParamKind::Type { .. } = param.kind {
+            let param_id = cx.tcx.hir().local_def_id(param.hir_id).to_def_id();
+            Some((param_id, BoundLocation::GenericParam, param.bounds))
+        } else {
+            None
+        }
+    });
+
+    let mut seen: FxHashMap<DefId, Vec<(&PolyTraitRef<'_>, TraitBoundModifier, BoundLocation)>> = FxHashMap::default();
+    for (param_id, location, bounds) in where_bounds.chain(param_bounds) {
+        for bound in bounds {
+            let (poly_trait_ref, modifier) = match bound {
+                GenericBound::Trait(poly_trait_ref, modifier) if poly_trait_ref.trait_ref.path.res != Res::Err => {
+                    (poly_trait_ref, *modifier)
+                },
+                _ => continue,
+            };
+            let previous_bounds = seen.entry(param_id).or_default();
+            let previous = previous_bounds.iter().find(|(previous, previous_modifier, _)| {
+                *previous_modifier == modifier && is_same_trait_ref(cx, previous, poly_trait_ref)
+            });
+            if let Some((_, _, previous_location)) = previous {
+                let msg = match previous_location {
+                    BoundLocation::WhereClause => "this trait bound is already specified in the where clause",
+                    BoundLocation::GenericParam => "this trait bound is already specified in the generic parameters",
+                };
+                span_lint_and_help(
+                    cx,
+                    TRAIT_DUPLICATION_IN_BOUNDS,
+                    poly_trait_ref.span,
+                    msg,
+                    None,
+                    "consider removing this trait bound",
+                );
+            } else {
+                previous_bounds.push((poly_trait_ref, modifier, location));
             }
         }
     }
 }
+
+/// Checks if both bounds are the same trait with the same generic arguments, e.g. `From<u8>` and
+/// `std::convert::From<u8>`, but not `From<u16>`.
+fn is_same_trait_ref(cx: &LateContext<'_>, left: &PolyTraitRef<'_>, right: &PolyTraitRef<'_>) -> bool {
+    let (left, right) = (&left.trait_ref.path, &right.trait_ref.path);
+    left.res == right.res
+        && match (left.segments.last(), right.segments.last()) {
+            (Some(left), Some(right)) => SpanlessEq::new(cx).eq_path_segment(left, right),
+            _ => false,
+        }
+}
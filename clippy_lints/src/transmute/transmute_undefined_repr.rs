@@ -0,0 +1,24 @@
+use super::TRANSMUTE_UNDEFINED_REPR;
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::ty::{has_defined_layout, is_layout_compatible};
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+
+/// Checks for `transmute_undefined_repr` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    if is_layout_compatible(cx, from_ty, to_ty) {
+        return false;
+    }
+    let msg = match (has_defined_layout(cx, from_ty), has_defined_layout(cx, to_ty)) {
+        (false, false) => format!(
+            "transmute from `{}` to `{}`, both of which have an undefined layout",
+            from_ty, to_ty
+        ),
+        (false, true) => format!("transmute from `{}` which has an undefined layout", from_ty),
+        (true, _) => format!("transmute into `{}` which has an undefined layout", to_ty),
+    };
+    span_lint(cx, TRANSMUTE_UNDEFINED_REPR, e.span, &msg);
+    true
+}
@@ -0,0 +1,215 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::in_external_macro;
+use clippy_utils::source::{first_line_of_span, preceding_comments};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    Block, BlockCheckMode, Expr, ExprKind, Impl, ImplItemKind, Item, ItemKind, Node, StmtKind, TraitItemKind,
+    UnsafeSource, Unsafety,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks without a `// SAFETY:` comment right before
+    /// them, either on the lines above or on the same line. With the `undocumented-unsafe-impls`
+    /// configuration option, `unsafe impl`s need the comment as well.
+    ///
+    /// **Why is this bad?** An `unsafe` block relies on invariants which the compiler can't check.
+    /// Writing down why they hold helps reviewers, and whoever changes the code later, to check
+    /// that they still do.
+    ///
+    /// **Known problems:** `unsafe` blocks in macro expansions are not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let ptr: *const u8 = &0;
+    /// let value = unsafe { *ptr };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let ptr: *const u8 = &0;
+    /// // SAFETY: `ptr` points to a local variable which outlives this statement
+    /// let value = unsafe { *ptr };
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub UNDOCUMENTED_UNSAFE_BLOCKS,
+    restriction,
+    "`unsafe` block without a `// SAFETY:` comment"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `// SAFETY:` comments on statements, expressions and `impl`s
+    /// which don't contain any `unsafe` block, or aren't `unsafe impl`s.
+    ///
+    /// **Why is this bad?** The comment is either left over from code which has been made safe,
+    /// or meant for `unsafe` code somewhere else. In both cases it is misleading.
+    ///
+    /// **Known problems:** Code in `unsafe` blocks and `unsafe fn`s is not checked, as a comment
+    /// there may well be about an unsafe operation outside of a nested `unsafe` block.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // SAFETY: the vector is never empty
+    /// let first = vec![1, 2, 3][0];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let first = vec![1, 2, 3][0];
+    /// ```
+    #[clippy::version = "1.55.0"]
+    pub UNNECESSARY_SAFETY_COMMENT,
+    restriction,
+    "`// SAFETY:` comment on safe code"
+}
+
+pub struct UndocumentedUnsafeBlocks {
+    undocumented_unsafe_impls: bool,
+}
+
+impl UndocumentedUnsafeBlocks {
+    pub fn new(undocumented_unsafe_impls: bool) -> Self {
+        Self {
+            undocumented_unsafe_impls,
+        }
+    }
+}
+
+impl_lint_pass!(UndocumentedUnsafeBlocks => [UNDOCUMENTED_UNSAFE_BLOCKS, UNNECESSARY_SAFETY_COMMENT]);
+
+impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() || in_external_macro(cx.sess(), block.span) {
+            return;
+        }
+
+        if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) && !has_safety_comment(cx, block.span)
+        {
+            span_lint_and_help(
+                cx,
+                UNDOCUMENTED_UNSAFE_BLOCKS,
+                block.span,
+                "unsafe block missing a safety comment",
+                None,
+                "consider adding a `// SAFETY:` comment explaining why the unsafe code is sound",
+            );
+        }
+
+        // Unsafe operations don't need an `unsafe` block of their own here
+        if is_in_unsafe_context(cx, block) {
+            return;
+        }
+
+        for stmt in block.stmts {
+            let expr = match stmt.kind {
+                StmtKind::Local(local) => local.init,
+                StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+                StmtKind::Item(_) => continue,
+            };
+            if !stmt.span.from_expansion() && !expr.map_or(false, |expr| contains_unsafe_block(cx, expr)) {
+                check_unnecessary_safety_comment(cx, stmt.span, "statement");
+            }
+        }
+        if let Some(expr) = block.expr {
+            if !expr.span.from_expansion() && !contains_unsafe_block(cx, expr) {
+                check_unnecessary_safety_comment(cx, expr.span, "expression");
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if item.span.from_expansion() || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+
+        if let ItemKind::Impl(Impl { unsafety, .. }) = item.kind {
+            if unsafety == Unsafety::Unsafe {
+                if self.undocumented_unsafe_impls && !has_safety_comment(cx, item.span) {
+                    span_lint_and_help(
+                        cx,
+                        UNDOCUMENTED_UNSAFE_BLOCKS,
+                        item.span,
+                        "unsafe impl missing a safety comment",
+                        None,
+                        "consider adding a `// SAFETY:` comment explaining why the implementation is sound",
+                    );
+                }
+            } else {
+                check_unnecessary_safety_comment(cx, item.span, "impl");
+            }
+        }
+    }
+}
+
+/// Checks if `block` is an `unsafe` block, or inside of one or of an `unsafe fn`.
+fn is_in_unsafe_context(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+        || cx.tcx.hir().parent_iter(block.hir_id).find_map(|(_, node)| match node {
+            Node::Block(block) if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) => Some(true),
+            Node::Item(item) => {
+                Some(matches!(item.kind, ItemKind::Fn(sig, ..) if sig.header.unsafety == Unsafety::Unsafe))
+            },
+            Node::ImplItem(item) => {
+                Some(matches!(item.kind, ImplItemKind::Fn(sig, _) if sig.header.unsafety == Unsafety::Unsafe))
+            },
+            Node::TraitItem(item) => {
+                Some(matches!(item.kind, TraitItemKind::Fn(sig, _) if sig.header.unsafety == Unsafety::Unsafe))
+            },
+            _ => None,
+        }) == Some(true)
+}
+
+fn has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
+    preceding_comments(cx, span).map_or(false, |comments| comments.contains("SAFETY:"))
+}
+
+fn check_unnecessary_safety_comment(cx: &LateContext<'_>, span: Span, kind: &str) {
+    // Code after something else on the same line, like the body of `unsafe { f() }`, doesn't own
+    // the comments above it
+    if first_line_of_span(cx, span).lo() == span.lo() && has_safety_comment(cx, span) {
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_SAFETY_COMMENT,
+            span,
+            &format!("{} has unnecessary safety comment", kind),
+            |diag| {
+                diag.help(&format!("the {} doesn't contain any `unsafe` code", kind));
+                diag.help("consider removing the safety comment");
+            },
+        );
+    }
+}
+
+fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    let mut visitor = UnsafeBlockVisitor { cx, found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct UnsafeBlockVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeBlockVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found {
+            return;
+        }
+        if let ExprKind::Block(block, _) = expr.kind {
+            if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) {
+                self.found = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    // The body of a closure can contain the `unsafe` block
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -3,18 +3,42 @@
 #![allow(clippy::module_name_repetitions)]
 
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::{env, fmt, fs, io};
 
 /// Holds information used by `MISSING_ENFORCED_IMPORT_RENAMES` lint.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rename {
     pub path: String,
     pub rename: String,
 }
 
+/// A path disallowed by `DISALLOWED_METHOD` or `DISALLOWED_TYPE`, either written as a string or as a
+/// table with a reason that is shown in the lint message.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    Simple(String),
+    WithReason { path: String, reason: Option<String> },
+}
+
+impl DisallowedPath {
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Simple(path) | Self::WithReason { path, .. } => path,
+        }
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::WithReason { reason, .. } => reason.as_deref(),
+            Self::Simple(_) => None,
+        }
+    }
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -41,6 +65,8 @@ macro_rules! define_Conf {
         ($name:ident: $ty:ty = $default:expr),
     )*) => {
         /// Clippy lint configuration
+        #[derive(Serialize)]
+        #[serde(rename_all = "kebab-case")]
         pub struct Conf {
             $(#[doc = $doc] pub $name: $ty,)*
         }
@@ -132,9 +158,9 @@ macro_rules! define_Conf {
 
 // N.B., this macro is parsed by util/lintlib.py
 define_Conf! {
-    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION. Suppress lints whenever the suggested change would cause breakage for other crates.
+    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, NEEDLESS_PASS_BY_REF_MUT, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, ONLY_USED_IN_RECURSION. Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE. The minimum rust version that the project supports
+    /// Lint: MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, PTR_CAST_CONSTNESS, IF_THEN_SOME_ELSE_NONE, SEEK_FROM_CURRENT, MANUAL_RETAIN, MANUAL_BITS, MANUAL_CLAMP, MANUAL_SPLIT_ONCE. The minimum rust version that the project supports
     (msrv: Option<String> = None),
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names: Vec<String> = ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
@@ -164,6 +190,8 @@ define_Conf! {
         "MinGW",
         "CamelCase",
     ].iter().map(ToString::to_string).collect()),
+    /// Lint: MISSING_PANICS_DOC. Whether `unwrap` and `expect` calls on `Option` and `Result` are allowed in functions without a `# Panics` section
+    (allow_unwrap_without_panics_doc: bool = false),
     /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
     (too_many_arguments_threshold: u64 = 7),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
@@ -184,6 +212,8 @@ define_Conf! {
     (trivial_copy_size_limit: Option<u64> = None),
     /// Lint: LARGE_TYPE_PASS_BY_MOVE. The minimum size (in bytes) to consider a type for passing by reference instead of by value.
     (pass_by_value_size_limit: u64 = 256),
+    /// Lint: COGNITIVE_COMPLEXITY, IF_SAME_THEN_ELSE, BRANCHES_SHARING_CODE, IFS_SAME_COND, SAME_FUNCTIONS_IN_IF_CONDITION, MATCH_SAME_ARMS. The maximum number of HIR nodes in a function body before these lints skip it
+    (body_node_limit: u64 = 100_000),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
     (too_many_lines_threshold: u64 = 100),
     /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS. The maximum allowed size for arrays on the stack
@@ -198,10 +228,14 @@ define_Conf! {
     (max_fn_params_bools: u64 = 3),
     /// Lint: WILDCARD_IMPORTS. Whether to allow certain wildcard imports (prelude, super in tests).
     (warn_on_all_wildcard_imports: bool = false),
-    /// Lint: DISALLOWED_METHOD. The list of disallowed methods, written as fully qualified paths.
-    (disallowed_methods: Vec<String> = Vec::new()),
-    /// Lint: DISALLOWED_TYPE. The list of disallowed types, written as fully qualified paths.
-    (disallowed_types: Vec<String> = Vec::new()),
+    /// Lint: DISALLOWED_METHOD. The list of disallowed methods, written as fully qualified paths, or as tables like `{ path = "std::time::Instant::now", reason = "use the mocked clock" }`.
+    (disallowed_methods: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
+    /// Lint: DISALLOWED_TYPE. The list of disallowed types, written as fully qualified paths, or as tables like `{ path = "std::time::Instant", reason = "use the mocked clock" }`.
+    (disallowed_types: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
+    /// Lint: ASYNC_BLOCKING_CALL. Additional blocking functions to lint in async code, written as fully qualified paths.
+    (disallowed_blocking_paths: Vec<String> = Vec::new()),
+    /// Lint: SIGNIFICANT_DROP_IN_SCRUTINEE. Additional types whose `Drop` implementation has a significant effect, like releasing a lock, written as fully qualified paths of their definitions.
+    (significant_drop_types: Vec<String> = Vec::new()),
     /// Lint: UNREADABLE_LITERAL. Should the fraction of a decimal be linted to include separators.
     (unreadable_literal_lint_fractions: bool = true),
     /// Lint: UPPER_CASE_ACRONYMS. Enables verbose mode. Triggers if there is more than one uppercase char next to each other
@@ -214,35 +248,125 @@ define_Conf! {
     (enforced_import_renames: Vec<crate::utils::conf::Rename> = Vec::new()),
     /// Lint: RESTRICTED_SCRIPTS. The list of unicode scripts allowed to be used in the scope.
     (allowed_scripts: Vec<String> = vec!["Latin".to_string()]),
+    /// Lint: UNDOCUMENTED_UNSAFE_BLOCKS. Whether `unsafe impl`s also need a `// SAFETY:` comment.
+    (undocumented_unsafe_impls: bool = false),
+    /// Lint: NON_SEND_FIELDS_IN_SEND_TY. Types which are sound to send to another thread within a type implementing `Send`, even though they don't implement `Send` themselves, written as fully qualified paths of their definitions, like `alloc::rc::Rc`.
+    (allowed_non_send_types: Vec<String> = Vec::new()),
+    /// Lint: ARITHMETIC_SIDE_EFFECTS. Types whose arithmetic operator implementations are known not to overflow or panic, written as fully qualified paths of their definitions, like `core::num::wrapping::Wrapping`.
+    (arithmetic_side_effects_allowed: Vec<String> = Vec::new()),
+    /// Lint: UNCHECKED_DURATION_SUBTRACTION. Whether to allow unchecked `Instant` subtractions in test functions and modules
+    (allow_unchecked_duration_subtraction_in_tests: bool = false),
+    /// Lint: NONE. Whether the keys of the configuration files in the parent directories are inherited. Set it to `false` to stop the lookup at this file.
+    (inherit_config: bool = true),
+    /// Lint: NONE. The lints and lint groups to allow, like passing them to `--allow` before the arguments of the command line.
+    (allow: Vec<String> = Vec::new()),
+    /// Lint: NONE. The lints and lint groups to warn about, like passing them to `--warn` before the arguments of the command line.
+    (warn: Vec<String> = Vec::new()),
+    /// Lint: NONE. The lints and lint groups to deny, like passing them to `--deny` before the arguments of the command line.
+    (deny: Vec<String> = Vec::new()),
+    /// Lint: NONE. The lints and lint groups to forbid, like passing them to `--forbid` before the arguments of the command line.
+    (forbid: Vec<String> = Vec::new()),
 }
 
-/// Search for the configuration file.
-pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
+/// The directory in which the lookup of the configuration files starts: `CLIPPY_CONF_DIR`, or
+/// failing that, `CARGO_MANIFEST_DIR`. If neither of those exist, e.g. if `clippy-driver` is run
+/// on a single file, the directory of `source_file`, or ".".
+pub fn conf_dir(source_file: Option<&Path>) -> PathBuf {
+    let dir = env::var_os("CLIPPY_CONF_DIR")
+        .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
+        .map(PathBuf::from)
+        .or_else(|| {
+            source_file
+                .and_then(Path::parent)
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| PathBuf::from("."));
+    dir.canonicalize().unwrap_or(dir)
+}
+
+/// Search for the configuration files in `dir` and its parent directories and read them, the
+/// nearest file first.
+///
+/// The search ends before `stop_at`, at a file which sets `inherit-config = false`, or at the first
+/// file found if `inherit` is `false`.
+pub fn lookup_conf_files(dir: &Path, stop_at: Option<&Path>, inherit: bool) -> io::Result<Vec<(PathBuf, String)>> {
     /// Possible filename to search for.
     const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
 
-    // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
-    // If neither of those exist, use ".".
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
-        .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
-        .map_or_else(|| PathBuf::from("."), PathBuf::from);
-    loop {
+    let mut files = Vec::new();
+    for current in dir.ancestors() {
+        if Some(current) == stop_at {
+            break;
+        }
+
         for config_file_name in &CONFIG_FILE_NAMES {
-            if let Ok(config_file) = current.join(config_file_name).canonicalize() {
-                match fs::metadata(&config_file) {
-                    Err(e) if e.kind() == io::ErrorKind::NotFound => {},
-                    Err(e) => return Err(e),
-                    Ok(md) if md.is_dir() => {},
-                    Ok(_) => return Ok(Some(config_file)),
-                }
+            let config_file = current.join(config_file_name);
+            let content = match fs::read_to_string(&config_file) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(_) if config_file.is_dir() => continue,
+                Err(e) => return Err(e),
+                Ok(content) => content,
+            };
+            let inherits = inherits(&content);
+            files.push((config_file, content));
+            if !inherit || !inherits {
+                return Ok(files);
             }
+            // Only the first file name is read in each directory
+            break;
         }
+    }
+    Ok(files)
+}
+
+/// Whether the configuration `content` inherits the keys of the files in the parent directories.
+pub fn inherits(content: &str) -> bool {
+    toml::from_str::<toml::Value>(content)
+        .ok()
+        .and_then(|value| value.get("inherit-config")?.as_bool())
+        .unwrap_or(true)
+}
 
-        // If the current directory has no parent, we're done searching.
-        if !current.pop() {
-            return Ok(None);
+/// Reads the configuration files of the crate in `dir`, see `lookup_conf_files`. `resolved` is the
+/// configuration `cargo clippy` read for the whole workspace, and the directory it was read in. If
+/// `dir` is below that directory, only the files up to it are read, on top of `resolved`.
+pub fn read_conf_files(dir: &Path, resolved: Option<(&str, &Path)>, inherit: bool) -> (Conf, Vec<String>) {
+    let resolved = resolved.filter(|&(_, root)| dir.starts_with(root));
+    let files = match lookup_conf_files(dir, resolved.map(|(_, root)| root), inherit) {
+        Ok(files) => files,
+        Err(error) => {
+            return (
+                Conf::default(),
+                vec![format!("error finding Clippy's configuration file: {}", error)],
+            );
+        },
+    };
+
+    let mut layers = LayeredConf::default();
+    let mut errors = Vec::new();
+    if let Some((serialized, _)) = resolved {
+        // The outermost file found below the root may not inherit the resolved configuration
+        let inherits = files.last().map_or(true, |(_, content)| inherit && inherits(content));
+        if inherits {
+            errors.extend(
+                layers
+                    .add(serialized)
+                    .into_iter()
+                    .map(|error| format!("error reading Clippy's configuration from `CLIPPY_CONF`: {}", error)),
+            );
         }
     }
+    for (file_name, content) in files.iter().rev() {
+        errors.extend(layers.add(content).into_iter().map(|error| {
+            format!(
+                "error reading Clippy's configuration file `{}`: {}",
+                file_name.display(),
+                error
+            )
+        }));
+    }
+    (layers.into_conf(), errors)
 }
 
 /// Read the `toml` configuration file.
@@ -253,5 +377,109 @@ pub fn read(path: &Path) -> TryConf {
         Err(e) => return TryConf::from_error(e),
         Ok(content) => content,
     };
-    toml::from_str(&content).unwrap_or_else(TryConf::from_error)
+    read_str(&content)
+}
+
+/// Read a configuration from a `toml` string.
+///
+/// In case of error, the function tries to continue as much as possible.
+pub fn read_str(content: &str) -> TryConf {
+    toml::from_str(content).unwrap_or_else(TryConf::from_error)
+}
+
+/// A configuration read from several `toml` sources, the keys of each source overriding those of
+/// the sources added before.
+#[derive(Default)]
+pub struct LayeredConf {
+    table: toml::value::Table,
+}
+
+impl LayeredConf {
+    /// Adds a source on top of the previous ones, and returns the errors found in it.
+    pub fn add(&mut self, content: &str) -> Vec<String> {
+        // Check each source on its own, so that the errors can be attributed to it
+        let TryConf { errors, .. } = read_str(content);
+        if let Ok(toml::Value::Table(table)) = toml::from_str(content) {
+            self.table.extend(table);
+        }
+        errors
+    }
+
+    pub fn into_conf(self) -> Conf {
+        // The errors were already reported by `add`
+        read_str(&toml::Value::Table(self.table).to_string()).conf
+    }
+}
+
+/// Serialize a configuration to `toml`, so that it can be read again with `read_str`.
+pub fn to_string(conf: &Conf) -> Result<String, toml::ser::Error> {
+    // Going through a `Value` makes sure that tables are written after all other values
+    toml::Value::try_from(conf).map(|value| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_conf_files;
+    use std::path::{Path, PathBuf};
+    use std::{env, fs, process};
+
+    /// Creates a workspace with a `clippy.toml` in its root and in its `member` directory. The root
+    /// file has to set `inherit-config = false`, so that the files above the temporary directory
+    /// aren't read.
+    fn workspace(name: &str, root_conf: &str, member_conf: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("clippy-conf-{}-{}", name, process::id()));
+        fs::create_dir_all(root.join("member")).unwrap();
+        fs::write(root.join("clippy.toml"), root_conf).unwrap();
+        fs::write(root.join("member").join("clippy.toml"), member_conf).unwrap();
+        root
+    }
+
+    /// Returns the `too-many-arguments-threshold` and `cognitive-complexity-threshold` read in
+    /// `dir`.
+    fn thresholds(dir: &Path, resolved: Option<(&str, &Path)>, inherit: bool) -> (u64, u64) {
+        let (conf, errors) = read_conf_files(dir, resolved, inherit);
+        assert!(errors.is_empty(), "{:?}", errors);
+        (conf.too_many_arguments_threshold, conf.cognitive_complexity_threshold)
+    }
+
+    const ROOT: &str =
+        "inherit-config = false\ntoo-many-arguments-threshold = 3\ncognitive-complexity-threshold = 10\n";
+
+    #[test]
+    fn inherit_parent_keys() {
+        let root = workspace("inherit", ROOT, "too-many-arguments-threshold = 5\n");
+        assert_eq!(thresholds(&root.join("member"), None, true), (5, 10));
+        assert_eq!(thresholds(&root, None, true), (3, 10));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn inherit_config_false() {
+        let root = workspace(
+            "no-inherit-key",
+            ROOT,
+            "inherit-config = false\ntoo-many-arguments-threshold = 5\n",
+        );
+        assert_eq!(thresholds(&root.join("member"), None, true), (5, 25));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn no_inherit_config_flag() {
+        let root = workspace("no-inherit-flag", ROOT, "too-many-arguments-threshold = 5\n");
+        assert_eq!(thresholds(&root.join("member"), None, false), (5, 25));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn conf_root() {
+        let root = workspace("conf-root", ROOT, "too-many-arguments-threshold = 5\n");
+        let resolved = "cognitive-complexity-threshold = 20\n";
+        // The files up to the root are read on top of the resolved configuration, the root file
+        // itself is part of it
+        assert_eq!(thresholds(&root.join("member"), Some((resolved, &root)), true), (5, 20));
+        // The resolved configuration doesn't apply outside of its root
+        assert_eq!(thresholds(&root, Some((resolved, &root.join("member"))), true), (3, 10));
+        fs::remove_dir_all(root).unwrap();
+    }
 }
@@ -3,8 +3,8 @@ use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sug
 use clippy_utils::source::snippet;
 use clippy_utils::ty::match_type;
 use clippy_utils::{
-    is_else_clause, is_expn_of, is_expr_path_def_path, is_lint_allowed, match_def_path, method_calls, path_to_res,
-    paths, SpanlessEq,
+    fn_def_id, get_def_path, is_else_clause, is_expn_of, is_expr_path_def_path, is_lint_allowed, match_def_path,
+    method_calls, path_to_res, paths, SpanlessEq,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::{Crate as AstCrate, ItemKind, LitKind, ModKind, NodeId};
@@ -312,6 +312,31 @@ declare_clippy_lint! {
     "non-idiomatic `if_chain!` usage"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths written as arrays of string literals in calls to
+    /// `clippy_utils` functions.
+    ///
+    /// **Why is this bad?** Paths in `clippy_utils::paths` can be reused and are checked by
+    /// `invalid_paths`, so a typo or a path that moved in `std` is noticed.
+    ///
+    /// **Known problems:** Paths into `clippy_utils` itself are not linted, as they can't be
+    /// resolved from `clippy_utils::paths`.
+    ///
+    /// **Example:**
+    /// Bad:
+    /// ```rust,ignore
+    /// match_def_path(cx, def_id, &["core", "option", "Option"])
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// match_def_path(cx, def_id, &paths::OPTION)
+    /// ```
+    pub DEF_PATH_LITERAL,
+    internal,
+    "path written as string literals instead of a `clippy_utils::paths` constant"
+}
+
 declare_lint_pass!(ClippyLintsInternal => [CLIPPY_LINTS_INTERNAL]);
 
 impl EarlyLintPass for ClippyLintsInternal {
@@ -851,7 +876,7 @@ pub fn check_path(cx: &LateContext<'_>, path: &[&str]) -> bool {
     let path_syms: Vec<_> = path.iter().map(|p| Symbol::intern(p)).collect();
     let lang_items = cx.tcx.lang_items();
     for item_def_id in lang_items.items().iter().flatten() {
-        let lang_item_path = cx.get_def_path(*item_def_id);
+        let lang_item_path = get_def_path(cx, *item_def_id);
         if path_syms.starts_with(&lang_item_path) {
             if let [item] = &path_syms[lang_item_path.len()..] {
                 for child in cx.tcx.item_children(*item_def_id) {
@@ -1233,3 +1258,103 @@ fn if_chain_local_span(cx: &LateContext<'_>, local: &Local<'_>, if_chain_span: S
     let span = sm.span_extend_to_next_char(span, ';', false);
     Span::new(span.lo() - BytePos(3), span.hi() + BytePos(1), span.ctxt())
 }
+
+/// The `clippy_utils` functions taking the path to an item.
+const DEF_PATH_FUNCTIONS: [&str; 10] = [
+    "def_path_res",
+    "get_trait_def_id",
+    "intern_def_path",
+    "is_expr_path_def_path",
+    "is_qpath_def_path",
+    "match_def_path",
+    "match_function_call",
+    "match_trait_method",
+    "match_type",
+    "path_to_res",
+];
+
+declare_lint_pass!(DefPathLiteral => [DEF_PATH_LITERAL]);
+
+impl<'tcx> LateLintPass<'tcx> for DefPathLiteral {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+        if is_lint_allowed(cx, DEF_PATH_LITERAL, expr.hir_id) {
+            return;
+        }
+
+        if_chain! {
+            if let ExprKind::Call(_, args) = expr.kind;
+            if let Some(fn_def_id) = fn_def_id(cx, expr);
+            if cx.tcx.crate_name(fn_def_id.krate).as_str() == "clippy_utils";
+            if DEF_PATH_FUNCTIONS.contains(&&*cx.tcx.item_name(fn_def_id).as_str());
+            then {
+                for arg in args {
+                    if let Some(segments) = literal_def_path(arg) {
+                        check_def_path_literal(cx, fn_def_id, arg, &segments);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the segments of `expr` if it is a reference to an array of at least two string
+/// literals, e.g. `&["core", "option", "Option"]`.
+fn literal_def_path(expr: &hir::Expr<'_>) -> Option<Vec<SymbolStr>> {
+    if_chain! {
+        if let ExprKind::AddrOf(_, _, array) = expr.kind;
+        if let ExprKind::Array(exprs) = array.kind;
+        if exprs.len() > 1;
+        then {
+            exprs
+                .iter()
+                .map(|expr| match expr.kind {
+                    ExprKind::Lit(ref lit) => match lit.node {
+                        LitKind::Str(sym, _) => Some(sym.as_str()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect()
+        } else {
+            None
+        }
+    }
+}
+
+fn check_def_path_literal(cx: &LateContext<'_>, fn_def_id: DefId, arg: &hir::Expr<'_>, segments: &[SymbolStr]) {
+    // Paths into `clippy_utils` can't be resolved from `clippy_utils::paths`
+    if &*segments[0] == "clippy_utils" {
+        return;
+    }
+
+    let segments: Vec<&str> = segments.iter().map(|sym| &**sym).collect();
+    let diag_item = path_to_res(cx, &segments).opt_def_id().and_then(|def_id| {
+        cx.tcx
+            .diagnostic_items(def_id.krate)
+            .iter()
+            .find_map(|(name, &id)| if id == def_id { Some(*name) } else { None })
+    });
+    match diag_item {
+        // `match_type_on_diagnostic_item` already suggests using the diagnostic item
+        Some(_) if match_def_path(cx, fn_def_id, &["clippy_utils", "ty", "match_type"]) => {},
+        Some(name) => span_lint_and_help(
+            cx,
+            DEF_PATH_LITERAL,
+            arg.span,
+            "path written as string literals",
+            None,
+            &format!(
+                "the path is the diagnostic item `sym::{}`, consider using it instead",
+                name
+            ),
+        ),
+        None => span_lint_and_help(
+            cx,
+            DEF_PATH_LITERAL,
+            arg.span,
+            "path written as string literals",
+            None,
+            "consider adding a constant to `clippy_utils::paths`, or using an existing one",
+        ),
+    }
+}
@@ -27,8 +27,8 @@ use std::path::Path;
 
 use crate::utils::internal_lints::is_lint_ref_type;
 use clippy_utils::{
-    diagnostics::span_lint, last_path_segment, match_def_path, match_function_call, match_path, paths, ty::match_type,
-    ty::walk_ptrs_ty_depth,
+    attrs::get_attr, diagnostics::span_lint, last_path_segment, match_def_path, match_function_call, match_path, paths,
+    ty::match_type, ty::walk_ptrs_ty_depth,
 };
 
 /// This is the output file of the lint collector.
@@ -86,12 +86,13 @@ macro_rules! CONFIGURATION_VALUE_TEMPLATE {
     };
 }
 
-const LINT_EMISSION_FUNCTIONS: [&[&str]; 7] = [
+const LINT_EMISSION_FUNCTIONS: [&[&str]; 8] = [
     &["clippy_utils", "diagnostics", "span_lint"],
     &["clippy_utils", "diagnostics", "span_lint_and_help"],
     &["clippy_utils", "diagnostics", "span_lint_and_note"],
     &["clippy_utils", "diagnostics", "span_lint_hir"],
     &["clippy_utils", "diagnostics", "span_lint_and_sugg"],
+    &["clippy_utils", "diagnostics", "span_lint_and_sugg_multipart"],
     &["clippy_utils", "diagnostics", "span_lint_and_then"],
     &["clippy_utils", "diagnostics", "span_lint_hir_and_then"],
 ];
@@ -110,6 +111,7 @@ const SUGGESTION_FUNCTIONS: [&[&str]; 2] = [
     &["clippy_utils", "diagnostics", "multispan_sugg"],
     &["clippy_utils", "diagnostics", "multispan_sugg_with_applicability"],
 ];
+const SUGGESTION_BUILDER_TYPE: [&str; 3] = ["clippy_utils", "diagnostics", "SuggestionBuilder"];
 const DEPRECATED_LINT_TYPE: [&str; 3] = ["clippy_lints", "deprecated_lints", "ClippyDeprecatedLint"];
 
 /// The index of the applicability name of `paths::APPLICABILITY_VALUES`
@@ -136,7 +138,8 @@ declare_clippy_lint! {
     ///         "line": 1
     ///     },
     ///     "group": "clippy::internal",
-    ///     "docs": " **What it does:** Collects metadata about clippy lints for the website. [...] "
+    ///     "docs": " **What it does:** Collects metadata about clippy lints for the website. [...] ",
+    ///     "config": []
     /// }
     /// ```
     pub INTERNAL_METADATA_COLLECTOR,
@@ -174,6 +177,14 @@ impl MetadataCollector {
             .reduce(|acc, x| acc + &x)
             .map(|configurations| format!(CONFIGURATION_SECTION_TEMPLATE!(), configurations = configurations))
     }
+
+    fn get_lint_config_names(&self, lint_name: &str) -> Vec<String> {
+        self.config
+            .iter()
+            .filter(|config| config.lints.iter().any(|lint| lint == lint_name))
+            .map(|config| config.name.clone())
+            .collect()
+    }
 }
 
 impl Drop for MetadataCollector {
@@ -209,20 +220,35 @@ struct LintMetadata {
     id_span: SerializableSpan,
     group: String,
     level: &'static str,
+    /// The Rust version the lint was added in, from its `#[clippy::version]` attribute. This is
+    /// `None` for deprecated lints.
+    version: Option<String>,
     docs: String,
+    /// The names of the configuration values of this lint, as used in `clippy.toml`
+    config: Vec<String>,
     /// This field is only used in the output and will only be
     /// mapped shortly before the actual output.
     applicability: Option<ApplicabilityInfo>,
 }
 
 impl LintMetadata {
-    fn new(id: String, id_span: SerializableSpan, group: String, level: &'static str, docs: String) -> Self {
+    fn new(
+        id: String,
+        id_span: SerializableSpan,
+        group: String,
+        level: &'static str,
+        version: Option<String>,
+        docs: String,
+        config: Vec<String>,
+    ) -> Self {
         Self {
             id,
             id_span,
             group,
             level,
+            version,
             docs,
+            config,
             applicability: None,
         }
     }
@@ -375,6 +401,7 @@ impl<'hir> LateLintPass<'hir> for MetadataCollector {
     /// ```rust, ignore
     /// declare_clippy_lint! {
     ///     /// **What it does:** Something IDK.
+    ///     #[clippy::version = "1.55.0"]
     ///     pub SOME_LINT,
     ///     internal,
     ///     "Who am I?"
@@ -393,16 +420,20 @@ impl<'hir> LateLintPass<'hir> for MetadataCollector {
                 if let Some((group, level)) = get_lint_group_and_level_or_lint(cx, &lint_name, item);
                 if let Some(mut docs) = extract_attr_docs_or_lint(cx, item);
                 then {
+                    let version = extract_clippy_version_value_or_lint(cx, item);
                     if let Some(configuration_section) = self.get_lint_configs(&lint_name) {
                         docs.push_str(&configuration_section);
                     }
 
+                    let config = self.get_lint_config_names(&lint_name);
                     self.lints.push(LintMetadata::new(
                         lint_name,
                         SerializableSpan::from_item(cx, item),
                         group,
                         level,
+                        version,
                         docs,
+                        config,
                     ));
                 }
             }
@@ -420,7 +451,9 @@ impl<'hir> LateLintPass<'hir> for MetadataCollector {
                         SerializableSpan::from_item(cx, item),
                         DEPRECATED_LINT_GROUP_STR.to_string(),
                         DEPRECATED_LINT_LEVEL,
+                        None,
                         docs,
+                        Vec::new(),
                     ));
                 }
             }
@@ -515,6 +548,17 @@ fn extract_attr_docs(cx: &LateContext<'_>, item: &Item<'_>) -> Option<String> {
     Some(docs)
 }
 
+fn extract_clippy_version_value_or_lint(cx: &LateContext<'_>, item: &Item<'_>) -> Option<String> {
+    let attrs = cx.tcx.hir().attrs(item.hir_id());
+    let version = get_attr(cx.sess(), attrs, "version")
+        .find_map(ast::Attribute::value_str)
+        .map(|version| version.to_string());
+    if version.is_none() {
+        lint_collection_error_item(cx, item, "could not find the `#[clippy::version]` attribute");
+    }
+    version
+}
+
 fn get_lint_group_and_level_or_lint(
     cx: &LateContext<'_>,
     lint_name: &str,
@@ -624,6 +668,13 @@ fn extract_emission_info<'hir>(
             lints.append(&mut resolved_lints);
         } else if match_type(cx, arg_ty, &paths::APPLICABILITY) {
             applicability = resolve_applicability(cx, arg);
+        } else if match_type(cx, arg_ty, &SUGGESTION_BUILDER_TYPE) {
+            // The applicability is passed to `SuggestionBuilder::new`
+            multi_part = true;
+            applicability = match get_parent_local(cx, arg).and_then(|local| local.init) {
+                Some(init) => resolve_applicability(cx, init),
+                None => resolve_applicability(cx, arg),
+            };
         } else if arg_ty.is_closure() {
             multi_part |= check_is_multi_part(cx, arg);
             // TODO xFrednet 2021-03-01: don't use or_else but rather a comparison
@@ -3,3 +3,4 @@ pub mod conf;
 pub mod inspector;
 #[cfg(any(feature = "internal-lints", feature = "metadata-collector-lint"))]
 pub mod internal_lints;
+pub mod timings;
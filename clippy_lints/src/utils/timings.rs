@@ -0,0 +1,159 @@
+//! Records the time spent in each late lint pass and the number of emitted diagnostics of each
+//! lint if `CLIPPY_TIME_LINTS=1` (or `CLIPPY_TIME_LINTS=json`) is set, and prints a report once the
+//! crate has been linted.
+
+use clippy_utils::diagnostics::{count_emitted_lints, take_emitted_lints};
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintStore};
+use rustc_session::lint::LintPass;
+use rustc_session::Session;
+use rustc_span::Span;
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Wraps the late passes registered from `first_pass` on, so that the time spent in each of them
+/// is recorded, if `CLIPPY_TIME_LINTS` is set to `1` or `json`.
+pub fn time_late_passes(store: &mut LintStore, sess: &Session, first_pass: usize) {
+    let format = match env::var("CLIPPY_TIME_LINTS").as_deref() {
+        Ok("1") => ReportFormat::Text,
+        Ok("json") => ReportFormat::Json,
+        _ => return,
+    };
+
+    count_emitted_lints();
+    let timings = Arc::new(Timings {
+        crate_name: sess.opts.crate_name.clone().unwrap_or_default(),
+        format,
+        passes: Mutex::default(),
+    });
+    for pass_factory in store.late_passes.split_off(first_pass) {
+        let timings = Arc::clone(&timings);
+        store.register_late_pass(move || {
+            box TimedPass {
+                pass: pass_factory(),
+                elapsed: Duration::ZERO,
+                timings: Arc::clone(&timings),
+            }
+        });
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ReportFormat {
+    /// Human readable tables
+    Text,
+    /// A single line of JSON
+    Json,
+}
+
+/// The total time spent in each lint pass of the crate. The report is printed when the last pass
+/// and pass factory are dropped.
+struct Timings {
+    crate_name: String,
+    format: ReportFormat,
+    passes: Mutex<FxHashMap<&'static str, Duration>>,
+}
+
+impl Drop for Timings {
+    fn drop(&mut self) {
+        let passes = self.passes.get_mut().unwrap();
+        if passes.is_empty() {
+            return;
+        }
+
+        let mut passes: Vec<_> = passes.drain().collect();
+        passes.sort_by(|(name1, time1), (name2, time2)| time2.cmp(time1).then_with(|| name1.cmp(name2)));
+        let total: Duration = passes.iter().map(|&(_, time)| time).sum();
+
+        let mut lints: Vec<_> = take_emitted_lints()
+            .into_iter()
+            .map(|(name, count)| (name.to_ascii_lowercase(), count))
+            .collect();
+        lints.sort_by(|(name1, count1), (name2, count2)| count2.cmp(count1).then_with(|| name1.cmp(name2)));
+
+        match self.format {
+            ReportFormat::Text => {
+                eprintln!(
+                    "Clippy lint pass timings for crate `{}` (total {:.3}s):",
+                    self.crate_name,
+                    total.as_secs_f64()
+                );
+                for (name, time) in passes {
+                    eprintln!("{:>12.3}ms  {}", time.as_secs_f64() * 1000.0, name);
+                }
+                let total_count: usize = lints.iter().map(|&(_, count)| count).sum();
+                eprintln!(
+                    "Clippy lints emitted in crate `{}` (total {}):",
+                    self.crate_name, total_count
+                );
+                for (name, count) in lints {
+                    eprintln!("{:>12}  {}", count, name);
+                }
+            },
+            ReportFormat::Json => {
+                // All names are identifiers, so they don't need to be escaped
+                let passes = passes
+                    .iter()
+                    .map(|(name, time)| format!(r#"{{"name":"{}","ms":{:.3}}}"#, name, time.as_secs_f64() * 1000.0))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let lints = lints
+                    .iter()
+                    .map(|(name, count)| format!(r#"{{"name":"{}","count":{}}}"#, name, count))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                eprintln!(
+                    r#"{{"crate":"{}","total_ms":{:.3},"passes":[{}],"lints":[{}]}}"#,
+                    self.crate_name,
+                    total.as_secs_f64() * 1000.0,
+                    passes,
+                    lints
+                );
+            },
+        }
+    }
+}
+
+struct TimedPass {
+    pass: Box<dyn for<'tcx> LateLintPass<'tcx> + Send + Sync>,
+    elapsed: Duration,
+    timings: Arc<Timings>,
+}
+
+impl Drop for TimedPass {
+    fn drop(&mut self) {
+        // Passes that were skipped never ran
+        if !self.elapsed.is_zero() {
+            *self
+                .timings
+                .passes
+                .lock()
+                .unwrap()
+                .entry(self.pass.name())
+                .or_default() += self.elapsed;
+        }
+    }
+}
+
+impl LintPass for TimedPass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! timed_late_lint_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        impl<$hir> LateLintPass<$hir> for TimedPass {
+            $(fn $name(&mut self, cx: &LateContext<$hir>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.elapsed += start.elapsed();
+            })*
+        }
+    };
+}
+
+rustc_lint::late_lint_methods!(timed_late_lint_methods, [], ['tcx]);
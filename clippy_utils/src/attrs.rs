@@ -23,6 +23,7 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ),
     ("dump", DeprecationStatus::None),
     ("msrv", DeprecationStatus::None),
+    ("version", DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
@@ -8,13 +8,69 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, DiagnosticBuilder};
-use rustc_hir::HirId;
+use rustc_hir::{HirId, Node, CRATE_HIR_ID};
 use rustc_lint::{LateContext, Lint, LintContext};
 use rustc_span::source_map::{MultiSpan, Span};
+use std::cell::RefCell;
 use std::env;
 
+thread_local! {
+    /// The number of emitted diagnostics of each lint, `None` unless `count_emitted_lints` was
+    /// called.
+    static EMITTED_LINTS: RefCell<Option<FxHashMap<&'static str, usize>>> = RefCell::new(None);
+}
+
+/// Starts counting the diagnostics emitted by the functions of this module on the current thread.
+pub fn count_emitted_lints() {
+    EMITTED_LINTS.with(|emitted| *emitted.borrow_mut() = Some(FxHashMap::default()));
+}
+
+/// Returns the number of emitted diagnostics of each lint, keyed by the lint name, since
+/// `count_emitted_lints` was called, and resets the counts.
+pub fn take_emitted_lints() -> FxHashMap<&'static str, usize> {
+    EMITTED_LINTS.with(|emitted| emitted.borrow_mut().as_mut().map(std::mem::take).unwrap_or_default())
+}
+
+/// Adds a note to `diag` pointing at the item which is or encloses `hir_id`, where an
+/// `#[allow]` attribute for `lint` can be added. IDEs can read the location from the JSON output.
+/// Nothing is added for the crate root and for items from macro expansions.
+///
+/// This is meant for lints which are usually allowed for single items, like some restriction
+/// lints. The note points at the innermost item, e.g. at the function enclosing a closure, or
+/// at the method instead of its `impl` block.
+///
+/// # Example
+///
+/// ```ignore
+/// span_lint_hir_and_then(cx, LINT, expr.hir_id, expr.span, msg, |diag| {
+///     note_allow_location(cx, diag, LINT, expr.hir_id);
+/// });
+/// ```
+pub fn note_allow_location(cx: &LateContext<'_>, diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint, hir_id: HirId) {
+    let hir = cx.tcx.hir();
+    let item_id = match hir.find(hir_id) {
+        Some(Node::Item(_) | Node::TraitItem(_) | Node::ImplItem(_) | Node::ForeignItem(_)) => hir_id,
+        _ => hir.get_parent_item(hir_id),
+    };
+    let span = hir.span(item_id);
+    if item_id != CRATE_HIR_ID && !span.from_expansion() {
+        diag.span_note(
+            span.shrink_to_lo(),
+            &format!("`#[allow({})]` can be added to this item", lint.name_lower()),
+        );
+    }
+}
+
 fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
+    // Every emission function calls this, and only if the lint isn't allowed
+    EMITTED_LINTS.with(|emitted| {
+        if let Some(emitted) = emitted.borrow_mut().as_mut() {
+            *emitted.entry(lint.name).or_default() += 1;
+        }
+    });
+
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
             diag.help(&format!(
@@ -213,6 +269,125 @@ pub fn span_lint_and_sugg<'a, T: LintContext>(
     });
 }
 
+/// Add a span lint with a suggestion that replaces several spans at once.
+///
+/// Use a `SuggestionBuilder` to collect the replacements. The applicability of the suggestion is
+/// the one of the builder, which is downgraded if any of the replaced spans comes from a macro.
+///
+/// # Example
+///
+/// ```ignore
+/// error: called `map(<f>).unwrap_or(<a>)` on an `Option` value. This can be done more directly by calling `map_or(<a>, <f>)` instead
+///   --> $DIR/map_unwrap_or.rs:16:13
+///    |
+/// 16 |       let _ = opt.map(|x| x + 1)
+///    |  _____________^
+/// 17 | |         // Should lint even though this call is on a separate line.
+/// 18 | |         .unwrap_or(0);
+///    | |_____________________^
+///    |
+/// help: use `map_or(<a>, <f>)` instead
+///    |
+/// 16 |     let _ = opt.map_or(0, |x| x + 1);
+///    |                 ^^^^^^ ^^          --
+/// ```
+pub fn span_lint_and_sugg_multipart<'a, T: LintContext>(
+    cx: &'a T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: &str,
+    help: &str,
+    sugg: SuggestionBuilder,
+) {
+    span_lint_and_then(cx, lint, sp, msg, |diag| {
+        sugg.add_to(diag, help);
+    });
+}
+
+/// Collects the `span → replacement` parts of a suggestion that changes several places at once.
+///
+/// Prefer this over building a single replacement for a large span from snippets. Only the parts
+/// that actually change are replaced, so comments and macro calls in between stay untouched.
+///
+/// If a replaced span comes from a macro expansion, the applicability is downgraded to
+/// `MaybeIncorrect`, since rustfix would have to change the macro call instead.
+#[derive(Debug)]
+pub struct SuggestionBuilder {
+    parts: Vec<(Span, String)>,
+    applicability: Applicability,
+}
+
+impl SuggestionBuilder {
+    #[must_use]
+    pub fn new(applicability: Applicability) -> Self {
+        Self {
+            parts: Vec::new(),
+            applicability,
+        }
+    }
+
+    /// Replaces the code at `span` with `replacement`.
+    pub fn replace(&mut self, span: Span, replacement: impl Into<String>) -> &mut Self {
+        if span.from_expansion() && self.applicability == Applicability::MachineApplicable {
+            self.applicability = Applicability::MaybeIncorrect;
+        }
+        self.parts.push((span, replacement.into()));
+        self
+    }
+
+    /// Removes the code at `span`.
+    pub fn remove(&mut self, span: Span) -> &mut Self {
+        self.replace(span, String::new())
+    }
+
+    /// Inserts `text` directly before `span`.
+    pub fn insert_before(&mut self, span: Span, text: impl Into<String>) -> &mut Self {
+        self.replace(span.shrink_to_lo(), text)
+    }
+
+    /// Inserts `text` directly after `span`.
+    pub fn insert_after(&mut self, span: Span, text: impl Into<String>) -> &mut Self {
+        self.replace(span.shrink_to_hi(), text)
+    }
+
+    /// Lowers the applicability of the suggestion to `applicability`, if it is currently higher.
+    ///
+    /// This is useful if the replacements are built from snippets with
+    /// `snippet_with_applicability`.
+    pub fn downgrade_applicability(&mut self, applicability: Applicability) -> &mut Self {
+        if applicability_rank(applicability) < applicability_rank(self.applicability) {
+            self.applicability = applicability;
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn applicability(&self) -> Applicability {
+        self.applicability
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Adds the suggestion to `diag` with the `help` message.
+    pub fn add_to(self, diag: &mut DiagnosticBuilder<'_>, help: &str) {
+        if !self.parts.is_empty() {
+            diag.multipart_suggestion(help, self.parts, self.applicability);
+        }
+    }
+}
+
+fn applicability_rank(applicability: Applicability) -> u8 {
+    match applicability {
+        Applicability::Unspecified => 0,
+        Applicability::HasPlaceholders => 1,
+        Applicability::MaybeIncorrect => 2,
+        Applicability::MachineApplicable => 3,
+    }
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
@@ -3,6 +3,7 @@
 //! Things to consider:
 //!  - has the expression side-effects?
 //!  - is the expression computationally expensive?
+//!  - can the expression be moved into a closure at all?
 //!
 //! See lints:
 //!  - unnecessary-lazy-evaluations
@@ -12,125 +13,138 @@
 use crate::is_ctor_or_promotable_const_function;
 use crate::ty::is_type_diagnostic_item;
 use rustc_hir::def::{DefKind, Res};
-
-use rustc_hir::intravisit;
-use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
-
-use rustc_hir::{Block, Expr, ExprKind, Path, QPath};
+use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind};
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
 use rustc_span::sym;
+use std::ops;
 
-/// Is the expr pure (is it free from side-effects)?
-/// This function is named so to stress that it isn't exhaustive and returns FNs.
-fn identify_some_pure_patterns(expr: &Expr<'_>) -> bool {
-    match expr.kind {
-        ExprKind::Lit(..) | ExprKind::ConstBlock(..) | ExprKind::Path(..) | ExprKind::Field(..) => true,
-        ExprKind::AddrOf(_, _, addr_of_expr) => identify_some_pure_patterns(addr_of_expr),
-        ExprKind::Tup(tup_exprs) => tup_exprs.iter().all(|expr| identify_some_pure_patterns(expr)),
-        ExprKind::Struct(_, fields, expr) => {
-            fields.iter().all(|f| identify_some_pure_patterns(f.expr))
-                && expr.map_or(true, |e| identify_some_pure_patterns(e))
-        },
-        ExprKind::Call(
-            &Expr {
-                kind:
-                    ExprKind::Path(QPath::Resolved(
-                        _,
-                        Path {
-                            res: Res::Def(DefKind::Ctor(..) | DefKind::Variant, ..),
-                            ..
-                        },
-                    )),
-                ..
-            },
-            args,
-        ) => args.iter().all(|expr| identify_some_pure_patterns(expr)),
-        ExprKind::Block(
-            &Block {
-                stmts,
-                expr: Some(expr),
-                ..
-            },
-            _,
-        ) => stmts.is_empty() && identify_some_pure_patterns(expr),
-        ExprKind::Box(..)
-        | ExprKind::Array(..)
-        | ExprKind::Call(..)
-        | ExprKind::MethodCall(..)
-        | ExprKind::Binary(..)
-        | ExprKind::Unary(..)
-        | ExprKind::Cast(..)
-        | ExprKind::Type(..)
-        | ExprKind::DropTemps(..)
-        | ExprKind::Loop(..)
-        | ExprKind::If(..)
-        | ExprKind::Match(..)
-        | ExprKind::Closure(..)
-        | ExprKind::Block(..)
-        | ExprKind::Assign(..)
-        | ExprKind::AssignOp(..)
-        | ExprKind::Index(..)
-        | ExprKind::Break(..)
-        | ExprKind::Continue(..)
-        | ExprKind::Ret(..)
-        | ExprKind::InlineAsm(..)
-        | ExprKind::LlvmInlineAsm(..)
-        | ExprKind::Repeat(..)
-        | ExprKind::Yield(..)
-        | ExprKind::Err => false,
+/// The cost and effects of evaluating an expression, from the perspective of moving it into or
+/// out of a closure.
+///
+/// The variants are ordered, a compound expression has the greatest classification of its
+/// subexpressions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EagernessSuggestion {
+    /// The expression is cheap and free of side effects, like literals, paths and constructors,
+    /// so it can be evaluated eagerly.
+    Eager,
+    /// The expression may be cheap, but evaluating it eagerly could change the behavior of the
+    /// program, e.g. because it can panic. Suggest neither variant.
+    NoChange,
+    /// The expression calls functions or methods, or allocates, so it should be evaluated lazily.
+    Lazy,
+    /// The expression can't be moved into a closure, because it contains control flow like
+    /// `return` or `?`.
+    ForceNoChange,
+}
+
+impl ops::BitOr for EagernessSuggestion {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.max(rhs)
     }
 }
 
-/// Identify some potentially computationally expensive patterns.
-/// This function is named so to stress that its implementation is non-exhaustive.
-/// It returns FNs and FPs.
-fn identify_some_potentially_expensive_patterns<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
-    // Searches an expression for method calls or function calls that aren't ctors
-    struct FunCallFinder<'a, 'tcx> {
-        cx: &'a LateContext<'tcx>,
-        found: bool,
+impl ops::BitOrAssign for EagernessSuggestion {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
     }
+}
 
-    impl<'a, 'tcx> intravisit::Visitor<'tcx> for FunCallFinder<'a, 'tcx> {
-        type Map = Map<'tcx>;
+struct EagernessVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    eagerness: EagernessSuggestion,
+}
 
-        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
-            let call_found = match &expr.kind {
-                // ignore enum and struct constructors
-                ExprKind::Call(..) => !is_ctor_or_promotable_const_function(self.cx, expr),
-                ExprKind::Index(obj, _) => {
-                    let ty = self.cx.typeck_results().expr_ty(obj);
-                    is_type_diagnostic_item(self.cx, ty, sym::hashmap_type)
-                        || is_type_diagnostic_item(self.cx, ty, sym::BTreeMap)
-                },
-                ExprKind::MethodCall(..) => true,
-                _ => false,
-            };
+impl<'a, 'tcx> Visitor<'tcx> for EagernessVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
 
-            if call_found {
-                self.found |= true;
-            }
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        use EagernessSuggestion::{ForceNoChange, Lazy, NoChange};
 
-            if !self.found {
-                intravisit::walk_expr(self, expr);
-            }
+        if self.eagerness == ForceNoChange {
+            return;
         }
-
-        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
-            NestedVisitorMap::None
+        match expr.kind {
+            ExprKind::Lit(..)
+            | ExprKind::ConstBlock(..)
+            | ExprKind::Path(..)
+            | ExprKind::Field(..)
+            | ExprKind::AddrOf(..)
+            | ExprKind::Tup(..)
+            | ExprKind::Struct(..) => (),
+            ExprKind::Call(callee, _) => {
+                let is_ctor = match callee.kind {
+                    ExprKind::Path(ref qpath) => matches!(
+                        self.cx.qpath_res(qpath, callee.hir_id),
+                        Res::Def(DefKind::Ctor(..) | DefKind::Variant, _)
+                    ),
+                    _ => false,
+                };
+                if !is_ctor {
+                    self.eagerness |= if is_ctor_or_promotable_const_function(self.cx, expr) {
+                        NoChange
+                    } else {
+                        Lazy
+                    };
+                }
+            },
+            ExprKind::MethodCall(..) => self.eagerness |= Lazy,
+            ExprKind::Index(obj, _) => {
+                let ty = self.cx.typeck_results().expr_ty(obj);
+                self.eagerness |= if is_type_diagnostic_item(self.cx, ty, sym::hashmap_type)
+                    || is_type_diagnostic_item(self.cx, ty, sym::BTreeMap)
+                {
+                    Lazy
+                } else {
+                    // indexing can panic
+                    NoChange
+                };
+            },
+            ExprKind::Block(
+                &Block {
+                    stmts: [],
+                    expr: Some(_),
+                    ..
+                },
+                _,
+            ) => (),
+            // `?` is desugared to a `return`. `break` and `continue` are conservatively treated
+            // the same way, even if they only refer to a loop in the expression.
+            ExprKind::Ret(..) | ExprKind::Break(..) | ExprKind::Continue(..) | ExprKind::Yield(..) => {
+                self.eagerness = ForceNoChange;
+                return;
+            },
+            _ => self.eagerness |= NoChange,
         }
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
     }
+}
 
-    let mut finder = FunCallFinder { cx, found: false };
-    finder.visit_expr(expr);
-    finder.found
+/// Classifies the cost and effects of evaluating `expr`, see [`EagernessSuggestion`].
+pub fn expr_eagerness<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> EagernessSuggestion {
+    let mut visitor = EagernessVisitor {
+        cx,
+        eagerness: EagernessSuggestion::Eager,
+    };
+    visitor.visit_expr(expr);
+    visitor.eagerness
 }
 
+/// Whether the lazily evaluated `expr`, e.g. the body of a closure, is cheap enough to be
+/// evaluated eagerly instead.
 pub fn is_eagerness_candidate<'a, 'tcx>(cx: &'a LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
-    !identify_some_potentially_expensive_patterns(cx, expr) && identify_some_pure_patterns(expr)
+    expr_eagerness(cx, expr) == EagernessSuggestion::Eager
 }
 
+/// Whether the eagerly evaluated `expr` is expensive enough to be evaluated lazily instead, and
+/// can be moved into a closure.
 pub fn is_lazyness_candidate<'a, 'tcx>(cx: &'a LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
-    identify_some_potentially_expensive_patterns(cx, expr)
+    expr_eagerness(cx, expr) == EagernessSuggestion::Lazy
 }
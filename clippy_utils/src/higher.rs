@@ -3,13 +3,14 @@
 
 #![deny(clippy::missing_docs_in_private_items)]
 
-use crate::{is_expn_of, match_def_path, paths};
+use crate::{is_expn_of, last_path_segment, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::{self, LitKind};
 use rustc_hir as hir;
-use rustc_hir::{BorrowKind, Expr, ExprKind, StmtKind, UnOp};
+use rustc_hir::{BorrowKind, Expr, ExprField, ExprKind, QPath, StmtKind, UnOp};
 use rustc_lint::LateContext;
 use rustc_span::{sym, ExpnKind, Span, Symbol};
+use std::convert::TryFrom;
 
 /// Represent a range akin to `ast::ExprKind::Range`.
 #[derive(Debug, Copy, Clone)]
@@ -124,7 +125,7 @@ pub fn for_loop<'tcx>(
         if let hir::StmtKind::Local(local) = let_stmt.kind;
         if let hir::StmtKind::Expr(expr) = body.kind;
         then {
-            return Some((&*local.pat, &iterargs[0], expr, arms[0].span));
+            return Some((local.pat, &iterargs[0], expr, arms[0].span));
         }
     }
     None
@@ -172,7 +173,7 @@ pub fn vec_macro<'e>(cx: &LateContext<'_>, expr: &'e hir::Expr<'_>) -> Option<Ve
                     if let hir::ExprKind::Box(boxed) = args[0].kind;
                     if let hir::ExprKind::Array(args) = boxed.kind;
                     then {
-                        return Some(VecArgs::Vec(&*args));
+                        return Some(VecArgs::Vec(args));
                     }
                 }
 
@@ -345,3 +346,119 @@ impl FormatArgsExpn<'tcx> {
         }
     }
 }
+
+impl FormatArgsExpn<'tcx> {
+    /// Returns the arguments of the `format_args!` expansion in the order they appear in the
+    /// format string, paired with the value they format
+    pub fn args(&self) -> Option<Vec<FormatArgsArg<'tcx>>> {
+        if let Some(expr) = self.fmt_expr {
+            if_chain! {
+                if let ExprKind::AddrOf(BorrowKind::Ref, _, expr) = expr.kind;
+                if let ExprKind::Array(exprs) = expr.kind;
+                then {
+                    exprs
+                        .iter()
+                        .map(|fmt| {
+                            if_chain! {
+                                // struct `core::fmt::rt::v1::Argument`
+                                if let ExprKind::Struct(_, fields, _) = fmt.kind;
+                                if let Some(position_field) = fields.iter().find(|f| f.ident.as_str() == "position");
+                                if let ExprKind::Lit(lit) = &position_field.expr.kind;
+                                if let LitKind::Int(position, _) = lit.node;
+                                if let Some(arg) = usize::try_from(position).ok().and_then(|i| self.args.get(i));
+                                if let Some(value) = self.value_of(arg);
+                                then {
+                                    Some(FormatArgsArg { value, arg, fmt: Some(fmt) })
+                                } else {
+                                    None
+                                }
+                            }
+                        })
+                        .collect()
+                } else {
+                    None
+                }
+            }
+        } else {
+            // Without format specs every value is formatted exactly once, in order
+            Some(
+                self.value_args
+                    .iter()
+                    .zip(self.args.iter())
+                    .map(|(value, arg)| FormatArgsArg { value, arg, fmt: None })
+                    .collect(),
+            )
+        }
+    }
+
+    /// Finds the value formatted by an `ArgumentV1::new(argN, _)` expression
+    fn value_of(&self, arg: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+        if_chain! {
+            if let ExprKind::Call(_, [arg_name, _]) = arg.kind;
+            if let ExprKind::Path(QPath::Resolved(None, path)) = arg_name.kind;
+            if let [segment] = path.segments;
+            let name = segment.ident.as_str();
+            if let Some(i) = name.strip_prefix("arg");
+            if let Ok(i) = i.parse::<usize>();
+            then {
+                self.value_args.get(i).copied()
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// An argument of a `format_args!` expansion, e.g. `x` and `{:?}` in `format_args!("{:?}", x)`
+pub struct FormatArgsArg<'tcx> {
+    /// The value being formatted, an element of `value_args`
+    pub value: &'tcx Expr<'tcx>,
+    /// The `ArgumentV1::new(argN, Display::fmt)` expression, an element of `args`
+    pub arg: &'tcx Expr<'tcx>,
+    /// The `core::fmt::rt::v1::Argument` holding the format spec, if the format string has any
+    pub fmt: Option<&'tcx Expr<'tcx>>,
+}
+
+impl FormatArgsArg<'tcx> {
+    /// Returns the name of the formatting trait, e.g. `Display` for `{}` or `Debug` for `{:?}`
+    pub fn format_trait(&self) -> Option<Symbol> {
+        if_chain! {
+            if let ExprKind::Call(_, [_, fmt]) = self.arg.kind;
+            if let ExprKind::Path(QPath::Resolved(_, path)) = fmt.kind;
+            if let [.., t, _] = path.segments;
+            then {
+                Some(t.ident.name)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if a width or a precision is given, e.g. `{:>8}` or `{:.3}`, which would
+    /// affect the output of a string
+    pub fn has_string_formatting(&self) -> bool {
+        self.fmt.map_or(false, |fmt| {
+            if_chain! {
+                // struct `core::fmt::rt::v1::Argument`
+                if let ExprKind::Struct(_, fields, _) = fmt.kind;
+                if let Some(format_field) = fields.iter().find(|f| f.ident.name == sym::format);
+                // struct `core::fmt::rt::v1::FormatSpec`
+                if let ExprKind::Struct(_, spec_fields, _) = format_field.expr.kind;
+                if is_implied_count(spec_fields, sym::precision) && is_implied_count(spec_fields, sym::width);
+                then {
+                    false
+                } else {
+                    true
+                }
+            }
+        })
+    }
+}
+
+/// Checks if the `core::fmt::rt::v1::Count` field `name` is `Count::Implied`
+fn is_implied_count(fields: &[ExprField<'_>], name: Symbol) -> bool {
+    fields.iter().any(|f| {
+        f.ident.name == name
+            && matches!(f.expr.kind, ExprKind::Path(ref qpath) if last_path_segment(qpath).ident.name == sym::Implied)
+    })
+}
@@ -58,21 +58,26 @@ pub mod visitors;
 pub use self::attrs::*;
 pub use self::hir_utils::{both, count_eq, eq_expr_value, over, SpanlessEq, SpanlessHash};
 
+use std::cell::RefCell;
 use std::collections::hash_map::Entry;
 use std::hash::BuildHasherDefault;
+use std::iter;
+use std::rc::Rc;
 
 use if_chain::if_chain;
 use rustc_ast::ast::{self, Attribute, BorrowKind, LitKind};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::DefId;
+use rustc_hir::definitions::DefPathData;
 use rustc_hir::intravisit::{self, walk_expr, ErasedMap, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::LangItem::{ResultErr, ResultOk};
 use rustc_hir::{
-    def, Arm, BindingAnnotation, Block, Body, Constness, Destination, Expr, ExprKind, FnDecl, GenericArgs, HirId, Impl,
-    ImplItem, ImplItemKind, IsAsync, Item, ItemKind, LangItem, Local, MatchSource, Node, Param, Pat, PatKind, Path,
-    PathSegment, QPath, Stmt, StmtKind, TraitItem, TraitItemKind, TraitRef, TyKind, UnOp,
+    def, Arm, BindingAnnotation, Block, Body, BodyId, Constness, Destination, Expr, ExprKind, FnDecl, GenericArgs, HirId,
+    Impl, ImplItem, ImplItemKind, IsAsync, Item, ItemKind, LangItem, Local, MatchSource, Node, Param, Pat, PatKind,
+    Path, PathSegment, QPath, Stmt, StmtKind, TraitItem, TraitItemKind, TraitRef, TyKind, UnOp,
 };
 use rustc_lint::{LateContext, Level, Lint, LintContext};
 use rustc_middle::hir::exports::Export;
@@ -81,7 +86,8 @@ use rustc_middle::ty as rustc_ty;
 use rustc_middle::ty::{layout::IntegerExt, DefIdTree, Ty, TyCtxt, TypeFoldable};
 use rustc_semver::RustcVersion;
 use rustc_session::Session;
-use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::edition::Edition;
+use rustc_span::hygiene::{ExpnId, ExpnKind, MacroKind};
 use rustc_span::source_map::original_sp;
 use rustc_span::sym;
 use rustc_span::symbol::{kw, Symbol};
@@ -106,6 +112,13 @@ pub fn meets_msrv(msrv: Option<&RustcVersion>, lint_msrv: &RustcVersion) -> bool
     msrv.map_or(true, |msrv| msrv.meets(*lint_msrv))
 }
 
+/// Checks if the code at `span` is written in at least the given edition. Code expanded from a
+/// macro has the edition of the crate defining the macro, which can differ from the edition of the
+/// crate being linted.
+pub fn meets_edition(span: Span, edition: Edition) -> bool {
+    span.edition() >= edition
+}
+
 #[macro_export]
 macro_rules! extract_msrv_attr {
     (LateContext) => {
@@ -244,6 +257,64 @@ pub fn is_lang_ctor(cx: &LateContext<'_>, qpath: &QPath<'_>, lang_item: LangItem
     false
 }
 
+/// Returns `true` if `def_id` is the `new` function of one of the std collections or `String`,
+/// which return the same value as `Default::default()`.
+pub fn is_default_equivalent_ctor(cx: &LateContext<'_>, def_id: DefId, path: &QPath<'_>) -> bool {
+    let std_types_symbols = &[
+        sym::string_type,
+        sym::vec_type,
+        sym::vecdeque_type,
+        sym::LinkedList,
+        sym::hashmap_type,
+        sym::BTreeMap,
+        sym::hashset_type,
+        sym::BTreeSet,
+        sym::BinaryHeap,
+    ];
+
+    if let QPath::TypeRelative(_, method) = path {
+        if method.ident.name == sym::new {
+            if let Some(impl_did) = cx.tcx.impl_of_method(def_id) {
+                if let Some(adt) = cx.tcx.type_of(impl_did).ty_adt_def() {
+                    return std_types_symbols
+                        .iter()
+                        .any(|&symbol| cx.tcx.is_diagnostic_item(symbol, adt.did));
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Returns `true` if the expression evaluates to the same value as `Default::default()` of its
+/// type, e.g. `0`, `false`, `None`, `String::new()` or `Default::default()` itself.
+///
+/// This is conservative, e.g. constants and other functions returning the default value aren't
+/// recognized.
+pub fn is_default_equivalent(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Bool(value) => !value,
+            LitKind::Int(value, _) => value == 0,
+            LitKind::Float(value, _) => value.as_str().parse::<f64>().map_or(false, |value| value == 0.0),
+            LitKind::Str(value, _) => value.is_empty(),
+            _ => false,
+        },
+        ExprKind::Tup(exprs) => exprs.iter().all(|e| is_default_equivalent(cx, e)),
+        ExprKind::Call(func, []) => {
+            if let ExprKind::Path(ref qpath) = func.kind {
+                if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id() {
+                    return is_diag_trait_item(cx, def_id, sym::Default)
+                        || is_default_equivalent_ctor(cx, def_id, qpath);
+                }
+            }
+            false
+        },
+        ExprKind::Path(ref qpath) => is_lang_ctor(cx, qpath, LangItem::OptionNone),
+        _ => false,
+    }
+}
+
 /// Returns `true` if this `span` was expanded by any macro.
 #[must_use]
 pub fn in_macro(span: Span) -> bool {
@@ -254,6 +325,42 @@ pub fn in_macro(span: Span) -> bool {
     }
 }
 
+/// Declares a thread local map `$name` caching the results of a query for the whole session.
+///
+/// The keys, like `DefId`s or `ExpnId`s, are only unique within a session. This is fine because the
+/// driver only runs one session per process, and lints run on a single thread.
+macro_rules! session_cache {
+    ($name:ident: $key:ty => $value:ty) => {
+        thread_local! {
+            static $name: RefCell<FxHashMap<$key, $value>> = RefCell::default();
+        }
+    };
+}
+
+/// Returns `true` if `span` was expanded by a macro defined in another crate, or comes from a
+/// desugaring.
+///
+/// This is `rustc_middle::lint::in_external_macro`, but the result is cached per expansion. Lints
+/// ask this for many spans coming from the same expansion, which is slow on macro heavy code.
+#[must_use]
+pub fn in_external_macro(sess: &Session, span: Span) -> bool {
+    session_cache!(CACHE: ExpnId => bool);
+
+    let expn = span.ctxt().outer_expn();
+    if expn == ExpnId::root() {
+        return false;
+    }
+    CACHE.with(|cache| {
+        if let Some(&external) = cache.borrow().get(&expn) {
+            return external;
+        }
+        #[allow(clippy::disallowed_method)]
+        let external = rustc_middle::lint::in_external_macro(sess, span);
+        cache.borrow_mut().insert(expn, external);
+        external
+    })
+}
+
 /// Checks if given pattern is a wildcard (`_`)
 pub fn is_wild<'tcx>(pat: &impl std::ops::Deref<Target = Pat<'tcx>>) -> bool {
     matches!(pat.kind, PatKind::Wild)
@@ -461,6 +568,13 @@ pub fn path_to_local_id(expr: &Expr<'_>, id: HirId) -> bool {
     path_to_local(expr) == Some(id)
 }
 
+/// Checks if a crate named `name` is loaded, i.e. if `path_to_res` looks into it for paths
+/// starting with `name`.
+pub fn is_crate_loaded(cx: &LateContext<'_>, name: &str) -> bool {
+    let tcx = cx.tcx;
+    tcx.crates(()).iter().any(|&num| tcx.crate_name(num).as_str() == name)
+}
+
 /// Gets the definition associated to a path.
 #[allow(clippy::shadow_unrelated)] // false positive #6563
 pub fn path_to_res(cx: &LateContext<'_>, path: &[&str]) -> Res {
@@ -1035,7 +1149,7 @@ pub fn is_refutable(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
         },
         PatKind::Tuple(pats, _) => are_refutable(cx, pats.iter().map(|pat| &**pat)),
         PatKind::Struct(ref qpath, fields, _) => {
-            is_enum_variant(cx, qpath, pat.hir_id) || are_refutable(cx, fields.iter().map(|field| &*field.pat))
+            is_enum_variant(cx, qpath, pat.hir_id) || are_refutable(cx, fields.iter().map(|field| field.pat))
         },
         PatKind::TupleStruct(ref qpath, pats, _) => {
             is_enum_variant(cx, qpath, pat.hir_id) || are_refutable(cx, pats.iter().map(|pat| &**pat))
@@ -1233,20 +1347,119 @@ pub fn match_function_call<'tcx>(
     None
 }
 
+/// Gets the def path of `did`, e.g. `["core", "option", "Option", "unwrap"]`.
+///
+/// This is `LateContext::get_def_path`, but the result is cached per `DefId`. Lints match the def
+/// paths of the same few functions and types over and over again, building the path every time
+/// is slow.
+pub fn get_def_path(cx: &LateContext<'_>, did: DefId) -> Rc<[Symbol]> {
+    session_cache!(CACHE: DefId => Rc<[Symbol]>);
+
+    CACHE.with(|cache| {
+        if let Some(path) = cache.borrow().get(&did) {
+            return Rc::clone(path);
+        }
+        #[allow(clippy::disallowed_method)]
+        let path: Rc<[Symbol]> = cx.get_def_path(did).into();
+        cache.borrow_mut().insert(did, Rc::clone(&path));
+        path
+    })
+}
+
+/// Interns the segments of a def path like `["core", "option", "Option"]`. Lints checking the same
+/// paths over and over again should intern them once and use `match_def_path_interned`.
+pub fn intern_def_path(path: &[&str]) -> Vec<Symbol> {
+    path.iter().map(|&segment| Symbol::intern(segment)).collect()
+}
+
+/// Resolves a def path like `["core", "option", "Option", "unwrap"]` to the `DefId`s of the items
+/// it names in every crate of that name. Returns `None` if the path can't be resolved, e.g. because
+/// it goes through an impl block or the crate isn't a dependency. Re-exports are followed, so
+/// `["std", "vec", "IntoIter"]` resolves to `alloc::vec::into_iter::IntoIter`.
+pub fn def_path_res(cx: &LateContext<'_>, path: &[&str]) -> Option<Rc<[DefId]>> {
+    def_path_res_interned(cx, &intern_def_path(path))
+}
+
+/// Same as `def_path_res`, but for a path interned by `intern_def_path`.
+///
+/// Paths are only resolved once per session, after that this is a lookup by their symbols.
+pub fn def_path_res_interned(cx: &LateContext<'_>, path: &[Symbol]) -> Option<Rc<[DefId]>> {
+    // Keyed by the interned segments, so a path is resolved once no matter where it is stored
+    session_cache!(CACHE: Vec<Symbol> => Option<Rc<[DefId]>>);
+
+    CACHE.with(|cache| {
+        if let Some(def_ids) = cache.borrow().get(path) {
+            return def_ids.clone();
+        }
+        let def_ids: Option<Rc<[DefId]>> = resolve_def_path(cx.tcx, path).map(Into::into);
+        cache.borrow_mut().insert(path.to_vec(), def_ids.clone());
+        def_ids
+    })
+}
+
+fn resolve_def_path(tcx: TyCtxt<'_>, path: &[Symbol]) -> Option<Vec<DefId>> {
+    fn children_by_name(tcx: TyCtxt<'tcx>, def_id: DefId, name: Symbol) -> impl Iterator<Item = Res> + 'tcx {
+        let inherent_impls: &[DefId] = match tcx.def_kind(def_id) {
+            DefKind::Enum | DefKind::Struct | DefKind::Union => tcx.inherent_impls(def_id),
+            _ => &[],
+        };
+        iter::once(def_id)
+            .chain(inherent_impls.iter().copied())
+            .flat_map(move |def_id| tcx.item_children(def_id))
+            .filter(move |item| item.ident.name == name)
+            .map(|item| item.res)
+    }
+
+    let (&krate, path) = path.split_first()?;
+    let (&last, path) = path.split_last()?;
+    let crates = tcx.crates(()).iter().filter(|&&num| tcx.crate_name(num) == krate);
+    let mut def_ids = Vec::new();
+    for &krate_num in crates {
+        let parent = path
+            .iter()
+            // `get_def_path` generates empty segments for extern blocks, their items are children
+            // of the surrounding module
+            .filter(|&&segment| segment != kw::Empty)
+            .try_fold(krate_num.as_def_id(), |def_id, &segment| {
+                children_by_name(tcx, def_id, segment).find_map(|res| match res {
+                    Res::Def(DefKind::Mod | DefKind::Enum | DefKind::Struct | DefKind::Union | DefKind::Trait, id) => {
+                        Some(id)
+                    },
+                    _ => None,
+                })
+            })?;
+        let len = def_ids.len();
+        def_ids.extend(children_by_name(tcx, parent, last).filter_map(|res| res.opt_def_id()));
+        if def_ids.len() == len {
+            return None;
+        }
+    }
+    (!def_ids.is_empty()).then(|| def_ids)
+}
+
 /// Checks if the given `DefId` matches any of the paths. Returns the index of matching path, if
 /// any.
 pub fn match_any_def_paths(cx: &LateContext<'_>, did: DefId, paths: &[&[&str]]) -> Option<usize> {
-    let search_path = cx.get_def_path(did);
-    paths
-        .iter()
-        .position(|p| p.iter().map(|x| Symbol::intern(x)).eq(search_path.iter().copied()))
+    paths.iter().position(|p| match_def_path(cx, did, p))
 }
 
 /// Checks if the given `DefId` matches the path.
 pub fn match_def_path<'tcx>(cx: &LateContext<'tcx>, did: DefId, syms: &[&str]) -> bool {
-    // We should probably move to Symbols in Clippy as well rather than interning every time.
-    let path = cx.get_def_path(did);
-    syms.iter().map(|x| Symbol::intern(x)).eq(path.iter().copied())
+    match_def_path_interned(cx, did, &intern_def_path(syms))
+}
+
+/// Same as `match_def_path`, but for a path interned by `intern_def_path`.
+pub fn match_def_path_interned(cx: &LateContext<'_>, did: DefId, path: &[Symbol]) -> bool {
+    // Constructors have the def path of their struct or variant
+    let did = match cx.tcx.def_key(did).disambiguated_data.data {
+        DefPathData::Ctor => cx.tcx.parent(did).unwrap_or(did),
+        _ => did,
+    };
+    // Items of the local crate can't be found by `def_path_res`
+    match def_path_res_interned(cx, path) {
+        Some(def_ids) if !did.is_local() => def_ids.contains(&did),
+        _ => *get_def_path(cx, did) == *path,
+    }
 }
 
 pub fn match_panic_call(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
@@ -1707,6 +1920,76 @@ pub fn is_test_module_or_function(tcx: TyCtxt<'_>, item: &Item<'_>) -> bool {
     matches!(item.kind, ItemKind::Mod(..)) && item.ident.name.as_str().contains("test")
 }
 
+/// Checks if `id` is inside an item accepted by [`is_test_module_or_function`].
+pub fn is_in_test_module_or_function(tcx: TyCtxt<'_>, id: HirId) -> bool {
+    tcx.hir()
+        .parent_iter(id)
+        .any(|(_, node)| matches!(node, Node::Item(item) if is_test_module_or_function(tcx, item)))
+}
+
+/// Checks if the body `body_id` has more than `limit` HIR nodes. Lints doing quadratic work on a
+/// body use this to skip pathological ones, like generated parser tables or bindgen output. A note
+/// is emitted the first time a body is found to be too large.
+pub fn is_body_too_large(cx: &LateContext<'_>, body_id: BodyId, limit: u64) -> bool {
+    // The node count of each body, which is also used to only emit the note once per body
+    session_cache!(CACHE: BodyId => u64);
+
+    let (count, newly_counted) = CACHE.with(|cache| {
+        if let Some(&count) = cache.borrow().get(&body_id) {
+            return (count, false);
+        }
+        let mut counter = NodeCounter {
+            map: cx.tcx.hir(),
+            count: 0,
+        };
+        counter.visit_body(cx.tcx.hir().body(body_id));
+        cache.borrow_mut().insert(body_id, counter.count);
+        (counter.count, true)
+    });
+
+    let too_large = count > limit;
+    if too_large && newly_counted {
+        let owner = cx.tcx.hir().body_owner_def_id(body_id);
+        cx.sess().span_note_without_error(
+            cx.tcx.def_span(owner),
+            &format!(
+                "skipping expensive Clippy lints on this body, it has more than {} HIR nodes \
+                (see the `body-node-limit` option of clippy.toml)",
+                limit
+            ),
+        );
+    }
+    too_large
+}
+
+struct NodeCounter<'tcx> {
+    map: Map<'tcx>,
+    count: u64,
+}
+
+impl<'tcx> Visitor<'tcx> for NodeCounter<'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        self.count += 1;
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'_>) {
+        self.count += 1;
+        intravisit::walk_pat(self, pat);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'_>) {
+        self.count += 1;
+        intravisit::walk_stmt(self, stmt);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.map)
+    }
+}
+
 macro_rules! op_utils {
     ($($name:ident $assign:ident)*) => {
         /// Binary operation traits like `LangItem::Add`
@@ -12,8 +12,10 @@ macro_rules! msrv_aliases {
 
 // names may refer to stabilized feature flags or library items
 msrv_aliases! {
-    1,53,0 { OR_PATTERNS }
-    1,50,0 { BOOL_THEN }
+    1,53,0 { OR_PATTERNS, BTREE_RETAIN, INTEGER_BITS }
+    1,52,0 { STR_SPLIT_ONCE }
+    1,51,0 { SEEK_STREAM_POSITION }
+    1,50,0 { BOOL_THEN, CLAMP }
     1,46,0 { CONST_IF_MATCH }
     1,45,0 { STR_STRIP_PREFIX }
     1,42,0 { MATCHES_MACRO }
@@ -25,6 +27,7 @@ msrv_aliases! {
     1,35,0 { OPTION_COPIED, RANGE_CONTAINS }
     1,34,0 { TRY_FROM }
     1,30,0 { ITERATOR_FIND_MAP }
+    1,26,0 { STRING_RETAIN }
     1,17,0 { FIELD_INIT_SHORTHAND, STATIC_IN_CONST }
     1,16,0 { STR_REPEAT }
 }
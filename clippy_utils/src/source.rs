@@ -3,12 +3,14 @@
 #![allow(clippy::module_name_repetitions)]
 
 use crate::line_span;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LintContext};
 use rustc_span::hygiene;
 use rustc_span::{BytePos, Pos, Span, SyntaxContext};
 use std::borrow::Cow;
+use std::rc::Rc;
 
 /// Like `snippet_block`, but add braces if the expr is not an `ExprKind::Block`.
 /// Also takes an `Option<String>` which can be put inside the braces.
@@ -89,6 +91,44 @@ pub fn is_present_in_source<T: LintContext>(cx: &T, span: Span) -> bool {
     true
 }
 
+/// Returns the comments directly preceding `span`, in source order and including their
+/// delimiters: a block comment right before it on its line, and the comment lines right above
+/// its line. A line that isn't a comment, like a blank line, ends the comments.
+///
+/// ```rust,ignore
+/// // SAFETY: the comments of these two lines
+/// /* are returned */
+/// let _ = /* and this one */ unsafe { f() };
+/// //                         ^^^^^^^^^^^^^^ for this span
+/// ```
+pub fn preceding_comments<T: LintContext>(cx: &T, span: Span) -> Option<String> {
+    let line_info = cx.sess().source_map().lookup_line(span.lo()).ok()?;
+    let file = line_info.sf;
+    let line = file.get_line(line_info.line)?;
+    let offset = (span.lo() - file.lines[line_info.line]).to_usize();
+    let prefix = line.get(..offset)?.trim_end();
+
+    let mut comments = Vec::new();
+    if prefix.ends_with("*/") {
+        comments.push(prefix.get(prefix.rfind("/*")?..)?.to_string());
+    }
+    let mut in_block_comment = false;
+    for line_no in (0..line_info.line).rev() {
+        let line = file.get_line(line_no)?;
+        let line = line.trim();
+        if in_block_comment {
+            in_block_comment = !line.starts_with("/*");
+        } else if line.ends_with("*/") {
+            in_block_comment = !line.starts_with("/*");
+        } else if !line.starts_with("//") {
+            break;
+        }
+        comments.push(line.to_string());
+    }
+    comments.reverse();
+    Some(comments.join("\n"))
+}
+
 /// Returns the positon just before rarrow
 ///
 /// ```rust,ignore
@@ -205,6 +245,24 @@ pub fn snippet_opt<T: LintContext>(cx: &T, span: Span) -> Option<String> {
     cx.sess().source_map().span_to_snippet(span).ok()
 }
 
+/// The snippets already copied out of the source by `snippet_opt_interned`. Create one for the
+/// scope the spans are used in, like a single expression, instead of keeping it for a whole crate.
+#[derive(Default)]
+pub struct SnippetInterner {
+    snippets: FxHashMap<Span, Option<Rc<str>>>,
+}
+
+/// Same as `snippet_opt`, but the snippet is only copied out of the source the first time
+/// `interner` is asked for `span`, and shared afterwards. Use this when building many
+/// suggestions from the same expressions.
+pub fn snippet_opt_interned<T: LintContext>(cx: &T, interner: &mut SnippetInterner, span: Span) -> Option<Rc<str>> {
+    interner
+        .snippets
+        .entry(span)
+        .or_insert_with(|| snippet_opt(cx, span).map(Into::into))
+        .clone()
+}
+
 /// Converts a span (from a block) to a code snippet if available, otherwise use default.
 ///
 /// This trims the code of indentation, except for the first line. Use it for blocks or block-like
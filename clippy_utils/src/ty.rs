@@ -10,17 +10,22 @@ use rustc_hir::{TyKind, Unsafety};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LateContext;
 use rustc_middle::ty::subst::{GenericArg, GenericArgKind};
-use rustc_middle::ty::{self, AdtDef, IntTy, Ty, TypeFoldable, UintTy};
+use rustc_middle::ty::{self, AdtDef, IntTy, ParamEnv, Ty, TyCtxt, TypeFoldable, UintTy};
 use rustc_span::sym;
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::DUMMY_SP;
 use rustc_trait_selection::infer::InferCtxtExt;
 use rustc_trait_selection::traits::query::normalize::AtExt;
 
-use crate::{match_def_path, must_use_attr};
+use crate::{intern_def_path, match_def_path, match_def_path_interned, must_use_attr, paths};
 
 pub fn is_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
-    ty.is_copy_modulo_regions(cx.tcx.at(DUMMY_SP), cx.param_env)
+    is_copy_with_env(cx.tcx, cx.param_env, ty)
+}
+
+/// Same as `is_copy`, but in the given `ParamEnv` instead of the one of the item being linted.
+pub fn is_copy_with_env<'tcx>(tcx: TyCtxt<'tcx>, param_env: ParamEnv<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.is_copy_modulo_regions(tcx.at(DUMMY_SP), param_env)
 }
 
 /// Checks whether a type can be partially moved.
@@ -120,18 +125,31 @@ pub fn implements_trait<'tcx>(
     ty: Ty<'tcx>,
     trait_id: DefId,
     ty_params: &[GenericArg<'tcx>],
+) -> bool {
+    implements_trait_with_env(cx.tcx, cx.param_env, ty, trait_id, ty_params)
+}
+
+/// Same as `implements_trait`, but in the given `ParamEnv` instead of the one of the item being
+/// linted. Use this when `ty` refers to the generic parameters of another item, e.g. of a callee,
+/// or of a method while checking its `impl` block.
+pub fn implements_trait_with_env<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+    trait_id: DefId,
+    ty_params: &[GenericArg<'tcx>],
 ) -> bool {
     // Clippy shouldn't have infer types
     assert!(!ty.needs_infer());
 
-    let ty = cx.tcx.erase_regions(ty);
+    let ty = tcx.erase_regions(ty);
     if ty.has_escaping_bound_vars() {
         return false;
     }
-    let ty_params = cx.tcx.mk_substs(ty_params.iter());
-    cx.tcx.infer_ctxt().enter(|infcx| {
+    let ty_params = tcx.mk_substs(ty_params.iter());
+    tcx.infer_ctxt().enter(|infcx| {
         infcx
-            .type_implements_trait(trait_id, ty, ty_params, cx.param_env)
+            .type_implements_trait(trait_id, ty, ty_params, param_env)
             .must_apply_modulo_regions()
     })
 }
@@ -144,6 +162,48 @@ pub fn has_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     }
 }
 
+/// The guards whose `Drop` implementation releases a lock or a borrow.
+const SIGNIFICANT_DROP_PATHS: [&[&str]; 8] = [
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+    &paths::PARKING_LOT_MUTEX_GUARD,
+    &paths::PARKING_LOT_RWLOCK_READ_GUARD,
+    &paths::PARKING_LOT_RWLOCK_WRITE_GUARD,
+    &paths::REFCELL_REF,
+    &paths::REFCELL_REFMUT,
+];
+
+/// Interns the paths of the `std` and `parking_lot` guards followed by `extra_paths` (like
+/// `"my_crate::Guard"`), for `has_significant_drop`.
+pub fn significant_drop_paths(extra_paths: &[String]) -> Vec<Vec<Symbol>> {
+    SIGNIFICANT_DROP_PATHS
+        .iter()
+        .map(|path| intern_def_path(path))
+        .chain(
+            extra_paths
+                .iter()
+                .map(|path| path.split("::").map(Symbol::intern).collect()),
+        )
+        .collect()
+}
+
+/// Checks whether dropping a value of this type has an effect the surrounding code may depend on,
+/// like releasing a lock or a `RefCell` borrow, and not only frees memory. This is true for the
+/// types defined at one of the `paths` returned by `significant_drop_paths`, and for types
+/// containing any of these by value (e.g. `Option<MutexGuard<'_, T>>`).
+pub fn has_significant_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, paths: &[Vec<Symbol>]) -> bool {
+    match *ty.kind() {
+        ty::Adt(adt, substs) => {
+            paths.iter().any(|path| match_def_path_interned(cx, adt.did, path))
+                || substs.types().any(|ty| has_significant_drop(cx, ty, paths))
+        },
+        ty::Tuple(substs) => substs.types().any(|ty| has_significant_drop(cx, ty, paths)),
+        ty::Array(ty, _) | ty::Slice(ty) => has_significant_drop(cx, ty, paths),
+        _ => false,
+    }
+}
+
 // Returns whether the type has #[must_use] attribute
 pub fn is_must_use_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     match ty.kind() {
@@ -360,3 +420,72 @@ pub fn same_type_and_consts(a: Ty<'tcx>, b: Ty<'tcx>) -> bool {
         _ => a == b,
     }
 }
+
+/// Checks if the layout of `ty` is guaranteed by the language. These are primitives, thin
+/// pointers, arrays of such types, `Option`s of non-nullable thin pointers and `repr(C)`,
+/// `repr(transparent)` or `repr(<int>)` types whose fields all have a defined layout. The layout
+/// of `repr(Rust)` types, tuples and fat pointers is unspecified and can change between compiler
+/// versions.
+pub fn has_defined_layout<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match *ty.kind() {
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Never | ty::FnPtr(_) | ty::FnDef(..) => {
+            true
+        },
+        ty::Ref(_, pointee, _) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => is_sized(cx, pointee),
+        ty::Array(elem_ty, _) => has_defined_layout(cx, elem_ty),
+        ty::Tuple(tys) => tys.is_empty(),
+        ty::Adt(..) if ty.is_box() => is_sized(cx, ty.boxed_ty()),
+        ty::Adt(adt, substs) => {
+            if cx.tcx.is_diagnostic_item(sym::option_type, adt.did) {
+                // `None` is guaranteed to be represented as the null pointer
+                let inner = substs.type_at(0);
+                return match inner.kind() {
+                    ty::Ref(..) | ty::FnPtr(_) => has_defined_layout(cx, inner),
+                    ty::Adt(..) => inner.is_box() && has_defined_layout(cx, inner),
+                    _ => false,
+                };
+            }
+            if !(adt.repr.c() || adt.repr.transparent() || adt.repr.int.is_some()) {
+                // Structs without fields are zero-sized, e.g. `PhantomData`
+                return adt.is_struct() && adt.all_fields().next().is_none();
+            }
+            adt.all_fields()
+                .all(|field| has_defined_layout(cx, field.ty(cx.tcx, substs)))
+        },
+        _ => false,
+    }
+}
+
+/// Checks if reinterpreting a value of type `from` as `to` doesn't depend on unspecified layout.
+/// This is the case if both types have a defined layout (see [`has_defined_layout`]), if they
+/// only differ in lifetimes, or if they are pointers with the same kind of metadata, e.g. `&[u8]`
+/// and `&str`.
+pub fn is_layout_compatible<'tcx>(cx: &LateContext<'tcx>, from: Ty<'tcx>, to: Ty<'tcx>) -> bool {
+    let from = cx.tcx.erase_regions(from);
+    let to = cx.tcx.erase_regions(to);
+    if from == to {
+        return true;
+    }
+    match (pointee_ty(from), pointee_ty(to)) {
+        (Some(from_pointee), Some(to_pointee))
+            if from_pointee == to_pointee
+                || (matches!(from_pointee.kind(), ty::Slice(_) | ty::Str)
+                    && matches!(to_pointee.kind(), ty::Slice(_) | ty::Str)) =>
+        {
+            true
+        },
+        _ => has_defined_layout(cx, from) && has_defined_layout(cx, to),
+    }
+}
+
+/// Returns the type a reference or raw pointer points to.
+fn pointee_ty(ty: Ty<'_>) -> Option<Ty<'_>> {
+    match *ty.kind() {
+        ty::Ref(_, pointee, _) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => Some(pointee),
+        _ => None,
+    }
+}
+
+fn is_sized<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.is_sized(cx.tcx.at(DUMMY_SP), cx.param_env)
+}
@@ -1,9 +1,14 @@
 use crate::path_to_local_id;
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::{self, walk_expr, ErasedMap, NestedVisitorMap, Visitor};
-use rustc_hir::{def::Res, Arm, Block, Body, BodyId, Destination, Expr, ExprKind, HirId, Stmt};
+use rustc_hir::{Arm, BinOpKind, Block, Body, BodyId, Destination, Expr, ExprKind, HirId, Stmt, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{Ty, TypeckResults};
+use rustc_span::source_map::Spanned;
+use std::ops::ControlFlow;
 
 /// returns `true` if expr contains match expr desugared from try
 fn contains_try(expr: &hir::Expr<'_>) -> bool {
@@ -260,3 +265,224 @@ pub fn is_res_used(cx: &LateContext<'_>, res: Res, body: BodyId) -> bool {
     v.visit_expr(&cx.tcx.hir().body(body).value);
     v.found
 }
+
+/// Checks if evaluating the expression has no side effects, i.e. it doesn't call any functions or
+/// methods other than constructors, doesn't assign to anything and doesn't change the control flow.
+///
+/// Built-in operators which can't panic, like comparisons, bitwise operators and floating point
+/// arithmetic, and dereferencing of references and `Box`es are considered pure. Integer
+/// arithmetic, which may overflow or divide by zero, and indexing, which may be out of bounds,
+/// aren't. Overloaded operators call trait methods and aren't either, neither are implicit
+/// dereferences calling `Deref` implementations. Creating a closure is pure, its body isn't
+/// evaluated. Blocks, `if` and `match` expressions are conservatively considered to have side
+/// effects.
+pub fn is_expr_pure(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    struct V<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        is_pure: bool,
+    }
+    impl<'v> Visitor<'v> for V<'_, '_> {
+        type Map = ErasedMap<'v>;
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+
+        fn visit_expr(&mut self, e: &'v Expr<'v>) {
+            if !self.is_pure {
+                return;
+            }
+            let typeck = self.cx.typeck_results();
+            if typeck.is_method_call(e)
+                || typeck
+                    .expr_adjustments(e)
+                    .iter()
+                    .any(|adjust| matches!(adjust.kind, Adjust::Deref(Some(_))))
+            {
+                self.is_pure = false;
+                return;
+            }
+            match e.kind {
+                ExprKind::Lit(_) | ExprKind::Path(_) | ExprKind::Closure(..) | ExprKind::ConstBlock(_) => (),
+                ExprKind::Call(callee, args) => match callee.kind {
+                    ExprKind::Path(ref qpath)
+                        if matches!(
+                            self.cx.qpath_res(qpath, callee.hir_id),
+                            Res::Def(DefKind::Ctor(..), _) | Res::SelfCtor(_)
+                        ) =>
+                    {
+                        args.iter().for_each(|arg| self.visit_expr(arg));
+                    },
+                    _ => self.is_pure = false,
+                },
+                ExprKind::Unary(..) | ExprKind::Binary(..) if may_overflow(typeck, e) => self.is_pure = false,
+                ExprKind::Unary(..)
+                | ExprKind::Binary(..)
+                | ExprKind::Field(..)
+                | ExprKind::AddrOf(..)
+                | ExprKind::Cast(..)
+                | ExprKind::Type(..)
+                | ExprKind::DropTemps(_)
+                | ExprKind::Box(_)
+                | ExprKind::Repeat(..)
+                | ExprKind::Array(_)
+                | ExprKind::Tup(_)
+                | ExprKind::Struct(..) => walk_expr(self, e),
+                _ => self.is_pure = false,
+            }
+        }
+    }
+
+    let mut v = V { cx, is_pure: true };
+    v.visit_expr(e);
+    v.is_pure
+}
+
+/// Checks if the built-in operator `e` may panic, because it overflows or divides by zero.
+fn may_overflow(typeck: &TypeckResults<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Unary(UnOp::Neg, operand) => !typeck.expr_ty(operand).is_floating_point(),
+        ExprKind::Binary(op, lhs, _) => {
+            matches!(
+                op.node,
+                BinOpKind::Add
+                    | BinOpKind::Sub
+                    | BinOpKind::Mul
+                    | BinOpKind::Div
+                    | BinOpKind::Rem
+                    | BinOpKind::Shl
+                    | BinOpKind::Shr
+            ) && !typeck.expr_ty(lhs).is_floating_point()
+        },
+        _ => false,
+    }
+}
+
+/// Checks if evaluating the expression never finishes normally, because it always returns,
+/// breaks, continues, panics or loops forever.
+///
+/// Only the sub-expressions which are always evaluated are taken into account, e.g.
+/// `(return, 0)` diverges, while `if c { return } else { 0 }` and `c && panic!()` don't. Loops
+/// diverge if their type says so, i.e. if they don't contain a `break` out of them.
+pub fn expr_diverges(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    struct V<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        diverges: bool,
+    }
+    impl<'v> Visitor<'v> for V<'_, '_> {
+        type Map = ErasedMap<'v>;
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+
+        fn visit_expr(&mut self, e: &'v Expr<'v>) {
+            if self.diverges {
+                return;
+            }
+            if self.cx.typeck_results().expr_ty(e).is_never() {
+                self.diverges = true;
+                return;
+            }
+            match e.kind {
+                // Only the condition or the left operand is always evaluated
+                ExprKind::If(cond, ..)
+                | ExprKind::Match(cond, ..)
+                | ExprKind::Binary(
+                    Spanned {
+                        node: BinOpKind::And | BinOpKind::Or,
+                        ..
+                    },
+                    cond,
+                    _,
+                ) => self.visit_expr(cond),
+                // A `break` or `continue` inside of a loop only leaves the loop, and the body of a
+                // closure isn't evaluated
+                ExprKind::Loop(..) | ExprKind::Closure(..) => (),
+                _ => walk_expr(self, e),
+            }
+        }
+    }
+
+    let mut v = V { cx, diverges: false };
+    v.visit_expr(e);
+    v.diverges
+}
+
+/// Calls `f` with the type of each temporary created while evaluating `e` which is borrowed
+/// instead of being moved, like the `String` in `String::new().len()`. These are only dropped at
+/// the end of the enclosing statement, or of the `match` or `if let` with `e` as its scrutinee.
+///
+/// Stops as soon as `f` returns `ControlFlow::Break`, and returns its result.
+pub fn for_each_unconsumed_temporary<'tcx, B>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'tcx>,
+    f: impl FnMut(Ty<'tcx>) -> ControlFlow<B>,
+) -> ControlFlow<B> {
+    struct V<'a, 'tcx, F, B> {
+        cx: &'a LateContext<'tcx>,
+        f: F,
+        res: ControlFlow<B>,
+    }
+    impl<'tcx, F: FnMut(Ty<'tcx>) -> ControlFlow<B>, B> V<'_, 'tcx, F, B> {
+        /// Reports `e` if it creates a temporary, places like locals aren't temporaries.
+        fn visit_borrowed(&mut self, e: &'tcx Expr<'tcx>) {
+            if !matches!(e.kind, ExprKind::Path(_)) {
+                self.res = (self.f)(self.cx.typeck_results().expr_ty(e));
+                self.visit_expr(e);
+            }
+        }
+    }
+    impl<'tcx, F: FnMut(Ty<'tcx>) -> ControlFlow<B>, B> Visitor<'tcx> for V<'_, 'tcx, F, B> {
+        type Map = ErasedMap<'tcx>;
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+
+        fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+            if matches!(self.res, ControlFlow::Break(_)) {
+                return;
+            }
+            match e.kind {
+                // Taking the reference of a value leaves a temporary, e.g. the `String` in
+                // `&String::new()`. The remaining fields are temporaries as well, e.g. the `String`
+                // in `(String::new(), 0).1`.
+                ExprKind::AddrOf(_, _, inner) | ExprKind::Field(inner, _) => self.visit_borrowed(inner),
+                // The base is always taken by reference, e.g. the `Vec` in `vec![0][0]`
+                ExprKind::Index(base, index) => {
+                    self.visit_borrowed(base);
+                    self.visit_expr(index);
+                },
+                ExprKind::MethodCall(_, _, [self_arg, args @ ..], _) => {
+                    let self_by_ref = self
+                        .cx
+                        .typeck_results()
+                        .type_dependent_def_id(e.hir_id)
+                        .map_or(false, |id| self.cx.tcx.fn_sig(id).skip_binder().inputs()[0].is_ref());
+                    if self_by_ref {
+                        self.visit_borrowed(self_arg);
+                    } else {
+                        self.visit_expr(self_arg);
+                    }
+                    args.iter().for_each(|arg| self.visit_expr(arg));
+                },
+                // Either explicitly drops values, or changes control flow
+                ExprKind::DropTemps(_)
+                | ExprKind::Ret(_)
+                | ExprKind::Break(..)
+                | ExprKind::Yield(..)
+                | ExprKind::Block(Block { expr: None, .. }, _)
+                | ExprKind::Loop(..) => (),
+                // Only the temporaries of the final expression outlive the block
+                ExprKind::Block(Block { expr: Some(expr), .. }, _) => self.visit_expr(expr),
+                _ => walk_expr(self, e),
+            }
+        }
+    }
+
+    let mut v = V {
+        cx,
+        f,
+        res: ControlFlow::Continue(()),
+    };
+    v.visit_expr(e);
+    v.res
+}
@@ -0,0 +1,96 @@
+//! `cargo clippy -- --warn-on-all-allows` lets every linted crate record the number of Clippy lints
+//! allowed by its attributes in a directory, which is summarized once all crates have been linted.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs::{self, DirBuilder};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Creates the directory passed to `clippy-driver` in `CLIPPY_ALLOW_AUDIT`, in which every crate
+/// records its numbers in a file of its own. The directory is new and, on Unix, only accessible by
+/// the current user, so nobody else can plant files or symlinks in it. Every run uses a new
+/// directory, which also makes Cargo lint the crates again whose numbers an earlier run recorded.
+pub fn create_record_dir() -> io::Result<PathBuf> {
+    let mut builder = DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+    let mut attempt = 0;
+    loop {
+        let dir = env::temp_dir().join(format!("clippy-allow-audit-{}-{}", process::id(), attempt));
+        match builder.create(&dir) {
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists && attempt < 16 => attempt += 1,
+            result => return result.map(|()| dir),
+        }
+    }
+}
+
+/// Reads and removes the directory written to by `clippy-driver`, and returns the summary of all
+/// crates, or `None` if no Clippy lint was allowed.
+pub fn take_summary(dir: &Path) -> Option<String> {
+    let mut content = String::new();
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        content.push_str(&fs::read_to_string(entry.path()).unwrap_or_default());
+    }
+    let _ = fs::remove_dir_all(dir);
+    summary(&content)
+}
+
+/// Adds up the `<lint> <count>` lines of every crate.
+fn summary(content: &str) -> Option<String> {
+    let mut allowed = BTreeMap::<&str, usize>::new();
+    for line in content.lines() {
+        if let Some((lint_name, count)) = line.split_once(' ') {
+            *allowed.entry(lint_name).or_default() += count.parse::<usize>().unwrap_or_default();
+        }
+    }
+
+    let total: usize = allowed.values().sum();
+    if total == 0 {
+        return None;
+    }
+    let mut summary = format!(
+        "warning: {} Clippy lint{} allowed by attributes\n",
+        total,
+        if total == 1 { " is" } else { "s are" }
+    );
+    for (lint_name, count) in allowed {
+        let _ = writeln!(summary, "  = note: `{}`: {}", lint_name, count);
+    }
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_up_crates() {
+        let content = "clippy::needless_return 2\nclippy::all 1\nclippy::needless_return 1\n";
+        assert_eq!(
+            summary(content).as_deref(),
+            Some(
+                "warning: 4 Clippy lints are allowed by attributes\n  = note: `clippy::all`: 1\n  = note: `clippy::needless_return`: 3\n"
+            )
+        );
+        assert_eq!(summary(""), None);
+    }
+
+    #[test]
+    fn take_summary_of_record_dir() {
+        let dir = create_record_dir().unwrap();
+        let other = create_record_dir().unwrap();
+        assert_ne!(other, dir);
+        fs::remove_dir(other).unwrap();
+        fs::write(dir.join("1"), "clippy::needless_return 2\n").unwrap();
+        fs::write(dir.join("2"), "clippy::needless_return 1\n").unwrap();
+        assert_eq!(
+            take_summary(&dir).as_deref(),
+            Some("warning: 3 Clippy lints are allowed by attributes\n  = note: `clippy::needless_return`: 3\n")
+        );
+        assert!(!dir.exists());
+    }
+}
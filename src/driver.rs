@@ -11,10 +11,13 @@
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
+extern crate rustc_lint;
 extern crate rustc_session;
 extern crate rustc_span;
 
 use rustc_interface::interface;
+use rustc_lint::{Level, LintStore};
+use rustc_session::config::Input;
 use rustc_session::parse::ParseSess;
 use rustc_span::symbol::Symbol;
 use rustc_tools_util::VersionInfo;
@@ -63,6 +66,68 @@ fn test_arg_value() {
     assert_eq!(arg_value(args, "--foo", |_| true), None);
 }
 
+/// Removes every `--clippy-args <args>` and `--clippy-args=<args>` from `args` and returns the
+/// Clippy arguments they contain, which are separated by whitespace. `None` if the last argument is
+/// a `--clippy-args` without a value.
+fn take_clippy_args(args: &mut Vec<String>) -> Option<Vec<String>> {
+    let mut clippy_args = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        let value = if args[i] == "--clippy-args" {
+            if i + 1 == args.len() {
+                return None;
+            }
+            args.remove(i);
+            args.remove(i)
+        } else if let Some(value) = args[i].strip_prefix("--clippy-args=") {
+            let value = value.to_string();
+            args.remove(i);
+            value
+        } else {
+            i += 1;
+            continue;
+        };
+        clippy_args.extend(value.split_whitespace().map(ToString::to_string));
+    }
+    Some(clippy_args)
+}
+
+#[test]
+fn test_take_clippy_args() {
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    let mut args = strings(&[
+        "clippy-driver",
+        "--clippy-args",
+        "-W clippy::pedantic  --no-deps",
+        "foo.rs",
+    ]);
+    assert_eq!(
+        take_clippy_args(&mut args),
+        Some(strings(&["-W", "clippy::pedantic", "--no-deps"]))
+    );
+    assert_eq!(args, strings(&["clippy-driver", "foo.rs"]));
+
+    let mut args = strings(&[
+        "clippy-driver",
+        "--clippy-args=-Dwarnings",
+        "foo.rs",
+        "--clippy-args",
+        "-A",
+        "x",
+    ]);
+    assert_eq!(take_clippy_args(&mut args), Some(strings(&["-Dwarnings", "-A"])));
+    assert_eq!(args, strings(&["clippy-driver", "foo.rs", "x"]));
+
+    let mut args = strings(&["clippy-driver", "foo.rs"]);
+    assert_eq!(take_clippy_args(&mut args), Some(Vec::new()));
+
+    let mut args = strings(&["clippy-driver", "foo.rs", "--clippy-args"]);
+    assert_eq!(take_clippy_args(&mut args), None);
+}
+
 fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>) {
     parse_sess.env_depinfo.get_mut().insert((
         Symbol::intern("CLIPPY_ARGS"),
@@ -70,6 +135,31 @@ fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>)
     ));
 }
 
+/// Makes Cargo recheck the crate if the configuration passed by `cargo clippy` changes.
+fn track_clippy_conf(parse_sess: &mut ParseSess) {
+    parse_sess.env_depinfo.get_mut().insert((
+        Symbol::intern("CLIPPY_CONF"),
+        env::var("CLIPPY_CONF").ok().as_deref().map(Symbol::intern),
+    ));
+}
+
+/// Makes Cargo recheck the crate if `CLIPPY_TIME_LINTS` changes, so that the timings are reported.
+fn track_clippy_time_lints(parse_sess: &mut ParseSess) {
+    parse_sess.env_depinfo.get_mut().insert((
+        Symbol::intern("CLIPPY_TIME_LINTS"),
+        env::var("CLIPPY_TIME_LINTS").ok().as_deref().map(Symbol::intern),
+    ));
+}
+
+/// Makes Cargo recheck the crate if `CLIPPY_ALLOW_AUDIT` changes. `cargo clippy` passes a new
+/// directory for every run, so that the allowed lints of every crate are counted in its summary.
+fn track_clippy_allow_audit(parse_sess: &mut ParseSess) {
+    parse_sess.env_depinfo.get_mut().insert((
+        Symbol::intern("CLIPPY_ALLOW_AUDIT"),
+        env::var("CLIPPY_ALLOW_AUDIT").ok().as_deref().map(Symbol::intern),
+    ));
+}
+
 struct DefaultCallbacks;
 impl rustc_driver::Callbacks for DefaultCallbacks {}
 
@@ -88,17 +178,77 @@ impl rustc_driver::Callbacks for RustcCallbacks {
     }
 }
 
+/// Prints the name, group, default level and description of every Clippy lint as one JSON object
+/// per line, for the rules of the SARIF output of `cargo clippy`.
+fn print_lint_metadata(lint_store: &LintStore) {
+    let groups: Vec<_> = lint_store
+        .get_lint_groups()
+        .into_iter()
+        .filter(|(name, ..)| name.starts_with("clippy::") && *name != "clippy::all")
+        .collect();
+    for lint in lint_store.get_lints() {
+        let name = lint.name_lower();
+        let name = match name.strip_prefix("clippy::") {
+            Some(name) => name,
+            None => continue,
+        };
+        let group = groups
+            .iter()
+            .find(|(_, lints, _)| lints.iter().any(|id| id.lint_name_raw() == lint.name))
+            .and_then(|(group, ..)| group.strip_prefix("clippy::"));
+        println!(
+            "{}",
+            serde_json::json!({
+                "name": name,
+                "group": group,
+                "level": lint.default_level.as_str(),
+                "desc": lint.desc,
+            })
+        );
+    }
+}
+
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    warn_on_all_allows: bool,
+    inherit_config: bool,
+    print_lint_metadata: bool,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let warn_on_all_allows = self.warn_on_all_allows;
+        let inherit_config = self.inherit_config;
+        let print_lint_metadata = self.print_lint_metadata;
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
+            track_clippy_conf(parse_sess);
+            track_clippy_time_lints(parse_sess);
+            track_clippy_allow_audit(parse_sess);
         }));
+
+        let source_file = match &config.input {
+            Input::File(path) => Some(path.as_path()),
+            Input::Str { .. } => None,
+        };
+        let (conf, conf_errors) = clippy_lints::read_conf(source_file, inherit_config);
+        // The lint levels of the configuration are passed before those of the command line, so that
+        // the latter take precedence
+        config
+            .opts
+            .lint_opts
+            .splice(0..0, clippy_lints::conf_lint_levels(&conf));
+        // `--warn-on-all-allows` comes before the configuration and the command line, which can
+        // change the level of the lint again
+        if warn_on_all_allows {
+            config
+                .opts
+                .lint_opts
+                .insert(0, ("clippy::allowed_clippy_lint".to_string(), Level::Warn));
+        }
+
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
             // is there already. Certainly it can't hurt.
@@ -106,10 +256,17 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_lints::read_conf(sess);
+            // all conf errors are non-fatal, we just use the default conf in case of error
+            for error in &conf_errors {
+                sess.struct_err(error).emit();
+            }
             clippy_lints::register_plugins(lint_store, sess, &conf);
             clippy_lints::register_pre_expansion_lints(lint_store);
             clippy_lints::register_renamed(lint_store);
+            if print_lint_metadata {
+                self::print_lint_metadata(lint_store);
+                exit(0);
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -127,6 +284,7 @@ Checks a package to catch common mistakes and improve your Rust code.
 
 Usage:
     cargo clippy [options] [--] [<opts>...]
+    clippy-driver [<rustc opts>...] [--clippy-args <args>] <file>
 
 Common options:
     -h, --help               Print this message
@@ -146,6 +304,13 @@ with:
 You can use tool lints to allow or deny lints from your code, eg.:
 
     #[allow(clippy::needless_lifetimes)]
+
+To lint a single file without Cargo, run `clippy-driver` like `rustc`. All options,
+like `--edition` or `--crate-type`, are passed to rustc, which also writes its output
+files as usual. The configuration files are looked up from the directory of the file
+upwards. Options for Clippy, separated by spaces, can be passed with `--clippy-args`:
+
+    clippy-driver --edition 2018 --clippy-args \"-W clippy::pedantic --no-inherit-config\" foo.rs
 "
     );
 }
@@ -287,6 +452,30 @@ pub fn main() {
             exit(0);
         }
 
+        // `cargo clippy` uses this to read the configuration once and pass it to every crate of
+        // the workspace in `CLIPPY_CONF`
+        if orig_args.iter().any(|a| a == "--resolve-conf") {
+            match clippy_lints::resolve_conf(!orig_args.iter().any(|a| a == "--no-inherit-config")) {
+                Ok(conf) => print!("{}", conf),
+                Err(errors) => {
+                    for error in errors {
+                        eprintln!("error: {}", error);
+                    }
+                    exit(1);
+                },
+            }
+            exit(0);
+        }
+
+        // `cargo clippy --message-format=sarif` uses this to describe the lints, the driver is run on
+        // an empty input to register them
+        let print_lint_metadata = if let Some(pos) = orig_args.iter().position(|a| a == "--print-lint-metadata") {
+            orig_args.remove(pos);
+            true
+        } else {
+            false
+        };
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -296,6 +485,13 @@ pub fn main() {
             orig_args.remove(1);
         }
 
+        // `clippy-driver --clippy-args <args> foo.rs` works like `CLIPPY_ARGS`, for running Clippy
+        // on a single file without Cargo
+        let extra_clippy_args = take_clippy_args(&mut orig_args).unwrap_or_else(|| {
+            eprintln!("error: `--clippy-args` requires a value");
+            exit(1);
+        });
+
         if !wrapper_mode && (orig_args.iter().any(|a| a == "--help" || a == "-h") || orig_args.len() == 1) {
             display_help();
             exit(0);
@@ -310,17 +506,28 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut warn_on_all_allows = false;
+        let mut inherit_config = true;
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
             .unwrap_or_default()
             .split("__CLIPPY_HACKERY__")
+            .chain(extra_clippy_args.iter().map(String::as_str))
             .filter_map(|s| match s {
                 "" => None,
                 "--no-deps" => {
                     no_deps = true;
                     None
                 },
+                "--warn-on-all-allows" => {
+                    warn_on_all_allows = true;
+                    None
+                },
+                "--no-inherit-config" => {
+                    inherit_config = false;
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
@@ -335,13 +542,20 @@ pub fn main() {
         let cap_lints_allow = arg_value(&orig_args, "--cap-lints", |val| val == "allow").is_some();
         let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
 
-        let clippy_enabled = clippy_tests_set || (!cap_lints_allow && (!no_deps || in_primary_package));
+        let clippy_enabled =
+            print_lint_metadata || clippy_tests_set || (!cap_lints_allow && (!no_deps || in_primary_package));
         if clippy_enabled {
             args.extend(clippy_args);
         }
 
         if clippy_enabled {
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var }).run()
+            let mut callbacks = ClippyCallbacks {
+                clippy_args_var,
+                warn_on_all_allows,
+                inherit_config,
+                print_lint_metadata,
+            };
+            rustc_driver::RunCompiler::new(&args, &mut callbacks).run()
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var }).run()
         }
@@ -0,0 +1,237 @@
+//! `cargo clippy --keep-going` lints every selected workspace package with its own Cargo
+//! invocation, so that a package failing to compile doesn't stop the others from being linted.
+//! The diagnostics are printed as they arrive and summarized per target at the end.
+
+use cargo_metadata::diagnostic::DiagnosticLevel;
+use cargo_metadata::Message;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::BufRead;
+
+/// The packages selected by the Cargo arguments.
+#[derive(Debug, Default, PartialEq)]
+pub struct Packages {
+    /// The packages passed with `-p`. All workspace members are linted if there are none.
+    pub selected: Vec<String>,
+    /// The workspace members passed with `--exclude`
+    pub excluded: Vec<String>,
+}
+
+/// Removes the package selection (`-p`, `--package`, `--workspace`, `--all` and `--exclude`)
+/// from the Cargo arguments `args` and returns it. Every package is linted with its own `-p`, which
+/// Cargo doesn't accept together with `--exclude`.
+pub fn take_packages(args: &mut Vec<String>) -> Packages {
+    let mut packages = Packages::default();
+    let mut iter = std::mem::take(args).into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-p" | "--package" => packages.selected.extend(iter.next()),
+            "--exclude" => packages.excluded.extend(iter.next()),
+            "--workspace" | "--all" => {},
+            _ => {
+                if let Some(package) = arg.strip_prefix("--package=").or_else(|| arg.strip_prefix("-p")) {
+                    packages.selected.push(package.to_string());
+                } else if let Some(package) = arg.strip_prefix("--exclude=") {
+                    packages.excluded.push(package.to_string());
+                } else {
+                    args.push(arg);
+                }
+            },
+        }
+    }
+    packages
+}
+
+/// The diagnostics of a target, or of a package if Cargo failed before a target was checked.
+#[derive(Debug, Default, PartialEq)]
+pub struct TargetSummary {
+    pub warnings: usize,
+    pub errors: usize,
+    /// Whether Cargo reported the target as checked
+    pub checked: bool,
+}
+
+/// The summaries by package name and target, like `lib` or `bin "foo"`.
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub targets: BTreeMap<(String, String), TargetSummary>,
+}
+
+impl Summary {
+    /// Reads the JSON messages of the Cargo invocation linting `package` from `input`. The
+    /// diagnostics of `package` are printed to stderr and counted. The ones of other workspace
+    /// packages it depends on are left out, they are reported when linting those packages.
+    pub fn collect(&mut self, input: impl BufRead, package: &str) {
+        for message in Message::parse_stream(input).flatten() {
+            let (package_id, target) = match &message {
+                Message::CompilerMessage(message) => (&message.package_id, &message.target),
+                Message::CompilerArtifact(artifact) => (&artifact.package_id, &artifact.target),
+                _ => continue,
+            };
+            if package_id.repr.split_whitespace().next() != Some(package) {
+                continue;
+            }
+            let target = match target.kind.first() {
+                Some(kind) if kind == "lib" => kind.clone(),
+                Some(kind) => format!("{} \"{}\"", kind, target.name),
+                None => target.name.clone(),
+            };
+            let summary = self.targets.entry((package.to_string(), target)).or_default();
+
+            match message {
+                Message::CompilerMessage(message) => {
+                    let diag = message.message;
+                    if let Some(rendered) = &diag.rendered {
+                        eprint!("{}", rendered);
+                    }
+                    // Diagnostics without a location summarize the others, like "2 warnings emitted"
+                    if diag.spans.is_empty() {
+                        continue;
+                    }
+                    match diag.level {
+                        DiagnosticLevel::Ice | DiagnosticLevel::Error => summary.errors += 1,
+                        DiagnosticLevel::Warning => summary.warnings += 1,
+                        _ => {},
+                    }
+                },
+                Message::CompilerArtifact(_) => summary.checked = true,
+                _ => {},
+            }
+        }
+    }
+
+    /// Records that linting `package` failed. If none of its targets has an error, the failure
+    /// happened before, e.g. in a dependency, and is reported for the whole package.
+    pub fn package_failed(&mut self, package: &str) {
+        let has_errors = self
+            .targets
+            .iter()
+            .any(|((name, _), summary)| name == package && summary.errors > 0);
+        if !has_errors {
+            self.targets
+                .entry((package.to_string(), "-".to_string()))
+                .or_default()
+                .errors += 1;
+        }
+    }
+
+    /// Renders the summary as a table with a row for every target.
+    pub fn table(&self) -> String {
+        let header = ("package", "target");
+        let package_width = self
+            .targets
+            .keys()
+            .map(|(package, _)| package.len())
+            .chain([header.0.len()])
+            .max()
+            .unwrap_or_default();
+        let target_width = self
+            .targets
+            .keys()
+            .map(|(_, target)| target.len())
+            .chain([header.1.len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut table = String::new();
+        let _ = writeln!(
+            table,
+            "{:pw$}  {:tw$}  warnings  errors  status",
+            header.0,
+            header.1,
+            pw = package_width,
+            tw = target_width
+        );
+        for ((package, target), summary) in &self.targets {
+            let status = if summary.errors > 0 {
+                "failed"
+            } else if summary.checked {
+                "ok"
+            } else {
+                "not checked"
+            };
+            let _ = writeln!(
+                table,
+                "{:pw$}  {:tw$}  {:>8}  {:>6}  {}",
+                package,
+                target,
+                summary.warnings,
+                summary.errors,
+                status,
+                pw = package_width,
+                tw = target_width
+            );
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_packages_from_args() {
+        let mut args: Vec<String> = ["-p", "foo", "--all-targets", "--package=bar", "-pbaz", "--workspace"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            take_packages(&mut args),
+            Packages {
+                selected: vec!["foo".to_string(), "bar".to_string(), "baz".to_string()],
+                excluded: Vec::new(),
+            }
+        );
+        assert_eq!(args, ["--all-targets"]);
+
+        let mut args: Vec<String> = ["--workspace", "--exclude", "foo", "--tests", "--exclude=bar"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            take_packages(&mut args),
+            Packages {
+                selected: Vec::new(),
+                excluded: vec!["foo".to_string(), "bar".to_string()],
+            }
+        );
+        assert_eq!(args, ["--tests"]);
+
+        let mut args = vec!["--all".to_string(), "--tests".to_string()];
+        assert_eq!(take_packages(&mut args), Packages::default());
+        assert_eq!(args, ["--tests"]);
+    }
+
+    #[test]
+    fn collect_summary() {
+        let input = r#"{"reason":"compiler-message","package_id":"foo 0.1.0 (path+file:///foo)","target":{"kind":["lib"],"crate_types":["lib"],"name":"foo","src_path":"/foo/src/lib.rs","edition":"2018","doctest":true,"test":true},"message":{"rendered":"","children":[],"code":{"code":"clippy::eq_op","explanation":null},"level":"error","message":"equal expressions as operands to `==`","spans":[{"byte_end":30,"byte_start":24,"column_end":13,"column_start":7,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":2,"line_start":2,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}
+{"reason":"compiler-message","package_id":"foo 0.1.0 (path+file:///foo)","target":{"kind":["lib"],"crate_types":["lib"],"name":"foo","src_path":"/foo/src/lib.rs","edition":"2018","doctest":true,"test":true},"message":{"rendered":"","children":[],"code":null,"level":"error","message":"aborting due to previous error","spans":[]}}
+{"reason":"compiler-artifact","package_id":"bar 0.1.0 (path+file:///bar)","target":{"kind":["lib"],"crate_types":["lib"],"name":"bar","src_path":"/bar/src/lib.rs","edition":"2018","doctest":true,"test":true},"profile":{"opt_level":"0","debuginfo":2,"debug_assertions":true,"overflow_checks":true,"test":false},"features":[],"filenames":[],"executable":null,"fresh":true}
+{"reason":"build-finished","success":false}
+"#;
+        let mut summary = Summary::default();
+        summary.collect(input.as_bytes(), "foo");
+        summary.package_failed("foo");
+        summary.package_failed("baz");
+
+        let foo = &summary.targets[&("foo".to_string(), "lib".to_string())];
+        assert_eq!(
+            foo,
+            &TargetSummary {
+                warnings: 0,
+                errors: 1,
+                checked: false
+            }
+        );
+        // `bar` is only a dependency of `foo`
+        assert_eq!(summary.targets.len(), 2);
+        assert_eq!(summary.targets[&("baz".to_string(), "-".to_string())].errors, 1);
+        assert_eq!(
+            summary.table(),
+            "package  target  warnings  errors  status\n\
+             baz      -              0       1  failed\n\
+             foo      lib            0       1  failed\n"
+        );
+    }
+}
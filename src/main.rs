@@ -2,11 +2,17 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
+mod allow_audit;
+mod keep_going;
+mod sarif;
+
 use rustc_tools_util::VersionInfo;
+use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsString;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -16,6 +22,16 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --fix                    Automatically apply lint suggestions. This flag implies `--no-deps`
+    --fix-dirty              Like `--fix`, but also works if the working directory has uncommitted
+                             changes
+    --changed-since <REV>    Only lint the workspace packages containing files that changed since the
+                             git revision <REV>, including uncommitted and untracked files
+    --message-format=sarif   Print the diagnostics as a SARIF 2.1 log, e.g. for GitHub code scanning.
+                             Other message formats are passed to Cargo
+    --keep-going             Lint every selected package (the whole workspace by default) separately,
+                             so that a package failing to compile doesn't stop the others, and print
+                             a summary of the diagnostics of every target at the end
 
 Other options are the same as `cargo check`.
 
@@ -58,30 +74,93 @@ pub fn main() {
     }
 }
 
+#[derive(Clone)]
 struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    changed_since: Option<String>,
+    /// `--manifest-path`, which is also passed to Cargo
+    manifest_path: Option<PathBuf>,
+    /// `--message-format=sarif`, Cargo is run with `--message-format=json` and the messages are
+    /// converted
+    sarif: bool,
+    /// `--keep-going`, every package is linted with its own Cargo invocation
+    keep_going: bool,
 }
 
 impl ClippyCmd {
-    fn new<I>(mut old_args: I) -> Self
+    fn new<I>(mut old_args: I) -> Result<Self, String>
     where
         I: Iterator<Item = String>,
     {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
+        let mut changed_since = None;
+        let mut manifest_path = None;
+        let mut sarif = false;
+        let mut keep_going = false;
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
                     continue;
                 },
+                "--fix-dirty" => {
+                    cargo_subcommand = "fix";
+                    args.push("--allow-dirty".into());
+                    args.push("--allow-staged".into());
+                    continue;
+                },
+                "--changed-since" => {
+                    match old_args.next() {
+                        Some(rev) if rev != "--" => changed_since = Some(rev),
+                        _ => return Err("`--changed-since` requires a git revision".into()),
+                    }
+                    continue;
+                },
+                "--manifest-path" => {
+                    let path = old_args.next();
+                    manifest_path = path.as_ref().map(PathBuf::from);
+                    args.push(arg);
+                    args.extend(path);
+                    continue;
+                },
+                "--message-format" => {
+                    match old_args.next() {
+                        Some(format) if format == "sarif" => {
+                            sarif = true;
+                            args.push("--message-format=json".into());
+                        },
+                        format => {
+                            args.push(arg);
+                            args.extend(format);
+                        },
+                    }
+                    continue;
+                },
+                "--message-format=sarif" => {
+                    sarif = true;
+                    args.push("--message-format=json".into());
+                    continue;
+                },
+                "--keep-going" => {
+                    keep_going = true;
+                    continue;
+                },
                 "--" => break,
                 _ => {},
             }
 
+            if let Some(rev) = arg.strip_prefix("--changed-since=") {
+                changed_since = Some(rev.to_string());
+                continue;
+            }
+            if let Some(path) = arg.strip_prefix("--manifest-path=") {
+                manifest_path = Some(PathBuf::from(path));
+            }
+
             args.push(arg);
         }
 
@@ -90,11 +169,15 @@ impl ClippyCmd {
             clippy_args.push("--no-deps".into());
         }
 
-        ClippyCmd {
+        Ok(ClippyCmd {
             cargo_subcommand,
             args,
             clippy_args,
-        }
+            changed_since,
+            manifest_path,
+            sarif,
+            keep_going,
+        })
     }
 
     fn path() -> PathBuf {
@@ -143,19 +226,220 @@ impl ClippyCmd {
     }
 }
 
+/// Reads the configuration of the directory `cargo clippy` runs in (or `CLIPPY_CONF_DIR`) once for
+/// the whole workspace, instead of letting `clippy-driver` look it up and parse it again for every
+/// crate. Returns the configuration and the directory it was read in.
+fn resolve_conf(inherit: bool) -> Result<Option<(String, PathBuf)>, i32> {
+    let dir = match env::var_os("CLIPPY_CONF_DIR").map_or_else(env::current_dir, |dir| Ok(PathBuf::from(dir))) {
+        Ok(dir) => dir.canonicalize().unwrap_or(dir),
+        Err(_) => return Ok(None),
+    };
+
+    let mut cmd = Command::new(ClippyCmd::path());
+    cmd.arg("--resolve-conf").env("CLIPPY_CONF_DIR", &dir);
+    if !inherit {
+        cmd.arg("--no-inherit-config");
+    }
+    let output = match cmd.stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        // Fall back to reading the configuration in `clippy-driver`
+        Err(_) => return Ok(None),
+    };
+
+    if output.status.success() {
+        Ok(String::from_utf8(output.stdout).ok().map(|conf| (conf, dir)))
+    } else {
+        Err(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// Returns the Clippy lints registered by `clippy-driver`, which are the rules of the SARIF log.
+/// The driver doesn't compile anything, the empty input is only required to set up the lint store.
+fn lint_metadata() -> Vec<sarif::LintMetadata> {
+    let output = Command::new(ClippyCmd::path())
+        .args(&["--print-lint-metadata", "-"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            sarif::LintMetadata::parse_all(&String::from_utf8_lossy(&output.stdout))
+        },
+        _ => {
+            eprintln!("warning: could not read the lint metadata, the SARIF log won't contain rule descriptions");
+            Vec::new()
+        },
+    }
+}
+
+/// Runs `git` with the given arguments and returns the lines of its output.
+fn git_lines(args: &[&str]) -> Result<Vec<String>, i32> {
+    let output = match Command::new("git").args(args).stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("could not run git: {}", e);
+            return Err(-1);
+        },
+    };
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(ToString::to_string)
+            .collect())
+    } else {
+        Err(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// Runs `cargo metadata` for the workspace of `manifest_path`, or the one of the current directory.
+fn workspace_metadata(manifest_path: Option<&Path>) -> Result<cargo_metadata::Metadata, i32> {
+    let mut cmd = cargo_metadata::MetadataCommand::new();
+    if let Some(manifest_path) = manifest_path {
+        cmd.manifest_path(manifest_path);
+    }
+    cmd.no_deps().exec().map_err(|e| {
+        eprintln!("could not read the workspace metadata: {}", e);
+        -1
+    })
+}
+
+/// Returns the names of the workspace packages containing files that changed since `rev`, or
+/// `None` if a file of the workspace outside of every package changed (e.g. `clippy.toml` or
+/// `Cargo.lock` in the workspace root), in which case the whole workspace has to be linted. Files
+/// outside of the workspace are ignored.
+fn changed_packages(rev: &str, manifest_path: Option<&Path>) -> Result<Option<BTreeSet<String>>, i32> {
+    let toplevel = match git_lines(&["rev-parse", "--show-toplevel"])?.pop() {
+        Some(toplevel) => PathBuf::from(toplevel),
+        None => return Err(-1),
+    };
+    let mut files = git_lines(&["diff", "--name-only", rev, "--"])?;
+    files.extend(git_lines(&[
+        "ls-files",
+        "--others",
+        "--exclude-standard",
+        "--full-name",
+        ":/",
+    ])?);
+
+    let metadata = workspace_metadata(manifest_path)?;
+    let package_dirs: Vec<(&Path, &str)> = metadata
+        .packages
+        .iter()
+        .filter_map(|package| Some((package.manifest_path.parent()?, package.name.as_str())))
+        .collect();
+
+    let mut packages = BTreeSet::new();
+    for file in files {
+        let file = toplevel.join(file);
+        if !file.starts_with(&metadata.workspace_root) {
+            continue;
+        }
+        // Nested packages have the longest matching directory
+        let package = package_dirs
+            .iter()
+            .filter(|(dir, _)| file.starts_with(dir))
+            .max_by_key(|(dir, _)| dir.components().count());
+        match package {
+            Some((_, name)) => packages.insert(name.to_string()),
+            None => return Ok(None),
+        };
+    }
+
+    Ok(Some(packages))
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
-    let cmd = ClippyCmd::new(old_args);
+    let mut cmd = match ClippyCmd::new(old_args) {
+        Ok(cmd) => cmd,
+        Err(e) => {
+            eprintln!("{}", e);
+            return Err(1);
+        },
+    };
+
+    if let Some(rev) = cmd.changed_since.take() {
+        if let Some(packages) = changed_packages(&rev, cmd.manifest_path.as_deref())? {
+            if packages.is_empty() {
+                eprintln!("no packages changed since `{}`, nothing to lint", rev);
+                return Ok(());
+            }
+            for package in packages {
+                cmd.args.push("-p".into());
+                cmd.args.push(package);
+            }
+        }
+    }
+
+    if cmd.keep_going && (cmd.sarif || cmd.args.iter().any(|arg| arg.starts_with("--message-format"))) {
+        eprintln!("`--keep-going` can't be combined with `--message-format`");
+        return Err(1);
+    }
+
+    let inherit_config = !cmd.clippy_args.iter().any(|arg| arg == "--no-inherit-config");
+    let conf = resolve_conf(inherit_config)?;
+    let allow_audit = if cmd.clippy_args.iter().any(|arg| arg == "--warn-on-all-allows") {
+        // Without the directory every crate prints its own summary
+        allow_audit::create_record_dir()
+            .map_err(|e| {
+                eprintln!(
+                    "warning: could not create a directory for the allowed Clippy lints: {}",
+                    e
+                )
+            })
+            .ok()
+    } else {
+        None
+    };
+    let sarif = cmd.sarif;
+    let to_std_cmd = |cmd: ClippyCmd| {
+        let mut cmd = cmd.into_std_cmd();
+        if let Some((conf, dir)) = &conf {
+            // Each crate only reads the configuration files below `dir` itself
+            cmd.env("CLIPPY_CONF", conf).env("CLIPPY_CONF_ROOT", dir);
+        }
+        if let Some(path) = &allow_audit {
+            cmd.env("CLIPPY_ALLOW_AUDIT", path);
+        }
+        cmd
+    };
 
-    let mut cmd = cmd.into_std_cmd();
+    let result = if cmd.keep_going {
+        keep_going(cmd, to_std_cmd)
+    } else {
+        run(to_std_cmd(cmd), sarif)
+    };
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    if let Some(summary) = allow_audit.as_deref().and_then(allow_audit::take_summary) {
+        eprintln!();
+        eprint!("{}", summary);
+    }
+    result
+}
+
+/// Runs Cargo once, converting its messages if `sarif` is set.
+fn run(mut cmd: Command, sarif: bool) -> Result<(), i32> {
+    let exit_status = if sarif {
+        let lints = lint_metadata();
+        let mut child = cmd.stdout(Stdio::piped()).spawn().expect("could not run cargo");
+        let stdout = child.stdout.take().expect("stdout of cargo is piped");
+        let version_info = rustc_tools_util::get_version_info!();
+        let version = format!("{}.{}.{}", version_info.major, version_info.minor, version_info.patch);
+        let log = sarif::convert(BufReader::new(stdout), &lints, &version);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&log).expect("failed to serialize the SARIF log")
+        );
+        child.wait().expect("failed to wait for cargo?")
+    } else {
+        cmd.spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?")
+    };
 
     if exit_status.success() {
         Ok(())
@@ -164,14 +448,59 @@ where
     }
 }
 
+/// Lints the packages selected by `cmd`, or all workspace packages, one after another. Returns the
+/// exit code of the first Cargo invocation that failed, after all of them ran.
+fn keep_going(mut cmd: ClippyCmd, to_std_cmd: impl Fn(ClippyCmd) -> Command) -> Result<(), i32> {
+    let selection = keep_going::take_packages(&mut cmd.args);
+    let packages = if selection.selected.is_empty() {
+        let metadata = workspace_metadata(cmd.manifest_path.as_deref())?;
+        metadata
+            .packages
+            .iter()
+            .filter(|package| {
+                metadata.workspace_members.contains(&package.id) && !selection.excluded.contains(&package.name)
+            })
+            .map(|package| package.name.clone())
+            .collect()
+    } else {
+        selection.selected
+    };
+
+    let mut summary = keep_going::Summary::default();
+    let mut result = Ok(());
+    for package in packages {
+        let mut package_cmd = cmd.clone();
+        package_cmd
+            .args
+            .extend(["-p".to_string(), package.clone(), "--message-format=json".to_string()]);
+        let mut child = to_std_cmd(package_cmd)
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("could not run cargo");
+        let stdout = child.stdout.take().expect("stdout of cargo is piped");
+        summary.collect(BufReader::new(stdout), &package);
+
+        let exit_status = child.wait().expect("failed to wait for cargo?");
+        if !exit_status.success() {
+            summary.package_failed(&package);
+            result = result.and(Err(exit_status.code().unwrap_or(-1)));
+        }
+    }
+
+    eprintln!();
+    eprint!("{}", summary.table());
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::ClippyCmd;
+    use std::path::Path;
 
     #[test]
     fn fix() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("fix", cmd.cargo_subcommand);
         assert!(!cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
     }
@@ -179,7 +508,7 @@ mod tests {
     #[test]
     fn fix_implies_no_deps() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
     }
 
@@ -188,14 +517,116 @@ mod tests {
         let args = "cargo clippy --fix -- --no-deps"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn fix_dirty() {
+        let args = "cargo clippy --fix-dirty".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert!(cmd.args.iter().any(|arg| arg == "--allow-dirty"));
+        assert!(cmd.args.iter().any(|arg| arg == "--allow-staged"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
+    }
+
+    #[test]
+    fn changed_since() {
+        let args = "cargo clippy --changed-since main --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.changed_since.as_deref(), Some("main"));
+        assert_eq!(cmd.args, ["--all-targets"]);
+
+        let args = "cargo clippy --changed-since=HEAD~1 -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.changed_since.as_deref(), Some("HEAD~1"));
+        assert!(cmd.args.is_empty());
+        assert_eq!(cmd.clippy_args, ["-D", "warnings"]);
+    }
+
+    #[test]
+    fn changed_since_without_rev() {
+        let args = "cargo clippy --changed-since"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        assert!(ClippyCmd::new(args).is_err());
+
+        let args = "cargo clippy --changed-since -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        assert!(ClippyCmd::new(args).is_err());
+    }
+
+    #[test]
+    fn manifest_path() {
+        let args = "cargo clippy --manifest-path sub/Cargo.toml --changed-since main"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.manifest_path.as_deref(), Some(Path::new("sub/Cargo.toml")));
+        assert_eq!(cmd.args, ["--manifest-path", "sub/Cargo.toml"]);
+
+        let args = "cargo clippy --manifest-path=sub/Cargo.toml"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.manifest_path.as_deref(), Some(Path::new("sub/Cargo.toml")));
+        assert_eq!(cmd.args, ["--manifest-path=sub/Cargo.toml"]);
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("check", cmd.cargo_subcommand);
     }
+
+    #[test]
+    fn keep_going() {
+        let args = "cargo clippy --keep-going --all-targets -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.keep_going);
+        assert_eq!(cmd.args, ["--all-targets"]);
+    }
+
+    #[test]
+    fn message_format_sarif() {
+        let args = "cargo clippy --message-format=sarif --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.sarif);
+        assert_eq!(cmd.args, ["--message-format=json", "--all-targets"]);
+
+        let args = "cargo clippy --message-format sarif"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.sarif);
+        assert_eq!(cmd.args, ["--message-format=json"]);
+
+        let args = "cargo clippy --message-format short"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .skip(2);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(!cmd.sarif);
+        assert_eq!(cmd.args, ["--message-format", "short"]);
+    }
 }
@@ -0,0 +1,188 @@
+//! Converts the JSON diagnostics of `cargo check --message-format=json` into a
+//! [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log, which can
+//! be uploaded to GitHub code scanning and read by other static analysis tools.
+
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel, DiagnosticSpan};
+use cargo_metadata::Message;
+use serde_json::{json, Value};
+use std::io::BufRead;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const CLIPPY_URI: &str = "https://github.com/rust-lang/rust-clippy";
+const DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+/// A lint registered by Clippy, as printed by `clippy-driver --print-lint-metadata`.
+pub struct LintMetadata {
+    /// The name without the `clippy::` prefix
+    pub name: String,
+    pub group: Option<String>,
+    /// The default level, `allow`, `warn`, `deny` or `forbid`
+    pub level: String,
+    pub desc: String,
+}
+
+impl LintMetadata {
+    /// Parses the output of `clippy-driver --print-lint-metadata`, one JSON object per line.
+    pub fn parse_all(output: &str) -> Vec<Self> {
+        output
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter_map(|lint| {
+                Some(Self {
+                    name: lint["name"].as_str()?.to_string(),
+                    group: lint["group"].as_str().map(ToString::to_string),
+                    level: lint["level"].as_str()?.to_string(),
+                    desc: lint["desc"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn to_rule(&self) -> Value {
+        let level = match self.level.as_str() {
+            "deny" | "forbid" => "error",
+            "warn" => "warning",
+            _ => "none",
+        };
+        json!({
+            "id": format!("clippy::{}", self.name),
+            "name": self.name,
+            "shortDescription": { "text": self.desc },
+            "helpUri": format!("{}#{}", DOCS_LINK, self.name),
+            "defaultConfiguration": { "level": level },
+            "properties": {
+                "category": self.group,
+                "tags": self.group.iter().collect::<Vec<_>>(),
+            },
+        })
+    }
+}
+
+/// Reads the messages of Cargo from `input` and returns the SARIF log of the compiler diagnostics
+/// among them. The rule metadata of Clippy lints is taken from `lints`.
+pub fn convert(input: impl BufRead, lints: &[LintMetadata], version: &str) -> Value {
+    let mut results: Vec<Value> = Vec::new();
+    for message in Message::parse_stream(input).flatten() {
+        if let Message::CompilerMessage(message) = message {
+            if let Some(result) = to_result(&message.message, lints) {
+                // The diagnostics of a file are reported again for every target including it
+                if !results.contains(&result) {
+                    results.push(result);
+                }
+            }
+        }
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "informationUri": CLIPPY_URI,
+                    "version": version,
+                    "rules": lints.iter().map(LintMetadata::to_rule).collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Returns `None` for diagnostics without a location, like the summary of the emitted warnings.
+fn to_result(diag: &Diagnostic, lints: &[LintMetadata]) -> Option<Value> {
+    let locations: Vec<Value> = diag
+        .spans
+        .iter()
+        .filter(|span| span.is_primary)
+        .map(to_location)
+        .collect();
+    if locations.is_empty() {
+        return None;
+    }
+
+    let level = match diag.level {
+        DiagnosticLevel::Ice | DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        _ => "note",
+    };
+    let mut text = diag.message.clone();
+    for child in &diag.children {
+        let kind = match child.level {
+            DiagnosticLevel::Help => "help",
+            _ => "note",
+        };
+        text.push_str(&format!("\n{}: {}", kind, child.message));
+    }
+
+    let mut result = json!({
+        "level": level,
+        "message": { "text": text },
+        "locations": locations,
+    });
+    if let Some(code) = &diag.code {
+        result["ruleId"] = json!(code.code);
+        let rule_index = code
+            .code
+            .strip_prefix("clippy::")
+            .and_then(|name| lints.iter().position(|lint| lint.name == name));
+        if let Some(rule_index) = rule_index {
+            result["ruleIndex"] = json!(rule_index);
+        }
+    }
+    Some(result)
+}
+
+fn to_location(span: &DiagnosticSpan) -> Value {
+    // SARIF columns are 1-based like rustc's, but the end column is exclusive in both
+    json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": span.file_name.replace('\\', "/") },
+            "region": {
+                "startLine": span.line_start,
+                "startColumn": span.column_start,
+                "endLine": span.line_end,
+                "endColumn": span.column_end,
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_clippy_message() {
+        let lints = LintMetadata::parse_all(
+            r#"{"name":"needless_bool","group":"complexity","level":"warn","desc":"if-statements with plain booleans"}
+{"name":"eq_op","group":"correctness","level":"deny","desc":"equal operands on both sides"}"#,
+        );
+        assert_eq!(lints.len(), 2);
+
+        let input = r#"{"reason":"compiler-message","package_id":"foo 0.1.0 (path+file:///foo)","target":{"kind":["lib"],"crate_types":["lib"],"name":"foo","src_path":"/foo/src/lib.rs","edition":"2018","doctest":true,"test":true},"message":{"rendered":"","children":[{"children":[],"code":null,"level":"note","message":"`#[deny(clippy::eq_op)]` on by default","rendered":null,"spans":[]}],"code":{"code":"clippy::eq_op","explanation":null},"level":"error","message":"equal expressions as operands to `==`","spans":[{"byte_end":30,"byte_start":24,"column_end":13,"column_start":7,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":2,"line_start":2,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}
+{"reason":"build-finished","success":false}
+"#;
+        let sarif = convert(input.as_bytes(), &lints, "0.1.55");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "clippy::eq_op");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["level"],
+            "error"
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "clippy::eq_op");
+        assert_eq!(results[0]["ruleIndex"], 1);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["message"]["text"],
+            "equal expressions as operands to `==`\nnote: `#[deny(clippy::eq_op)]` on by default"
+        );
+        let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["startColumn"], 7);
+    }
+}
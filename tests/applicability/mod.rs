@@ -0,0 +1,235 @@
+//! Checks `//@applicability: <Applicability>` directives in UI tests against the applicability of
+//! the suggestions Clippy emits.
+//!
+//! A directive on a line of its own applies to every suggestion in the test file. A directive at
+//! the end of a line applies to the suggestions of the lints emitted on that line, and also
+//! requires that such a suggestion exists.
+//!
+//! Downgrading the applicability of a suggestion changes nothing in the `.stderr` files, but stops
+//! `cargo clippy --fix` from applying it. These directives make such downgrades fail the tests.
+
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DIRECTIVE: &str = "//@applicability:";
+const APPLICABILITIES: &[&str] = &["MachineApplicable", "MaybeIncorrect", "HasPlaceholders", "Unspecified"];
+
+#[derive(Deserialize)]
+pub struct Diagnostic {
+    pub spans: Vec<DiagnosticSpan>,
+    pub children: Vec<Diagnostic>,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub is_primary: bool,
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<String>,
+}
+
+impl Diagnostic {
+    /// Returns the line of the primary span, if there is one
+    fn line(&self) -> Option<usize> {
+        self.spans
+            .iter()
+            .find(|span| span.is_primary)
+            .map(|span| span.line_start)
+    }
+
+    /// Collects the applicability of all suggestions of this diagnostic and its children
+    fn applicabilities<'a>(&'a self, out: &mut Vec<&'a str>) {
+        for span in &self.spans {
+            if span.suggested_replacement.is_some() {
+                out.push(span.suggestion_applicability.as_deref().unwrap_or("Unspecified"));
+            }
+        }
+        for child in &self.children {
+            child.applicabilities(out);
+        }
+    }
+}
+
+#[derive(Default)]
+struct Directives {
+    /// The applicability every suggestion in the file should have
+    file: Option<String>,
+    /// The applicability the suggestions on a line should have, by 1-based line number
+    lines: Vec<(usize, String)>,
+}
+
+fn parse_directives(src: &str) -> Result<Directives, String> {
+    let mut directives = Directives::default();
+    for (idx, line) in src.lines().enumerate() {
+        let pos = match line.find(DIRECTIVE) {
+            Some(pos) => pos,
+            None => continue,
+        };
+        let applicability = line[pos + DIRECTIVE.len()..].trim();
+        if !APPLICABILITIES.contains(&applicability) {
+            return Err(format!(
+                "line {}: unknown applicability `{}`, expected one of {:?}",
+                idx + 1,
+                applicability,
+                APPLICABILITIES
+            ));
+        }
+
+        if line[..pos].trim().is_empty() {
+            if directives.file.is_some() {
+                return Err(format!(
+                    "line {}: duplicate file level applicability directive",
+                    idx + 1
+                ));
+            }
+            directives.file = Some(applicability.to_string());
+        } else {
+            directives.lines.push((idx + 1, applicability.to_string()));
+        }
+    }
+    Ok(directives)
+}
+
+/// Returns the flags from the `// compile-flags:` and `// edition:` headers of a test file.
+fn header_flags(src: &str) -> Vec<String> {
+    let mut flags = Vec::new();
+    for line in src.lines() {
+        if let Some(rest) = line.strip_prefix("// compile-flags:") {
+            flags.extend(rest.split_whitespace().map(ToString::to_string));
+        } else if let Some(edition) = line.strip_prefix("// edition:") {
+            flags.push(format!("--edition={}", edition.trim()));
+        }
+    }
+    flags
+}
+
+/// Runs Clippy on `input` with the flags of the UI test `test` and returns the JSON diagnostics
+/// it emits on stderr. `input` is usually `test` itself, but may be a modified copy of it.
+pub fn run_clippy(config: &compiletest_rs::Config, test: &Path, input: &Path, src: &str) -> String {
+    let aux_dir = config
+        .build_base
+        .join(test.strip_prefix(&config.src_base).unwrap())
+        .with_extension("stage-id.aux");
+    let out_dir = config.build_base.join("applicability");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let output = Command::new(&config.rustc_path)
+        .arg(input)
+        .args(
+            config
+                .target_rustcflags
+                .as_deref()
+                .unwrap_or_default()
+                .split_whitespace(),
+        )
+        .args(header_flags(src))
+        .arg("-L")
+        .arg(aux_dir)
+        .arg("--out-dir")
+        .arg(out_dir)
+        .arg("--error-format=json")
+        .output()
+        .expect("failed to run clippy-driver");
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn check_file(config: &compiletest_rs::Config, path: &Path, src: &str, directives: &Directives) -> Vec<String> {
+    let stderr = run_clippy(config, path, path, src);
+
+    let mut errors = Vec::new();
+    let mut found_lines = Vec::new();
+    for line in stderr.lines().filter(|line| line.starts_with('{')) {
+        let diag: Diagnostic = match serde_json::from_str(line) {
+            Ok(diag) => diag,
+            Err(e) => {
+                errors.push(format!("invalid JSON diagnostic ({}): {}", e, line));
+                continue;
+            },
+        };
+        let diag_line = match diag.line() {
+            Some(diag_line) => diag_line,
+            None => continue,
+        };
+        let mut applicabilities = Vec::new();
+        diag.applicabilities(&mut applicabilities);
+
+        let expected = directives
+            .lines
+            .iter()
+            .find(|(line, _)| *line == diag_line)
+            .map(|(_, applicability)| applicability)
+            .or_else(|| directives.file.as_ref());
+        if let Some(expected) = expected {
+            for found in &applicabilities {
+                if found != expected {
+                    errors.push(format!(
+                        "line {}: expected a suggestion with applicability `{}`, found `{}`",
+                        diag_line, expected, found
+                    ));
+                }
+            }
+        }
+        if !applicabilities.is_empty() {
+            found_lines.push(diag_line);
+        }
+    }
+
+    for (line, expected) in &directives.lines {
+        if !found_lines.contains(line) {
+            errors.push(format!(
+                "line {}: expected a suggestion with applicability `{}`, found none",
+                line, expected
+            ));
+        }
+    }
+
+    errors
+}
+
+/// Collects the files with the extension `extension` in `dir` and its subdirectories, except for
+/// auxiliary crates.
+pub fn collect_test_files(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if !path.ends_with("auxiliary") {
+                collect_test_files(&path, extension, files);
+            }
+        } else if path.extension().map_or(false, |ext| ext == extension) {
+            files.push(path);
+        }
+    }
+}
+
+/// Checks the applicability directives of all tests in `config.src_base`.
+pub fn run(config: &compiletest_rs::Config) {
+    let mut files = Vec::new();
+    collect_test_files(&config.src_base, "rs", &mut files);
+
+    let mut failures = Vec::new();
+    for path in &files {
+        let path_str = path.to_string_lossy();
+        if !config.filters.is_empty() && !config.filters.iter().any(|filter| path_str.contains(filter)) {
+            continue;
+        }
+        let src = fs::read_to_string(path).unwrap();
+        if !src.contains(DIRECTIVE) {
+            continue;
+        }
+
+        let errors = match parse_directives(&src) {
+            Ok(directives) => check_file(config, path, &src, &directives),
+            Err(error) => vec![error],
+        };
+        failures.extend(errors.into_iter().map(|error| format!("{}: {}", path.display(), error)));
+    }
+
+    if !failures.is_empty() {
+        panic!("applicability directives failed:\n{}", failures.join("\n"));
+    }
+}
@@ -10,7 +10,9 @@ use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
+mod applicability;
 mod cargo;
+mod roundtrip;
 
 // whether to run internal tests or not
 const RUN_INTERNAL_TESTS: bool = cfg!(feature = "internal-lints");
@@ -111,6 +113,8 @@ fn run_ui(cfg: &mut compiletest::Config) {
     // use tests/clippy.toml
     let _g = VarGuard::set("CARGO_MANIFEST_DIR", std::fs::canonicalize("tests").unwrap());
     compiletest::run_tests(cfg);
+    applicability::run(cfg);
+    roundtrip::run(cfg);
 }
 
 fn run_internal_tests(cfg: &mut compiletest::Config) {
@@ -123,6 +127,14 @@ fn run_internal_tests(cfg: &mut compiletest::Config) {
     compiletest::run_tests(cfg);
 }
 
+fn run_ui_json(cfg: &mut compiletest::Config) {
+    // The tests in this directory set `--error-format json` themselves. This makes compiletest
+    // compare the raw JSON diagnostics instead of the rendered messages.
+    cfg.mode = TestMode::Ui;
+    cfg.src_base = Path::new("tests").join("ui-json");
+    compiletest::run_tests(cfg);
+}
+
 fn run_ui_toml(config: &mut compiletest::Config) {
     fn run_tests(config: &compiletest::Config, mut tests: Vec<tester::TestDescAndFn>) -> Result<bool, io::Error> {
         let mut result = true;
@@ -276,6 +288,7 @@ fn compile_test() {
     prepare_env();
     let mut config = default_config();
     run_ui(&mut config);
+    run_ui_json(&mut config);
     run_ui_toml(&mut config);
     run_ui_cargo(&mut config);
     run_internal_tests(&mut config);
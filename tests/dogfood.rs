@@ -2,6 +2,8 @@
 //! long error messages
 //!
 //! See [Eating your own dog food](https://en.wikipedia.org/wiki/Eating_your_own_dog_food) for context
+//!
+//! `cargo dev dogfood` runs the same checks on selected crates, and can apply the suggestions
 
 // Dogfood cannot run on Windows
 #![cfg(not(windows))]
@@ -21,33 +23,8 @@ fn dogfood_clippy() {
     if cargo::is_rustc_test_suite() {
         return;
     }
-    let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-
-    let mut command = Command::new(&*CLIPPY_PATH);
-    command
-        .current_dir(root_dir)
-        .env("CLIPPY_DOGFOOD", "1")
-        .env("CARGO_INCREMENTAL", "0")
-        .arg("clippy")
-        .arg("--all-targets")
-        .arg("--all-features")
-        .arg("--")
-        .args(&["-D", "clippy::all"])
-        .args(&["-D", "clippy::pedantic"])
-        .arg("-Cdebuginfo=0"); // disable debuginfo to generate less data in the target dir
-
-    // internal lints only exist if we build with the internal-lints feature
-    if cfg!(feature = "internal-lints") {
-        command.args(&["-D", "clippy::internal"]);
-    }
 
-    let output = command.output().unwrap();
-
-    println!("status: {}", output.status);
-    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
-    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
-
-    assert!(output.status.success());
+    run_clippy_for_project(".");
 }
 
 fn test_no_deps_ignores_path_deps_in_workspaces() {
@@ -227,13 +204,14 @@ fn run_clippy_for_project(project: &str) {
         .arg("--all-targets")
         .arg("--all-features")
         .arg("--")
-        .args(&["-D", "clippy::all"])
-        .args(&["-D", "clippy::pedantic"])
         .arg("-Cdebuginfo=0"); // disable debuginfo to generate less data in the target dir
 
-    // internal lints only exist if we build with the internal-lints feature
-    if cfg!(feature = "internal-lints") {
-        command.args(&["-D", "clippy::internal"]);
+    // `cargo dev dogfood` denies the same lints
+    for lint in clippy_dev::dogfood::LINTS {
+        // internal lints only exist if we build with the internal-lints feature
+        if *lint != "clippy::internal" || cfg!(feature = "internal-lints") {
+            command.args(&["-D", lint]);
+        }
     }
 
     let output = command.output().unwrap();
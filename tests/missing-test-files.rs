@@ -22,31 +22,34 @@ fn test_missing_tests() {
 /*
 Test for missing files.
 
-Since rs files are alphabetically before stderr/stdout, we can sort by the full name
-and iter in that order. If we've seen the file stem for the first time and it's not
-a rust file, it means the rust file has to be missing.
+Every stderr/stdout file has to belong to a rust file with the same file stem. The reference files
+of test revisions have the revision as an additional extension, e.g. `foo.edition2018.stderr`
+belongs to `foo.rs`.
 */
 fn explore_directory(dir: &Path) -> Vec<String> {
     let mut missing_files: Vec<String> = Vec::new();
-    let mut current_file = String::new();
     let mut files: Vec<DirEntry> = fs::read_dir(dir).unwrap().filter_map(Result::ok).collect();
     files.sort_by_key(std::fs::DirEntry::path);
+    let test_files: Vec<String> = files
+        .iter()
+        .map(DirEntry::path)
+        .filter(|path| path.extension().map_or(false, |ext| ext == "rs"))
+        .map(|path| path.file_stem().unwrap().to_str().unwrap().to_string())
+        .collect();
     for entry in &files {
         let path = entry.path();
         if path.is_dir() {
             missing_files.extend(explore_directory(&path));
-        } else {
-            let file_stem = path.file_stem().unwrap().to_str().unwrap().to_string();
-            if let Some(ext) = path.extension() {
-                match ext.to_str().unwrap() {
-                    "rs" => current_file = file_stem.clone(),
-                    "stderr" | "stdout" => {
-                        if file_stem != current_file {
-                            missing_files.push(path.to_str().unwrap().to_string());
-                        }
-                    },
-                    _ => continue,
-                };
+        } else if let Some("stderr" | "stdout") = path.extension().and_then(|ext| ext.to_str()) {
+            let file_stem = path.file_stem().unwrap().to_str().unwrap();
+            let has_test_file = test_files.iter().any(|test| {
+                file_stem == test
+                    || file_stem
+                        .strip_prefix(test.as_str())
+                        .map_or(false, |revision| revision.starts_with('.'))
+            });
+            if !has_test_file {
+                missing_files.push(path.to_str().unwrap().to_string());
             }
         }
     }
@@ -0,0 +1,252 @@
+//! Checks that Clippy has no machine applicable suggestions left for the `.fixed` files of the UI
+//! tests, which contain the code after applying its suggestions. A suggestion that is still
+//! emitted for the fixed code means that `cargo clippy --fix` doesn't reach a fixed point.
+//!
+//! With `SUGGESTION_ROUNDS=<n>`, the remaining suggestions are applied again up to `n` times to
+//! tell whether they converge, or cycle between the same pieces of code, like two lints suggesting
+//! each other's code.
+//!
+//! Tests can opt out with a `//@no-roundtrip` comment, which ends up in the `.fixed` file.
+
+use crate::applicability::{collect_test_files, run_clippy, Diagnostic, DiagnosticSpan};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const IGNORE: &str = "//@no-roundtrip";
+
+/// The spans of a suggestion, which are applied together
+type Suggestion<'a> = Vec<&'a DiagnosticSpan>;
+
+fn parse_diagnostics(stderr: &str) -> Vec<Diagnostic> {
+    stderr
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("invalid JSON diagnostic ({}): {}", e, line)))
+        .collect()
+}
+
+/// Collects the machine applicable suggestions for `file_name` of `diag` and its children
+fn machine_applicable<'a>(diag: &'a Diagnostic, file_name: &str, out: &mut Vec<Suggestion<'a>>) {
+    let spans: Suggestion<'a> = diag
+        .spans
+        .iter()
+        .filter(|span| span.suggested_replacement.is_some())
+        .collect();
+    if !spans.is_empty()
+        && spans.iter().all(|span| {
+            span.file_name == file_name && span.suggestion_applicability.as_deref() == Some("MachineApplicable")
+        })
+    {
+        out.push(spans);
+    }
+    for child in &diag.children {
+        machine_applicable(child, file_name, out);
+    }
+}
+
+/// Applies `suggestions` to `src`. Like rustfix, suggestions overlapping one that was applied
+/// before are skipped.
+fn apply_suggestions(src: &str, suggestions: &[Suggestion<'_>]) -> String {
+    let mut replacements: Vec<(usize, usize, &str)> = Vec::new();
+    for suggestion in suggestions {
+        let overlaps = suggestion.iter().any(|span| {
+            replacements
+                .iter()
+                .any(|&(start, end, _)| span.byte_start < end && start < span.byte_end)
+        });
+        if !overlaps {
+            replacements.extend(suggestion.iter().map(|span| {
+                (
+                    span.byte_start,
+                    span.byte_end,
+                    span.suggested_replacement.as_deref().unwrap(),
+                )
+            }));
+        }
+    }
+    replacements.sort_by_key(|&(start, ..)| start);
+
+    let mut fixed = String::with_capacity(src.len());
+    let mut pos = 0;
+    for (start, end, replacement) in replacements {
+        fixed.push_str(&src[pos..start]);
+        fixed.push_str(replacement);
+        pos = end;
+    }
+    fixed.push_str(&src[pos..]);
+    fixed
+}
+
+/// Applies the suggestions for the copy of the `.fixed` file `path` again and again, and returns
+/// how that ends.
+fn check_cycles(config: &compiletest_rs::Config, path: &Path, src: &str, rounds: usize) -> String {
+    let copy = config
+        .build_base
+        .join("roundtrip")
+        .join(path.strip_prefix(&config.src_base).unwrap())
+        .with_extension("rs");
+    fs::create_dir_all(copy.parent().unwrap()).unwrap();
+    let file_name = copy.to_string_lossy();
+
+    find_cycle(src, rounds, |current| {
+        fs::write(&copy, current).unwrap();
+        let diagnostics = parse_diagnostics(&run_clippy(config, path, &copy, current));
+        let mut suggestions = Vec::new();
+        for diag in &diagnostics {
+            machine_applicable(diag, &file_name, &mut suggestions);
+        }
+        (!suggestions.is_empty()).then(|| apply_suggestions(current, &suggestions))
+    })
+}
+
+/// Calls `fix` with `src`, and then with the code it returns, up to `rounds` times, and describes
+/// how that ends. `fix` returns `None` if no suggestions remain for the code.
+fn find_cycle(src: &str, rounds: usize, mut fix: impl FnMut(&str) -> Option<String>) -> String {
+    let mut seen = vec![src.to_string()];
+    for round in 1..=rounds {
+        let fixed = match fix(seen.last().unwrap()) {
+            Some(fixed) => fixed,
+            None => {
+                return format!(
+                    "the suggestions converge after applying them {} more time(s)",
+                    round - 1
+                );
+            },
+        };
+
+        match seen.iter().position(|prev| *prev == fixed) {
+            Some(prev) if prev == round - 1 => return "the suggestions don't change the code".to_string(),
+            Some(prev) => {
+                return format!(
+                    "the suggestions cycle, applying them {} more time(s) results in the code after {} time(s)",
+                    round, prev
+                );
+            },
+            None => seen.push(fixed),
+        }
+    }
+    format!("the suggestions don't converge within {} rounds", rounds)
+}
+
+fn check_file(config: &compiletest_rs::Config, path: &Path, src: &str, rounds: Option<usize>) -> Option<String> {
+    let diagnostics = parse_diagnostics(&run_clippy(config, path, path, src));
+    let mut suggestions = Vec::new();
+    for diag in &diagnostics {
+        machine_applicable(diag, &path.to_string_lossy(), &mut suggestions);
+    }
+    if suggestions.is_empty() {
+        return None;
+    }
+
+    let mut lines: Vec<usize> = suggestions.iter().map(|spans| spans[0].line_start).collect();
+    lines.sort_unstable();
+    lines.dedup();
+    let mut error = format!(
+        "{} machine applicable suggestion(s) remain in the fixed code, on line(s) {:?}",
+        suggestions.len(),
+        lines
+    );
+    if let Some(rounds) = rounds {
+        error.push_str(", ");
+        error.push_str(&check_cycles(config, path, src, rounds));
+    }
+    Some(error)
+}
+
+/// Checks the `.fixed` files of all tests in `config.src_base`.
+pub fn run(config: &compiletest_rs::Config) {
+    let rounds = env::var("SUGGESTION_ROUNDS").ok().map(|rounds| {
+        rounds
+            .parse()
+            .expect("`SUGGESTION_ROUNDS` should be the number of times to apply the suggestions")
+    });
+
+    let mut files = Vec::new();
+    collect_test_files(&config.src_base, "fixed", &mut files);
+
+    let mut failures = Vec::new();
+    for path in &files {
+        let path_str = path.to_string_lossy();
+        if !config.filters.is_empty() && !config.filters.iter().any(|filter| path_str.contains(filter)) {
+            continue;
+        }
+        let src = fs::read_to_string(path).unwrap();
+        if src.contains(IGNORE) {
+            continue;
+        }
+
+        if let Some(error) = check_file(config, path, &src, rounds) {
+            failures.push(format!("{}: {}", path.display(), error));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("suggestion round-trips failed:\n{}", failures.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_suggestions, find_cycle};
+    use crate::applicability::DiagnosticSpan;
+
+    fn span(byte_start: usize, byte_end: usize, replacement: &str) -> DiagnosticSpan {
+        DiagnosticSpan {
+            file_name: "test.rs".to_string(),
+            byte_start,
+            byte_end,
+            line_start: 1,
+            is_primary: true,
+            suggested_replacement: Some(replacement.to_string()),
+            suggestion_applicability: Some("MachineApplicable".to_string()),
+        }
+    }
+
+    #[test]
+    fn apply() {
+        let src = "let x = a + b;";
+        let (a, b, plus, whole) = (span(8, 9, "c"), span(12, 13, "d"), span(10, 11, "-"), span(8, 13, "e"));
+        assert_eq!(apply_suggestions(src, &[vec![&b], vec![&a]]), "let x = c + d;");
+        // The spans of a suggestion are applied together
+        assert_eq!(apply_suggestions(src, &[vec![&plus, &b]]), "let x = a - d;");
+        // Like rustfix, overlapping suggestions are skipped
+        assert_eq!(apply_suggestions(src, &[vec![&a], vec![&whole]]), "let x = c + b;");
+        assert_eq!(apply_suggestions(src, &[vec![&whole], vec![&plus, &b]]), "let x = e;");
+        assert_eq!(apply_suggestions(src, &[]), src);
+    }
+
+    /// Runs `find_cycle` with the suggestions of the code given by `steps`
+    fn find_cycle_with(steps: &[(&str, &str)], rounds: usize) -> String {
+        find_cycle("a", rounds, |current| {
+            steps
+                .iter()
+                .find(|(from, _)| *from == current)
+                .map(|(_, to)| (*to).to_string())
+        })
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(
+            find_cycle_with(&[], 5),
+            "the suggestions converge after applying them 0 more time(s)"
+        );
+        assert_eq!(
+            find_cycle_with(&[("a", "b"), ("b", "c")], 5),
+            "the suggestions converge after applying them 2 more time(s)"
+        );
+        assert_eq!(
+            find_cycle_with(&[("a", "b"), ("b", "b")], 5),
+            "the suggestions don't change the code"
+        );
+        assert_eq!(
+            find_cycle_with(&[("a", "b"), ("b", "c"), ("c", "b")], 5),
+            "the suggestions cycle, applying them 3 more time(s) results in the code after 1 time(s)"
+        );
+        assert_eq!(
+            find_cycle_with(&[("a", "b"), ("b", "c"), ("c", "d")], 2),
+            "the suggestions don't converge within 2 rounds"
+        );
+    }
+}
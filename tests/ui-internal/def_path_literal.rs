@@ -0,0 +1,39 @@
+#![deny(clippy::internal)]
+#![feature(rustc_private)]
+
+extern crate clippy_utils;
+extern crate rustc_hir;
+extern crate rustc_lint;
+
+#[macro_use]
+extern crate rustc_session;
+use clippy_utils::{fn_def_id, match_def_path, paths};
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+
+declare_lint! {
+    pub TEST_LINT,
+    Warn,
+    ""
+}
+
+declare_lint_pass!(Pass => [TEST_LINT]);
+
+const DROP: [&str; 3] = ["core", "mem", "drop"];
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr) {
+        if let Some(def_id) = fn_def_id(cx, expr) {
+            let _ = match_def_path(cx, def_id, &["core", "ops", "Range", "contains"]);
+            let _ = match_def_path(cx, def_id, &["alloc", "vec", "Vec"]);
+
+            // Ok
+            let _ = match_def_path(cx, def_id, &paths::VEC);
+            let _ = match_def_path(cx, def_id, &DROP);
+            let _ = match_def_path(cx, def_id, &["clippy_utils", "paths"]);
+            let _ = [&["core", "mem", "drop"]];
+        }
+    }
+}
+
+fn main() {}
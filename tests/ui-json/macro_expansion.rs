@@ -0,0 +1,14 @@
+// compile-flags: --error-format json
+
+#![warn(clippy::zero_divided_by_zero)]
+
+macro_rules! nan {
+    () => {
+        0.0 / 0.0
+    };
+}
+
+fn main() {
+    // the lint is emitted on code produced by a local macro
+    let _ = nan!();
+}
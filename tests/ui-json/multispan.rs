@@ -0,0 +1,18 @@
+// compile-flags: --error-format json
+
+#![warn(clippy::if_same_then_else, clippy::match_same_arms)]
+#![allow(clippy::needless_bool)]
+
+fn main() {
+    let x = 1;
+
+    // primary span plus a secondary note span
+    let _ = if x > 0 { 1 } else { 1 };
+
+    // primary span, a labelled secondary span and a help span
+    let _ = match x {
+        0 => 10,
+        1 => 11,
+        _ => 10,
+    };
+}
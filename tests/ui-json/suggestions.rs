@@ -0,0 +1,29 @@
+// compile-flags: --error-format json
+
+#![warn(
+    clippy::needless_return,
+    clippy::bind_instead_of_map,
+    clippy::misrefactored_assign_op
+)]
+
+// single span, machine applicable
+fn single() -> u32 {
+    return 1;
+}
+
+// multipart suggestion
+fn multipart(x: Option<u32>) -> Option<u32> {
+    x.and_then(|v| if v > 2 { Some(v) } else { Some(v + 1) })
+}
+
+// suggestions that are not machine applicable
+fn maybe_incorrect(mut a: u32) -> u32 {
+    a += a + 1;
+    a
+}
+
+fn main() {
+    single();
+    multipart(None);
+    maybe_incorrect(1);
+}
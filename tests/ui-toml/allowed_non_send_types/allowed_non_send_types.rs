@@ -0,0 +1,28 @@
+#![warn(clippy::non_send_fields_in_send_ty)]
+
+use std::cell::Cell;
+use std::rc::{Rc, Weak};
+
+/// Only ever accessed from the thread which created it.
+pub struct ThreadBound {
+    count: Cell<usize>,
+    _not_send: *const (),
+}
+
+// shouldn't lint, both types are allowed in the configuration
+pub struct Handle {
+    thread_bound: ThreadBound,
+    shared: Rc<u8>,
+}
+
+unsafe impl Send for Handle {}
+
+// should lint, only `Rc` is allowed, not `Weak`
+pub struct WeakHandle {
+    thread_bound: ThreadBound,
+    weak: Weak<u8>,
+}
+
+unsafe impl Send for WeakHandle {}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![warn(clippy::arithmetic_side_effects)]
+
+use std::ops::Add;
+
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0.saturating_add(other.0))
+    }
+}
+
+struct Feet(u32);
+
+impl Add for Feet {
+    type Output = Feet;
+
+    fn add(self, other: Feet) -> Feet {
+        Feet(self.0.saturating_add(other.0))
+    }
+}
+
+fn main() {
+    let _ = Meters(1) + Meters(2);
+    let _ = Feet(1) + Feet(2);
+}
@@ -0,0 +1,14 @@
+// edition:2018
+#![warn(clippy::async_blocking_call)]
+
+use std::process::Command;
+
+async fn run() {
+    let _ = Command::new("ls").output();
+    let _ = Command::new("ls").spawn();
+    let _ = std::fs::read("foo.txt");
+}
+
+fn main() {
+    let _ = Command::new("ls").output();
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::match_same_arms)]
+
+fn small(x: u32) -> u32 {
+    match x {
+        1 => 10,
+        2 => 10,
+        _ => 0,
+    }
+}
+
+fn large(x: u32) -> u32 {
+    let a = x + 1;
+    let b = a + 2;
+    let c = b + 3;
+    let d = c + 4;
+    match d {
+        1 => 10,
+        2 => 10,
+        _ => 0,
+    }
+}
+
+fn main() {
+    small(1);
+    large(1);
+}
@@ -0,0 +1,20 @@
+// The lint levels are set in `clippy.toml`
+
+mod foo {
+    // `module_name_repetitions` is allowed, even though its group is enabled
+    pub struct FooBar;
+}
+
+fn to_u32(x: u8) -> u32 {
+    // `cast_lossless` is enabled by its group
+    x as u32
+}
+
+fn main() {
+    let _ = foo::FooBar;
+    let _ = to_u32(1);
+    // `single_char_pattern` is allowed
+    let _ = "a,b".split(",");
+    // `unused_variables` is allowed, even though the `unused` group of rustc is enabled
+    let unused = 1;
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::missing_panics_doc)]
+
+fn main() {}
+
+/// This is okay because `unwrap` is allowed
+pub fn unwrap() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because `expect` is allowed
+pub fn expect() {
+    let result = Err("Hi");
+    result.expect("this is an error")
+}
+
+/// This still needs to be documented
+pub fn panic() {
+    panic!("This function panics")
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::significant_drop_in_scrutinee)]
+
+struct Guard;
+
+impl Guard {
+    fn value(&self) -> u32 {
+        0
+    }
+}
+
+fn lock() -> Guard {
+    Guard
+}
+
+fn main() {
+    match lock().value() {
+        0 => println!("zero"),
+        _ => println!("other"),
+    }
+
+    // The configured path goes through a re-export
+    match Vec::<u32>::new().into_iter().next() {
+        Some(_) => println!("some"),
+        None => println!("none"),
+    }
+}
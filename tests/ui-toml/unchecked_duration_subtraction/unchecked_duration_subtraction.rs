@@ -0,0 +1,18 @@
+#![warn(clippy::unchecked_duration_subtraction)]
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let _ = Instant::now() - Duration::from_secs(1);
+}
+
+mod tests {
+    use std::time::{Duration, Instant};
+
+    fn in_tests() {
+        let start = Instant::now();
+        let _ = start - Duration::from_secs(1);
+        let _ = Instant::now() - start;
+    }
+}
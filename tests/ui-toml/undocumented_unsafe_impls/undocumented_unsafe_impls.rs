@@ -0,0 +1,10 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+struct S;
+
+unsafe impl Send for S {}
+
+// SAFETY: `S` has no fields
+unsafe impl Sync for S {}
+
+fn main() {}
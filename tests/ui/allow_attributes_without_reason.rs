@@ -0,0 +1,21 @@
+#![feature(lint_reasons)]
+#![warn(clippy::allow_attributes_without_reason)]
+#![allow(unused)]
+
+#[allow(clippy::needless_return)]
+fn bare() {}
+
+#[allow(clippy::needless_return, clippy::let_and_return)]
+fn bare_multiple() {}
+
+#[allow(clippy::needless_return, reason = "the lint is wrong here")]
+fn with_reason() {}
+
+// Only Clippy lints are checked
+#[allow(dead_code)]
+fn rustc_lint() {}
+
+#[warn(clippy::needless_return)]
+fn warn() {}
+
+fn main() {}
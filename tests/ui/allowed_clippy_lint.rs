@@ -0,0 +1,26 @@
+// compile-flags: --clippy-args=--warn-on-all-allows
+
+#[allow(clippy::needless_return, clippy::all)]
+fn main() {
+    attribute_on_statement();
+    other_attributes();
+    silenced::f();
+}
+
+fn attribute_on_statement() {
+    #[allow(clippy::needless_return)]
+    let _ = 1;
+}
+
+// Other attributes and lints aren't reported
+#[allow(unused_variables)]
+#[warn(clippy::needless_return)]
+fn other_attributes() {
+    let x = 1;
+}
+
+#[allow(clippy::allowed_clippy_lint)]
+mod silenced {
+    #[allow(clippy::needless_return)]
+    pub fn f() {}
+}
@@ -0,0 +1,46 @@
+#![warn(clippy::arithmetic_side_effects)]
+#![allow(clippy::op_ref)]
+
+use std::num::Wrapping;
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+const SIZE: usize = 4 * 1024;
+
+fn main() {
+    let mut n = 1u32;
+    let m = 2u32;
+    let i = -1i32;
+
+    // Lint
+    let _ = n + m;
+    let _ = n * 2;
+    let _ = n / m;
+    let _ = n << m;
+    let _ = -i;
+    let _ = &n - m;
+    let _ = n + m * 2;
+    n += 1;
+    let _ = Meters(1) + Meters(2);
+
+    // Don't lint
+    let _ = n / 2;
+    let _ = n % 3;
+    let _ = 1 + 2;
+    let _ = -1;
+    let _ = SIZE;
+    let _ = [0u8; 2 * 3];
+    let _ = 1.0 + 2.5;
+    let _ = Wrapping(n) + Wrapping(m);
+    let _ = String::new() + "a";
+}
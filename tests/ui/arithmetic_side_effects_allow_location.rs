@@ -0,0 +1,38 @@
+#![warn(clippy::arithmetic_side_effects)]
+
+// The note points at the innermost item enclosing the operation
+
+fn outer(n: i32) -> i32 {
+    fn nested(n: i32) -> i32 {
+        n + 1
+    }
+    let closure = |m: i32| m * n;
+    nested(n) - closure(2)
+}
+
+struct S(i32);
+
+impl S {
+    fn method(&self) -> i32 {
+        self.0 << 1
+    }
+}
+
+trait T {
+    fn provided(&self, n: i32) -> i32 {
+        n - 1
+    }
+}
+
+mod inner {
+    pub fn function(n: i32) -> i32 {
+        -n
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn allowed(n: i32) -> i32 {
+    n + 1
+}
+
+fn main() {}
@@ -2,7 +2,7 @@
 #[warn(clippy::misrefactored_assign_op, clippy::assign_op_pattern)]
 fn main() {
     let mut a = 5;
-    a += a + 1;
+    a += a + 1; //@applicability: MaybeIncorrect
     a += 1 + a;
     a -= a - 1;
     a *= a * 99;
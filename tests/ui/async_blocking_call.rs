@@ -0,0 +1,51 @@
+// edition:2018
+#![feature(async_closure)]
+#![warn(clippy::async_blocking_call)]
+#![allow(clippy::unused_async)]
+
+use std::fs::{self, File};
+use std::thread;
+use std::time::Duration;
+
+fn spawn_blocking<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+async fn blocking() {
+    let _ = fs::read_to_string("foo.txt");
+    let _ = std::fs::read("foo.txt");
+    let _ = File::open("foo.txt");
+    thread::sleep(Duration::from_millis(10));
+
+    async {
+        let _ = fs::remove_file("foo.txt");
+    }
+    .await;
+
+    let closure = async move || {
+        let _ = fs::create_dir_all("foo");
+    };
+    closure().await;
+}
+
+struct S;
+
+impl S {
+    async fn method(&self) {
+        let _ = fs::metadata("foo.txt");
+    }
+}
+
+async fn not_blocking() {
+    // runs on a blocking thread
+    let _ = spawn_blocking(|| fs::read_to_string("foo.txt"));
+    // trait methods aren't linted
+    let _ = std::io::Read::read(&mut std::io::empty(), &mut []);
+}
+
+fn not_async() {
+    let _ = fs::read_to_string("foo.txt");
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
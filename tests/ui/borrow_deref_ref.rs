@@ -0,0 +1,50 @@
+// run-rustfix
+
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::borrow_deref_ref)]
+
+fn len(s: &str) -> usize {
+    s.len()
+}
+
+fn main() {
+    let s: &str = "foo";
+    let a = &*s;
+    let n = len(&*s);
+
+    let v = vec![1, 2, 3];
+    let slice: &[i32] = &v;
+    for x in &*slice {}
+
+    // `&String` derefs to `&str`, but `&*x` is only a reborrow of `x`
+    let string = String::from("foo");
+    let x = &string;
+    let b: &String = &*x;
+
+    // no lint, `&**x` calls the `Deref` implementation of `String`
+    let c: &str = &**x;
+
+    // no lint, `&*string` calls the `Deref` implementation of `String`
+    let d: &str = &*string;
+
+    // no lint, reborrowing a mutable reference changes the mutability
+    let mut e = 5;
+    let f = &mut e;
+    let g: &i32 = &*f;
+
+    // no lint, `&mut x` would allow changing `x`
+    let h = &mut &*s;
+}
+
+mod issue_macro {
+    macro_rules! reborrow {
+        ($e:expr) => {
+            &*$e
+        };
+    }
+
+    fn f(s: &str) -> &str {
+        // no lint in macros
+        reborrow!(s)
+    }
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::cast_possible_truncation)]
+
+use std::convert::{TryFrom, TryInto};
+use std::error::Error;
+use std::num::TryFromIntError;
+
+fn try_from_int_error(x: u64) -> Result<u8, TryFromIntError> {
+    let y = x as u8;
+    Ok(y)
+}
+
+fn boxed_error(x: u64) -> Result<u16, Box<dyn Error>> {
+    let y: u16 = x as _;
+    Ok(y)
+}
+
+fn string_error(x: u64) -> Result<u8, String> {
+    // `String` can't be converted from `TryFromIntError`
+    Ok(x as u8)
+}
+
+fn in_closure(x: u64) -> Result<u8, TryFromIntError> {
+    // The `?` would apply to the closure
+    let f = || x as u8;
+    Ok(f())
+}
+
+fn no_result(x: i64) -> i32 {
+    (x + 1) as i32
+}
+
+fn float(x: f64) -> Result<i32, TryFromIntError> {
+    // There is no `TryFrom` implementation for floats
+    Ok(x as i32)
+}
+
+fn main() {}
@@ -133,8 +133,8 @@ fn bloo() {
     }
 }
 
-// Short circuiting operations don't increase the complexity of a function.
-// Note that the minimum complexity of a function is 1.
+// A sequence of the same boolean operator only adds one to the complexity.
+// Note that the minimum complexity of a function is 0.
 #[clippy::cognitive_complexity = "1"]
 fn lots_of_short_circuits() -> bool {
     true && false && true && false && true && false && true
@@ -393,3 +393,19 @@ impl Moo {
         }
     }
 }
+
+#[clippy::cognitive_complexity = "1"]
+fn mixed_boolean_operators(a: bool, b: bool, c: bool) -> bool {
+    a && b || c && !a
+}
+
+#[clippy::cognitive_complexity = "1"]
+fn nested_loops(v: &mut Vec<u32>) {
+    while let Some(x) = v.pop() {
+        loop {
+            if x > 1 {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::collapsible_str_replace)]
+
+fn main() {
+    let misspelled = "hesuo worpd";
+
+    let _ = misspelled.replace('s', "l").replace('u', "l");
+    let _ = misspelled.replace('s', "l").replace('u', "l").replace('p', "l");
+    let _ = String::from(misspelled).replace('s', "l").replace('u', "l");
+
+    // Only the calls with the same replacement are collapsed
+    let _ = misspelled.replace('s', "l").replace('u', "l").replace('p', "d");
+    let _ = misspelled.replace('p', "d").replace('s', "l").replace('u', "l");
+
+    // Don't lint
+    let s = 's';
+    let _ = misspelled.replace('s', "l");
+    let _ = misspelled.replace('s', "l").replace('u', "d");
+    let _ = misspelled.replace(s, "l").replace('u', "l");
+    let _ = misspelled.replace("su", "l").replace("po", "l");
+}
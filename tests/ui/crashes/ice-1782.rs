@@ -1,4 +1,4 @@
-#![allow(dead_code, unused_variables)]
+#![allow(dead_code, unused_variables, clippy::doc_on_private_imports)]
 
 /// Should not trigger an ICE in `SpanlessEq` / `consts::constant`
 ///
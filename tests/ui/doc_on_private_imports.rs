@@ -0,0 +1,38 @@
+#![warn(clippy::doc_on_private_imports)]
+#![allow(unused_imports, unused_extern_crates)]
+
+/// The standard allocator
+extern crate alloc;
+
+/// For the lookup table
+use std::collections::HashMap;
+
+/// Hash sets,
+/// and more
+use std::collections::{HashSet, VecDeque};
+
+mod inner {
+    /// Why not
+    use std::fmt::Debug;
+
+    /// Also private
+    #[allow(unused)]
+    use std::fmt::Display;
+}
+
+// These are fine
+
+/// Shows up as a re-export
+pub use std::collections::BTreeMap;
+
+/// Crate visible
+pub(crate) use std::collections::BTreeSet;
+
+// A regular comment
+use std::collections::LinkedList;
+
+#[allow(clippy::doc_on_private_imports)]
+/// Allowed
+use std::collections::BinaryHeap;
+
+fn main() {}
@@ -0,0 +1,46 @@
+// run-rustfix
+
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::double_ended_iterator_last)]
+
+struct DoubleEnded(u32);
+
+impl Iterator for DoubleEnded {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.checked_sub(1).map(|n| {
+            self.0 = n;
+            n
+        })
+    }
+
+    fn last(mut self) -> Option<u32> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for DoubleEnded {
+    fn next_back(&mut self) -> Option<u32> {
+        self.next()
+    }
+}
+
+fn last_word(s: &str) -> Option<&str> {
+    s.split(' ').last()
+}
+
+fn main() {
+    let last = "foo bar baz".split(' ').last();
+    let last = vec![1, 2, 3].into_iter().rev().last();
+
+    // no lint, `&str` patterns don't search from the back
+    let last = "foo::bar".split("::").last();
+
+    // no lint, `next_back` wouldn't call the closure for every item
+    let last = vec![1, 2, 3].into_iter().map(|x| x * 2).last();
+
+    // no lint, `last` is implemented with `next_back`
+    let last = [1, 2, 3].iter().last();
+    let last = DoubleEnded(3).last();
+}
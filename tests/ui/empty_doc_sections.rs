@@ -0,0 +1,56 @@
+#![warn(clippy::empty_doc_sections)]
+
+/// Reads a byte.
+///
+/// # Safety
+pub unsafe fn read(ptr: *const u8) -> u8 {
+    *ptr
+}
+
+/// Parses a number.
+///
+/// # Errors
+///
+/// # Panics
+///
+/// Never.
+pub fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+/// # Examples
+/// ## Basic usage
+/// ## Advanced usage
+///
+/// `foo(2)`
+pub fn foo(_: u32) {}
+
+// These are fine
+
+/// Reads a byte.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads.
+pub unsafe fn read_documented(ptr: *const u8) -> u8 {
+    *ptr
+}
+
+/// # Examples
+/// ## Basic usage
+///
+/// `bar(2)`
+pub fn bar(_: u32) {}
+
+/// # Examples
+///
+/// ```
+/// assert_eq!(1 + 1, 2);
+/// ```
+pub fn baz() {}
+
+#[allow(clippy::empty_doc_sections)]
+/// # Safety
+pub unsafe fn allowed() {}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::empty_drop)]
+#![allow(unused)]
+
+// should cause an error
+struct Foo;
+
+impl Drop for Foo {
+    fn drop(&mut self) {}
+}
+
+// shouldn't cause an error
+struct Bar;
+
+impl Drop for Bar {
+    fn drop(&mut self) {
+        println!("dropping bar!");
+    }
+}
+
+// should cause an error
+struct Wrapper<T>(T);
+
+impl<T> Drop for Wrapper<T> {
+    fn drop(&mut self) {}
+}
+
+// shouldn't cause an error, the impl is generated by a macro
+macro_rules! empty_drop {
+    ($name:ident) => {
+        struct $name;
+
+        impl Drop for $name {
+            fn drop(&mut self) {}
+        }
+    };
+}
+
+empty_drop!(Baz);
+
+fn main() {}
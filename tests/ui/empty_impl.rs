@@ -0,0 +1,36 @@
+// run-rustfix
+
+#![warn(clippy::empty_impl)]
+#![allow(dead_code)]
+
+struct Foo;
+
+impl Foo {}
+
+impl Foo {
+    fn new() -> Self {
+        Foo
+    }
+}
+
+struct Generic<T>(T);
+
+impl<T> Generic<T> {}
+
+// shouldn't cause an error, the only item is removed by `cfg`
+impl Foo {
+    #[cfg(any())]
+    fn removed() {}
+}
+
+// shouldn't cause an error, the block only contains a comment
+impl Foo {
+    // TODO
+}
+
+// shouldn't cause an error, trait implementations are needed even without items
+trait Marker {}
+
+impl Marker for Foo {}
+
+fn main() {}
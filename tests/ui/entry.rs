@@ -156,4 +156,19 @@ fn btree_map<K: Eq + Ord + Copy, V: Copy>(m: &mut BTreeMap<K, V>, k: K, v: V, v2
     }
 }
 
+fn panicking_values(m: &mut HashMap<u32, u32>, k: u32, a: u32, b: u32, v: &[u32], i: usize) {
+    // the value may panic, use or_insert_with(..)
+    if !m.contains_key(&k) {
+        m.insert(k, a / b);
+    }
+    if !m.contains_key(&k) {
+        m.insert(k, v[i]);
+    }
+
+    // or_insert(..)
+    if !m.contains_key(&k) {
+        m.insert(k, a & b);
+    }
+}
+
 fn main() {}
@@ -0,0 +1,40 @@
+//@applicability: MaybeIncorrect
+#![allow(unused, clippy::needless_pass_by_value)]
+#![warn(clippy::map_entry)]
+
+use std::collections::HashMap;
+
+// the key is moved into the entry, but used again
+fn key_used(m: &mut HashMap<String, usize>, k: String) {
+    if !m.contains_key(&k) {
+        let len = k.len();
+        m.insert(k, len);
+    }
+}
+
+// the entry still borrows the map in the `else` branch
+fn map_used_in_else(m: &mut HashMap<String, usize>, k: String) -> usize {
+    if !m.contains_key(&k) {
+        m.insert(k, 0);
+        0
+    } else {
+        m.len()
+    }
+}
+
+// the occupied entry still borrows the map after the insertion
+fn map_used_after_insert(m: &mut HashMap<String, usize>, k: String) {
+    if m.contains_key(&k) {
+        m.insert(k, 1);
+        let _ = m.len();
+    }
+}
+
+// ok, the value is computed from the map
+fn map_used_in_value(m: &mut HashMap<String, usize>, k: String) {
+    if !m.contains_key(&k) {
+        m.insert(k, m.len());
+    }
+}
+
+fn main() {}
@@ -60,4 +60,10 @@ fn main() {
 
     // issue #2840
     let num = 0.000_000_000_01e-10f64;
+
+    // Trailing zeros and exactly representable values
+    let good_zeros32: f32 = 0.100_000_000_000;
+    let good_zeros64: f64 = 1.500_000_000_000_000_000_000;
+    let good_exact32: f32 = 0.100_000_001_490_116_119_384_765_625;
+    let good_exact64: f64 = 0.100_000_000_000_000_005_551_115_123_125_782_702_118_158_340_454_101_562_5;
 }
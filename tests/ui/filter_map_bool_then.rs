@@ -0,0 +1,29 @@
+// run-rustfix
+
+#![warn(clippy::filter_map_bool_then)]
+
+fn main() {
+    let v = vec![1, 2, 3, 4];
+    let _: Vec<i32> = v.clone().into_iter().filter_map(|x| (x % 2 == 0).then(|| x * 2)).collect();
+    let _: Vec<i32> = v.iter().filter_map(|x| (*x > 1).then(|| *x - 1)).collect();
+    let _: Vec<i32> = v.iter().filter_map(|x| x.is_positive().then(|| x + 1)).collect();
+
+    let words = vec![String::from("a"), String::new()];
+    let _: Vec<usize> = words.into_iter().filter_map(|s| (!s.is_empty()).then(|| s.len())).collect(); //@applicability: MaybeIncorrect
+
+    // `filter(..).map(..)` would be fused into `filter_map` again
+    let _: Vec<i32> = v
+        .iter()
+        .filter_map(|x| x.checked_sub(1).is_some().then(|| x.checked_sub(1).unwrap()))
+        .collect();
+    let numbers = vec!["1", "a"];
+    let _: Vec<i32> = numbers
+        .iter()
+        .filter_map(|s| s.parse::<i32>().is_ok().then(|| s.parse::<i32>().unwrap()))
+        .collect();
+
+    // Don't lint
+    let _: Vec<i32> = v.iter().filter_map(|&x| (x > 1).then(|| x)).collect();
+    let _: Vec<i32> = v.iter().filter_map(|x| x.checked_sub(1)).collect();
+    let _ = Some(1).map(|x| (x > 1).then(|| x));
+}
@@ -0,0 +1,37 @@
+// run-rustfix
+#![warn(clippy::fn_to_numeric_cast_any)]
+#![allow(clippy::fn_to_numeric_cast, clippy::fn_to_numeric_cast_with_truncation)]
+
+fn foo() -> u8 {
+    0
+}
+
+fn bar() -> u8 {
+    1
+}
+
+fn generic<T: Default>() -> T {
+    T::default()
+}
+
+fn main() {
+    // function items
+    let _ = foo as usize;
+    let _ = foo as i32;
+    let _ = generic::<u32> as u64;
+
+    // function pointers
+    let f: fn() -> u8 = foo;
+    let _ = f as usize;
+
+    // comparisons
+    let _ = foo as usize == bar as usize;
+    let _ = foo as usize != f as usize;
+
+    // ok, not an integer
+    let _ = foo as fn() -> u8;
+    let _ = foo as *const ();
+
+    // ok, explicitly cast to a function pointer first
+    let _ = foo as fn() -> u8 as usize;
+}
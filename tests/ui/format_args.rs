@@ -0,0 +1,63 @@
+// run-rustfix
+
+#![warn(clippy::to_string_in_format_args)]
+
+use std::io::{stdout, Write};
+use std::ops::Deref;
+use std::panic::Location;
+
+struct Somewhere;
+
+impl ToString for Somewhere {
+    fn to_string(&self) -> String {
+        String::from("somewhere")
+    }
+}
+
+struct X(u32);
+
+impl Deref for X {
+    type Target = u32;
+
+    fn deref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+fn main() {
+    let x = &X(1);
+    let s = "str";
+
+    let _ = format!("error: something failed at {}", Location::caller().to_string());
+    let _ = write!(
+        stdout(),
+        "error: something failed at {}",
+        Location::caller().to_string()
+    );
+    let _ = writeln!(
+        stdout(),
+        "error: something failed at {}",
+        Location::caller().to_string()
+    );
+    print!("error: something failed at {}", Location::caller().to_string());
+    println!("error: something failed at {}", Location::caller().to_string());
+    eprint!("error: something failed at {}", Location::caller().to_string());
+    eprintln!("error: something failed at {}", Location::caller().to_string());
+    let _ = format_args!("error: something failed at {}", Location::caller().to_string());
+    println!("{}", s.to_string());
+    println!("{} and {}", 1.to_string(), 'a'.to_string());
+
+    // Not `Display`, or not through `ToString::to_string`
+    println!("error: something failed at {}", Somewhere.to_string());
+    // The receiver is auto-dereferenced
+    println!("{}", x.to_string());
+    // Formatting affects the output
+    println!("{:>8}", Location::caller().to_string());
+    println!("{:.3}", Location::caller().to_string());
+    // Other formatting traits
+    println!("{:?}", Location::caller().to_string());
+    // The same value is formatted twice
+    println!("{0} {0:?}", Location::caller().to_string());
+    // Not a formatting macro
+    assert!(x.0 > 0, "error: something failed at {}", Location::caller().to_string());
+}
@@ -0,0 +1,24 @@
+#![allow(clippy::assertions_on_constants)]
+#![warn(clippy::format_in_format_args)]
+
+use std::io::{stdout, Write};
+use std::panic::Location;
+
+fn main() {
+    let error = Location::caller();
+
+    println!("error: {}", format!("something failed at {}", Location::caller()));
+    println!("{}: {}", error, format!("something failed at {}", Location::caller()));
+    let _ = format!("error: {}", format!("something failed at {}", Location::caller()));
+    let _ = write!(
+        stdout(),
+        "error: {}",
+        format!("something failed at {}", Location::caller())
+    );
+    eprintln!("error: {}", format!("something failed at {}", Location::caller()));
+
+    // Formatting affects the output
+    println!("error: {:>50}", format!("something failed at {}", Location::caller()));
+    // Not a formatting macro
+    assert!(true, "error: {}", format!("something failed at {}", Location::caller()));
+}
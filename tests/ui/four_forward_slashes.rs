@@ -0,0 +1,47 @@
+// run-rustfix
+#![warn(clippy::four_forward_slashes)]
+#![allow(dead_code)]
+
+//// whoops
+fn a() {}
+
+//// whoops
+#[inline]
+fn b() {}
+
+/// a doc comment
+//// whoops
+/// that continues here
+fn c() {}
+
+#[inline]
+//// whoops
+fn d() {}
+
+struct S;
+
+impl S {
+    //// whoops
+    fn e() {}
+}
+
+// These are fine
+
+/// a doc comment
+fn f() {}
+
+// a regular comment
+fn g() {}
+
+/////////////////// a separator
+fn h() {}
+
+//// not directly before an item
+
+fn i() {}
+
+#[allow(clippy::four_forward_slashes)]
+//// allowed
+fn j() {}
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, clippy::empty_impl)]
 #![warn(clippy::multiple_inherent_impl)]
 
 struct MyStruct;
@@ -128,4 +128,14 @@ async fn foo() -> bool {
     true
 }
 
+fn divergent_operand_test(c: bool) -> bool {
+    // `c` is always returned before `!` is applied
+    !(return c, true).1
+}
+
+fn maybe_divergent_operand_test(c: bool) -> bool {
+    // only panics if `c` is `true`
+    c && panic!()
+}
+
 fn main() {}
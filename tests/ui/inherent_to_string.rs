@@ -15,6 +15,7 @@ struct D;
 struct E;
 struct F;
 struct G;
+struct H<T>(T);
 
 impl A {
     // Should be detected; emit warning
@@ -81,6 +82,19 @@ impl G {
     }
 }
 
+impl<T> H<T> {
+    // Should be detected and emit error as H also implements Display, if `T` does
+    fn to_string(&self) -> String {
+        "H.to_string()".to_string()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for H<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "impl Display for H: {}", self.0)
+    }
+}
+
 fn main() {
     let a = A;
     a.to_string();
@@ -104,4 +118,7 @@ fn main() {
 
     let g = G;
     g.to_string::<1>();
+
+    let h = H(1);
+    h.to_string();
 }
@@ -0,0 +1,93 @@
+#![warn(clippy::iter_not_returning_iterator)]
+#![allow(dead_code)]
+
+struct Data {
+    begin: u32,
+}
+
+struct Counter {
+    count: u32,
+}
+
+impl Data {
+    fn iter(&self) -> Counter {
+        todo!()
+    }
+
+    fn iter_mut(&self) -> Counter {
+        todo!()
+    }
+}
+
+struct Data2 {
+    values: Vec<u32>,
+}
+
+struct Counter2 {
+    count: u32,
+}
+
+impl Data2 {
+    fn iter(&self) -> Counter2 {
+        todo!()
+    }
+
+    fn iter_mut(&self) -> Counter2 {
+        todo!()
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        todo!()
+    }
+}
+
+type CounterAlias = Counter;
+
+struct Data3 {
+    values: Vec<u32>,
+}
+
+impl Data3 {
+    // ok, `impl Iterator`
+    fn iter(&self) -> impl Iterator<Item = &u32> {
+        self.values.iter()
+    }
+
+    // ok, type alias of an `Iterator`
+    fn iter_mut(&mut self) -> CounterAlias {
+        todo!()
+    }
+}
+
+struct Data4 {
+    values: Vec<u32>,
+}
+
+impl Data4 {
+    // ok, `IntoIterator`
+    fn iter(&self) -> &Vec<u32> {
+        &self.values
+    }
+
+    // not a method
+    fn iter_mut() -> u32 {
+        0
+    }
+}
+
+trait Iter {
+    fn iter(&self) -> u32;
+}
+
+// ok, the signature is decided by the trait
+impl Iter for Data4 {
+    fn iter(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {}
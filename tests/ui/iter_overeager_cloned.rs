@@ -0,0 +1,32 @@
+// run-rustfix
+#![warn(clippy::iter_overeager_cloned)]
+#![allow(dead_code)]
+
+fn main() {
+    let vec = vec!["1".to_string(), "2".to_string(), "3".to_string()];
+
+    let _: Option<String> = vec.iter().cloned().last();
+
+    let _: Option<String> = vec.iter().chain(vec.iter()).cloned().next();
+
+    let _: usize = vec.iter().filter(|x| x == &"2").cloned().count();
+
+    let _: Vec<_> = vec.iter().cloned().take(2).collect();
+
+    let _: Vec<_> = vec.iter().cloned().skip(2).collect();
+
+    let _ = vec.iter().filter(|x| x == &"2").cloned().nth(2);
+
+    // Not machine applicable, the closure gets `&&String` afterwards
+    let _: Vec<String> = vec.iter().cloned().filter(|x| x.len() == 1).collect();
+
+    let _: Option<String> = vec.iter().cloned().find(|x| x.len() == 1);
+
+    // Should not lint
+    let _: Vec<String> = vec.iter().cloned().collect();
+    let _ = Some(&vec[0]).cloned().filter(|x| x.len() == 1);
+}
+
+fn cloned_in_generic<'a, T: Clone + 'a>(it: impl Iterator<Item = &'a T>) -> Option<T> {
+    it.cloned().last()
+}
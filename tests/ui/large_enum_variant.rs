@@ -1,3 +1,4 @@
+// run-rustfix
 // aux-build:macro_rules.rs
 
 #![allow(dead_code)]
@@ -56,6 +57,46 @@ enum LargeEnumOk {
     LargeB([i32; 8001]),
 }
 
+enum LargeEnumWithGenerics<T> {
+    Small,
+    Large(T, [u8; 1000]),
+}
+
+enum WithConstructors {
+    Small(u8),
+    Large([u8; 1000]),
+}
+
+fn make_large() -> WithConstructors {
+    WithConstructors::Large([0; 1000])
+}
+
+enum WithStructConstructors {
+    Small(u8),
+    Large { data: [u8; 1000], tag: u8 },
+}
+
+fn make_large_struct(data: [u8; 1000]) -> WithStructConstructors {
+    let tag = 0;
+    if data[0] == 0 {
+        WithStructConstructors::Large { data, tag }
+    } else {
+        WithStructConstructors::Large { data: [1; 1000], tag }
+    }
+}
+
+// Patterns which don't bind the boxed fields don't have to change
+fn tag(value: &WithStructConstructors) -> u8 {
+    match value {
+        WithStructConstructors::Small(tag) => *tag,
+        WithStructConstructors::Large { data: _, tag } => *tag,
+    }
+}
+
+fn is_large(value: &WithConstructors) -> bool {
+    matches!(value, WithConstructors::Large(_))
+}
+
 fn main() {
     large_enum_variant!();
 }
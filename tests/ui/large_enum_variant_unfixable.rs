@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+#![warn(clippy::large_enum_variant)]
+
+enum WithBinding {
+    Small(u8),
+    Large { data: [u8; 1000] },
+}
+
+// The binding would have to be dereferenced once the field is boxed
+fn data(value: &WithBinding) -> Option<[u8; 1000]> {
+    match value {
+        WithBinding::Small(_) => None,
+        WithBinding::Large { data } => Some(*data),
+    }
+}
+
+fn main() {}
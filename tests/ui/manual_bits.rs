@@ -0,0 +1,45 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_bits)]
+#![allow(clippy::no_effect, path_statements, unused_must_use, clippy::unnecessary_operation)]
+
+use std::mem::{size_of, size_of_val};
+
+fn main() {
+    size_of::<i8>() * 8;
+    size_of::<i16>() * 8;
+    size_of::<i32>() * 8;
+    size_of::<i64>() * 8;
+    size_of::<i128>() * 8;
+    size_of::<isize>() * 8;
+
+    size_of::<u8>() * 8;
+    size_of::<u16>() * 8;
+    size_of::<u32>() * 8;
+    size_of::<u64>() * 8;
+    size_of::<u128>() * 8;
+    size_of::<usize>() * 8;
+
+    8 * size_of::<i8>();
+    8 * std::mem::size_of::<u64>();
+
+    size_of::<usize>() * 4;
+    4 * size_of::<usize>();
+    size_of::<bool>() * 8;
+    8 * size_of::<bool>();
+    size_of_val(&0u32) * 8;
+
+    let _ = size_of::<u32>() * 8 < 64;
+    let _ = 1usize << (size_of::<u8>() * 8 - 1);
+}
+
+fn msrv_1_52() {
+    #![clippy::msrv = "1.52"]
+    size_of::<u32>() * 8;
+}
+
+fn msrv_1_53() {
+    #![clippy::msrv = "1.53"]
+    size_of::<u32>() * 8;
+}
@@ -0,0 +1,109 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_clamp)]
+#![allow(unused, clippy::min_max)]
+
+use std::cmp::{max, min};
+
+const LOW: i32 = -10;
+const HIGH: i32 = 10;
+
+fn main() {
+    let x = 42i32;
+
+    let _ = max(min(x, 100), 0);
+    let _ = min(max(x, 0), 100);
+    let _ = max(0, min(100, x));
+    let _ = std::cmp::min(std::cmp::max(0, x), 100);
+    let _ = x.max(0).min(100);
+    let _ = x.min(100).max(0);
+    let _ = max(x.min(100), 0);
+    let _ = max(min(x, HIGH), LOW);
+
+    let _ = if x < 0 {
+        0
+    } else if x > 100 {
+        100
+    } else {
+        x
+    };
+    let _ = if x > 100 {
+        100
+    } else if 0 > x {
+        0
+    } else {
+        x
+    };
+    let _ = if 100 <= x {
+        100
+    } else if x <= 0 {
+        0
+    } else {
+        x
+    };
+    let _ = if x - 1 < 0 {
+        0
+    } else if x - 1 > 100 {
+        100
+    } else {
+        x - 1
+    };
+
+    // no clamping
+    let _ = max(min(x, 0), 100);
+    let _ = min(min(x, 100), 0);
+    let _ = max(min(x, 100), 100);
+    let _ = max(min(x, HIGH), x);
+    let _ = if x < 0 {
+        0
+    } else if x < 100 {
+        100
+    } else {
+        x
+    };
+    let _ = if x < 0 {
+        1
+    } else if x > 100 {
+        100
+    } else {
+        x
+    };
+    let _ = if x < 0 {
+        0
+    } else if x > 100 {
+        100
+    } else {
+        x + 1
+    };
+    let _ = if x < 0 {
+        0
+    } else if x == 100 {
+        100
+    } else {
+        x
+    };
+
+    // `f32` isn't `Ord`
+    let f = 1.0f32;
+    let _ = f.max(0.0).min(1.0);
+    let _ = if f < 0.0 {
+        0.0
+    } else if f > 1.0 {
+        1.0
+    } else {
+        f
+    };
+}
+
+fn msrv_1_49() {
+    #![clippy::msrv = "1.49"]
+    let x = 42i32;
+    let _ = x.max(0).min(100);
+}
+
+fn msrv_1_50() {
+    #![clippy::msrv = "1.50"]
+    let x = 42i32;
+    let _ = x.max(0).min(100);
+}
@@ -0,0 +1,80 @@
+#![allow(unused)]
+#![warn(clippy::manual_let_else)]
+
+// The lint only runs with `#![feature(let_else)]`, which the compiler doesn't know yet, so none of
+// these are linted. Enable the feature and bless the output once it's available.
+
+fn main() {}
+
+fn lint_match(opt: Option<u32>) {
+    let x = match opt {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut x = match opt {
+        None => panic!("no value"),
+        Some(v) => v,
+    };
+
+    let x = match opt {
+        Some(v) => v,
+        None => { /* nothing to do */ return },
+    };
+}
+
+fn lint_if_let(res: Result<u32, ()>) {
+    let x = if let Ok(v) = res { v } else { return; };
+}
+
+fn lint_loop(opts: &[Option<u32>]) {
+    for &opt in opts {
+        let x = match opt {
+            Some(v) => v,
+            None => continue,
+        };
+        let y = if let Some(v) = opt { v } else { break };
+    }
+}
+
+fn lint_comment(opt: Option<u32>) {
+    let x = match opt {
+        // only keep the value
+        Some(v) => v,
+        None => return,
+    };
+}
+
+fn no_lint(opt: Option<u32>, res: Result<u32, String>) -> Result<u32, String> {
+    // the diverging arm uses a binding
+    let x = match res {
+        Ok(v) => v,
+        Err(e) => panic!("{}", e),
+    };
+
+    // the binding is modified
+    let x = match opt {
+        Some(v) => v + 1,
+        None => return Ok(0),
+    };
+
+    // more than one binding
+    let x = match Some((1, 2)) {
+        Some((a, _b)) => a,
+        None => return Ok(0),
+    };
+
+    // guard
+    let x = match opt {
+        Some(v) if v > 1 => v,
+        _ => return Ok(0),
+    };
+
+    // type annotation
+    let x: u32 = match opt {
+        Some(v) => v,
+        None => return Ok(0),
+    };
+
+    Ok(0)
+}
@@ -0,0 +1,50 @@
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_retain)]
+#![allow(unused)]
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+fn main() {
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().filter(|&x| x % 2 == 0).copied().collect();
+
+    let mut deque: VecDeque<_> = (0..3).collect();
+    deque = deque.into_iter().filter(|x| x % 2 == 0).collect();
+
+    let mut hash_set: HashSet<_> = (0..3).collect();
+    hash_set = hash_set.into_iter().filter(|x| x % 2 == 0).collect();
+
+    let mut btree_set: BTreeSet<_> = (0..3).collect();
+    btree_set = btree_set.iter().filter(|&x| x % 2 == 0).cloned().collect();
+
+    let mut hash_map: HashMap<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    hash_map = hash_map.into_iter().filter(|(k, v)| *k % 2 == 0 && *v > 0).collect();
+
+    let mut btree_map: BTreeMap<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    btree_map = btree_map.into_iter().filter(|&(k, v)| k != v).collect();
+
+    let mut s = String::from("foo bar");
+    s = s.chars().filter(|&c| c != 'o').collect();
+
+    // Ok
+    let other = vec![1, 2];
+    vec = other.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.into_iter().map(|x| x + 1).filter(|x| x % 2 == 0).collect();
+    let _: Vec<i32> = vec.iter().filter(|&x| x % 2 == 0).copied().collect();
+    // The closure takes a `&char`, and can't be rewritten
+    s = s.chars().filter(|c| *c != 'o').collect();
+    hash_map = hash_map.into_iter().filter(|kv| kv.0 > 0).collect();
+}
+
+fn msrv_1_52() {
+    #![clippy::msrv = "1.52"]
+    let mut btree_map: BTreeMap<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    btree_map = btree_map.into_iter().filter(|(k, _)| *k > 0).collect();
+}
+
+fn msrv_1_53() {
+    #![clippy::msrv = "1.53"]
+    let mut btree_map: BTreeMap<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    btree_map = btree_map.into_iter().filter(|(k, _)| *k > 0).collect();
+}
@@ -0,0 +1,25 @@
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_split_once)]
+
+fn main() {
+    let s = "key=value";
+    let _ = s.split('=').next().unwrap();
+    let _ = "key=>value".split("=>").next().unwrap();
+    let _ = String::from(s).split('=').next().unwrap();
+
+    // not `str::split`
+    let _ = [1, 2, 3].split(|&x| x == 2).next().unwrap();
+    let _ = s.split('=').nth(1).unwrap();
+    let _ = s.splitn(2, '=').next().unwrap();
+    let _ = s.split('=').next();
+}
+
+fn msrv_1_51() {
+    #![clippy::msrv = "1.51"]
+    let _ = "key=value".split('=').next().unwrap();
+}
+
+fn msrv_1_52() {
+    #![clippy::msrv = "1.52"]
+    let _ = "key=value".split('=').next().unwrap();
+}
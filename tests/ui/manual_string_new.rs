@@ -0,0 +1,44 @@
+// run-rustfix
+// aux-build:macro_rules.rs
+
+#![warn(clippy::manual_string_new)]
+#![allow(clippy::string_add, unused)]
+
+#[macro_use]
+extern crate macro_rules;
+
+macro_rules! empty_string {
+    () => {
+        "".to_string()
+    };
+}
+
+struct Foo {
+    s: String,
+}
+
+impl Default for Foo {
+    fn default() -> Self {
+        Self { s: "".into() }
+    }
+}
+
+fn main() {
+    let _ = "".to_string();
+    let _ = "".to_owned();
+    let _: String = "".into();
+    let _ = String::from("");
+    let _ = <String as From<&str>>::from("");
+    let _ = Foo { s: "".to_string() };
+
+    #[allow(clippy::useless_format)]
+    let _ = format!("");
+
+    // Don't lint
+    let _ = String::new();
+    let _ = " ".to_string();
+    let _ = String::from("foo");
+    let _: Box<str> = "".into();
+    let _ = empty_string!();
+    string_add!();
+}
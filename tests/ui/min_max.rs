@@ -1,4 +1,5 @@
 #![warn(clippy::all)]
+#![allow(clippy::manual_clamp, clippy::needless_late_init)]
 
 use std::cmp::max as my_max;
 use std::cmp::min as my_min;
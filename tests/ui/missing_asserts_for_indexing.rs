@@ -0,0 +1,59 @@
+#![warn(clippy::missing_asserts_for_indexing)]
+
+fn sum_without_assert(s: &[u32]) -> u32 {
+    s[0] + s[1] + s[2]
+}
+
+fn sum_with_assert(s: &[u32]) -> u32 {
+    assert!(s.len() > 2);
+    s[0] + s[1] + s[2]
+}
+
+fn sum_with_assert_ge(s: &[u32]) -> u32 {
+    assert!(3 <= s.len());
+    s[0] + s[1] + s[2]
+}
+
+fn sum_with_assert_eq(v: Vec<u32>) -> u32 {
+    assert_eq!(v.len(), 3);
+    v[0] + v[1] + v[2]
+}
+
+fn sum_with_short_assert(s: &[u32]) -> u32 {
+    assert!(s.len() > 1);
+    s[0] + s[1] + s[2]
+}
+
+fn assert_after_indexing(s: &[u32]) -> u32 {
+    let sum = s[0] + s[1];
+    assert!(s.len() > 1);
+    sum
+}
+
+fn vec_without_assert(v: Vec<u32>) -> u32 {
+    v[0] + v[1]
+}
+
+// Indexing arrays with constants isn't checked at runtime
+fn array(a: [u32; 3]) -> u32 {
+    a[0] + a[1] + a[2]
+}
+
+fn same_index(s: &[u32]) -> u32 {
+    s[0] + s[0]
+}
+
+fn different_slices(a: &[u32], b: &[u32]) -> u32 {
+    a[0] + b[1]
+}
+
+fn nested_block(s: &[u32]) -> u32 {
+    let first = s[0];
+    let rest = {
+        assert!(s.len() > 2);
+        s[1] + s[2]
+    };
+    first + rest
+}
+
+fn main() {}
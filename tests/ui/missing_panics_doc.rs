@@ -152,3 +152,19 @@ pub fn debug_assertions() {
     debug_assert_eq!(1, 2);
     debug_assert_ne!(1, 2);
 }
+
+/// This needs to be documented
+pub fn expect() {
+    let result = Err("Hi");
+    result.expect("this is an error")
+}
+
+/// This is documented
+///
+/// # Panics
+///
+/// Panics if `result` is an error
+pub fn expect_documented() {
+    let result = Err("Hi");
+    result.expect("this is an error")
+}
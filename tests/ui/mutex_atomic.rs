@@ -1,8 +1,34 @@
 #![warn(clippy::all)]
 #![warn(clippy::mutex_integer)]
+#![warn(clippy::mutex_unit)]
+#![allow(dead_code)]
+
+use std::sync::{Mutex, RwLock};
+
+struct Fields {
+    flag: Mutex<bool>,
+    count: RwLock<u64>,
+    signal: Mutex<()>,
+    data: Mutex<Vec<u8>>,
+}
+
+impl Fields {
+    // The fields are only linted at their definition
+    fn new() -> Self {
+        Self {
+            flag: Mutex::new(false),
+            count: RwLock::new(0),
+            signal: Mutex::new(()),
+            data: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn is_set(&self) -> bool {
+        *self.flag.lock().unwrap()
+    }
+}
 
 fn main() {
-    use std::sync::Mutex;
     Mutex::new(true);
     Mutex::new(5usize);
     Mutex::new(9isize);
@@ -12,4 +38,7 @@ fn main() {
     Mutex::new(0u32);
     Mutex::new(0i32);
     Mutex::new(0f32); // there are no float atomics, so this should not lint
+    RwLock::new(true);
+    RwLock::new(0i32);
+    Mutex::new(());
 }
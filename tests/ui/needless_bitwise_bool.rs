@@ -24,8 +24,20 @@ fn main() {
     if y & !x {
         println!("true")
     }
+    if y & (0 < 1) {
+        println!("true") // This is a BinOp with no side effects
+    }
+    if y & ([x][0] == y) {
+        println!("true") // Indexing may panic if the index is out of bounds
+    }
+    if y & !Wrapper(x).0 {
+        println!("true") // Neither has calling a constructor
+    }
+    if y & (Wrapper(x) == Wrapper(y)) {
+        println!("true") // `==` calls `PartialEq::eq` of `Wrapper`
+    }
 
-    // BELOW: lints we hope to catch as `Expr::can_have_side_effects` improves.
+    // BELOW: lints we hope to catch as the check for side effects improves.
     if y & !const_returns_bool() {
         println!("true") // This is a const function, in an UnOp
     }
@@ -33,8 +45,7 @@ fn main() {
     if y & "abcD".is_empty() {
         println!("true") // This is a const method call
     }
-
-    if y & (0 < 1) {
-        println!("true") // This is a BinOp with no side effects
-    }
 }
+
+#[derive(PartialEq)]
+struct Wrapper(bool);
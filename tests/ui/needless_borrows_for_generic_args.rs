@@ -0,0 +1,67 @@
+// run-rustfix
+
+#![warn(clippy::needless_borrows_for_generic_args)]
+
+use std::borrow::Borrow;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::path::Path;
+
+fn exists<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref().exists()
+}
+
+fn takes_borrow<T: Borrow<u32>>(x: T) -> u32 {
+    *x.borrow()
+}
+
+fn takes_display<T: Display>(x: T) -> String {
+    x.to_string()
+}
+
+fn returns_arg<S: AsRef<str>>(s: S) -> S {
+    s
+}
+
+fn same_type<S: AsRef<str>>(_: S, _: S) {}
+
+fn takes_ref<S: AsRef<str> + ?Sized>(_: &S) {}
+
+fn takes_deref<T: Deref<Target = String>>(x: T) -> usize {
+    x.len()
+}
+
+fn takes_any_deref<T: Deref>(_: T) {}
+
+struct Opener;
+
+impl Opener {
+    fn open<P: AsRef<Path>>(&self, path: P) -> bool {
+        path.as_ref().exists()
+    }
+}
+
+fn main() {
+    let s = "Cargo.toml";
+    let string = String::from("Cargo.toml");
+
+    let _ = exists(&"Cargo.toml");
+    let _ = exists(&s);
+    let _ = exists(&String::from("Cargo.toml"));
+    let _ = takes_borrow(&1u32);
+    let _ = Opener.open(&s);
+
+    // Moves `string`
+    let _ = exists(&string);
+    let _ = string.len();
+    // Doesn't implement `AsRef`, `Borrow` or `Deref`
+    let _ = takes_display(&1);
+    // Changes the return type
+    let _ = returns_arg(&s);
+    // Changes the type of the other argument
+    same_type(&s, &s);
+    takes_ref(&s);
+    // The reference derefs to another type than the value
+    let _ = takes_deref(&string);
+    takes_any_deref(&Box::new(1));
+}
@@ -81,3 +81,27 @@ fn allow_test() {
     let v = [1].iter().collect::<Vec<_>>();
     v.into_iter().collect::<HashSet<_>>();
 }
+
+fn lint_set_is_empty(sample: &[i32]) {
+    let set: HashSet<_> = sample.iter().collect();
+    if set.is_empty() {}
+}
+
+fn dont_lint_set_len(sample: &[i32]) -> usize {
+    let set: HashSet<_> = sample.iter().collect();
+    set.len()
+}
+
+fn dont_lint_use_in_loop(sample: &[i32]) {
+    let v: Vec<_> = sample.iter().collect();
+    for _ in 0..3 {
+        if v.is_empty() {}
+    }
+}
+
+fn dont_lint_source_used_in_between() -> usize {
+    let mut sample = vec![1, 2, 3];
+    let v: Vec<_> = sample.drain(..).collect();
+    sample.push(4);
+    v.len()
+}
@@ -0,0 +1,113 @@
+// run-rustfix
+
+#![warn(clippy::needless_default_impl)]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+impl Default for Foo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Foo {
+    names: Vec<String>,
+    count: usize,
+    ratio: f64,
+    enabled: bool,
+    label: &'static str,
+    parent: Option<Box<Foo>>,
+    cache: HashMap<u32, String>,
+    pair: (u8, i32),
+}
+
+impl Foo {
+    fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            count: 0,
+            ratio: 0.0,
+            enabled: false,
+            label: "",
+            parent: None,
+            cache: HashMap::default(),
+            pair: (0, 0),
+        }
+    }
+}
+
+mod inner {
+    impl Default for Bar {
+        fn default() -> Self {
+            Bar::new()
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Bar {
+        value: u32,
+    }
+
+    impl Bar {
+        pub fn new() -> Bar {
+            Bar {
+                value: Default::default(),
+            }
+        }
+    }
+}
+
+// shouldn't cause an error, `new` doesn't use the default value
+struct NonDefault {
+    count: usize,
+}
+
+impl NonDefault {
+    fn new() -> Self {
+        Self { count: 1 }
+    }
+}
+
+impl Default for NonDefault {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// shouldn't cause an error, the derived implementation would require `T: Default`
+struct Generic<T> {
+    values: Vec<T>,
+}
+
+impl<T> Generic<T> {
+    fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<T> Default for Generic<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// shouldn't cause an error, `default` does more than calling `new`
+struct Logged {
+    count: usize,
+}
+
+impl Logged {
+    fn new() -> Self {
+        Self { count: 0 }
+    }
+}
+
+impl Default for Logged {
+    fn default() -> Self {
+        println!("creating a default `Logged`");
+        Self::new()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![allow(unused)]
+
+fn main() {
+    let a;
+    let n = 1;
+    match n {
+        1 => a = "one",
+        _ => {
+            a = "two";
+        },
+    }
+
+    let b;
+    if n == 3 {
+        b = "four";
+    } else {
+        b = "five"
+    }
+
+    let c;
+    if n == 4 {
+        c = 1;
+    } else {
+        return;
+    }
+
+    // don't lint: the `if` has no `else` branch
+    let d;
+    if n == 5 {
+        d = 1;
+    }
+
+    // don't lint: the branches assign other bindings too
+    let e;
+    let f;
+    if n == 6 {
+        f = 1;
+        e = 2;
+    } else {
+        f = 3;
+        e = 4;
+    }
+
+    // don't lint: declaring `g` after `h` changes the drop order
+    let g: String;
+    let h = String::new();
+    g = h.clone();
+
+    // don't lint: the binding is mutable
+    let mut i;
+    i = 1;
+    i += 1;
+}
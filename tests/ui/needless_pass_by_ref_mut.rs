@@ -0,0 +1,73 @@
+#![warn(clippy::needless_pass_by_ref_mut)]
+#![allow(clippy::ptr_arg)]
+
+// Should warn
+fn read_only(s: &mut Vec<u32>) -> usize {
+    s.len()
+}
+
+// Only `s` should warn
+fn mutates_one(s: &mut Vec<u32>, x: &mut u32) {
+    *x += s.len() as u32;
+}
+
+// Should warn, the lifetime is kept
+fn with_lifetime<'a>(s: &'a mut Vec<u32>) -> &'a u32 {
+    &s[0]
+}
+
+// Should warn, the closure only reads `x`
+fn read_in_closure(x: &mut u32) -> u32 {
+    let f = || *x + 1;
+    f()
+}
+
+// Should not warn
+fn push(v: &mut Vec<u32>) {
+    v.push(1);
+}
+
+fn mutate_in_closure(x: &mut u32) {
+    let mut f = || *x += 1;
+    f();
+}
+
+fn reborrow(v: &mut Vec<u32>) {
+    push(v);
+}
+
+fn returns_it(x: &mut u32) -> &mut u32 {
+    x
+}
+
+fn get_mut(v: &mut Vec<u32>) -> Option<&mut u32> {
+    v.get_mut(0)
+}
+
+fn unused(_x: &mut u32) {}
+
+struct Foo(u32);
+
+impl Foo {
+    // Should warn
+    fn get(&mut self) -> u32 {
+        self.0
+    }
+
+    fn set(&mut self, x: u32) {
+        self.0 = x;
+    }
+}
+
+trait Bar {
+    fn bar(&mut self, x: &mut u32) -> u32;
+}
+
+impl Bar for Foo {
+    // The signature is given by the trait
+    fn bar(&mut self, x: &mut u32) -> u32 {
+        self.0 + *x
+    }
+}
+
+fn main() {}
@@ -1,5 +1,6 @@
 // run-rustfix
 // edition:2018
+//@applicability: MachineApplicable
 
 #![allow(unused)]
 #![allow(
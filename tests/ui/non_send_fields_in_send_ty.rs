@@ -0,0 +1,51 @@
+#![warn(clippy::non_send_fields_in_send_ty)]
+
+use std::rc::Rc;
+
+// should lint, `Rc` can't be sent to another thread
+pub struct SharedHandle {
+    name: String,
+    shared: Rc<String>,
+}
+
+unsafe impl Send for SharedHandle {}
+
+// should lint, `T` needs a `Send` bound
+pub struct Wrapper<T> {
+    value: T,
+}
+
+unsafe impl<T> Send for Wrapper<T> {}
+
+// shouldn't lint, the field is `Send` with the bound of the `impl`
+pub struct BoundedWrapper<T> {
+    value: T,
+}
+
+unsafe impl<T: Send> Send for BoundedWrapper<T> {}
+
+// shouldn't lint, raw pointers to types which are `Send` are assumed to be owned
+pub struct RawBuffer<T> {
+    ptr: *mut u8,
+    len: usize,
+    items: Vec<*const T>,
+}
+
+unsafe impl<T: Send> Send for RawBuffer<T> {}
+
+// should lint, the pointer points to an `Rc`
+pub struct RcPointer {
+    ptr: *const Rc<u8>,
+}
+
+unsafe impl Send for RcPointer {}
+
+// should lint, the fields of every variant are checked
+pub enum Either {
+    Left(Rc<u8>),
+    Right(u8),
+}
+
+unsafe impl Send for Either {}
+
+fn main() {}
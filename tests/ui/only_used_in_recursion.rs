@@ -0,0 +1,77 @@
+#![warn(clippy::only_used_in_recursion)]
+
+fn simple(a: usize, b: usize) -> usize {
+    if a == 0 { 1 } else { simple(a - 1, b) }
+}
+
+fn with_calc(a: usize, b: isize) -> usize {
+    if a == 0 { 1 } else { with_calc(a - 1, -b + 1) }
+}
+
+fn tuple((a, b): (usize, usize)) -> usize {
+    if a == 0 { 1 } else { tuple((a - 1, b + 1)) }
+}
+
+fn let_tuple(a: usize, b: usize) -> usize {
+    let (c, d) = (a, b);
+    if c == 0 { 1 } else { let_tuple(c - 1, d + 1) }
+}
+
+fn really_used(a: usize, b: usize) -> usize {
+    if a == 0 { b } else { really_used(a - 1, b) }
+}
+
+fn swapped(a: usize, b: usize) -> usize {
+    if a == 0 { 1 } else { swapped(b, a - 1) }
+}
+
+fn multiple(a: usize, b: usize, c: &str) -> usize {
+    if a == 0 {
+        1
+    } else if a == 1 {
+        multiple(a - 1, b, c)
+    } else {
+        multiple(a - 2, b, c)
+    }
+}
+
+fn in_closure(a: usize, b: usize) -> usize {
+    let f = |x: usize| in_closure(x, b);
+    if a == 0 { 1 } else { f(a - 1) }
+}
+
+fn underscore(a: usize, _b: usize) -> usize {
+    if a == 0 { 1 } else { underscore(a - 1, _b) }
+}
+
+struct A;
+
+impl A {
+    fn method(&self, a: usize, b: usize) -> usize {
+        if a == 0 { 1 } else { self.method(a - 1, b) }
+    }
+
+    fn associated(a: usize, b: usize) -> usize {
+        if a == 0 { 1 } else { Self::associated(a - 1, b) }
+    }
+
+    fn unused_self(&self, a: usize) -> usize {
+        if a == 0 { 1 } else { self.unused_self(a - 1) }
+    }
+}
+
+trait B {
+    fn trait_method(&self, a: usize, b: usize) -> usize;
+
+    fn default_method(&self, a: usize, b: usize) -> usize {
+        if a == 0 { 1 } else { self.default_method(a - 1, b) }
+    }
+}
+
+impl B for A {
+    fn trait_method(&self, a: usize, b: usize) -> usize {
+        if a == 0 { 1 } else { self.trait_method(a - 1, b) }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+// run-rustfix
+
+#![warn(clippy::ptr_cast_constness)]
+#![feature(custom_inner_attributes)]
+
+fn main() {
+    let ptr: *const u32 = &42_u32;
+    let mut_ptr: *mut u32 = &mut 42_u32;
+
+    let _ = ptr as *mut i32;
+    let _ = mut_ptr as *const i32;
+
+    // Make sure the lint can handle the difference in their operator precedences.
+    unsafe {
+        let ptr_ptr: *const *const u32 = &ptr;
+        let _ = *ptr_ptr as *mut i32;
+    }
+
+    // The pointee type is inferred from the context
+    let _: *mut i32 = ptr as *mut _;
+    let _: *const i32 = mut_ptr as _;
+
+    // The pointee type is written like in the cast
+    type Int = i32;
+    let _ = ptr as *mut Int;
+
+    // Only the mutability or only the pointee type changes. Do not lint this.
+    let _ = ptr as *mut u32;
+    let _ = mut_ptr as *const u32;
+    let _ = ptr as *const i32;
+    let _ = mut_ptr as *mut i32;
+
+    // `pointer::cast` cannot perform unsized coercions unlike `as`. Do not lint this.
+    let mut_ptr_of_array: *mut [u32; 4] = &mut [1, 2, 3, 4];
+    let _ = mut_ptr_of_array as *const [u32];
+}
+
+fn _msrv_1_37() {
+    #![clippy::msrv = "1.37"]
+    let ptr: *const u32 = &42_u32;
+    let mut_ptr: *mut u32 = &mut 42_u32;
+
+    // `pointer::cast` was stabilized in 1.38. Do not lint this
+    let _ = ptr as *mut i32;
+    let _ = mut_ptr as *const i32;
+}
+
+fn _msrv_1_38() {
+    #![clippy::msrv = "1.38"]
+    let ptr: *const u32 = &42_u32;
+    let mut_ptr: *mut u32 = &mut 42_u32;
+
+    let _ = ptr as *mut i32;
+    let _ = mut_ptr as *const i32;
+}
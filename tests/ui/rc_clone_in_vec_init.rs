@@ -0,0 +1,24 @@
+#![warn(clippy::rc_clone_in_vec_init)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak as RcWeak};
+use std::sync::{Arc, Mutex};
+
+fn main() {}
+
+fn should_warn() {
+    let v = vec![Rc::new(RefCell::new(1)); 2];
+    let v = vec![Arc::new(Mutex::new("x".to_string())); 3];
+    let v = vec![RcWeak::<u32>::new(); 2];
+}
+
+fn should_not_warn() {
+    // The sharing is explicit
+    let data = Rc::new(1);
+    let v = vec![data; 2];
+
+    // Cloning a `RefCell` clones its value
+    let v = vec![RefCell::new(1); 2];
+
+    let v = vec![Rc::new(1), Rc::new(2)];
+}
@@ -0,0 +1,75 @@
+// edition:2018
+
+#![allow(unused, clippy::shadow_unrelated)]
+#![warn(clippy::redundant_locals)]
+
+macro_rules! rebind {
+    ($x:ident) => {
+        let $x = $x;
+    };
+}
+
+fn parameter(x: i32) {
+    let x = x;
+}
+
+fn mutable_parameter(mut x: i32) {
+    // no lint, assignments to the redefinition don't affect the parameter
+    let mut x = x;
+}
+
+fn same_scope() {
+    let a = 1;
+    let a = a;
+
+    let mut b = 1;
+    let mut b = b;
+    b += 1;
+}
+
+fn changes() {
+    // no lint, changes the mutability
+    let c = 1;
+    let mut c = c;
+
+    // no lint, has a type annotation
+    let d = 1;
+    let d: i32 = d;
+
+    // no lint, keeps assignments from affecting the outer binding
+    let mut e = 1;
+    {
+        let mut e = e;
+        e += 1;
+    }
+
+    // no lint, changes when the value is dropped
+    let s = String::new();
+    let s = s;
+}
+
+fn closure() {
+    // no lint, moves `f` into the closure
+    let f = 1;
+    let g = || {
+        let f = f;
+        f + 1
+    };
+
+    let h = || {
+        let i = 1;
+        let i = i;
+        i + 1
+    };
+}
+
+fn macros() {
+    let j = 1;
+    rebind!(j);
+}
+
+async fn async_parameter(x: i32) -> i32 {
+    x
+}
+
+fn main() {}
@@ -55,4 +55,9 @@ fn main() {
 
     if let Ready(_) = Ready(String::new()) {}
     if let Ready(_) = Ready((String::new(), ())) {}
+
+    // The tuple holding the lock is only dropped after the `if let`
+    if let Some(_) = (m.lock(), Some(0)).1 {}
+    // The lock is moved into `ok` and `map`, and released in the closure
+    if let Some(_) = m.lock().ok().map(|guard| guard.0) {}
 }
@@ -0,0 +1,33 @@
+// run-rustfix
+#![feature(custom_inner_attributes)]
+#![warn(clippy::seek_from_current)]
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+
+fn lint(file: &mut File) -> io::Result<()> {
+    let _ = file.seek(SeekFrom::Current(0))?;
+    let _ = file.seek(SeekFrom::Current(0i64)).unwrap();
+    Ok(())
+}
+
+fn no_lint(file: &mut File) -> io::Result<()> {
+    file.seek(SeekFrom::Current(1))?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(b"")?;
+    Ok(())
+}
+
+fn msrv_1_50(file: &mut File) -> io::Result<()> {
+    #![clippy::msrv = "1.50"]
+    let _ = file.seek(SeekFrom::Current(0))?;
+    Ok(())
+}
+
+fn msrv_1_51(file: &mut File) -> io::Result<()> {
+    #![clippy::msrv = "1.51"]
+    let _ = file.seek(SeekFrom::Current(0))?;
+    Ok(())
+}
+
+fn main() {}
@@ -1,5 +1,6 @@
 #![warn(clippy::semicolon_if_nothing_returned)]
 #![feature(label_break_value)]
+#![allow(clippy::needless_late_init)]
 
 fn get_unit() {}
 
@@ -10,6 +10,7 @@
     unused_variables,
     clippy::manual_unwrap_or,
     clippy::missing_docs_in_private_items,
+    clippy::needless_late_init,
     clippy::single_match
 )]
 
@@ -0,0 +1,70 @@
+#![warn(clippy::significant_drop_in_scrutinee)]
+#![allow(clippy::single_match, clippy::match_single_binding)]
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+fn main() {}
+
+fn mutex(mutex: &Mutex<Vec<u32>>) {
+    match mutex.lock().unwrap().len() {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+
+    if let Some(x) = mutex.lock().unwrap().first() {
+        println!("{}", x);
+    }
+
+    while let Some(x) = mutex.lock().unwrap().pop() {
+        println!("{}", x);
+    }
+
+    // Ok, the guard is bound and used in the arms
+    if let Ok(mut guard) = mutex.lock() {
+        guard.push(1);
+    }
+
+    // Ok, the guard is moved into the binding
+    match mutex.lock().unwrap() {
+        guard => println!("{:?}", *guard),
+    }
+
+    // Ok, the temporary is dropped at the end of the `let` statement
+    let len = mutex.lock().unwrap().len();
+    match len {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+
+    // Ok, the temporary is dropped at the end of the closure
+    match (|| mutex.lock().unwrap().len())() {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+}
+
+fn rwlock(lock: &RwLock<Option<u32>>) {
+    match *lock.read().unwrap() {
+        Some(_) => *lock.write().unwrap() = None,
+        None => {},
+    }
+}
+
+struct State {
+    count: u32,
+}
+
+fn ref_cell(cell: &RefCell<State>) {
+    match cell.borrow().count {
+        0 => cell.borrow_mut().count = 1,
+        _ => {},
+    }
+
+    // Ok, `count` is copied out of the temporary before the `match`
+    let count = cell.borrow().count;
+    match count {
+        0 => cell.borrow_mut().count = 1,
+        _ => {},
+    }
+}
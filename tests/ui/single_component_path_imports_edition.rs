@@ -0,0 +1,18 @@
+// revisions: edition2015 edition2018
+//[edition2018] edition:2018
+#![warn(clippy::single_component_path_imports)]
+#![allow(unused_imports)]
+
+// The import is only redundant since the 2018 edition, where the paths of `use` items aren't
+// relative to the crate root anymore
+mod m {
+    use std;
+
+    pub fn max() -> u32 {
+        std::u32::MAX
+    }
+}
+
+fn main() {
+    m::max();
+}
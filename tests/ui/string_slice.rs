@@ -0,0 +1,32 @@
+#![warn(clippy::string_slice)]
+
+fn main() {
+    let s = "Ölkanne";
+    let string = String::from(s);
+
+    // Lint
+    let _ = &s[0..1];
+    let _ = &s[1..];
+    let _ = &string[..2];
+    let _ = &s[..=s.len()];
+    let i = 2;
+    let _ = &s[i..];
+
+    // Ok: the bounds are char boundaries
+    let _ = &s[..];
+    let _ = &s[0..s.len()];
+    let _ = &string[..string.len()];
+    let _ = &s[s.find('k').unwrap()..];
+    let pos = s.rfind("ne").expect("no `ne`");
+    let _ = &s[..pos];
+    let _ = &s[pos + 2..];
+    if let Some(colon) = s.find(':') {
+        let _ = &s[..colon];
+        let _ = &s[colon + 1..];
+    }
+
+    // Lint: the offset doesn't match the length of the pattern
+    if let Some(a) = s.find('Ö') {
+        let _ = &s[a + 1..];
+    }
+}
@@ -0,0 +1,28 @@
+// run-rustfix
+
+#![warn(clippy::swap_ptr_to_ref)]
+
+fn main() {
+    let mut x = 0u32;
+    let mut y = 1u32;
+    let xp: *mut u32 = &mut x;
+    let yp: *mut u32 = &mut y;
+    let mut arr = [0u32; 4];
+    let ap = arr.as_mut_ptr();
+
+    unsafe {
+        core::mem::swap(&mut *xp, &mut *yp);
+        core::mem::swap(&mut *xp.add(0), &mut *xp.add(0));
+        core::mem::swap(&mut *ap.add(2).sub(1), &mut *ap.add(1));
+        core::mem::swap(&mut *ap.add(1), &mut *yp.add(2));
+    }
+
+    // Don't lint
+    unsafe {
+        core::mem::swap(&mut *xp, &mut y);
+        let _ = core::mem::replace(&mut *xp, 2);
+        core::mem::swap(&mut *ap, &mut *ap.add(1));
+        core::mem::swap(&mut *ap.add(1), &mut *ap.add(3).sub(1));
+    }
+    core::mem::swap(&mut x, &mut y);
+}
@@ -28,4 +28,48 @@ where
     unimplemented!();
 }
 
+fn bad_where_clause<T>(arg: T)
+where
+    T: Clone + Default,
+    T: Clone,
+{
+    unimplemented!();
+}
+
+fn bad_generic_params<T: Clone + Clone>(arg: T) {
+    unimplemented!();
+}
+
+fn bad_path<T: std::clone::Clone>(arg: T)
+where
+    T: Clone,
+{
+    unimplemented!();
+}
+
+fn bad_generic_args<T: From<u8>>(arg: T)
+where
+    T: From<u8>,
+{
+    unimplemented!();
+}
+
+fn good_generic_args<T: From<u8>>(arg: T)
+where
+    T: From<u16>,
+{
+    unimplemented!();
+}
+
+trait Foo {
+    type Item;
+}
+
+fn good_associated_type<T: Foo + Clone>(arg: T)
+where
+    T::Item: Clone,
+{
+    unimplemented!();
+}
+
 fn main() {}
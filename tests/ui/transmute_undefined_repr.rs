@@ -0,0 +1,40 @@
+#![warn(clippy::transmute_undefined_repr)]
+#![allow(dead_code)]
+
+use core::marker::PhantomData;
+use core::mem::transmute;
+
+struct Rust(u32, u32);
+
+#[repr(C)]
+struct C(u32, u32);
+
+#[repr(transparent)]
+struct Transparent(u64, PhantomData<u8>);
+
+#[repr(u8)]
+enum Fieldless {
+    A,
+    B,
+}
+
+struct Empty;
+
+fn main() {
+    unsafe {
+        let _: u64 = transmute(Rust(0, 0)); // Lint, the field order isn't guaranteed
+        let _: Rust = transmute(0u64); // Lint
+        let _: (u32, u32) = transmute(Rust(0, 0)); // Lint, both have an undefined layout
+        let _: Vec<i8> = transmute(Vec::<u8>::new()); // Lint, generic types may be laid out differently
+        let _: (usize, usize) = transmute(&[0u8][..]); // Lint, fat pointer
+
+        let _: u64 = transmute(C(0, 0));
+        let _: [u32; 2] = transmute(C(0, 0));
+        let _: C = transmute(Transparent(0, PhantomData));
+        let _: u8 = transmute(Fieldless::A);
+        let _: Empty = transmute(());
+        let _: usize = transmute(Some(&0u8));
+        let _: usize = transmute(Box::new(0u8));
+        let _: Option<fn()> = transmute(0usize);
+    }
+}
@@ -69,4 +69,14 @@ mod issue4326 {
     }
 }
 
+// All bounds are combined, including `?Sized` and lifetimes
+pub fn baz<T>(_t: &T)
+where
+    T: ?Sized,
+    T: std::fmt::Debug,
+    T: 'static,
+{
+    unimplemented!();
+}
+
 fn main() {}
@@ -0,0 +1,19 @@
+#![warn(clippy::unbuffered_bytes)]
+
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+
+fn lint(file: File, file_ref: &File) {
+    let _ = file.bytes();
+    let _ = file_ref.bytes();
+}
+
+fn no_lint(file: File, stdin: io::Stdin) -> io::Result<()> {
+    let _ = stdin.bytes();
+    let _ = BufReader::new(file).bytes();
+    let mut contents = String::new();
+    File::open("file")?.read_to_string(&mut contents)?;
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// run-rustfix
+
+#![warn(clippy::unchecked_duration_subtraction)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let start = Instant::now();
+    let second = Duration::from_secs(1);
+
+    let _ = start - second;
+    let _ = start - Duration::from_secs(5);
+    let mut earlier = start;
+    earlier -= second;
+
+    let end = Instant::now();
+    let _ = end - start;
+    let _ = end - start.checked_sub(second).unwrap();
+    let _ = Instant::now() - start;
+    let _ = Instant::now() - earlier;
+
+    // Don't lint
+    let _ = start + second;
+    let _ = second - Duration::from_millis(10);
+    let _ = start.checked_sub(second);
+    let _ = start.elapsed();
+}
@@ -0,0 +1,73 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+unsafe fn f() -> u8 {
+    0
+}
+
+fn line_comment() {
+    // SAFETY: `f` has no preconditions
+    unsafe { f() };
+}
+
+fn block_comment() {
+    /* SAFETY: `f` has no preconditions */
+    unsafe { f() };
+}
+
+fn multiline_block_comment() {
+    /*
+     * SAFETY: `f` has no preconditions
+     */
+    unsafe { f() };
+}
+
+fn same_line_comment() {
+    let _ = /* SAFETY: `f` has no preconditions */ unsafe { f() };
+}
+
+fn comment_above_statement() {
+    // SAFETY: `f` has no preconditions
+    let _ = unsafe { f() };
+}
+
+fn longer_comment() {
+    // SAFETY: `f` has no preconditions,
+    // and the comment can go on for several lines
+    unsafe { f() };
+}
+
+fn no_comment() {
+    unsafe { f() };
+}
+
+fn other_comment() {
+    // `f` has no preconditions
+    unsafe { f() };
+}
+
+fn blank_line_in_between() {
+    // SAFETY: `f` has no preconditions
+
+    unsafe { f() };
+}
+
+fn in_closure() {
+    let _ = Some(0).map(|_| unsafe { f() });
+}
+
+macro_rules! unsafe_call {
+    () => {
+        unsafe { f() }
+    };
+}
+
+fn in_macro() {
+    unsafe_call!();
+}
+
+struct S;
+
+// Only linted with `undocumented-unsafe-impls`
+unsafe impl Send for S {}
+
+fn main() {}
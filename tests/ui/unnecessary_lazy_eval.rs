@@ -70,6 +70,8 @@ fn main() {
     let _ = deep.0.or_else(some_call);
     let _ = deep.0.or_else(|| some_call());
     let _ = opt.ok_or_else(|| ext_arr[0]);
+    let _ = opt.unwrap_or_else(|| astronomers_pi + 1);
+    let _: Option<Vec<usize>> = None.or_else(|| Some(vec![]));
 
     // should not lint, bind_instead_of_map takes priority
     let _ = Some(10).and_then(|idx| Some(ext_arr[idx]));
@@ -0,0 +1,54 @@
+#![warn(clippy::unnecessary_safety_comment)]
+
+unsafe fn f() -> u8 {
+    0
+}
+
+fn documented_unsafe() -> u8 {
+    // SAFETY: `f` has no preconditions
+    let a = unsafe { f() };
+    // SAFETY: `f` has no preconditions
+    let b = Some(0).map(|_| unsafe { f() });
+    // SAFETY: `f` has no preconditions
+    a + b.unwrap() + unsafe { f() }
+}
+
+fn safe_code() -> u8 {
+    // SAFETY: the array isn't empty
+    let a = [1, 2, 3][0];
+    // The comment doesn't start with `SAFETY:`
+    let b = a + 1;
+    // SAFETY: there is nothing unsafe here
+    b
+}
+
+unsafe fn in_unsafe_fn() -> u8 {
+    // SAFETY: the caller upholds the preconditions of `f`
+    let a = f();
+    // SAFETY: the caller upholds the preconditions of `f`
+    a + f()
+}
+
+fn in_unsafe_block() -> u8 {
+    // SAFETY: `f` has no preconditions
+    unsafe {
+        // SAFETY: `f` has no preconditions
+        let a = f();
+        {
+            // SAFETY: `f` has no preconditions
+            a + f()
+        }
+    }
+}
+
+trait Marker {}
+
+struct S;
+
+// SAFETY: `S` has no fields
+impl Marker for S {}
+
+// SAFETY: `S` has no fields
+unsafe impl Send for S {}
+
+fn main() {}
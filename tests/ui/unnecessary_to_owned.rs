@@ -0,0 +1,53 @@
+// run-rustfix
+
+#![allow(clippy::ptr_arg, clippy::redundant_clone)]
+#![warn(clippy::unnecessary_to_owned)]
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+fn main() {
+    let s = "hello";
+    let string = String::from("world");
+    let slice = &[1, 2, 3][..];
+    let vec = vec![4, 5, 6];
+    let os_str = OsStr::new("os_str");
+    let path = Path::new("path");
+
+    require_str(&s.to_string());
+    require_str(&s.to_owned());
+    require_str(&string.to_string());
+    require_slice(&slice.to_vec());
+    require_slice(&vec.to_vec());
+    require_os_str(&os_str.to_os_string());
+    require_path(&path.to_path_buf());
+
+    require_as_ref_str(s.to_string());
+    require_as_ref_str(string.to_owned());
+    require_as_ref_path(path.to_path_buf());
+
+    // should not lint
+    require_string(&s.to_string());
+    require_vec(&slice.to_vec());
+    require_as_ref_slice(slice.to_vec());
+    require_as_ref_str_static(s.to_string());
+    require_into_iter(vec.to_vec());
+    require_display(1.to_string());
+    let _ = returns_arg(s.to_string());
+}
+
+fn require_str(_: &str) {}
+fn require_string(_: &String) {}
+fn require_slice<T>(_: &[T]) {}
+fn require_vec<T>(_: &Vec<T>) {}
+fn require_os_str(_: &OsStr) {}
+fn require_path(_: &Path) {}
+fn require_as_ref_str(_: impl AsRef<str>) {}
+fn require_as_ref_slice<T>(_: impl AsRef<[T]>) {}
+fn require_as_ref_path(_: impl AsRef<Path>) {}
+fn require_as_ref_str_static(_: impl AsRef<str> + 'static) {}
+fn require_into_iter<T>(_: impl IntoIterator<Item = T>) {}
+fn require_display(_: impl std::fmt::Display) {}
+fn returns_arg<S: AsRef<str>>(s: S) -> S {
+    s
+}
@@ -61,4 +61,10 @@ fn combine_or(file: &str) -> Result<(), Error> {
     Ok(())
 }
 
+fn let_underscore<T: io::Write>(s: &mut T) -> io::Result<()> {
+    // The amount is discarded explicitly
+    let _ = s.write(b"test")?;
+    Ok(())
+}
+
 fn main() {}